@@ -7,6 +7,7 @@ import { JSONSchema } from '@apidevtools/json-schema-ref-parser'
 import { JsonSchemaPrompter } from '../../helpers/JsonSchemaPrompt'
 import { flags } from '@oclif/command'
 import fs from 'fs'
+import path from 'path'
 import ExitCodes from '../../ExitCodes'
 import { ContentId } from '@joystream/types/media'
 import ipfsHash from 'ipfs-only-hash'
@@ -19,12 +20,14 @@ import last from 'it-last'
 import toBuffer from 'it-to-buffer'
 import ffprobeInstaller from '@ffprobe-installer/ffprobe'
 import ffmpeg from 'fluent-ffmpeg'
+import mime from 'mime-types'
 import MediaCommandBase from '../../base/MediaCommandBase'
 import { getInputJson, validateInput, IOFlags } from '../../helpers/InputOutput'
 
 ffmpeg.setFfprobePath(ffprobeInstaller.path)
 
 const DATA_OBJECT_TYPE_ID = 1
+const DEFAULT_MIME_TYPE = 'application/octet-stream'
 const MAX_FILE_SIZE = 2000 * 1024 * 1024
 
 type VideoMetadata = {
@@ -394,6 +397,8 @@ export default class UploadVideoCommand extends MediaCommandBase {
       DATA_OBJECT_TYPE_ID,
       fileSize,
       ipfsCid,
+      mime.lookup(filePath) || DEFAULT_MIME_TYPE,
+      path.extname(filePath).slice(1),
     ])
 
     const dataObject = await this.getApi().dataObjectByContentId(contentId)
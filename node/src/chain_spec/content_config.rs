@@ -2,7 +2,7 @@ use codec::Decode;
 use node_runtime::common::constraints::InputValidationLengthConstraint;
 use node_runtime::{
     content_wg::{Channel, ChannelId, Principal, PrincipalId},
-    data_directory::DataObject,
+    data_directory::{default_storage_quota_limits, DataObject},
     primitives::{AccountId, BlockNumber, Credential},
     versioned_store::{Class, ClassId, Entity, EntityId},
     versioned_store_permissions::ClassPermissions,
@@ -282,6 +282,7 @@ pub fn empty_data_directory_config() -> DataDirectoryConfig {
     DataDirectoryConfig {
         data_object_by_content_id: vec![],
         known_content_ids: vec![],
+        default_quota_limits: default_storage_quota_limits(),
     }
 }
 
@@ -302,6 +303,7 @@ pub fn data_directory_config_from_json(data_file: &Path) -> DataDirectoryConfig
             .into_iter()
             .map(|object| object.content_id)
             .collect(),
+        default_quota_limits: default_storage_quota_limits(),
     }
 }
 
@@ -32,10 +32,10 @@ use node_runtime::{
     membership, AuthorityDiscoveryConfig, BabeConfig, Balance, BalancesConfig,
     ContentDirectoryConfig, ContentDirectoryWorkingGroupConfig, ContentWorkingGroupConfig,
     CouncilConfig, CouncilElectionConfig, DataDirectoryConfig, DataObjectStorageRegistryConfig,
-    DataObjectTypeRegistryConfig, ElectionParameters, ForumConfig, GrandpaConfig, ImOnlineConfig,
-    MembersConfig, Moment, ProposalsCodexConfig, SessionConfig, SessionKeys, Signature,
-    StakerStatus, StakingConfig, StorageWorkingGroupConfig, SudoConfig, SystemConfig,
-    VersionedStoreConfig, VersionedStorePermissionsConfig, DAYS, WASM_BINARY,
+    DataObjectTypeRegistryConfig, ElectionParameters, ForumConfig, ForumWorkingGroupConfig,
+    GrandpaConfig, ImOnlineConfig, MembersConfig, Moment, ProposalsCodexConfig, SessionConfig,
+    SessionKeys, Signature, StakerStatus, StakingConfig, StorageWorkingGroupConfig, SudoConfig,
+    SystemConfig, VersionedStoreConfig, VersionedStorePermissionsConfig, DAYS, WASM_BINARY,
 };
 
 // Exported to be used by chain-spec-builder
@@ -314,6 +314,13 @@ pub fn testnet_genesis(
         data_object_storage_registry: Some(DataObjectStorageRegistryConfig {
             first_relationship_id: 1,
         }),
+        working_group_Instance1: Some(ForumWorkingGroupConfig {
+            phantom: Default::default(),
+            working_group_mint_capacity: 0,
+            opening_human_readable_text_constraint: default_text_constraint,
+            worker_application_human_readable_text_constraint: default_text_constraint,
+            worker_exit_rationale_text_constraint: default_text_constraint,
+        }),
         working_group_Instance2: Some(StorageWorkingGroupConfig {
             phantom: Default::default(),
             working_group_mint_capacity: 0,
@@ -388,6 +395,25 @@ pub fn testnet_genesis(
                 .terminate_working_group_leader_role_proposal_voting_period,
             terminate_working_group_leader_role_proposal_grace_period: cpcp
                 .terminate_working_group_leader_role_proposal_grace_period,
+            ranked_choice_proposal_voting_period: cpcp.ranked_choice_proposal_voting_period,
+            ranked_choice_proposal_grace_period: cpcp.ranked_choice_proposal_grace_period,
+            batch_proposal_voting_period: cpcp.batch_proposal_voting_period,
+            batch_proposal_grace_period: cpcp.batch_proposal_grace_period,
+            amend_constitution_proposal_voting_period: cpcp
+                .amend_constitution_proposal_voting_period,
+            amend_constitution_proposal_grace_period: cpcp.amend_constitution_proposal_grace_period,
+            set_proposal_parameters_proposal_voting_period: cpcp
+                .set_proposal_parameters_proposal_voting_period,
+            set_proposal_parameters_proposal_grace_period: cpcp
+                .set_proposal_parameters_proposal_grace_period,
+            set_default_storage_quota_limits_proposal_voting_period: cpcp
+                .set_default_storage_quota_limits_proposal_voting_period,
+            set_default_storage_quota_limits_proposal_grace_period: cpcp
+                .set_default_storage_quota_limits_proposal_grace_period,
+            set_working_group_spending_limit_proposal_voting_period: cpcp
+                .set_working_group_spending_limit_proposal_voting_period,
+            set_working_group_spending_limit_proposal_grace_period: cpcp
+                .set_working_group_spending_limit_proposal_grace_period,
         }),
     }
 }
@@ -32,8 +32,12 @@
 
 use std::sync::Arc;
 
-use node_runtime::UncheckedExtrinsic;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result as RpcResult};
+use jsonrpc_derive::rpc;
 use node_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Hash, Index};
+use node_runtime::{
+    ContentId, DataDirectoryApi, ProposalId, ProposalInfo, ProposalsApi, UncheckedExtrinsic,
+};
 use sc_consensus_babe::{Config, Epoch};
 use sc_consensus_babe_rpc::BabeRpcHandler;
 use sc_consensus_epochs::SharedEpochChanges;
@@ -46,6 +50,7 @@ use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
 use sp_consensus::SelectChain;
 use sp_consensus_babe::BabeApi;
+use sp_runtime::generic::BlockId;
 use sp_transaction_pool::TransactionPool;
 
 /// Light client extra dependencies.
@@ -108,6 +113,8 @@ where
     >,
     C::Api: BabeApi<Block>,
     C::Api: BlockBuilder<Block>,
+    C::Api: ProposalsApi<Block>,
+    C::Api: DataDirectoryApi<Block>,
     P: TransactionPool + 'static,
     M: jsonrpc_core::Metadata + Default,
     SC: SelectChain<Block> + 'static,
@@ -145,6 +152,12 @@ where
     io.extend_with(TransactionPaymentApi::to_delegate(TransactionPayment::new(
         client.clone(),
     )));
+    io.extend_with(ProposalsRpcApi::to_delegate(ProposalsRpc::new(
+        client.clone(),
+    )));
+    io.extend_with(DataDirectoryRpcApi::to_delegate(DataDirectoryRpc::new(
+        client.clone(),
+    )));
     io.extend_with(sc_consensus_babe_rpc::BabeApi::to_delegate(
         BabeRpcHandler::new(
             client,
@@ -186,3 +199,149 @@ where
 
     io
 }
+
+/// Proposals RPC methods.
+#[rpc]
+pub trait ProposalsRpcApi<BlockHash> {
+    /// Returns proposals open for voting.
+    #[rpc(name = "proposals_activeProposals")]
+    fn active_proposals(&self, at: Option<BlockHash>) -> RpcResult<Vec<ProposalInfo>>;
+
+    /// Returns approved proposals with the grace period in effect.
+    #[rpc(name = "proposals_pendingExecutionProposals")]
+    fn pending_execution_proposals(&self, at: Option<BlockHash>) -> RpcResult<Vec<ProposalInfo>>;
+
+    /// Returns the proposal by its id, if exists.
+    #[rpc(name = "proposals_proposal")]
+    fn proposal(
+        &self,
+        proposal_id: ProposalId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<ProposalInfo>>;
+}
+
+/// Proposals RPC methods implementation based on the `ProposalsApi` runtime API.
+pub struct ProposalsRpc<C> {
+    client: Arc<C>,
+}
+
+impl<C> ProposalsRpc<C> {
+    /// Creates a new instance of the proposals RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        ProposalsRpc { client }
+    }
+}
+
+impl<C> ProposalsRpc<C>
+where
+    C: HeaderBackend<Block>,
+{
+    // Returns the requested block id or the best block id.
+    fn block_id(&self, at: Option<Hash>) -> BlockId<Block> {
+        BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash))
+    }
+}
+
+impl<C> ProposalsRpcApi<Hash> for ProposalsRpc<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: ProposalsApi<Block>,
+{
+    fn active_proposals(&self, at: Option<Hash>) -> RpcResult<Vec<ProposalInfo>> {
+        self.client
+            .runtime_api()
+            .active_proposals(&self.block_id(at))
+            .map_err(runtime_error)
+    }
+
+    fn pending_execution_proposals(&self, at: Option<Hash>) -> RpcResult<Vec<ProposalInfo>> {
+        self.client
+            .runtime_api()
+            .pending_execution_proposals(&self.block_id(at))
+            .map_err(runtime_error)
+    }
+
+    fn proposal(
+        &self,
+        proposal_id: ProposalId,
+        at: Option<Hash>,
+    ) -> RpcResult<Option<ProposalInfo>> {
+        self.client
+            .runtime_api()
+            .proposal(&self.block_id(at), proposal_id)
+            .map_err(runtime_error)
+    }
+}
+
+/// Data directory RPC methods.
+#[rpc]
+pub trait DataDirectoryRpcApi<BlockHash> {
+    /// Returns the number of the content ids known to the system.
+    #[rpc(name = "dataDirectory_knownContentIdsCount")]
+    fn known_content_ids_count(&self, at: Option<BlockHash>) -> RpcResult<u64>;
+
+    /// Returns a page of the content ids known to the system, starting from the provided position.
+    #[rpc(name = "dataDirectory_knownContentIds")]
+    fn known_content_ids(
+        &self,
+        start: u64,
+        limit: u64,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<ContentId>>;
+}
+
+/// Data directory RPC methods implementation based on the `DataDirectoryApi` runtime API.
+pub struct DataDirectoryRpc<C> {
+    client: Arc<C>,
+}
+
+impl<C> DataDirectoryRpc<C> {
+    /// Creates a new instance of the data directory RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        DataDirectoryRpc { client }
+    }
+}
+
+impl<C> DataDirectoryRpc<C>
+where
+    C: HeaderBackend<Block>,
+{
+    // Returns the requested block id or the best block id.
+    fn block_id(&self, at: Option<Hash>) -> BlockId<Block> {
+        BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash))
+    }
+}
+
+impl<C> DataDirectoryRpcApi<Hash> for DataDirectoryRpc<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: DataDirectoryApi<Block>,
+{
+    fn known_content_ids_count(&self, at: Option<Hash>) -> RpcResult<u64> {
+        self.client
+            .runtime_api()
+            .known_content_ids_count(&self.block_id(at))
+            .map_err(runtime_error)
+    }
+
+    fn known_content_ids(
+        &self,
+        start: u64,
+        limit: u64,
+        at: Option<Hash>,
+    ) -> RpcResult<Vec<ContentId>> {
+        self.client
+            .runtime_api()
+            .known_content_ids(&self.block_id(at), start, limit)
+            .map_err(runtime_error)
+    }
+}
+
+// Converts the runtime API call error to the RPC error.
+fn runtime_error<E: std::fmt::Debug>(err: E) -> RpcError {
+    RpcError {
+        code: ErrorCode::ServerError(1),
+        message: "Unable to query the runtime API.".into(),
+        data: Some(format!("{:?}", err).into()),
+    }
+}
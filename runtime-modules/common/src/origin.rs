@@ -4,6 +4,11 @@ use system::RawOrigin;
 pub trait ActorOriginValidator<Origin, ActorId, AccountId> {
     /// Check for valid combination of origin and actor_id.
     fn ensure_actor_origin(origin: Origin, actor_id: ActorId) -> Result<AccountId, &'static str>;
+
+    /// Creates an actor passing the validation with the signed origin of the account.
+    /// Used by the benchmarks only.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn create_benchmark_actor(account_id: &AccountId) -> ActorId;
 }
 
 // TODO: delete when T::Origin will support the clone()
@@ -0,0 +1,552 @@
+//! Content directory module benchmarking.
+
+use super::*;
+use frame_benchmarking::benchmarks;
+use sp_arithmetic::traits::SaturatedConversion;
+use system::RawOrigin;
+
+fn text(len: u32) -> Vec<u8> {
+    vec![b'a'; len as usize]
+}
+
+fn create_curator_group<T: Trait>(lead: &T::AccountId, curators: u32) -> T::CuratorGroupId {
+    let curator_group_id = Module::<T>::next_curator_group_id();
+
+    Module::<T>::add_curator_group(RawOrigin::Signed(lead.clone()).into())
+        .expect("curator group added");
+
+    for curator_id in 0..curators {
+        Module::<T>::add_curator_to_group(
+            RawOrigin::Signed(lead.clone()).into(),
+            curator_group_id,
+            curator_id.saturated_into(),
+        )
+        .expect("curator added");
+    }
+
+    curator_group_id
+}
+
+fn create_class<T: Trait>(lead: &T::AccountId) -> T::ClassId {
+    let class_id = Module::<T>::next_class_id();
+
+    Module::<T>::create_class(
+        RawOrigin::Signed(lead.clone()).into(),
+        text(1),
+        text(1),
+        ClassPermissions::default(),
+        T::MaxNumberOfEntitiesPerClass::get(),
+        T::IndividualEntitiesCreationLimit::get(),
+    )
+    .expect("class created");
+
+    class_id
+}
+
+fn property<T: Trait>(index: u32, property_type: PropertyType<T::ClassId>) -> Property<T::ClassId> {
+    Property {
+        property_type,
+        required: false,
+        unique: false,
+        name: index.encode(),
+        description: text(1),
+        locking_policy: PropertyLockingPolicy::default(),
+    }
+}
+
+// Unique text properties of the maximum length: the values are hashed.
+fn text_properties<T: Trait>(count: u32) -> Vec<Property<T::ClassId>> {
+    let text_type = PropertyType::Single(Type::Text(T::TextMaxLengthConstraint::get()));
+
+    (0..count)
+        .map(|index| Property {
+            unique: true,
+            ..property::<T>(index, text_type)
+        })
+        .collect()
+}
+
+fn text_values<T: Trait>(count: u32, byte: u8) -> BTreeMap<PropertyId, InputPropertyValue<T>> {
+    let value = vec![byte; T::TextMaxLengthConstraint::get() as usize];
+
+    (0..count)
+        .map(|index| {
+            let property_id = index as PropertyId;
+
+            (
+                property_id,
+                InputPropertyValue::Single(InputValue::Text(value.clone())),
+            )
+        })
+        .collect()
+}
+
+fn add_class_schema<T: Trait>(
+    lead: &T::AccountId,
+    class_id: T::ClassId,
+    properties: Vec<Property<T::ClassId>>,
+) -> SchemaId {
+    let schema_id = Module::<T>::class_by_id(class_id).get_schemas().len() as SchemaId;
+
+    Module::<T>::add_class_schema(
+        RawOrigin::Signed(lead.clone()).into(),
+        class_id,
+        BTreeSet::new(),
+        properties,
+    )
+    .expect("class schema added");
+
+    schema_id
+}
+
+fn create_entity<T: Trait>(lead: &T::AccountId, class_id: T::ClassId) -> T::EntityId {
+    let entity_id = Module::<T>::next_entity_id();
+
+    Module::<T>::create_entity(
+        RawOrigin::Signed(lead.clone()).into(),
+        class_id,
+        Actor::Lead,
+    )
+    .expect("entity created");
+
+    entity_id
+}
+
+fn add_schema_support_to_entity<T: Trait>(
+    lead: &T::AccountId,
+    entity_id: T::EntityId,
+    schema_id: SchemaId,
+    property_values: BTreeMap<PropertyId, InputPropertyValue<T>>,
+) {
+    Module::<T>::add_schema_support_to_entity(
+        RawOrigin::Signed(lead.clone()).into(),
+        Actor::Lead,
+        entity_id,
+        schema_id,
+        property_values,
+    )
+    .expect("schema support added");
+}
+
+// Creates the entity supporting the schema with the maximum number of the unique text properties.
+fn create_entity_with_text_values<T: Trait>(lead: &T::AccountId, properties: u32) -> T::EntityId {
+    let class_id = create_class::<T>(lead);
+    let schema_id = add_class_schema::<T>(lead, class_id, text_properties::<T>(properties));
+    let entity_id = create_entity::<T>(lead, class_id);
+    add_schema_support_to_entity::<T>(
+        lead,
+        entity_id,
+        schema_id,
+        text_values::<T>(properties, b'a'),
+    );
+
+    entity_id
+}
+
+// Creates the entity with the vector property, which is one value short of the maximum length.
+fn create_entity_with_vector_value<T: Trait>(lead: &T::AccountId) -> T::EntityId {
+    let max_length = T::VecMaxLengthConstraint::get();
+    let vector_type = PropertyType::Vector(VecPropertyType::new(Type::Uint64, max_length));
+
+    let class_id = create_class::<T>(lead);
+    let schema_id = add_class_schema::<T>(lead, class_id, vec![property::<T>(0, vector_type)]);
+    let entity_id = create_entity::<T>(lead, class_id);
+
+    let mut property_values = BTreeMap::new();
+    property_values.insert(
+        0,
+        InputPropertyValue::Vector(VecInputValue::Uint64(vec![0; max_length as usize - 1])),
+    );
+    add_schema_support_to_entity::<T>(lead, entity_id, schema_id, property_values);
+
+    entity_id
+}
+
+fn vector_value_length<T: Trait>(entity_id: T::EntityId) -> usize {
+    let entity = Module::<T>::entity_by_id(entity_id);
+
+    match entity
+        .get_values_ref()
+        .get(&0)
+        .and_then(|value| value.as_vec_property_value())
+        .map(|value| value.get_vec_value_ref())
+    {
+        Some(VecStoredValue::Uint64(vector)) => vector.len(),
+        _ => 0,
+    }
+}
+
+benchmarks! {
+    _ { }
+
+    add_curator_group {
+        let lead = T::create_benchmark_lead();
+        let curator_group_id = Module::<T>::next_curator_group_id();
+    }: _(RawOrigin::Signed(lead))
+    verify {
+        assert!(<CuratorGroupById<T>>::contains_key(curator_group_id));
+    }
+
+    remove_curator_group {
+        let lead = T::create_benchmark_lead();
+        let curator_group_id = create_curator_group::<T>(&lead, 0);
+    }: _(RawOrigin::Signed(lead), curator_group_id)
+    verify {
+        assert!(!<CuratorGroupById<T>>::contains_key(curator_group_id));
+    }
+
+    set_curator_group_status {
+        let lead = T::create_benchmark_lead();
+        let curator_group_id = create_curator_group::<T>(&lead, 0);
+    }: _(RawOrigin::Signed(lead), curator_group_id, true)
+    verify {
+        assert!(Module::<T>::curator_group_by_id(curator_group_id).is_active());
+    }
+
+    add_curator_to_group {
+        let lead = T::create_benchmark_lead();
+        let curators = T::MaxNumberOfCuratorsPerGroup::get() - 1;
+        let curator_group_id = create_curator_group::<T>(&lead, curators);
+        let curator_id: T::CuratorId = curators.saturated_into();
+    }: _(RawOrigin::Signed(lead), curator_group_id, curator_id)
+    verify {
+        assert!(Module::<T>::curator_group_by_id(curator_group_id).is_curator(&curator_id));
+    }
+
+    remove_curator_from_group {
+        let lead = T::create_benchmark_lead();
+        let curators = T::MaxNumberOfCuratorsPerGroup::get();
+        let curator_group_id = create_curator_group::<T>(&lead, curators);
+        let curator_id: T::CuratorId = 0u32.saturated_into();
+    }: _(RawOrigin::Signed(lead), curator_group_id, curator_id)
+    verify {
+        assert!(!Module::<T>::curator_group_by_id(curator_group_id).is_curator(&curator_id));
+    }
+
+    update_entity_creation_voucher {
+        let lead = T::create_benchmark_lead();
+        let class_id = create_class::<T>(&lead);
+        create_entity::<T>(&lead, class_id);
+        let maximum_entities_count = T::IndividualEntitiesCreationLimit::get() - One::one();
+    }: _(RawOrigin::Signed(lead), class_id, EntityController::Lead, maximum_entities_count)
+    verify {
+        let voucher = Module::<T>::entity_creation_vouchers(class_id, EntityController::Lead);
+
+        assert!(voucher.maximum_entities_count == maximum_entities_count);
+    }
+
+    create_class {
+        let name_constraint = T::ClassNameLengthConstraint::get();
+        let description_constraint = T::ClassDescriptionLengthConstraint::get();
+        let i in (name_constraint.min() + description_constraint.min()) as u32 ..
+            (name_constraint.max() + description_constraint.max()) as u32;
+
+        let lead = T::create_benchmark_lead();
+        let name_length = (i - description_constraint.min() as u32).min(name_constraint.max() as u32);
+        let class_id = Module::<T>::next_class_id();
+    }: _(
+        RawOrigin::Signed(lead),
+        text(name_length),
+        text(i - name_length),
+        ClassPermissions::default(),
+        T::MaxNumberOfEntitiesPerClass::get(),
+        T::IndividualEntitiesCreationLimit::get()
+    )
+    verify {
+        assert!(<ClassById<T>>::contains_key(class_id));
+    }
+
+    add_maintainer_to_class {
+        let lead = T::create_benchmark_lead();
+        let class_id = create_class::<T>(&lead);
+        let maintainers = (1..T::MaxNumberOfMaintainersPerClass::get())
+            .map(|_| create_curator_group::<T>(&lead, 0))
+            .collect::<BTreeSet<_>>();
+        Module::<T>::update_class_permissions(
+            RawOrigin::Signed(lead.clone()).into(),
+            class_id,
+            None,
+            None,
+            None,
+            Some(maintainers),
+        )
+        .expect("maintainers added");
+        let curator_group_id = create_curator_group::<T>(&lead, 0);
+    }: _(RawOrigin::Signed(lead), class_id, curator_group_id)
+    verify {
+        let class = Module::<T>::class_by_id(class_id);
+
+        assert!(class.get_permissions_ref().is_maintainer(&curator_group_id));
+    }
+
+    remove_maintainer_from_class {
+        let lead = T::create_benchmark_lead();
+        let class_id = create_class::<T>(&lead);
+        let curator_group_id = create_curator_group::<T>(&lead, 0);
+        Module::<T>::add_maintainer_to_class(
+            RawOrigin::Signed(lead.clone()).into(),
+            class_id,
+            curator_group_id,
+        )
+        .expect("maintainer added");
+    }: _(RawOrigin::Signed(lead), class_id, curator_group_id)
+    verify {
+        let class = Module::<T>::class_by_id(class_id);
+
+        assert!(!class.get_permissions_ref().is_maintainer(&curator_group_id));
+    }
+
+    update_class_permissions {
+        let lead = T::create_benchmark_lead();
+        let class_id = create_class::<T>(&lead);
+        let maintainers = (0..T::MaxNumberOfMaintainersPerClass::get())
+            .map(|_| create_curator_group::<T>(&lead, 0))
+            .collect::<BTreeSet<_>>();
+    }: _(
+        RawOrigin::Signed(lead),
+        class_id,
+        Some(true),
+        Some(true),
+        Some(true),
+        Some(maintainers.clone())
+    )
+    verify {
+        let class = Module::<T>::class_by_id(class_id);
+
+        assert!(*class.get_permissions_ref().get_maintainers() == maintainers);
+    }
+
+    add_class_schema {
+        let p in 1 .. T::MaxNumberOfPropertiesPerSchema::get();
+
+        let lead = T::create_benchmark_lead();
+        let class_id = create_class::<T>(&lead);
+    }: _(RawOrigin::Signed(lead), class_id, BTreeSet::new(), text_properties::<T>(p))
+    verify {
+        assert_eq!(Module::<T>::class_by_id(class_id).get_schemas().len(), 1);
+    }
+
+    update_class_schema_status {
+        let lead = T::create_benchmark_lead();
+        let class_id = create_class::<T>(&lead);
+        let schema_id = add_class_schema::<T>(&lead, class_id, text_properties::<T>(1));
+    }: _(RawOrigin::Signed(lead), class_id, schema_id, false)
+    verify {
+        let class = Module::<T>::class_by_id(class_id);
+
+        assert!(!class.get_schemas()[schema_id as usize].is_active());
+    }
+
+    update_entity_permissions {
+        let lead = T::create_benchmark_lead();
+        let class_id = create_class::<T>(&lead);
+        let entity_id = create_entity::<T>(&lead, class_id);
+    }: _(RawOrigin::Signed(lead), entity_id, Some(true), Some(false))
+    verify {
+        assert!(Module::<T>::entity_by_id(entity_id).get_permissions_ref().frozen);
+    }
+
+    transfer_entity_ownership {
+        let p in 1 .. T::MaxNumberOfPropertiesPerSchema::get();
+
+        let lead = T::create_benchmark_lead();
+        let new_controller = EntityController::Member(T::MemberId::default());
+
+        // Entities of the new controller to be referenced by the transferred entity.
+        let referenced_class_id = create_class::<T>(&lead);
+        let referenced_entity_ids = (0..p)
+            .map(|_| {
+                let entity_id = create_entity::<T>(&lead, referenced_class_id);
+                Module::<T>::transfer_entity_ownership(
+                    RawOrigin::Signed(lead.clone()).into(),
+                    entity_id,
+                    new_controller.clone(),
+                    BTreeMap::new(),
+                )
+                .expect("entity ownership transferred");
+
+                entity_id
+            })
+            .collect::<Vec<_>>();
+
+        // Entity with the references having the same owner flag set.
+        let reference_type = PropertyType::Single(Type::Reference(referenced_class_id, true));
+        let properties = (0..p)
+            .map(|index| property::<T>(index, reference_type))
+            .collect();
+        let class_id = create_class::<T>(&lead);
+        let schema_id = add_class_schema::<T>(&lead, class_id, properties);
+        let entity_id = create_entity::<T>(&lead, class_id);
+        add_schema_support_to_entity::<T>(&lead, entity_id, schema_id, BTreeMap::new());
+
+        let references = referenced_entity_ids
+            .into_iter()
+            .enumerate()
+            .map(|(index, entity_id)| {
+                (
+                    index as PropertyId,
+                    InputPropertyValue::Single(InputValue::Reference(entity_id)),
+                )
+            })
+            .collect::<BTreeMap<_, _>>();
+    }: _(RawOrigin::Signed(lead), entity_id, new_controller.clone(), references)
+    verify {
+        let entity = Module::<T>::entity_by_id(entity_id);
+
+        assert!(entity.get_permissions_ref().controller_is_equal_to(&new_controller));
+    }
+
+    create_entity {
+        let lead = T::create_benchmark_lead();
+        let class_id = create_class::<T>(&lead);
+        let entity_id = Module::<T>::next_entity_id();
+    }: _(RawOrigin::Signed(lead), class_id, Actor::Lead)
+    verify {
+        assert!(<EntityById<T>>::contains_key(entity_id));
+    }
+
+    remove_entity {
+        let lead = T::create_benchmark_lead();
+        let properties = T::MaxNumberOfPropertiesPerSchema::get();
+        let entity_id = create_entity_with_text_values::<T>(&lead, properties);
+    }: _(RawOrigin::Signed(lead), Actor::Lead, entity_id)
+    verify {
+        assert!(!<EntityById<T>>::contains_key(entity_id));
+    }
+
+    add_schema_support_to_entity {
+        let p in 1 .. T::MaxNumberOfPropertiesPerSchema::get();
+
+        let lead = T::create_benchmark_lead();
+        let class_id = create_class::<T>(&lead);
+        let schema_id = add_class_schema::<T>(&lead, class_id, text_properties::<T>(p));
+        let entity_id = create_entity::<T>(&lead, class_id);
+    }: _(RawOrigin::Signed(lead), Actor::Lead, entity_id, schema_id, text_values::<T>(p, b'a'))
+    verify {
+        assert_eq!(Module::<T>::entity_by_id(entity_id).get_values_ref().len(), p as usize);
+    }
+
+    update_entity_property_values {
+        let p in 1 .. T::MaxNumberOfPropertiesPerSchema::get();
+
+        let lead = T::create_benchmark_lead();
+        let entity_id = create_entity_with_text_values::<T>(&lead, p);
+        let new_property_values = text_values::<T>(p, b'b');
+    }: _(RawOrigin::Signed(lead), Actor::Lead, entity_id, new_property_values.clone())
+    verify {
+        let output_property_values = Module::<T>::make_output_property_values(new_property_values);
+
+        assert!(Module::<T>::entity_by_id(entity_id).get_values() == output_property_values);
+    }
+
+    clear_entity_property_vector {
+        let lead = T::create_benchmark_lead();
+        let entity_id = create_entity_with_vector_value::<T>(&lead);
+    }: _(RawOrigin::Signed(lead), Actor::Lead, entity_id, 0)
+    verify {
+        assert_eq!(vector_value_length::<T>(entity_id), 0);
+    }
+
+    remove_at_entity_property_vector {
+        let lead = T::create_benchmark_lead();
+        let entity_id = create_entity_with_vector_value::<T>(&lead);
+        let length = vector_value_length::<T>(entity_id);
+    }: _(RawOrigin::Signed(lead), Actor::Lead, entity_id, 0, 0, T::Nonce::zero())
+    verify {
+        assert_eq!(vector_value_length::<T>(entity_id), length - 1);
+    }
+
+    insert_at_entity_property_vector {
+        let lead = T::create_benchmark_lead();
+        let entity_id = create_entity_with_vector_value::<T>(&lead);
+        let length = vector_value_length::<T>(entity_id);
+    }: _(
+        RawOrigin::Signed(lead),
+        Actor::Lead,
+        entity_id,
+        0,
+        0,
+        InputValue::Uint64(0),
+        T::Nonce::zero()
+    )
+    verify {
+        assert_eq!(vector_value_length::<T>(entity_id), length + 1);
+    }
+
+    transaction {
+        let o in 1 .. T::MaxNumberOfOperationsDuringAtomicBatching::get();
+
+        let lead = T::create_benchmark_lead();
+        let class_id = create_class::<T>(&lead);
+        let uint_type = PropertyType::Single(Type::Uint64);
+        let schema_id = add_class_schema::<T>(&lead, class_id, vec![property::<T>(0, uint_type)]);
+        let entity_id = Module::<T>::next_entity_id();
+
+        // Creates the entity, adds the schema support to it and updates its property value
+        // with the rest of the operations.
+        let property_value = |value: u64| ParametrizedClassPropertyValue {
+            in_class_index: 0,
+            value: ParametrizedPropertyValue::InputPropertyValue(InputPropertyValue::Single(
+                InputValue::Uint64(value),
+            )),
+        };
+        let operations = (0..o)
+            .map(|index| match index {
+                0 => OperationType::CreateEntity(CreateEntityOperation { class_id }),
+                1 => OperationType::AddSchemaSupportToEntity(AddSchemaSupportToEntityOperation {
+                    entity_id: ParameterizedEntity::InternalEntityJustAdded(0),
+                    schema_id,
+                    parametrized_property_values: vec![property_value(0)],
+                }),
+                _ => OperationType::UpdatePropertyValues(UpdatePropertyValuesOperation {
+                    entity_id: ParameterizedEntity::InternalEntityJustAdded(0),
+                    new_parametrized_property_values: vec![property_value(index as u64)],
+                }),
+            })
+            .collect::<Vec<_>>();
+    }: _(RawOrigin::Signed(lead), Actor::Lead, operations)
+    verify {
+        assert!(<EntityById<T>>::contains_key(entity_id));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{with_test_externalities, Runtime};
+    use frame_support::assert_ok;
+
+    // Every benchmark runs in the fresh storage: the setups create the classes, which are
+    // limited by the mock.
+    fn assert_benchmark_runs(benchmark: fn() -> Result<(), &'static str>) {
+        with_test_externalities(|| {
+            assert_ok!(benchmark());
+        });
+    }
+
+    #[test]
+    fn content_directory_benchmarks_run_successfully() {
+        assert_benchmark_runs(test_benchmark_add_curator_group::<Runtime>);
+        assert_benchmark_runs(test_benchmark_remove_curator_group::<Runtime>);
+        assert_benchmark_runs(test_benchmark_set_curator_group_status::<Runtime>);
+        assert_benchmark_runs(test_benchmark_add_curator_to_group::<Runtime>);
+        assert_benchmark_runs(test_benchmark_remove_curator_from_group::<Runtime>);
+        assert_benchmark_runs(test_benchmark_update_entity_creation_voucher::<Runtime>);
+        assert_benchmark_runs(test_benchmark_create_class::<Runtime>);
+        assert_benchmark_runs(test_benchmark_add_maintainer_to_class::<Runtime>);
+        assert_benchmark_runs(test_benchmark_remove_maintainer_from_class::<Runtime>);
+        assert_benchmark_runs(test_benchmark_update_class_permissions::<Runtime>);
+        assert_benchmark_runs(test_benchmark_add_class_schema::<Runtime>);
+        assert_benchmark_runs(test_benchmark_update_class_schema_status::<Runtime>);
+        assert_benchmark_runs(test_benchmark_update_entity_permissions::<Runtime>);
+        assert_benchmark_runs(test_benchmark_transfer_entity_ownership::<Runtime>);
+        assert_benchmark_runs(test_benchmark_create_entity::<Runtime>);
+        assert_benchmark_runs(test_benchmark_remove_entity::<Runtime>);
+        assert_benchmark_runs(test_benchmark_add_schema_support_to_entity::<Runtime>);
+        assert_benchmark_runs(test_benchmark_update_entity_property_values::<Runtime>);
+        assert_benchmark_runs(test_benchmark_clear_entity_property_vector::<Runtime>);
+        assert_benchmark_runs(test_benchmark_remove_at_entity_property_vector::<Runtime>);
+        assert_benchmark_runs(test_benchmark_insert_at_entity_property_vector::<Runtime>);
+        assert_benchmark_runs(test_benchmark_transaction::<Runtime>);
+    }
+}
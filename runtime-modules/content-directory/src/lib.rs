@@ -118,6 +118,9 @@
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 mod class;
 mod entity;
 mod errors;
@@ -146,6 +149,7 @@ use frame_support::{
     dispatch::{DispatchError, DispatchResult},
     ensure,
     traits::Get,
+    weights::Weight,
     Parameter,
 };
 #[cfg(feature = "std")]
@@ -182,6 +186,105 @@ pub type ClassOf<T> =
 pub type StoredPropertyValueOf<T> =
     StoredPropertyValue<<T as system::Trait>::Hash, <T as Trait>::EntityId, <T as Trait>::Nonce>;
 
+/// Extrinsic weights for the content directory module.
+/// `i` - total length of the class name and description in bytes,
+/// `p` - number of the provided properties or property values,
+/// `o` - number of the operations in the atomic batch.
+pub trait WeightInfo {
+    fn add_curator_group() -> Weight;
+    fn remove_curator_group() -> Weight;
+    fn set_curator_group_status() -> Weight;
+    fn add_curator_to_group() -> Weight;
+    fn remove_curator_from_group() -> Weight;
+    fn update_entity_creation_voucher() -> Weight;
+    fn create_class(i: u32) -> Weight;
+    fn add_maintainer_to_class() -> Weight;
+    fn remove_maintainer_from_class() -> Weight;
+    fn update_class_permissions() -> Weight;
+    fn add_class_schema(p: u32) -> Weight;
+    fn update_class_schema_status() -> Weight;
+    fn update_entity_permissions() -> Weight;
+    fn transfer_entity_ownership(p: u32) -> Weight;
+    fn create_entity() -> Weight;
+    fn remove_entity() -> Weight;
+    fn add_schema_support_to_entity(p: u32) -> Weight;
+    fn update_entity_property_values(p: u32) -> Weight;
+    fn clear_entity_property_vector() -> Weight;
+    fn remove_at_entity_property_vector() -> Weight;
+    fn insert_at_entity_property_vector() -> Weight;
+    fn transaction(o: u32) -> Weight;
+}
+
+// Default weights, used in the tests and by the runtimes without benchmarked weights.
+impl WeightInfo for () {
+    fn add_curator_group() -> Weight {
+        10_000_000
+    }
+    fn remove_curator_group() -> Weight {
+        10_000_000
+    }
+    fn set_curator_group_status() -> Weight {
+        10_000_000
+    }
+    fn add_curator_to_group() -> Weight {
+        10_000_000
+    }
+    fn remove_curator_from_group() -> Weight {
+        10_000_000
+    }
+    fn update_entity_creation_voucher() -> Weight {
+        10_000_000
+    }
+    fn create_class(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn add_maintainer_to_class() -> Weight {
+        10_000_000
+    }
+    fn remove_maintainer_from_class() -> Weight {
+        10_000_000
+    }
+    fn update_class_permissions() -> Weight {
+        10_000_000
+    }
+    fn add_class_schema(_p: u32) -> Weight {
+        10_000_000
+    }
+    fn update_class_schema_status() -> Weight {
+        10_000_000
+    }
+    fn update_entity_permissions() -> Weight {
+        10_000_000
+    }
+    fn transfer_entity_ownership(_p: u32) -> Weight {
+        10_000_000
+    }
+    fn create_entity() -> Weight {
+        10_000_000
+    }
+    fn remove_entity() -> Weight {
+        10_000_000
+    }
+    fn add_schema_support_to_entity(_p: u32) -> Weight {
+        10_000_000
+    }
+    fn update_entity_property_values(_p: u32) -> Weight {
+        10_000_000
+    }
+    fn clear_entity_property_vector() -> Weight {
+        10_000_000
+    }
+    fn remove_at_entity_property_vector() -> Weight {
+        10_000_000
+    }
+    fn insert_at_entity_property_vector() -> Weight {
+        10_000_000
+    }
+    fn transaction(_o: u32) -> Weight {
+        10_000_000
+    }
+}
+
 /// Module configuration trait for this Substrate module.
 pub trait Trait: system::Trait + ActorAuthenticator + Clone {
     /// The overarching event type.
@@ -275,6 +378,9 @@ pub trait Trait: system::Trait + ActorAuthenticator + Clone {
 
     /// Entities creation constraint per individual
     type IndividualEntitiesCreationLimit: Get<Self::EntityId>;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
 }
 
 decl_storage! {
@@ -321,7 +427,7 @@ decl_module! {
         // ======
 
         /// Add new curator group to runtime storage
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::add_curator_group()]
         pub fn add_curator_group(
             origin,
         ) -> DispatchResult {
@@ -347,7 +453,7 @@ decl_module! {
         }
 
         /// Remove curator group under given `curator_group_id` from runtime storage
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::remove_curator_group()]
         pub fn remove_curator_group(
             origin,
             curator_group_id: T::CuratorGroupId,
@@ -376,7 +482,7 @@ decl_module! {
         }
 
         /// Set `is_active` status for curator group under given `curator_group_id`
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::set_curator_group_status()]
         pub fn set_curator_group_status(
             origin,
             curator_group_id: T::CuratorGroupId,
@@ -404,7 +510,7 @@ decl_module! {
         }
 
         /// Add curator to curator group under given `curator_group_id`
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::add_curator_to_group()]
         pub fn add_curator_to_group(
             origin,
             curator_group_id: T::CuratorGroupId,
@@ -438,7 +544,7 @@ decl_module! {
         }
 
         /// Remove curator from a given curator group
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::remove_curator_from_group()]
         pub fn remove_curator_from_group(
             origin,
             curator_group_id: T::CuratorGroupId,
@@ -469,7 +575,7 @@ decl_module! {
         }
 
         /// Updates or creates new `EntityCreationVoucher` for given `EntityController` with individual limit
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::update_entity_creation_voucher()]
         pub fn update_entity_creation_voucher(
             origin,
             class_id: T::ClassId,
@@ -520,7 +626,7 @@ decl_module! {
         }
 
         /// Create new `Class` with provided parameters
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::create_class((name.len() + description.len()) as u32)]
         pub fn create_class(
             origin,
             name: Vec<u8>,
@@ -572,7 +678,7 @@ decl_module! {
         }
 
         /// Add curator group under given `curator_group_id` as `Class` maintainer
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::add_maintainer_to_class()]
         pub fn add_maintainer_to_class(
             origin,
             class_id: T::ClassId,
@@ -615,7 +721,7 @@ decl_module! {
         }
 
         /// Remove curator group under given `curator_group_id` from `Class` maintainers set
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::remove_maintainer_from_class()]
         pub fn remove_maintainer_from_class(
             origin,
             class_id: T::ClassId,
@@ -650,7 +756,7 @@ decl_module! {
         }
 
         /// Update `ClassPermissions` under specific `class_id`
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::update_class_permissions()]
         pub fn update_class_permissions(
             origin,
             class_id: T::ClassId,
@@ -711,7 +817,7 @@ decl_module! {
         }
 
         /// Create new class schema from existing property ids and new properties
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::add_class_schema((existing_properties.len() + new_properties.len()) as u32)]
         pub fn add_class_schema(
             origin,
             class_id: T::ClassId,
@@ -771,7 +877,7 @@ decl_module! {
         }
 
         /// Update `schema_status` under specific `schema_id` in `Class`
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::update_class_schema_status()]
         pub fn update_class_schema_status(
             origin,
             class_id: T::ClassId,
@@ -803,7 +909,7 @@ decl_module! {
         }
 
         /// Update entity permissions
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::update_entity_permissions()]
         pub fn update_entity_permissions(
             origin,
             entity_id: T::EntityId,
@@ -842,7 +948,7 @@ decl_module! {
 
         /// Transfer ownership to new `EntityController` for `Entity` under given `entity_id`
         /// `new_property_value_references_with_same_owner_flag_set` should be provided manually
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::transfer_entity_ownership(new_property_value_references_with_same_owner_flag_set.len() as u32)]
         pub fn transfer_entity_ownership(
             origin,
             entity_id: T::EntityId,
@@ -987,7 +1093,7 @@ decl_module! {
         /// Create entity.
         /// If someone is making an entity of this class for first time,
         /// then a voucher is also added with the class limit as the default limit value.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::create_entity()]
         pub fn create_entity(
             origin,
             class_id: T::ClassId,
@@ -1072,7 +1178,7 @@ decl_module! {
         }
 
         /// Remove `Entity` under provided `entity_id`
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::remove_entity()]
         pub fn remove_entity(
             origin,
             actor: Actor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
@@ -1138,7 +1244,7 @@ decl_module! {
         }
 
         /// Add schema support to entity under given `schema_id` and provided `property_values`
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::add_schema_support_to_entity(new_property_values.len() as u32)]
         pub fn add_schema_support_to_entity(
             origin,
             actor: Actor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
@@ -1238,7 +1344,7 @@ decl_module! {
         }
 
         /// Update `Entity` `InputPropertyValue`'s with provided ones
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::update_entity_property_values(new_property_values.len() as u32)]
         pub fn update_entity_property_values(
             origin,
             actor: Actor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
@@ -1337,7 +1443,7 @@ decl_module! {
         }
 
         /// Clear `PropertyValueVec` under given `entity_id` & `in_class_schema_property_id`
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::clear_entity_property_vector()]
         pub fn clear_entity_property_vector(
             origin,
             actor: Actor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
@@ -1417,7 +1523,7 @@ decl_module! {
 
         /// Remove value at given `index_in_property_vector`
         /// from `PropertyValueVec` under `in_class_schema_property_id`
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::remove_at_entity_property_vector()]
         pub fn remove_at_entity_property_vector(
             origin,
             actor: Actor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
@@ -1521,7 +1627,7 @@ decl_module! {
 
         /// Insert `SingleInputPropertyValue` at given `index_in_property_vector`
         /// into `PropertyValueVec` under `in_class_schema_property_id`
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::insert_at_entity_property_vector()]
         pub fn insert_at_entity_property_vector(
             origin,
             actor: Actor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
@@ -1630,7 +1736,7 @@ decl_module! {
         }
 
        /// Batch transaction
-       #[weight = 10_000_000] // TODO: adjust weight
+       #[weight = T::WeightInfo::transaction(operations.len() as u32)]
        pub fn transaction(origin, actor: Actor<T::CuratorGroupId, T::CuratorId, T::MemberId>, operations: Vec<OperationType<T>>) -> DispatchResult {
 
            // Ensure maximum number of operations during atomic batching limit not reached
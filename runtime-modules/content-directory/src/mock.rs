@@ -266,6 +266,7 @@ impl Trait for Runtime {
     type TextMaxLengthConstraint = TextMaxLengthConstraint;
     type HashedTextMaxLengthConstraint = HashedTextMaxLengthConstraint;
     type IndividualEntitiesCreationLimit = IndividualEntitiesCreationLimit;
+    type WeightInfo = ();
 }
 
 impl ActorAuthenticator for Runtime {
@@ -292,6 +293,11 @@ impl ActorAuthenticator for Runtime {
         let unknown_member_account_id = ensure_signed(Origin::signed(UNKNOWN_ORIGIN)).unwrap();
         *member_id < MaxNumberOfEntitiesPerClass::get() && unknown_member_account_id != *account_id
     }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn create_benchmark_lead() -> Self::AccountId {
+        ensure_signed(Origin::signed(LEAD_ORIGIN)).unwrap()
+    }
 }
 
 pub struct ExtBuilder {
@@ -67,6 +67,11 @@ pub trait ActorAuthenticator: system::Trait {
 
     /// Authorize actor as member
     fn is_member(member_id: &Self::MemberId, account_id: &Self::AccountId) -> bool;
+
+    /// Creates the lead, unless the lead is set, and returns its account.
+    /// Used by the benchmarks only.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn create_benchmark_lead() -> Self::AccountId;
 }
 
 /// Ensure curator authorization performed succesfully
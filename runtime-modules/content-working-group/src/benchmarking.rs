@@ -0,0 +1,464 @@
+//! Content working group module benchmarking.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::{StorageMap, StorageValue};
+use system::RawOrigin;
+
+const SEED: u32 = 0;
+const MAX_TEXT_LENGTH: u32 = 5000;
+const MAX_APPLICANTS: u32 = 20;
+const STAKE: u32 = 1000;
+const REWARD: u32 = 100;
+const HANDLE_LENGTH: u32 = 10;
+
+fn text(len: u32) -> Vec<u8> {
+    vec![b'a'; len as usize]
+}
+
+fn create_member<T: Trait>(name: &'static str, index: u32) -> (T::AccountId, T::MemberId) {
+    let account_id: T::AccountId = account(name, index, SEED);
+    let member_id = <membership::Module<T>>::insert_benchmark_member(&account_id);
+
+    (account_id, member_id)
+}
+
+// Relaxes the text constraints to the benchmarked lengths and sets the lead.
+fn create_lead<T: Trait>() -> T::AccountId {
+    let constraint = InputValidationLengthConstraint::new(1, MAX_TEXT_LENGTH as u16);
+
+    ChannelHandleConstraint::put(constraint);
+    ChannelTitleConstraint::put(constraint);
+    ChannelDescriptionConstraint::put(constraint);
+    ChannelAvatarConstraint::put(constraint);
+    ChannelBannerConstraint::put(constraint);
+    OpeningHumanReadableText::put(constraint);
+    CuratorApplicationHumanReadableText::put(constraint);
+    CuratorExitRationaleText::put(constraint);
+    ChannelCreationEnabled::put(true);
+
+    let (account_id, member_id) = create_member::<T>("lead", 0);
+    Module::<T>::replace_lead(
+        RawOrigin::Root.into(),
+        Some((member_id, account_id.clone())),
+    )
+    .expect("lead set");
+
+    account_id
+}
+
+fn create_channel<T: Trait>() -> (T::AccountId, ChannelId<T>) {
+    let (account_id, member_id) = create_member::<T>("channel_owner", 0);
+
+    let channel_id = Module::<T>::next_channel_id();
+    Module::<T>::create_channel(
+        RawOrigin::Signed(account_id.clone()).into(),
+        member_id,
+        account_id.clone(),
+        ChannelContentType::Video,
+        text(HANDLE_LENGTH),
+        None,
+        None,
+        None,
+        None,
+        ChannelPublicationStatus::Public,
+    )
+    .expect("channel created");
+
+    (account_id, channel_id)
+}
+
+fn add_opening<T: Trait>(
+    lead_account_id: &T::AccountId,
+    activate_at: hiring::ActivateOpeningAt<T::BlockNumber>,
+) -> CuratorOpeningId<T> {
+    let commitment = OpeningPolicyCommitment {
+        role_staking_policy: Some(hiring::StakingPolicy {
+            amount: STAKE.into(),
+            amount_mode: hiring::StakingAmountLimitMode::AtLeast,
+            crowded_out_unstaking_period_length: None,
+            review_period_expired_unstaking_period_length: None,
+        }),
+        ..OpeningPolicyCommitment::default()
+    };
+
+    let opening_id = Module::<T>::next_curator_opening_id();
+    Module::<T>::add_curator_opening(
+        RawOrigin::Signed(lead_account_id.clone()).into(),
+        activate_at,
+        commitment,
+        text(10),
+    )
+    .expect("opening added");
+
+    opening_id
+}
+
+// Applies on the opening with the role stake on behalf of the new member.
+fn apply_on_opening<T: Trait>(
+    opening_id: CuratorOpeningId<T>,
+    index: u32,
+) -> (T::AccountId, T::MemberId, CuratorApplicationId<T>) {
+    let (account_id, member_id) = create_member::<T>("applicant", index);
+    let _ = CurrencyOf::<T>::deposit_creating(&account_id, STAKE.into());
+
+    let application_id = Module::<T>::next_curator_application_id();
+    Module::<T>::apply_on_curator_opening(
+        RawOrigin::Signed(account_id.clone()).into(),
+        member_id,
+        opening_id,
+        account_id.clone(),
+        Some(STAKE.into()),
+        None,
+        text(10),
+    )
+    .expect("applied on opening");
+
+    (account_id, member_id, application_id)
+}
+
+fn reward_policy<T: Trait>() -> RewardPolicy<minting::BalanceOf<T>, T::BlockNumber> {
+    RewardPolicy {
+        amount_per_payout: REWARD.into(),
+        next_payment_at_block: <system::Module<T>>::block_number() + One::one(),
+        payout_interval: Some(One::one()),
+    }
+}
+
+// Hires the curator with the role stake and the reward relationship (the worst case
+// for the curator management).
+fn create_curator<T: Trait>(
+    lead_account_id: &T::AccountId,
+) -> (T::AccountId, T::MemberId, CuratorId<T>) {
+    let opening_id = add_opening::<T>(lead_account_id, hiring::ActivateOpeningAt::CurrentBlock);
+    let (account_id, member_id, application_id) = apply_on_opening::<T>(opening_id, 0);
+    Module::<T>::begin_curator_applicant_review(
+        RawOrigin::Signed(lead_account_id.clone()).into(),
+        opening_id,
+    )
+    .expect("review began");
+
+    let curator_id = Module::<T>::next_curator_id();
+    Module::<T>::fill_curator_opening(
+        RawOrigin::Signed(lead_account_id.clone()).into(),
+        opening_id,
+        vec![application_id].into_iter().collect(),
+        Some(reward_policy::<T>()),
+    )
+    .expect("opening filled");
+
+    (account_id, member_id, curator_id)
+}
+
+fn is_application_active<T: Trait>(application_id: CuratorApplicationId<T>) -> bool {
+    let application = Module::<T>::curator_application_by_id(application_id);
+
+    hiring::ApplicationById::<T>::get(application.application_id).stage
+        == hiring::ApplicationStage::Active
+}
+
+fn is_curator_active<T: Trait>(curator_id: CuratorId<T>) -> bool {
+    Module::<T>::curator_by_id(curator_id).stage == CuratorRoleStage::Active
+}
+
+benchmarks! {
+    _ { }
+
+    create_channel {
+        let i in 1 .. MAX_TEXT_LENGTH;
+
+        create_lead::<T>();
+        let (account_id, member_id) = create_member::<T>("channel_owner", 0);
+        let channel_id = Module::<T>::next_channel_id();
+    }: _(
+        RawOrigin::Signed(account_id.clone()),
+        member_id,
+        account_id,
+        ChannelContentType::Video,
+        text(i),
+        Some(text(MAX_TEXT_LENGTH)),
+        Some(text(MAX_TEXT_LENGTH)),
+        Some(text(MAX_TEXT_LENGTH)),
+        Some(text(MAX_TEXT_LENGTH)),
+        ChannelPublicationStatus::Public
+    )
+    verify {
+        assert!(<ChannelById<T>>::contains_key(channel_id));
+    }
+
+    transfer_channel_ownership {
+        create_lead::<T>();
+        let (account_id, channel_id) = create_channel::<T>();
+        let (new_account_id, new_member_id) = create_member::<T>("new_channel_owner", 0);
+    }: _(RawOrigin::Signed(account_id), channel_id, new_member_id, new_account_id)
+    verify {
+        assert_eq!(Module::<T>::channel_by_id(channel_id).owner, new_member_id);
+    }
+
+    // All the channel properties get updated, including the handle.
+    update_channel_as_owner {
+        create_lead::<T>();
+        let (account_id, channel_id) = create_channel::<T>();
+        let new_text = Some(text(MAX_TEXT_LENGTH));
+    }: _(
+        RawOrigin::Signed(account_id),
+        channel_id,
+        Some(text(MAX_TEXT_LENGTH)),
+        Some(new_text.clone()),
+        Some(new_text.clone()),
+        Some(new_text.clone()),
+        Some(new_text),
+        Some(ChannelPublicationStatus::Unlisted)
+    )
+    verify {
+        assert_eq!(Module::<T>::channel_by_id(channel_id).handle, text(MAX_TEXT_LENGTH));
+    }
+
+    update_channel_as_curation_actor {
+        let lead_account_id = create_lead::<T>();
+        let (account_id, _, curator_id) = create_curator::<T>(&lead_account_id);
+        let (_, channel_id) = create_channel::<T>();
+    }: _(
+        RawOrigin::Signed(account_id),
+        CurationActor::Curator(curator_id),
+        channel_id,
+        Some(true),
+        Some(ChannelCurationStatus::Censored)
+    )
+    verify {
+        assert!(Module::<T>::channel_by_id(channel_id).verified);
+    }
+
+    add_curator_opening {
+        let i in 1 .. MAX_TEXT_LENGTH;
+
+        let lead_account_id = create_lead::<T>();
+        let opening_id = Module::<T>::next_curator_opening_id();
+    }: _(
+        RawOrigin::Signed(lead_account_id),
+        hiring::ActivateOpeningAt::CurrentBlock,
+        OpeningPolicyCommitment::default(),
+        text(i)
+    )
+    verify {
+        assert!(<CuratorOpeningById<T>>::contains_key(opening_id));
+    }
+
+    accept_curator_applications {
+        let lead_account_id = create_lead::<T>();
+        let activate_at = <system::Module<T>>::block_number() + One::one();
+        let opening_id = add_opening::<T>(
+            &lead_account_id,
+            hiring::ActivateOpeningAt::ExactBlock(activate_at),
+        );
+    }: _(RawOrigin::Signed(lead_account_id), opening_id)
+    verify {
+        let opening = Module::<T>::curator_opening_by_id(opening_id);
+
+        assert!(hiring::Module::<T>::ensure_can_add_application(
+            opening.opening_id,
+            Some(STAKE.into()),
+            None
+        )
+        .is_ok());
+    }
+
+    begin_curator_applicant_review {
+        let lead_account_id = create_lead::<T>();
+        let opening_id = add_opening::<T>(&lead_account_id, hiring::ActivateOpeningAt::CurrentBlock);
+        apply_on_opening::<T>(opening_id, 0);
+    }: _(RawOrigin::Signed(lead_account_id), opening_id)
+    verify {
+        let opening = Module::<T>::curator_opening_by_id(opening_id);
+
+        assert!(hiring::Module::<T>::ensure_can_add_application(
+            opening.opening_id,
+            Some(STAKE.into()),
+            None
+        )
+        .is_err());
+    }
+
+    // Every application gets the role stake and the reward relationship.
+    fill_curator_opening {
+        let a in 1 .. MAX_APPLICANTS;
+
+        let lead_account_id = create_lead::<T>();
+        let opening_id = add_opening::<T>(&lead_account_id, hiring::ActivateOpeningAt::CurrentBlock);
+        let application_ids: CuratorApplicationIdSet<T> = (0..a)
+            .map(|index| apply_on_opening::<T>(opening_id, index).2)
+            .collect();
+        Module::<T>::begin_curator_applicant_review(
+            RawOrigin::Signed(lead_account_id.clone()).into(),
+            opening_id,
+        )
+        .expect("review began");
+        let curator_id = Module::<T>::next_curator_id();
+    }: _(
+        RawOrigin::Signed(lead_account_id),
+        opening_id,
+        application_ids,
+        Some(reward_policy::<T>())
+    )
+    verify {
+        assert!(is_curator_active::<T>(curator_id));
+    }
+
+    withdraw_curator_application {
+        let lead_account_id = create_lead::<T>();
+        let opening_id = add_opening::<T>(&lead_account_id, hiring::ActivateOpeningAt::CurrentBlock);
+        let (account_id, _, application_id) = apply_on_opening::<T>(opening_id, 0);
+    }: _(RawOrigin::Signed(account_id), application_id)
+    verify {
+        assert!(!is_application_active::<T>(application_id));
+    }
+
+    terminate_curator_application {
+        let lead_account_id = create_lead::<T>();
+        let opening_id = add_opening::<T>(&lead_account_id, hiring::ActivateOpeningAt::CurrentBlock);
+        let (_, _, application_id) = apply_on_opening::<T>(opening_id, 0);
+    }: _(RawOrigin::Signed(lead_account_id), application_id)
+    verify {
+        assert!(!is_application_active::<T>(application_id));
+    }
+
+    apply_on_curator_opening {
+        let i in 1 .. MAX_TEXT_LENGTH;
+
+        let lead_account_id = create_lead::<T>();
+        let opening_id = add_opening::<T>(&lead_account_id, hiring::ActivateOpeningAt::CurrentBlock);
+        let (account_id, member_id) = create_member::<T>("applicant", 0);
+        let _ = CurrencyOf::<T>::deposit_creating(&account_id, STAKE.into());
+        let application_id = Module::<T>::next_curator_application_id();
+    }: _(
+        RawOrigin::Signed(account_id.clone()),
+        member_id,
+        opening_id,
+        account_id,
+        Some(STAKE.into()),
+        None,
+        text(i)
+    )
+    verify {
+        assert!(is_application_active::<T>(application_id));
+    }
+
+    update_curator_role_account {
+        let lead_account_id = create_lead::<T>();
+        let (account_id, member_id, curator_id) = create_curator::<T>(&lead_account_id);
+        let new_role_account_id: T::AccountId = account("new_role_account", 0, SEED);
+    }: _(RawOrigin::Signed(account_id), member_id, curator_id, new_role_account_id.clone())
+    verify {
+        assert_eq!(Module::<T>::curator_by_id(curator_id).role_account, new_role_account_id);
+    }
+
+    update_curator_reward_account {
+        let lead_account_id = create_lead::<T>();
+        let (account_id, _, curator_id) = create_curator::<T>(&lead_account_id);
+        let new_reward_account_id: T::AccountId = account("new_reward_account", 0, SEED);
+    }: _(RawOrigin::Signed(account_id), curator_id, new_reward_account_id.clone())
+    verify {
+        let relationship_id = Module::<T>::curator_by_id(curator_id)
+            .reward_relationship
+            .expect("reward relationship set");
+        let relationship = recurringrewards::Module::<T>::reward_relationships(relationship_id);
+
+        assert_eq!(relationship.account, new_reward_account_id);
+    }
+
+    leave_curator_role {
+        let i in 1 .. MAX_TEXT_LENGTH;
+
+        let lead_account_id = create_lead::<T>();
+        let (account_id, _, curator_id) = create_curator::<T>(&lead_account_id);
+    }: _(RawOrigin::Signed(account_id), curator_id, text(i))
+    verify {
+        assert!(!is_curator_active::<T>(curator_id));
+    }
+
+    terminate_curator_role {
+        let i in 1 .. MAX_TEXT_LENGTH;
+
+        let lead_account_id = create_lead::<T>();
+        let (_, _, curator_id) = create_curator::<T>(&lead_account_id);
+    }: _(RawOrigin::Signed(lead_account_id), curator_id, text(i))
+    verify {
+        assert!(!is_curator_active::<T>(curator_id));
+    }
+
+    // The current lead gets unset first.
+    replace_lead {
+        create_lead::<T>();
+        let (account_id, member_id) = create_member::<T>("new_lead", 0);
+        let lead_id = Module::<T>::next_lead_id();
+    }: _(RawOrigin::Root, Some((member_id, account_id)))
+    verify {
+        assert_eq!(Module::<T>::current_lead_id(), Some(lead_id));
+    }
+
+    set_channel_creation_enabled {
+        let lead_account_id = create_lead::<T>();
+    }: _(RawOrigin::Signed(lead_account_id), false)
+    verify {
+        assert!(!Module::<T>::channel_creation_enabled());
+    }
+
+    increase_mint_capacity {
+        let capacity = <minting::Module<T>>::mints(Module::<T>::mint()).capacity();
+        let additional_capacity: minting::BalanceOf<T> = REWARD.into();
+    }: _(RawOrigin::Root, additional_capacity)
+    verify {
+        assert_eq!(
+            <minting::Module<T>>::mints(Module::<T>::mint()).capacity(),
+            capacity + additional_capacity
+        );
+    }
+
+    set_mint_capacity {
+        let capacity = <minting::Module<T>>::mints(Module::<T>::mint()).capacity();
+        let new_capacity = capacity + REWARD.into();
+    }: _(RawOrigin::Root, new_capacity)
+    verify {
+        assert_eq!(<minting::Module<T>>::mints(Module::<T>::mint()).capacity(), new_capacity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{Test, TestExternalitiesBuilder};
+    use frame_support::assert_ok;
+
+    // Every benchmark runs in the fresh storage: the setups create the members and the channels
+    // with the same accounts and handles.
+    fn assert_benchmark_runs(benchmark: fn() -> Result<(), &'static str>) {
+        TestExternalitiesBuilder::<Test>::default()
+            .build()
+            .execute_with(|| {
+                assert_ok!(benchmark());
+            });
+    }
+
+    #[test]
+    fn content_working_group_benchmarks_run_successfully() {
+        assert_benchmark_runs(test_benchmark_create_channel::<Test>);
+        assert_benchmark_runs(test_benchmark_transfer_channel_ownership::<Test>);
+        assert_benchmark_runs(test_benchmark_update_channel_as_owner::<Test>);
+        assert_benchmark_runs(test_benchmark_update_channel_as_curation_actor::<Test>);
+        assert_benchmark_runs(test_benchmark_add_curator_opening::<Test>);
+        assert_benchmark_runs(test_benchmark_accept_curator_applications::<Test>);
+        assert_benchmark_runs(test_benchmark_begin_curator_applicant_review::<Test>);
+        assert_benchmark_runs(test_benchmark_fill_curator_opening::<Test>);
+        assert_benchmark_runs(test_benchmark_withdraw_curator_application::<Test>);
+        assert_benchmark_runs(test_benchmark_terminate_curator_application::<Test>);
+        assert_benchmark_runs(test_benchmark_apply_on_curator_opening::<Test>);
+        assert_benchmark_runs(test_benchmark_update_curator_role_account::<Test>);
+        assert_benchmark_runs(test_benchmark_update_curator_reward_account::<Test>);
+        assert_benchmark_runs(test_benchmark_leave_curator_role::<Test>);
+        assert_benchmark_runs(test_benchmark_terminate_curator_role::<Test>);
+        assert_benchmark_runs(test_benchmark_replace_lead::<Test>);
+        assert_benchmark_runs(test_benchmark_set_channel_creation_enabled::<Test>);
+        assert_benchmark_runs(test_benchmark_increase_mint_capacity::<Test>);
+        assert_benchmark_runs(test_benchmark_set_mint_capacity::<Test>);
+    }
+}
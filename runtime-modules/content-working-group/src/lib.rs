@@ -14,6 +14,9 @@ mod tests;
 #[cfg(test)]
 mod mock;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 pub mod genesis;
 
 #[cfg(feature = "std")]
@@ -21,6 +24,7 @@ use serde::{Deserialize, Serialize};
 
 use codec::{Decode, Encode};
 use frame_support::traits::{Currency, ExistenceRequirement, WithdrawReasons};
+use frame_support::weights::Weight;
 use frame_support::{decl_event, decl_module, decl_storage, ensure};
 use sp_arithmetic::traits::{One, Zero};
 use sp_std::borrow::ToOwned;
@@ -31,6 +35,91 @@ use system::{ensure_root, ensure_signed};
 
 use common::constraints::InputValidationLengthConstraint;
 
+/// Extrinsic weights for the _Content working group_ module.
+/// `i` - text length in bytes, `a` - number of the successful applications.
+pub trait WeightInfo {
+    fn create_channel(i: u32) -> Weight;
+    fn transfer_channel_ownership() -> Weight;
+    fn update_channel_as_owner() -> Weight;
+    fn update_channel_as_curation_actor() -> Weight;
+    fn add_curator_opening(i: u32) -> Weight;
+    fn accept_curator_applications() -> Weight;
+    fn begin_curator_applicant_review() -> Weight;
+    fn fill_curator_opening(a: u32) -> Weight;
+    fn withdraw_curator_application() -> Weight;
+    fn terminate_curator_application() -> Weight;
+    fn apply_on_curator_opening(i: u32) -> Weight;
+    fn update_curator_role_account() -> Weight;
+    fn update_curator_reward_account() -> Weight;
+    fn leave_curator_role(i: u32) -> Weight;
+    fn terminate_curator_role(i: u32) -> Weight;
+    fn replace_lead() -> Weight;
+    fn set_channel_creation_enabled() -> Weight;
+    fn increase_mint_capacity() -> Weight;
+    fn set_mint_capacity() -> Weight;
+}
+
+// Default weights, used in the tests and by the runtimes without benchmarked weights.
+impl WeightInfo for () {
+    fn create_channel(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn transfer_channel_ownership() -> Weight {
+        10_000_000
+    }
+    fn update_channel_as_owner() -> Weight {
+        10_000_000
+    }
+    fn update_channel_as_curation_actor() -> Weight {
+        10_000_000
+    }
+    fn add_curator_opening(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn accept_curator_applications() -> Weight {
+        10_000_000
+    }
+    fn begin_curator_applicant_review() -> Weight {
+        10_000_000
+    }
+    fn fill_curator_opening(_a: u32) -> Weight {
+        10_000_000
+    }
+    fn withdraw_curator_application() -> Weight {
+        10_000_000
+    }
+    fn terminate_curator_application() -> Weight {
+        10_000_000
+    }
+    fn apply_on_curator_opening(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn update_curator_role_account() -> Weight {
+        10_000_000
+    }
+    fn update_curator_reward_account() -> Weight {
+        10_000_000
+    }
+    fn leave_curator_role(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn terminate_curator_role(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn replace_lead() -> Weight {
+        10_000_000
+    }
+    fn set_channel_creation_enabled() -> Weight {
+        10_000_000
+    }
+    fn increase_mint_capacity() -> Weight {
+        10_000_000
+    }
+    fn set_mint_capacity() -> Weight {
+        10_000_000
+    }
+}
+
 /// Module configuration trait for this Substrate module.
 pub trait Trait:
     system::Trait
@@ -43,6 +132,9 @@ pub trait Trait:
 {
     /// The event type.
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
 }
 
 /// Type constraint for identifer used for actors.
@@ -1097,7 +1189,7 @@ decl_module! {
          */
 
         /// Create a new channel.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_channel(handle.len() as u32)]
         pub fn create_channel(
             origin,
             owner: T::MemberId,
@@ -1184,7 +1276,7 @@ decl_module! {
         /// Notice that working group participants cannot do this.
         /// Notice that censored or unlisted channel may still be transferred.
         /// Notice that transfers are unilateral, so new owner cannot block. This may be problematic: https://github.com/Joystream/substrate-runtime-joystream/issues/95
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::transfer_channel_ownership()]
         pub fn transfer_channel_ownership(origin, channel_id: ChannelId<T>, new_owner: T::MemberId, new_role_account: T::AccountId) {
 
             // Ensure channel owner has signed
@@ -1209,7 +1301,7 @@ decl_module! {
         }
 
         /// Channel owner updates some channel properties
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::update_channel_as_owner()]
         pub fn update_channel_as_owner(
             origin,
             channel_id: ChannelId<T>,
@@ -1267,7 +1359,7 @@ decl_module! {
         }
 
         /// Update channel as a curation actor
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::update_channel_as_curation_actor()]
         pub fn update_channel_as_curation_actor(
             origin,
             curation_actor: CurationActor<CuratorId<T>>,
@@ -1297,7 +1389,7 @@ decl_module! {
         }
 
         /// Add an opening for a curator role.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::add_curator_opening(human_readable_text.len() as u32)]
         pub fn add_curator_opening(origin, activate_at: hiring::ActivateOpeningAt<T::BlockNumber>, commitment: OpeningPolicyCommitment<T::BlockNumber, BalanceOf<T>>, human_readable_text: Vec<u8>)  {
 
             // Ensure lead is set and is origin signer
@@ -1345,7 +1437,7 @@ decl_module! {
         }
 
         /// Begin accepting curator applications to an opening that is active.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::accept_curator_applications()]
         pub fn accept_curator_applications(origin, curator_opening_id: CuratorOpeningId<T>)  {
 
             // Ensure lead is set and is origin signer
@@ -1372,7 +1464,7 @@ decl_module! {
         }
 
         /// Begin reviewing, and therefore not accepting new applications.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::begin_curator_applicant_review()]
         pub fn begin_curator_applicant_review(origin, curator_opening_id: CuratorOpeningId<T>) {
 
             // Ensure lead is set and is origin signer
@@ -1399,7 +1491,7 @@ decl_module! {
         }
 
         /// Fill opening for curator
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::fill_curator_opening(successful_curator_application_ids.len() as u32)]
         pub fn fill_curator_opening(
             origin,
             curator_opening_id: CuratorOpeningId<T>,
@@ -1559,7 +1651,7 @@ decl_module! {
 
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::withdraw_curator_application()]
         pub fn withdraw_curator_application(
             origin,
             curator_application_id: CuratorApplicationId<T>
@@ -1596,7 +1688,7 @@ decl_module! {
         }
 
         /// Lead terminate curator application
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::terminate_curator_application()]
         pub fn terminate_curator_application(
             origin,
             curator_application_id: CuratorApplicationId<T>
@@ -1627,7 +1719,7 @@ decl_module! {
         }
 
         /// Apply on a curator opening.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::apply_on_curator_opening(human_readable_text.len() as u32)]
         pub fn apply_on_curator_opening(
             origin,
             member_id: T::MemberId,
@@ -1716,7 +1808,7 @@ decl_module! {
         }
 
         /// An active curator can update the associated role account.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::update_curator_role_account()]
         pub fn update_curator_role_account(
             origin,
             member_id: T::MemberId,
@@ -1744,7 +1836,7 @@ decl_module! {
 
         /// An active curator can update the reward account associated
         /// with a set reward relationship.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::update_curator_reward_account()]
         pub fn update_curator_reward_account(
             origin,
             curator_id: CuratorId<T>,
@@ -1776,7 +1868,7 @@ decl_module! {
         }
 
         /// An active curator leaves role
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::leave_curator_role(rationale_text.len() as u32)]
         pub fn leave_curator_role(
             origin,
             curator_id: CuratorId<T>,
@@ -1798,7 +1890,7 @@ decl_module! {
         }
 
         /// Lead can terminate and active curator
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::terminate_curator_role(rationale_text.len() as u32)]
         pub fn terminate_curator_role(
             origin,
             curator_id: CuratorId<T>,
@@ -1830,7 +1922,7 @@ decl_module! {
         /// If a value is provided for new_lead it will then set that new lead.
         /// It is responsibility of the caller to ensure the new lead can be set
         /// to avoid the lead role being vacant at the end of the call.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::replace_lead()]
         pub fn replace_lead(origin, new_lead: Option<(T::MemberId, T::AccountId)>) {
             // Ensure root is origin
             ensure_root(origin)?;
@@ -1847,7 +1939,7 @@ decl_module! {
         }
 
         /// Add an opening for a curator role.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::set_channel_creation_enabled()]
         pub fn set_channel_creation_enabled(origin, enabled: bool)  {
 
             // Ensure lead is set and is origin signer
@@ -1869,7 +1961,7 @@ decl_module! {
         /// both increase and decrease capacity. Although when considering that it may be executed
         /// by a proposal, given the temporal delay in approving a proposal, it might be more suitable
         /// than set_mint_capacity?
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::increase_mint_capacity()]
         pub fn increase_mint_capacity(
             origin,
             additional_capacity: minting::BalanceOf<T>
@@ -1887,7 +1979,7 @@ decl_module! {
         }
 
         /// Sets the capacity of the current active mint
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::set_mint_capacity()]
         pub fn set_mint_capacity(
             origin,
             new_capacity: minting::BalanceOf<T>
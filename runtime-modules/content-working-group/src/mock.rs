@@ -50,6 +50,7 @@ impl_outer_event! {
         versioned_store<T>,
         membership<T>,
         balances<T>,
+        recurringrewards<T>,
         system<T>,
         lib<T>,
     }
@@ -128,10 +129,16 @@ impl minting::Trait for Test {
 
 type TestRecipientId = u64;
 type TestRewardRelationshipId = u64;
+parameter_types! {
+    pub const MaxPayoutsPerBlock: u32 = 100;
+}
+
 impl recurringrewards::Trait for Test {
+    type Event = TestEvent;
     type PayoutStatusHandler = ();
     type RecipientId = TestRecipientId;
     type RewardRelationshipId = TestRewardRelationshipId;
+    type MaxPayoutsPerBlock = MaxPayoutsPerBlock;
 }
 
 type TestStakeId = u64;
@@ -146,11 +153,16 @@ impl stake::Trait for Test {
 
 type TestOpeningId = u64;
 type TestApplicationId = u64;
+parameter_types! {
+    pub const ApplicationsRetentionPeriod: u64 = 100;
+}
+
 impl hiring::Trait for Test {
     type OpeningId = TestOpeningId;
     type ApplicationId = TestApplicationId;
     type ApplicationDeactivatedHandler = ();
     type StakeHandlerProvider = hiring::Module<Self>;
+    type ApplicationsRetentionPeriod = ApplicationsRetentionPeriod;
 }
 
 impl versioned_store::Trait for Test {
@@ -171,10 +183,12 @@ impl membership::Trait for Test {
     type PaidTermId = u64;
     type SubscriptionId = u64;
     type ActorId = u64;
+    type WeightInfo = ();
 }
 
 impl Trait for Test {
     type Event = TestEvent;
+    type WeightInfo = ();
 }
 
 pub struct TestExternalitiesBuilder<T: Trait> {
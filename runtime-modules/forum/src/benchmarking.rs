@@ -0,0 +1,267 @@
+//! Forum module benchmarking.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks};
+use sp_std::vec;
+use system::RawOrigin;
+
+const SEED: u32 = 0;
+const MAX_TEXT_LENGTH: u32 = 5000;
+
+fn text(len: u32) -> Vec<u8> {
+    vec![b'a'; len as usize]
+}
+
+// Relaxes the text constraints to the benchmarked lengths and sets the forum sudo.
+fn create_forum_sudo<T: Trait>() -> T::AccountId {
+    let constraint = InputValidationLengthConstraint::new(1, MAX_TEXT_LENGTH as u16);
+
+    CategoryTitleConstraint::put(constraint);
+    CategoryDescriptionConstraint::put(constraint);
+    ThreadTitleConstraint::put(constraint);
+    PostTextConstraint::put(constraint);
+    ThreadModerationRationaleConstraint::put(constraint);
+    PostModerationRationaleConstraint::put(constraint);
+
+    let forum_sudo: T::AccountId = account("forum_sudo", 0, SEED);
+    <ForumSudo<T>>::put(forum_sudo.clone());
+
+    forum_sudo
+}
+
+fn create_forum_user<T: Trait>(name: &'static str) -> T::AccountId {
+    let account_id: T::AccountId = account(name, 0, SEED);
+    T::MembershipRegistry::add_benchmark_forum_user(&account_id);
+
+    account_id
+}
+
+fn create_category<T: Trait>(forum_sudo: &T::AccountId, parent: Option<CategoryId>) -> CategoryId {
+    let category_id = NextCategoryId::get();
+
+    Module::<T>::create_category(
+        RawOrigin::Signed(forum_sudo.clone()).into(),
+        parent,
+        text(10),
+        text(10),
+    )
+    .expect("category created");
+
+    category_id
+}
+
+fn create_thread<T: Trait>(author: &T::AccountId, category_id: CategoryId) -> T::ThreadId {
+    let thread_id = <NextThreadId<T>>::get();
+
+    Module::<T>::create_thread(
+        RawOrigin::Signed(author.clone()).into(),
+        category_id,
+        text(10),
+        text(10),
+    )
+    .expect("thread created");
+
+    thread_id
+}
+
+fn add_post<T: Trait>(author: &T::AccountId, thread_id: T::ThreadId) -> T::PostId {
+    let post_id = <NextPostId<T>>::get();
+
+    Module::<T>::add_post(
+        RawOrigin::Signed(author.clone()).into(),
+        thread_id,
+        text(10),
+    )
+    .expect("post added");
+
+    post_id
+}
+
+// Creates a thread in a subcategory, so the category tree path has the maximum length.
+fn create_thread_in_subcategory<T: Trait>(
+    forum_sudo: &T::AccountId,
+    author: &T::AccountId,
+) -> T::ThreadId {
+    let root_category_id = create_category::<T>(forum_sudo, None);
+    let category_id = create_category::<T>(forum_sudo, Some(root_category_id));
+
+    create_thread::<T>(author, category_id)
+}
+
+benchmarks! {
+    _ { }
+
+    set_forum_sudo {
+        let new_forum_sudo: T::AccountId = account("new_forum_sudo", 0, SEED);
+    }: _(RawOrigin::Root, Some(new_forum_sudo.clone()))
+    verify {
+        assert_eq!(<Module<T>>::forum_sudo(), Some(new_forum_sudo));
+    }
+
+    create_category {
+        let i in 2 .. MAX_TEXT_LENGTH;
+
+        let forum_sudo = create_forum_sudo::<T>();
+        let parent_category_id = create_category::<T>(&forum_sudo, None);
+        let category_id = NextCategoryId::get();
+    }: _(
+        RawOrigin::Signed(forum_sudo),
+        Some(parent_category_id),
+        text(i / 2),
+        text(i - i / 2)
+    )
+    verify {
+        assert!(<CategoryById<T>>::contains_key(category_id));
+    }
+
+    update_category {
+        let forum_sudo = create_forum_sudo::<T>();
+        let root_category_id = create_category::<T>(&forum_sudo, None);
+        let category_id = create_category::<T>(&forum_sudo, Some(root_category_id));
+    }: _(RawOrigin::Signed(forum_sudo), category_id, Some(true), None)
+    verify {
+        assert!(<Module<T>>::category_by_id(category_id).archived);
+    }
+
+    create_thread {
+        let i in 2 .. MAX_TEXT_LENGTH;
+
+        let forum_sudo = create_forum_sudo::<T>();
+        let author = create_forum_user::<T>("author");
+        let root_category_id = create_category::<T>(&forum_sudo, None);
+        let category_id = create_category::<T>(&forum_sudo, Some(root_category_id));
+        let thread_id = <NextThreadId<T>>::get();
+    }: _(RawOrigin::Signed(author), category_id, text(i / 2), text(i - i / 2))
+    verify {
+        assert!(<ThreadById<T>>::contains_key(thread_id));
+    }
+
+    moderate_thread {
+        let i in 1 .. MAX_TEXT_LENGTH;
+
+        let forum_sudo = create_forum_sudo::<T>();
+        let author = create_forum_user::<T>("author");
+        let thread_id = create_thread_in_subcategory::<T>(&forum_sudo, &author);
+        Module::<T>::update_thread(
+            RawOrigin::Signed(forum_sudo.clone()).into(),
+            thread_id,
+            None,
+            Some(true),
+        )
+        .expect("thread pinned");
+    }: _(RawOrigin::Signed(forum_sudo), thread_id, text(i))
+    verify {
+        assert!(<Module<T>>::thread_by_id(thread_id).moderation.is_some());
+    }
+
+    add_post {
+        let i in 1 .. MAX_TEXT_LENGTH;
+
+        let forum_sudo = create_forum_sudo::<T>();
+        let author = create_forum_user::<T>("author");
+        let thread_id = create_thread_in_subcategory::<T>(&forum_sudo, &author);
+        let post_id = <NextPostId<T>>::get();
+    }: _(RawOrigin::Signed(author), thread_id, text(i))
+    verify {
+        assert!(<PostById<T>>::contains_key(post_id));
+    }
+
+    edit_post_text {
+        let i in 1 .. MAX_TEXT_LENGTH;
+
+        let forum_sudo = create_forum_sudo::<T>();
+        let author = create_forum_user::<T>("author");
+        let thread_id = create_thread_in_subcategory::<T>(&forum_sudo, &author);
+        let post_id = add_post::<T>(&author, thread_id);
+    }: _(RawOrigin::Signed(author), post_id, text(i))
+    verify {
+        assert_eq!(<Module<T>>::post_by_id(post_id).current_text, text(i));
+    }
+
+    moderate_post {
+        let i in 1 .. MAX_TEXT_LENGTH;
+
+        let forum_sudo = create_forum_sudo::<T>();
+        let author = create_forum_user::<T>("author");
+        let thread_id = create_thread_in_subcategory::<T>(&forum_sudo, &author);
+        let post_id = add_post::<T>(&author, thread_id);
+    }: _(RawOrigin::Signed(forum_sudo), post_id, text(i))
+    verify {
+        assert!(<Module<T>>::post_by_id(post_id).moderation.is_some());
+    }
+
+    add_category_moderator {
+        let forum_sudo = create_forum_sudo::<T>();
+        let category_id = create_category::<T>(&forum_sudo, None);
+        let moderator_id: T::AccountId = account("moderator", 0, SEED);
+    }: _(RawOrigin::Signed(forum_sudo), category_id, moderator_id.clone())
+    verify {
+        assert!(<CategoryModerators<T>>::contains_key(category_id, moderator_id));
+    }
+
+    remove_category_moderator {
+        let forum_sudo = create_forum_sudo::<T>();
+        let category_id = create_category::<T>(&forum_sudo, None);
+        let moderator_id: T::AccountId = account("moderator", 0, SEED);
+        <CategoryModerators<T>>::insert(category_id, &moderator_id, ());
+    }: _(RawOrigin::Signed(forum_sudo), category_id, moderator_id.clone())
+    verify {
+        assert!(!<CategoryModerators<T>>::contains_key(category_id, moderator_id));
+    }
+
+    update_thread {
+        let forum_sudo = create_forum_sudo::<T>();
+        let author = create_forum_user::<T>("author");
+        let thread_id = create_thread_in_subcategory::<T>(&forum_sudo, &author);
+    }: _(RawOrigin::Signed(forum_sudo), thread_id, Some(true), Some(true))
+    verify {
+        assert!(<LockedThreads<T>>::contains_key(thread_id));
+    }
+
+    move_thread {
+        let forum_sudo = create_forum_sudo::<T>();
+        let author = create_forum_user::<T>("author");
+        let thread_id = create_thread_in_subcategory::<T>(&forum_sudo, &author);
+        let root_category_id = create_category::<T>(&forum_sudo, None);
+        let new_category_id = create_category::<T>(&forum_sudo, Some(root_category_id));
+    }: _(RawOrigin::Signed(forum_sudo), thread_id, new_category_id)
+    verify {
+        assert_eq!(<Module<T>>::thread_by_id(thread_id).category_id, new_category_id);
+    }
+
+    delete_post {
+        let forum_sudo = create_forum_sudo::<T>();
+        let author = create_forum_user::<T>("author");
+        let thread_id = create_thread_in_subcategory::<T>(&forum_sudo, &author);
+        let post_id = add_post::<T>(&author, thread_id);
+    }: _(RawOrigin::Signed(author), post_id)
+    verify {
+        assert!(<Module<T>>::post_by_id(post_id).moderation.is_some());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{build_test_externalities, default_genesis_config, Runtime};
+    use frame_support::assert_ok;
+
+    #[test]
+    fn forum_benchmarks_run_successfully() {
+        build_test_externalities(default_genesis_config()).execute_with(|| {
+            assert_ok!(test_benchmark_set_forum_sudo::<Runtime>());
+            assert_ok!(test_benchmark_create_category::<Runtime>());
+            assert_ok!(test_benchmark_update_category::<Runtime>());
+            assert_ok!(test_benchmark_create_thread::<Runtime>());
+            assert_ok!(test_benchmark_moderate_thread::<Runtime>());
+            assert_ok!(test_benchmark_add_post::<Runtime>());
+            assert_ok!(test_benchmark_edit_post_text::<Runtime>());
+            assert_ok!(test_benchmark_moderate_post::<Runtime>());
+            assert_ok!(test_benchmark_add_category_moderator::<Runtime>());
+            assert_ok!(test_benchmark_remove_category_moderator::<Runtime>());
+            assert_ok!(test_benchmark_update_thread::<Runtime>());
+            assert_ok!(test_benchmark_move_thread::<Runtime>());
+            assert_ok!(test_benchmark_delete_post::<Runtime>());
+        });
+    }
+}
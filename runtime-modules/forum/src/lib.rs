@@ -14,6 +14,7 @@ use serde::{Deserialize, Serialize};
 pub type DispatchResult = Result<(), &'static str>;
 
 use codec::{Codec, Decode, Encode};
+use frame_support::weights::Weight;
 use frame_support::{decl_event, decl_module, decl_storage, ensure, Parameter};
 use sp_arithmetic::traits::{BaseArithmetic, One};
 use sp_runtime::traits::{MaybeSerialize, Member};
@@ -21,6 +22,8 @@ use sp_std::borrow::ToOwned;
 use sp_std::vec;
 use sp_std::vec::Vec;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 mod mock;
 mod tests;
 
@@ -34,6 +37,9 @@ use common::BlockAndTime;
 /// The depth of a root category is 0.
 const MAX_CATEGORY_DEPTH: u16 = 3;
 
+/// Moderation rationale marking the posts deleted by their authors.
+pub const POST_DELETED_BY_AUTHOR_RATIONALE: &[u8] = b"Deleted by the author.";
+
 /// Error messages for dispatchables
 const ERROR_FORUM_SUDO_NOT_SET: &str = "Forum sudo not set.";
 const ERROR_ORIGIN_NOT_FORUM_SUDO: &str = "Origin not forum sudo.";
@@ -63,6 +69,11 @@ const ERROR_POST_MODERATION_RATIONALE_TOO_LONG: &str = "Post moderation rational
 const ERROR_CATEGORY_NOT_BEING_UPDATED: &str = "Category not being updated.";
 const ERROR_CATEGORY_CANNOT_BE_UNARCHIVED_WHEN_DELETED: &str =
     "Category cannot be unarchived when deleted.";
+const ERROR_ACCOUNT_ALREADY_CATEGORY_MODERATOR: &str = "Account is already a category moderator.";
+const ERROR_ACCOUNT_NOT_CATEGORY_MODERATOR: &str = "Account is not a category moderator.";
+const ERROR_THREAD_NOT_BEING_UPDATED: &str = "Thread not being updated.";
+const ERROR_THREAD_LOCKED: &str = "Thread is locked.";
+const ERROR_THREAD_ALREADY_IN_CATEGORY: &str = "Thread is already in the category.";
 
 use system::{ensure_root, ensure_signed};
 
@@ -79,6 +90,15 @@ pub struct ForumUser<AccountId> {
 /// Represents a regsitry of `ForumUser` instances.
 pub trait ForumUserRegistry<AccountId> {
     fn get_forum_user(id: &AccountId) -> Option<ForumUser<AccountId>>;
+
+    /// Registers the account as a forum user. Used by the benchmarks only.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn add_benchmark_forum_user(id: &AccountId);
+}
+
+/// Represents a registry of accounts allowed to moderate the forum alongside the forum sudo.
+pub trait ForumModeratorRegistry<AccountId> {
+    fn is_forum_moderator(id: &AccountId) -> bool;
 }
 
 /// Represents a moderation outcome applied to a post or a thread.
@@ -88,7 +108,7 @@ pub struct ModerationAction<BlockNumber, Moment, AccountId> {
     /// When action occured.
     moderated_at: BlockAndTime<BlockNumber, Moment>,
 
-    /// Account forum sudo which acted.
+    /// Account of the forum sudo or moderator which acted.
     moderator_id: AccountId,
 
     /// Moderation rationale
@@ -169,6 +189,7 @@ pub struct Thread<BlockNumber, Moment, AccountId, ThreadId> {
     ///
     /// When there is a moderation
     /// of a post, the variables are incremented and decremented, respectively.
+    /// Posts deleted by their authors are accounted as moderated ones.
     ///
     /// These values are vital for light clients, in order to validate that they are
     /// not being censored from posts in a thread.
@@ -236,6 +257,8 @@ pub struct Category<BlockNumber, Moment, AccountId> {
     ///
     /// When there is a moderation
     /// of a thread, the variables are incremented and decremented, respectively.
+    /// Threads moved to another category keep their slots in the former category and are
+    /// counted in `MovedOutThreadsCount` instead.
     ///
     /// These values are vital for light clients, in order to validate that they are
     /// not being censored from subcategories or threads in a category.
@@ -261,11 +284,78 @@ impl<BlockNumber, Moment, AccountId> Category<BlockNumber, Moment, AccountId> {
 type CategoryTreePath<BlockNumber, Moment, AccountId> =
     Vec<Category<BlockNumber, Moment, AccountId>>;
 
+/// Extrinsic weights for the forum module.
+/// `i` - total length of the provided text parameters in bytes.
+pub trait WeightInfo {
+    fn set_forum_sudo() -> Weight;
+    fn create_category(i: u32) -> Weight;
+    fn update_category() -> Weight;
+    fn create_thread(i: u32) -> Weight;
+    fn moderate_thread(i: u32) -> Weight;
+    fn add_post(i: u32) -> Weight;
+    fn edit_post_text(i: u32) -> Weight;
+    fn moderate_post(i: u32) -> Weight;
+    fn add_category_moderator() -> Weight;
+    fn remove_category_moderator() -> Weight;
+    fn update_thread() -> Weight;
+    fn move_thread() -> Weight;
+    fn delete_post() -> Weight;
+}
+
+// Default weights, used in the tests and by the runtimes without benchmarked weights.
+impl WeightInfo for () {
+    fn set_forum_sudo() -> Weight {
+        10_000_000
+    }
+    fn create_category(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn update_category() -> Weight {
+        10_000_000
+    }
+    fn create_thread(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn moderate_thread(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn add_post(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn edit_post_text(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn moderate_post(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn add_category_moderator() -> Weight {
+        10_000_000
+    }
+    fn remove_category_moderator() -> Weight {
+        10_000_000
+    }
+    fn update_thread() -> Weight {
+        10_000_000
+    }
+    fn move_thread() -> Weight {
+        10_000_000
+    }
+    fn delete_post() -> Weight {
+        10_000_000
+    }
+}
+
 pub trait Trait: system::Trait + pallet_timestamp::Trait + Sized {
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
 
     type MembershipRegistry: ForumUserRegistry<Self::AccountId>;
 
+    /// Accounts allowed to create categories and moderate threads and posts.
+    type ModeratorRegistry: ForumModeratorRegistry<Self::AccountId>;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
+
     /// Thread Id type
     type ThreadId: Parameter
         + Member
@@ -314,6 +404,22 @@ decl_storage! {
         /// Account of forum sudo.
         pub ForumSudo get(fn forum_sudo) config(): Option<T::AccountId>;
 
+        /// Accounts allowed to moderate threads and posts within a category and its subcategories.
+        pub CategoryModerators get(fn category_moderators): double_map hasher(blake2_128_concat)
+            CategoryId, hasher(blake2_128_concat) T::AccountId => ();
+
+        /// Threads locked against new posts.
+        pub LockedThreads get(fn locked_threads): map hasher(blake2_128_concat) T::ThreadId => ();
+
+        /// Number of threads moved out of a category. Accounted in the threads created in
+        /// the category along with its unmoderated and moderated threads.
+        pub MovedOutThreadsCount get(fn moved_out_threads_count): map hasher(blake2_128_concat)
+            CategoryId => u32;
+
+        /// Threads pinned within a category, in the order of pinning.
+        pub PinnedThreadIds get(fn pinned_thread_ids): map hasher(blake2_128_concat)
+            CategoryId => Vec<T::ThreadId>;
+
         /// Input constraints
         /// These are all forward looking, that is they are enforced on all
         /// future calls.
@@ -359,6 +465,24 @@ decl_event!(
 
         /// Given account was set as forum sudo.
         ForumSudoSet(Option<AccountId>, Option<AccountId>),
+
+        /// Given account was added as a moderator of the category with given id.
+        CategoryModeratorAdded(CategoryId, AccountId),
+
+        /// Given account was removed from moderators of the category with given id.
+        CategoryModeratorRemoved(CategoryId, AccountId),
+
+        /// A thread with given id was updated.
+        /// The second argument reflects the new lock status of the thread, if changed.
+        /// The third argument reflects the new pin status of the thread, if changed.
+        ThreadUpdated(ThreadId, Option<bool>, Option<bool>),
+
+        /// A thread with given id was moved from the category in the second argument
+        /// to the category in the third argument.
+        ThreadMoved(ThreadId, CategoryId, CategoryId),
+
+        /// Post with given id was deleted by its author.
+        PostDeleted(PostId),
     }
 );
 
@@ -368,7 +492,7 @@ decl_module! {
         fn deposit_event() = default;
 
         /// Set forum sudo.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::set_forum_sudo()]
         fn set_forum_sudo(origin, new_forum_sudo: Option<T::AccountId>) -> DispatchResult {
             ensure_root(origin)?;
 
@@ -394,14 +518,14 @@ decl_module! {
         }
 
         /// Add a new category.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::create_category((title.len() + description.len()) as u32)]
         fn create_category(origin, parent: Option<CategoryId>, title: Vec<u8>, description: Vec<u8>) -> DispatchResult {
 
             // Check that its a valid signature
             let who = ensure_signed(origin)?;
 
-            // Not signed by forum SUDO
-            Self::ensure_is_forum_sudo(&who)?;
+            // Not signed by forum SUDO or a forum moderator
+            Self::ensure_is_forum_sudo_or_moderator(&who)?;
 
             // Validate title
             Self::ensure_category_title_is_valid(&title)?;
@@ -474,14 +598,14 @@ decl_module! {
         }
 
         /// Update category
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::update_category()]
         fn update_category(origin, category_id: CategoryId, new_archival_status: Option<bool>, new_deletion_status: Option<bool>) -> DispatchResult {
 
             // Check that its a valid signature
             let who = ensure_signed(origin)?;
 
-            // Not signed by forum SUDO
-            Self::ensure_is_forum_sudo(&who)?;
+            // Not signed by forum SUDO or a forum moderator
+            Self::ensure_is_forum_sudo_or_moderator(&who)?;
 
             // Make sure something is actually being changed
             ensure!(
@@ -538,7 +662,7 @@ decl_module! {
         }
 
         /// Create new thread in category
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::create_thread((title.len() + text.len()) as u32)]
         fn create_thread(origin, category_id: CategoryId, title: Vec<u8>, text: Vec<u8>) -> DispatchResult {
 
             /*
@@ -582,30 +706,30 @@ decl_module! {
         }
 
         /// Moderate thread
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::moderate_thread(rationale.len() as u32)]
         fn moderate_thread(origin, thread_id: T::ThreadId, rationale: Vec<u8>) -> DispatchResult {
 
             // Check that its a valid signature
             let who = ensure_signed(origin)?;
 
-            // Signed by forum SUDO
-            Self::ensure_is_forum_sudo(&who)?;
-
             // Get thread
             let mut thread = Self::ensure_thread_exists(thread_id)?;
 
-            // Thread is not already moderated
-            ensure!(thread.moderation.is_none(), ERROR_THREAD_ALREADY_MODERATED);
-
-            // Rationale valid
-            Self::ensure_thread_moderation_rationale_is_valid(&rationale)?;
-
             // Can mutate in corresponding category
             let path = Self::build_category_tree_path(thread.category_id);
 
             // Path must be non-empty, as category id is from thread in state
             assert!(!path.is_empty());
 
+            // Signed by forum SUDO, a forum moderator or a moderator of the category subtree
+            Self::ensure_can_moderate_in_path(&who, &path)?;
+
+            // Thread is not already moderated
+            ensure!(thread.moderation.is_none(), ERROR_THREAD_ALREADY_MODERATED);
+
+            // Rationale valid
+            Self::ensure_thread_moderation_rationale_is_valid(&rationale)?;
+
             Self::ensure_can_mutate_in_path_leaf(&path)?;
 
             /*
@@ -627,6 +751,9 @@ decl_module! {
                 category.num_direct_moderated_threads += 1;
             });
 
+            // Moderated threads are no longer pinned
+            Self::unpin_thread(thread.category_id, thread_id);
+
             // Generate event
             Self::deposit_event(RawEvent::ThreadModerated(thread_id));
 
@@ -634,7 +761,7 @@ decl_module! {
         }
 
         /// Edit post text
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::add_post(text.len() as u32)]
         fn add_post(origin, thread_id: T::ThreadId, text: Vec<u8>) -> DispatchResult {
 
             /*
@@ -653,6 +780,9 @@ decl_module! {
             // Make sure thread exists and is mutable
             let thread = Self::ensure_thread_is_mutable(thread_id)?;
 
+            // Locked threads do not accept new posts
+            ensure!(!<LockedThreads<T>>::contains_key(thread_id), ERROR_THREAD_LOCKED);
+
             // Get path from parent to root of category tree.
             let category_tree_path = Self::ensure_valid_category_and_build_category_tree_path(thread.category_id)?;
 
@@ -672,7 +802,7 @@ decl_module! {
         }
 
         /// Edit post text
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::edit_post_text(new_text.len() as u32)]
         fn edit_post_text(origin, post_id: T::PostId, new_text: Vec<u8>) -> DispatchResult {
 
             /* Edit spec.
@@ -720,18 +850,20 @@ decl_module! {
         }
 
         /// Moderate post
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::moderate_post(rationale.len() as u32)]
         fn moderate_post(origin, post_id: T::PostId, rationale: Vec<u8>) -> DispatchResult {
 
             // Check that its a valid signature
             let who = ensure_signed(origin)?;
 
-            // Signed by forum SUDO
-            Self::ensure_is_forum_sudo(&who)?;
-
             // Make sure post exists and is mutable
             let post = Self::ensure_post_is_mutable(post_id)?;
 
+            // Signed by forum SUDO, a forum moderator or a moderator of the category subtree
+            let thread = <ThreadById<T>>::get(post.thread_id);
+            let path = Self::build_category_tree_path(thread.category_id);
+            Self::ensure_can_moderate_in_path(&who, &path)?;
+
             Self::ensure_post_moderation_rationale_is_valid(&rationale)?;
 
             /*
@@ -761,6 +893,216 @@ decl_module! {
             Ok(())
         }
 
+        /// Add a moderator to the category. Category moderators can moderate threads and posts
+        /// in the category and all of its subcategories.
+        #[weight = T::WeightInfo::add_category_moderator()]
+        fn add_category_moderator(origin, category_id: CategoryId, moderator_id: T::AccountId) -> DispatchResult {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Not signed by forum SUDO or a forum moderator
+            Self::ensure_is_forum_sudo_or_moderator(&who)?;
+
+            // Make sure category exists
+            ensure!(
+                <CategoryById<T>>::contains_key(&category_id),
+                ERROR_CATEGORY_DOES_NOT_EXIST
+            );
+
+            // Make sure account is not a moderator of the category yet
+            ensure!(
+                !<CategoryModerators<T>>::contains_key(category_id, &moderator_id),
+                ERROR_ACCOUNT_ALREADY_CATEGORY_MODERATOR
+            );
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            <CategoryModerators<T>>::insert(category_id, &moderator_id, ());
+
+            // Generate event
+            Self::deposit_event(RawEvent::CategoryModeratorAdded(category_id, moderator_id));
+
+            Ok(())
+        }
+
+        /// Remove a moderator from the category.
+        #[weight = T::WeightInfo::remove_category_moderator()]
+        fn remove_category_moderator(origin, category_id: CategoryId, moderator_id: T::AccountId) -> DispatchResult {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Not signed by forum SUDO or a forum moderator
+            Self::ensure_is_forum_sudo_or_moderator(&who)?;
+
+            // Make sure account is a moderator of the category
+            ensure!(
+                <CategoryModerators<T>>::contains_key(category_id, &moderator_id),
+                ERROR_ACCOUNT_NOT_CATEGORY_MODERATOR
+            );
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            <CategoryModerators<T>>::remove(category_id, &moderator_id);
+
+            // Generate event
+            Self::deposit_event(RawEvent::CategoryModeratorRemoved(category_id, moderator_id));
+
+            Ok(())
+        }
+
+        /// Update thread lock and pin status. Locked threads do not accept new posts,
+        /// pinned threads are listed first within their category.
+        #[weight = T::WeightInfo::update_thread()]
+        fn update_thread(origin, thread_id: T::ThreadId, new_lock_status: Option<bool>, new_pin_status: Option<bool>) -> DispatchResult {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Make sure something is actually being changed
+            ensure!(
+                new_lock_status.is_some() || new_pin_status.is_some(),
+                ERROR_THREAD_NOT_BEING_UPDATED
+            );
+
+            // Make sure thread exists and is mutable
+            let thread = Self::ensure_thread_is_mutable(thread_id)?;
+
+            // Signed by forum SUDO, a forum moderator or a moderator of the category subtree
+            let path = Self::build_category_tree_path(thread.category_id);
+            Self::ensure_can_moderate_in_path(&who, &path)?;
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            if let Some(locked) = new_lock_status {
+                if locked {
+                    <LockedThreads<T>>::insert(thread_id, ());
+                } else {
+                    <LockedThreads<T>>::remove(thread_id);
+                }
+            }
+
+            if let Some(pinned) = new_pin_status {
+                Self::unpin_thread(thread.category_id, thread_id);
+
+                if pinned {
+                    <PinnedThreadIds<T>>::mutate(thread.category_id, |ids| ids.push(thread_id));
+                }
+            }
+
+            // Generate event
+            Self::deposit_event(RawEvent::ThreadUpdated(thread_id, new_lock_status, new_pin_status));
+
+            Ok(())
+        }
+
+        /// Move thread to another category. The thread gets the next thread number in the new
+        /// category and loses its pin.
+        #[weight = T::WeightInfo::move_thread()]
+        fn move_thread(origin, thread_id: T::ThreadId, new_category_id: CategoryId) -> DispatchResult {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Make sure thread exists and is mutable
+            let thread = Self::ensure_thread_is_mutable(thread_id)?;
+
+            ensure!(thread.category_id != new_category_id, ERROR_THREAD_ALREADY_IN_CATEGORY);
+
+            // Get path from new category to root of category tree.
+            let new_category_tree_path = Self::ensure_valid_category_and_build_category_tree_path(new_category_id)?;
+
+            // No ancestor is blocking us doing mutation in the new category
+            Self::ensure_can_mutate_in_path_leaf(&new_category_tree_path)?;
+
+            // Must be allowed to moderate in both categories
+            let old_category_tree_path = Self::build_category_tree_path(thread.category_id);
+            Self::ensure_can_moderate_in_path(&who, &old_category_tree_path)?;
+            Self::ensure_can_moderate_in_path(&who, &new_category_tree_path)?;
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            let old_category_id = thread.category_id;
+
+            // Moved thread keeps its slot in the former category
+            <CategoryById<T>>::mutate(old_category_id, |c| {
+                c.num_direct_unmoderated_threads -= 1;
+            });
+            <MovedOutThreadsCount>::mutate(old_category_id, |count| *count += 1);
+
+            Self::unpin_thread(old_category_id, thread_id);
+
+            let new_category = <CategoryById<T>>::get(new_category_id);
+
+            <ThreadById<T>>::mutate(thread_id, |t| {
+                t.category_id = new_category_id;
+                t.nr_in_category = Self::num_threads_created(new_category_id, &new_category) + 1;
+            });
+
+            <CategoryById<T>>::mutate(new_category_id, |c| {
+                c.num_direct_unmoderated_threads += 1;
+            });
+
+            // Generate event
+            Self::deposit_event(RawEvent::ThreadMoved(thread_id, old_category_id, new_category_id));
+
+            Ok(())
+        }
+
+        /// Delete post by its author. The post text is cleared and the post is kept as moderated
+        /// by its author, so its slot in the thread stays verifiable.
+        #[weight = T::WeightInfo::delete_post()]
+        fn delete_post(origin, post_id: T::PostId) -> DispatchResult {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Check that account is forum member
+            Self::ensure_is_forum_member(&who)?;
+
+            // Make sure there exists a mutable post with post id `post_id`
+            let post = Self::ensure_post_is_mutable(post_id)?;
+
+            // Signer does not match creator of post with identifier postId
+            ensure!(post.author_id == who, ERROR_ACCOUNT_DOES_NOT_MATCH_POST_AUTHOR);
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            // Deleted post is kept as a tombstone moderated by its author
+            let moderation_action = ModerationAction{
+                moderated_at: common::current_block_time::<T>(),
+                moderator_id: who,
+                rationale: POST_DELETED_BY_AUTHOR_RATIONALE.to_vec(),
+            };
+
+            <PostById<T>>::mutate(post_id, |p| {
+                p.current_text = Vec::new();
+                p.text_change_history = Vec::new();
+                p.moderation = Some(moderation_action);
+            });
+
+            // Deleted post keeps its slot in the thread
+            <ThreadById<T>>::mutate(post.thread_id, |t| {
+                t.num_unmoderated_posts -= 1;
+                t.num_moderated_posts += 1;
+            });
+
+            // Generate event
+            Self::deposit_event(RawEvent::PostDeleted(post_id));
+
+            Ok(())
+        }
     }
 }
 
@@ -882,6 +1224,30 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    fn ensure_is_forum_sudo_or_moderator(account_id: &T::AccountId) -> DispatchResult {
+        if T::ModeratorRegistry::is_forum_moderator(account_id) {
+            return Ok(());
+        }
+
+        Self::ensure_is_forum_sudo(account_id)
+    }
+
+    fn ensure_can_moderate_in_path(
+        account_id: &T::AccountId,
+        category_tree_path: &[Category<T::BlockNumber, T::Moment, T::AccountId>],
+    ) -> DispatchResult {
+        // Moderators of a category moderate its subcategories as well
+        let is_category_moderator = category_tree_path
+            .iter()
+            .any(|c| <CategoryModerators<T>>::contains_key(c.id, account_id));
+
+        if is_category_moderator {
+            return Ok(());
+        }
+
+        Self::ensure_is_forum_sudo_or_moderator(account_id)
+    }
+
     fn ensure_is_forum_member(
         account_id: &T::AccountId,
     ) -> Result<ForumUser<T::AccountId>, &'static str> {
@@ -991,6 +1357,23 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    // Number of threads created in the category, including the threads moved out of it.
+    fn num_threads_created(
+        category_id: CategoryId,
+        category: &Category<T::BlockNumber, T::Moment, T::AccountId>,
+    ) -> u32 {
+        category.num_threads_created() + Self::moved_out_threads_count(category_id)
+    }
+
+    fn unpin_thread(category_id: CategoryId, thread_id: T::ThreadId) {
+        let mut pinned_thread_ids = <PinnedThreadIds<T>>::get(category_id);
+
+        if let Some(index) = pinned_thread_ids.iter().position(|id| *id == thread_id) {
+            pinned_thread_ids.remove(index);
+            <PinnedThreadIds<T>>::insert(category_id, pinned_thread_ids);
+        }
+    }
+
     fn add_new_thread(
         category_id: CategoryId,
         title: &[u8],
@@ -1006,7 +1389,7 @@ impl<T: Trait> Module<T> {
             id: new_thread_id,
             title: title.to_owned(),
             category_id,
-            nr_in_category: category.num_threads_created() + 1,
+            nr_in_category: Self::num_threads_created(category_id, &category) + 1,
             moderation: None,
             num_unmoderated_posts: 0,
             num_moderated_posts: 0,
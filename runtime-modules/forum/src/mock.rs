@@ -26,6 +26,8 @@ pub mod registry {
         trait Store for Module<T: Trait> as MockForumUserRegistry {
             pub ForumUserById get(fn forum_user_by_id) config(): map hasher(blake2_128_concat)
                 T::AccountId => Member<T::AccountId>;
+            pub ForumModeratorById get(fn forum_moderator_by_id): map hasher(blake2_128_concat)
+                T::AccountId => bool;
         }
     }
 
@@ -37,6 +39,10 @@ pub mod registry {
         pub fn add_member(member: &Member<T::AccountId>) {
             <ForumUserById<T>>::insert(member.id.clone(), member.clone());
         }
+
+        pub fn add_moderator(account_id: &T::AccountId) {
+            <ForumModeratorById<T>>::insert(account_id.clone(), true);
+        }
     }
 
     impl<T: Trait> ForumUserRegistry<T::AccountId> for Module<T> {
@@ -49,6 +55,17 @@ pub mod registry {
                 None
             }
         }
+
+        #[cfg(feature = "runtime-benchmarks")]
+        fn add_benchmark_forum_user(id: &T::AccountId) {
+            Self::add_member(&Member { id: id.clone() });
+        }
+    }
+
+    impl<T: Trait> ForumModeratorRegistry<T::AccountId> for Module<T> {
+        fn is_forum_moderator(id: &T::AccountId) -> bool {
+            <ForumModeratorById<T>>::get(id)
+        }
     }
 
     pub type TestMembershipRegistryModule = Module<Runtime>;
@@ -105,8 +122,10 @@ impl pallet_timestamp::Trait for Runtime {
 impl Trait for Runtime {
     type Event = ();
     type MembershipRegistry = registry::TestMembershipRegistryModule;
+    type ModeratorRegistry = registry::TestMembershipRegistryModule;
     type ThreadId = u64;
     type PostId = u64;
+    type WeightInfo = ();
 }
 
 #[derive(Clone)]
@@ -126,6 +145,14 @@ pub fn mock_origin(origin: OriginType) -> mock::Origin {
 
 pub const NOT_FORUM_SUDO_ORIGIN: OriginType = OriginType::Signed(111);
 
+pub const FORUM_MODERATOR_ORIGIN_ID: <Runtime as system::Trait>::AccountId = 124;
+
+pub const FORUM_MODERATOR_ORIGIN: OriginType = OriginType::Signed(FORUM_MODERATOR_ORIGIN_ID);
+
+pub const CATEGORY_MODERATOR_ORIGIN_ID: <Runtime as system::Trait>::AccountId = 125;
+
+pub const CATEGORY_MODERATOR_ORIGIN: OriginType = OriginType::Signed(CATEGORY_MODERATOR_ORIGIN_ID);
+
 pub const NOT_MEMBER_ORIGIN: OriginType = OriginType::Signed(222);
 
 pub const INVLAID_CATEGORY_ID: CategoryId = 333;
@@ -369,6 +396,48 @@ pub fn moderate_post(
     TestForumModule::moderate_post(mock_origin(forum_sudo), post_id, rationale)
 }
 
+pub fn add_category_moderator(
+    forum_sudo: OriginType,
+    category_id: CategoryId,
+    moderator_id: <Runtime as system::Trait>::AccountId,
+) -> DispatchResult {
+    TestForumModule::add_category_moderator(mock_origin(forum_sudo), category_id, moderator_id)
+}
+
+pub fn remove_category_moderator(
+    forum_sudo: OriginType,
+    category_id: CategoryId,
+    moderator_id: <Runtime as system::Trait>::AccountId,
+) -> DispatchResult {
+    TestForumModule::remove_category_moderator(mock_origin(forum_sudo), category_id, moderator_id)
+}
+
+pub fn update_thread(
+    forum_sudo: OriginType,
+    thread_id: RuntimeThreadId,
+    new_lock_status: Option<bool>,
+    new_pin_status: Option<bool>,
+) -> DispatchResult {
+    TestForumModule::update_thread(
+        mock_origin(forum_sudo),
+        thread_id,
+        new_lock_status,
+        new_pin_status,
+    )
+}
+
+pub fn move_thread(
+    forum_sudo: OriginType,
+    thread_id: RuntimeThreadId,
+    new_category_id: CategoryId,
+) -> DispatchResult {
+    TestForumModule::move_thread(mock_origin(forum_sudo), thread_id, new_category_id)
+}
+
+pub fn delete_post(origin: OriginType, post_id: RuntimePostId) -> DispatchResult {
+    TestForumModule::delete_post(mock_origin(origin), post_id)
+}
+
 pub fn archive_category(forum_sudo: OriginType, category_id: CategoryId) -> DispatchResult {
     TestForumModule::update_category(mock_origin(forum_sudo), category_id, Some(true), None)
 }
@@ -557,6 +557,502 @@ fn cannot_moderate_already_moderated_post() {
     });
 }
 
+// Forum moderator:
+// -----------------------------------------------------------------------------
+
+#[test]
+fn forum_moderator_can_create_category() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        registry::TestMembershipRegistryModule::add_moderator(&FORUM_MODERATOR_ORIGIN_ID);
+
+        assert_create_category(FORUM_MODERATOR_ORIGIN, None, Ok(()));
+    });
+}
+
+#[test]
+fn forum_moderator_can_create_category_when_forum_sudo_not_set() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        registry::TestMembershipRegistryModule::add_moderator(&FORUM_MODERATOR_ORIGIN_ID);
+        assert_ok!(TestForumModule::set_forum_sudo(
+            mock_origin(OriginType::Root),
+            None
+        ));
+
+        assert_create_category(FORUM_MODERATOR_ORIGIN, None, Ok(()));
+    });
+}
+
+#[test]
+fn forum_moderator_can_moderate_thread() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        registry::TestMembershipRegistryModule::add_moderator(&FORUM_MODERATOR_ORIGIN_ID);
+
+        let (_, _, thread_id) = create_root_category_and_thread(origin);
+        assert_eq!(
+            moderate_thread(FORUM_MODERATOR_ORIGIN, thread_id, good_rationale()),
+            Ok(())
+        );
+
+        let moderation = TestForumModule::thread_by_id(thread_id)
+            .moderation
+            .expect("Thread should be moderated");
+        assert_eq!(moderation.moderator_id, FORUM_MODERATOR_ORIGIN_ID);
+    });
+}
+
+#[test]
+fn forum_moderator_can_moderate_post() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        registry::TestMembershipRegistryModule::add_moderator(&FORUM_MODERATOR_ORIGIN_ID);
+
+        let (_, _, _, post_id) = create_root_category_and_thread_and_post(origin);
+        assert_eq!(
+            moderate_post(FORUM_MODERATOR_ORIGIN, post_id, good_rationale()),
+            Ok(())
+        );
+    });
+}
+
+// Category moderator:
+// -----------------------------------------------------------------------------
+
+#[test]
+fn category_moderator_can_moderate_thread_in_subcategory() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let root_category_id = create_root_category(origin.clone());
+        let subcategory_id = create_category(origin.clone(), Some(root_category_id));
+        assert_ok!(add_category_moderator(
+            origin,
+            root_category_id,
+            CATEGORY_MODERATOR_ORIGIN_ID
+        ));
+
+        let thread_id = TestForumModule::next_thread_id();
+        assert_create_thread(create_forum_member(), subcategory_id, Ok(()));
+
+        assert_eq!(
+            moderate_thread(CATEGORY_MODERATOR_ORIGIN, thread_id, good_rationale()),
+            Ok(())
+        );
+    });
+}
+
+#[test]
+fn category_moderator_can_moderate_post() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, category_id, _, post_id) = create_root_category_and_thread_and_post(origin.clone());
+        assert_ok!(add_category_moderator(
+            origin,
+            category_id,
+            CATEGORY_MODERATOR_ORIGIN_ID
+        ));
+
+        assert_eq!(
+            moderate_post(CATEGORY_MODERATOR_ORIGIN, post_id, good_rationale()),
+            Ok(())
+        );
+    });
+}
+
+#[test]
+fn category_moderator_cannot_moderate_thread_in_other_category() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, thread_id) = create_root_category_and_thread(origin.clone());
+        let other_category_id = create_root_category(origin.clone());
+        assert_ok!(add_category_moderator(
+            origin,
+            other_category_id,
+            CATEGORY_MODERATOR_ORIGIN_ID
+        ));
+
+        assert_eq!(
+            moderate_thread(CATEGORY_MODERATOR_ORIGIN, thread_id, good_rationale()),
+            Err(ERROR_ORIGIN_NOT_FORUM_SUDO)
+        );
+    });
+}
+
+#[test]
+fn removed_category_moderator_cannot_moderate_thread() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, category_id, thread_id) = create_root_category_and_thread(origin.clone());
+        assert_ok!(add_category_moderator(
+            origin.clone(),
+            category_id,
+            CATEGORY_MODERATOR_ORIGIN_ID
+        ));
+        assert_ok!(remove_category_moderator(
+            origin,
+            category_id,
+            CATEGORY_MODERATOR_ORIGIN_ID
+        ));
+
+        assert!(!<CategoryModerators<Runtime>>::contains_key(
+            category_id,
+            CATEGORY_MODERATOR_ORIGIN_ID
+        ));
+        assert_eq!(
+            moderate_thread(CATEGORY_MODERATOR_ORIGIN, thread_id, good_rationale()),
+            Err(ERROR_ORIGIN_NOT_FORUM_SUDO)
+        );
+    });
+}
+
+#[test]
+fn add_category_moderator_fails_with_invalid_category() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        assert_eq!(
+            add_category_moderator(origin, INVLAID_CATEGORY_ID, CATEGORY_MODERATOR_ORIGIN_ID),
+            Err(ERROR_CATEGORY_DOES_NOT_EXIST)
+        );
+    });
+}
+
+#[test]
+fn add_category_moderator_fails_when_already_moderator() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(origin.clone());
+        assert_ok!(add_category_moderator(
+            origin.clone(),
+            category_id,
+            CATEGORY_MODERATOR_ORIGIN_ID
+        ));
+
+        assert_eq!(
+            add_category_moderator(origin, category_id, CATEGORY_MODERATOR_ORIGIN_ID),
+            Err(ERROR_ACCOUNT_ALREADY_CATEGORY_MODERATOR)
+        );
+    });
+}
+
+#[test]
+fn remove_category_moderator_fails_when_not_moderator() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(origin.clone());
+
+        assert_eq!(
+            remove_category_moderator(origin, category_id, CATEGORY_MODERATOR_ORIGIN_ID),
+            Err(ERROR_ACCOUNT_NOT_CATEGORY_MODERATOR)
+        );
+    });
+}
+
+// Thread lifecycle:
+// -----------------------------------------------------------------------------
+
+#[test]
+fn locked_thread_does_not_accept_posts() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) = create_root_category_and_thread(origin.clone());
+
+        assert_ok!(update_thread(origin.clone(), thread_id, Some(true), None));
+        assert!(<LockedThreads<Runtime>>::contains_key(thread_id));
+        assert_create_post(member_origin.clone(), thread_id, Err(ERROR_THREAD_LOCKED));
+
+        assert_ok!(update_thread(origin, thread_id, Some(false), None));
+        assert!(!<LockedThreads<Runtime>>::contains_key(thread_id));
+        assert_create_post(member_origin, thread_id, Ok(()));
+    });
+}
+
+#[test]
+fn pinned_threads_are_tracked_per_category() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, category_id, first_thread_id) =
+            create_root_category_and_thread(origin.clone());
+        let second_thread_id = TestForumModule::next_thread_id();
+        assert_create_thread(member_origin, category_id, Ok(()));
+
+        assert_ok!(update_thread(
+            origin.clone(),
+            second_thread_id,
+            None,
+            Some(true)
+        ));
+        assert_ok!(update_thread(
+            origin.clone(),
+            first_thread_id,
+            None,
+            Some(true)
+        ));
+        // Pinning a pinned thread does not duplicate it.
+        assert_ok!(update_thread(
+            origin.clone(),
+            first_thread_id,
+            None,
+            Some(true)
+        ));
+        assert_eq!(
+            TestForumModule::pinned_thread_ids(category_id),
+            vec![second_thread_id, first_thread_id]
+        );
+
+        assert_ok!(update_thread(origin, second_thread_id, None, Some(false)));
+        assert_eq!(
+            TestForumModule::pinned_thread_ids(category_id),
+            vec![first_thread_id]
+        );
+    });
+}
+
+#[test]
+fn moderated_thread_is_unpinned() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, category_id, thread_id) = create_root_category_and_thread(origin.clone());
+        assert_ok!(update_thread(origin.clone(), thread_id, None, Some(true)));
+
+        assert_ok!(moderate_thread(origin, thread_id, good_rationale()));
+
+        assert!(TestForumModule::pinned_thread_ids(category_id).is_empty());
+    });
+}
+
+#[test]
+fn update_thread_fails_when_nothing_changes() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, thread_id) = create_root_category_and_thread(origin.clone());
+
+        assert_eq!(
+            update_thread(origin, thread_id, None, None),
+            Err(ERROR_THREAD_NOT_BEING_UPDATED)
+        );
+    });
+}
+
+#[test]
+fn move_thread_updates_category_counters() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, old_category_id, thread_id) =
+            create_root_category_and_thread(origin.clone());
+        let new_category_id = create_root_category(origin.clone());
+        assert_create_thread(member_origin, new_category_id, Ok(()));
+        assert_ok!(update_thread(origin.clone(), thread_id, None, Some(true)));
+
+        assert_ok!(move_thread(origin, thread_id, new_category_id));
+
+        let thread = TestForumModule::thread_by_id(thread_id);
+        assert_eq!(thread.category_id, new_category_id);
+        assert_eq!(thread.nr_in_category, 2);
+
+        let old_category = TestForumModule::category_by_id(old_category_id);
+        assert_eq!(old_category.num_direct_unmoderated_threads, 0);
+        assert_eq!(old_category.num_direct_moderated_threads, 0);
+        assert_eq!(TestForumModule::moved_out_threads_count(old_category_id), 1);
+        assert!(TestForumModule::pinned_thread_ids(old_category_id).is_empty());
+
+        let new_category = TestForumModule::category_by_id(new_category_id);
+        assert_eq!(new_category.num_direct_unmoderated_threads, 2);
+        assert_eq!(new_category.num_direct_moderated_threads, 0);
+        assert_eq!(TestForumModule::moved_out_threads_count(new_category_id), 0);
+    });
+}
+
+#[test]
+fn thread_created_after_move_keeps_moved_thread_slot() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, old_category_id, thread_id) =
+            create_root_category_and_thread(origin.clone());
+        let new_category_id = create_root_category(origin.clone());
+
+        assert_ok!(move_thread(origin, thread_id, new_category_id));
+
+        let new_thread_id = TestForumModule::next_thread_id();
+        assert_create_thread(member_origin, old_category_id, Ok(()));
+
+        let new_thread = TestForumModule::thread_by_id(new_thread_id);
+        assert_eq!(new_thread.nr_in_category, 2);
+
+        let old_category = TestForumModule::category_by_id(old_category_id);
+        assert_eq!(old_category.num_direct_unmoderated_threads, 1);
+        assert_eq!(old_category.num_direct_moderated_threads, 0);
+        assert_eq!(TestForumModule::moved_out_threads_count(old_category_id), 1);
+
+        let new_category = TestForumModule::category_by_id(new_category_id);
+        assert_eq!(new_category.num_direct_unmoderated_threads, 1);
+        assert_eq!(new_category.num_direct_moderated_threads, 0);
+        assert_eq!(TestForumModule::moved_out_threads_count(new_category_id), 0);
+    });
+}
+
+#[test]
+fn move_thread_fails_with_same_category() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, category_id, thread_id) = create_root_category_and_thread(origin.clone());
+
+        assert_eq!(
+            move_thread(origin, thread_id, category_id),
+            Err(ERROR_THREAD_ALREADY_IN_CATEGORY)
+        );
+    });
+}
+
+#[test]
+fn move_thread_fails_with_invalid_category() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, thread_id) = create_root_category_and_thread(origin.clone());
+
+        assert_eq!(
+            move_thread(origin, thread_id, INVLAID_CATEGORY_ID),
+            Err(ERROR_CATEGORY_DOES_NOT_EXIST)
+        );
+    });
+}
+
+#[test]
+fn category_moderator_cannot_move_thread_out_of_moderated_categories() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, category_id, thread_id) = create_root_category_and_thread(origin.clone());
+        let other_category_id = create_root_category(origin.clone());
+        assert_ok!(add_category_moderator(
+            origin,
+            category_id,
+            CATEGORY_MODERATOR_ORIGIN_ID
+        ));
+
+        assert_eq!(
+            move_thread(CATEGORY_MODERATOR_ORIGIN, thread_id, other_category_id),
+            Err(ERROR_ORIGIN_NOT_FORUM_SUDO)
+        );
+
+        let category = TestForumModule::category_by_id(category_id);
+        assert_eq!(category.num_direct_unmoderated_threads, 1);
+        assert_eq!(TestForumModule::moved_out_threads_count(category_id), 0);
+
+        let other_category = TestForumModule::category_by_id(other_category_id);
+        assert_eq!(other_category.num_direct_unmoderated_threads, 0);
+        assert_eq!(
+            TestForumModule::moved_out_threads_count(other_category_id),
+            0
+        );
+    });
+}
+
+#[test]
+fn author_can_delete_post() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id, post_id) =
+            create_root_category_and_thread_and_post(origin);
+
+        assert_ok!(delete_post(member_origin.clone(), post_id));
+
+        // The deleted post stays as a tombstone moderated by its author
+        let post = TestForumModule::post_by_id(post_id);
+        assert!(post.current_text.is_empty());
+        assert!(post.text_change_history.is_empty());
+        let moderation = post.moderation.expect("Deleted post is moderated");
+        assert_eq!(moderation.moderator_id, post.author_id);
+        assert_eq!(
+            moderation.rationale,
+            POST_DELETED_BY_AUTHOR_RATIONALE.to_vec()
+        );
+        assert_eq!(
+            delete_post(member_origin, post_id),
+            Err(ERROR_POST_MODERATED)
+        );
+
+        let thread = TestForumModule::thread_by_id(thread_id);
+        assert_eq!(thread.num_unmoderated_posts, 1);
+        assert_eq!(thread.num_moderated_posts, 1);
+    });
+}
+
+#[test]
+fn not_author_cannot_delete_post() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, _, post_id) = create_root_category_and_thread_and_post(origin);
+        let other_member_id = 321;
+        registry::TestMembershipRegistryModule::add_member(&registry::Member {
+            id: other_member_id,
+        });
+
+        assert_eq!(
+            delete_post(OriginType::Signed(other_member_id), post_id),
+            Err(ERROR_ACCOUNT_DOES_NOT_MATCH_POST_AUTHOR)
+        );
+    });
+}
+
+#[test]
+fn cannot_delete_moderated_post() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, _, post_id) =
+            create_root_category_and_thread_and_post(origin.clone());
+        assert_ok!(moderate_post(origin, post_id, good_rationale()));
+
+        assert_eq!(
+            delete_post(member_origin, post_id),
+            Err(ERROR_POST_MODERATED)
+        );
+    });
+}
+
 // Not a forum sudo:
 // -----------------------------------------------------------------------------
 
@@ -636,6 +1132,38 @@ fn not_forum_sudo_cannot_moderate_post() {
     });
 }
 
+#[test]
+fn not_forum_sudo_cannot_add_category_moderator() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(origin);
+        assert_eq!(
+            add_category_moderator(
+                NOT_FORUM_SUDO_ORIGIN,
+                category_id,
+                CATEGORY_MODERATOR_ORIGIN_ID
+            ),
+            Err(ERROR_ORIGIN_NOT_FORUM_SUDO)
+        );
+    });
+}
+
+#[test]
+fn not_forum_sudo_cannot_update_thread() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, thread_id) = create_root_category_and_thread(origin);
+        assert_eq!(
+            update_thread(NOT_FORUM_SUDO_ORIGIN, thread_id, Some(true), None),
+            Err(ERROR_ORIGIN_NOT_FORUM_SUDO)
+        );
+    });
+}
+
 // Not a member:
 // -----------------------------------------------------------------------------
 
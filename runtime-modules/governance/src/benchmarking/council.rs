@@ -0,0 +1,115 @@
+//! Council module benchmarking.
+
+use crate::council::*;
+use frame_benchmarking::{account, benchmarks};
+use sp_std::vec::Vec;
+use system::RawOrigin;
+
+const SEED: u32 = 0;
+const MAX_COUNCIL_SIZE: u32 = 20;
+
+fn create_council_mint<T: Trait>() {
+    let mint_id = <minting::Module<T>>::add_mint(minting::BalanceOf::<T>::from(1_000_000u32), None)
+        .expect("council mint created");
+
+    <CouncilMint<T>>::put(mint_id);
+}
+
+fn councilors<T: Trait>(number: u32) -> Vec<T::AccountId> {
+    (0..number).map(|i| account("councilor", i, SEED)).collect()
+}
+
+fn set_council_with_size<T: Trait>(size: u32) {
+    <Module<T>>::set_council(RawOrigin::Root.into(), councilors::<T>(size)).expect("council set");
+}
+
+benchmarks! {
+    _ { }
+
+    set_council {
+        let c in 1 .. MAX_COUNCIL_SIZE;
+
+        create_council_mint::<T>();
+        set_council_with_size::<T>(MAX_COUNCIL_SIZE);
+
+        let accounts: Vec<T::AccountId> = (0..c).map(|i| account("new_councilor", i, SEED)).collect();
+    }: _(RawOrigin::Root, accounts)
+    verify {
+        assert_eq!(<Module<T>>::active_council().len() as u32, c);
+    }
+
+    add_council_member {
+        create_council_mint::<T>();
+        set_council_with_size::<T>(MAX_COUNCIL_SIZE - 1);
+
+        let new_councilor: T::AccountId = account("new_councilor", 0, SEED);
+    }: _(RawOrigin::Root, new_councilor.clone())
+    verify {
+        assert!(<Module<T>>::is_councilor(&new_councilor));
+    }
+
+    remove_council_member {
+        create_council_mint::<T>();
+        set_council_with_size::<T>(MAX_COUNCIL_SIZE);
+
+        let removed_councilor: T::AccountId = account("councilor", 0, SEED);
+    }: _(RawOrigin::Root, removed_councilor.clone())
+    verify {
+        assert!(!<Module<T>>::is_councilor(&removed_councilor));
+    }
+
+    set_term_ends_at {
+        let ends_at = <system::Module<T>>::block_number() + T::BlockNumber::from(100u32);
+    }: _(RawOrigin::Root, ends_at)
+    verify {
+        assert_eq!(<Module<T>>::term_ends_at(), ends_at);
+    }
+
+    set_council_mint_capacity {
+        create_council_mint::<T>();
+
+        let capacity = minting::BalanceOf::<T>::from(2_000_000u32);
+    }: _(RawOrigin::Root, capacity)
+    verify {
+        assert_eq!(
+            <minting::Module<T>>::get_mint_capacity(<Module<T>>::council_mint()),
+            Ok(capacity)
+        );
+    }
+
+    spend_from_council_mint {
+        create_council_mint::<T>();
+
+        let destination: T::AccountId = account("destination", 0, SEED);
+        let amount = minting::BalanceOf::<T>::from(1_000u32);
+    }: _(RawOrigin::Root, amount, destination)
+
+    set_council_rewards {
+        let amount_per_payout = minting::BalanceOf::<T>::from(100u32);
+        let payout_interval = Some(T::BlockNumber::from(10u32));
+        let first_payout_after_reward_created = T::BlockNumber::from(10u32);
+    }: _(RawOrigin::Root, amount_per_payout, payout_interval, first_payout_after_reward_created)
+    verify {
+        assert_eq!(<Module<T>>::amount_per_payout(), amount_per_payout);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{initial_test_ext, Test};
+    use frame_support::assert_ok;
+
+    #[test]
+    fn council_benchmarks_run_successfully() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_set_council::<Test>());
+            assert_ok!(test_benchmark_add_council_member::<Test>());
+            assert_ok!(test_benchmark_remove_council_member::<Test>());
+            assert_ok!(test_benchmark_set_term_ends_at::<Test>());
+            assert_ok!(test_benchmark_set_council_mint_capacity::<Test>());
+            assert_ok!(test_benchmark_spend_from_council_mint::<Test>());
+            assert_ok!(test_benchmark_set_council_rewards::<Test>());
+        });
+    }
+}
@@ -0,0 +1,218 @@
+//! Election module benchmarking.
+
+use crate::election::*;
+use crate::election_params::ElectionParameters;
+use codec::Encode;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::{Currency, UnfilteredDispatchable};
+use sp_runtime::traits::Hash;
+use sp_std::vec;
+use sp_std::vec::Vec;
+use system::RawOrigin;
+
+const SEED: u32 = 0;
+const STAKE: u32 = 1000;
+const PERIOD: u32 = 100;
+const MAX_SALT_LENGTH: u32 = 32;
+// Number of the applicants, each backed by a voter, in the running election.
+const APPLICANTS: u32 = 10;
+
+fn election_parameters<T: Trait>() -> ElectionParameters<BalanceOf<T>, T::BlockNumber> {
+    ElectionParameters {
+        announcing_period: PERIOD.into(),
+        voting_period: PERIOD.into(),
+        revealing_period: PERIOD.into(),
+        council_size: APPLICANTS,
+        candidacy_limit: APPLICANTS,
+        new_term_duration: PERIOD.into(),
+        min_council_stake: STAKE.into(),
+        min_voting_stake: STAKE.into(),
+    }
+}
+
+// Dispatches the call, as the stage setters and the voting calls are private.
+fn dispatch<T: Trait>(call: Call<T>, origin: RawOrigin<T::AccountId>) {
+    call.dispatch_bypass_filter(origin.into())
+        .expect("call dispatched");
+}
+
+fn stage_ends_at<T: Trait>() -> T::BlockNumber {
+    <system::Module<T>>::block_number() + PERIOD.into()
+}
+
+// Creates the member with the balance to stake.
+fn create_member<T: Trait>(name: &'static str, index: u32) -> T::AccountId {
+    let account_id: T::AccountId = account(name, index, SEED);
+    <membership::Module<T>>::insert_benchmark_member(&account_id);
+
+    let balance: BalanceOf<T> = (2 * STAKE).into();
+    let _ = <T as GovernanceCurrency>::Currency::deposit_creating(&account_id, balance);
+
+    account_id
+}
+
+// Sets the election parameters and starts the election in the announcing stage.
+fn start_election<T: Trait>() {
+    dispatch::<T>(
+        Call::set_election_parameters(election_parameters::<T>()),
+        RawOrigin::Root,
+    );
+    dispatch::<T>(Call::force_start_election(), RawOrigin::Root);
+}
+
+// Applies with the minimum stake. Requires the announcing stage.
+fn apply<T: Trait>(index: u32) -> T::AccountId {
+    let account_id = create_member::<T>("applicant", index);
+    dispatch::<T>(
+        Call::apply(STAKE.into()),
+        RawOrigin::Signed(account_id.clone()),
+    );
+
+    account_id
+}
+
+fn make_commitment<T: Trait>(applicant: &T::AccountId, salt: &[u8]) -> T::Hash {
+    let mut payload = applicant.encode();
+    payload.extend_from_slice(salt);
+
+    <T as system::Trait>::Hashing::hash(&payload)
+}
+
+// Votes for the applicant with the minimum stake. Requires the voting stage.
+fn vote<T: Trait>(index: u32, applicant: &T::AccountId, salt: &[u8]) -> (T::AccountId, T::Hash) {
+    let account_id = create_member::<T>("voter", index);
+    let commitment = make_commitment::<T>(applicant, salt);
+    dispatch::<T>(
+        Call::vote(commitment, STAKE.into()),
+        RawOrigin::Signed(account_id.clone()),
+    );
+
+    (account_id, commitment)
+}
+
+benchmarks! {
+    _ { }
+
+    // The new applicant goes before the other applicants.
+    apply {
+        start_election::<T>();
+        for index in 1..APPLICANTS {
+            apply::<T>(index);
+        }
+
+        let account_id = create_member::<T>("applicant", 0);
+        let stake: BalanceOf<T> = STAKE.into();
+    }: _(RawOrigin::Signed(account_id.clone()), stake)
+    verify {
+        assert!(Module::<T>::applicants().contains(&account_id));
+    }
+
+    vote {
+        start_election::<T>();
+        let applicant = apply::<T>(0);
+        dispatch::<T>(Call::set_stage_voting(stage_ends_at::<T>()), RawOrigin::Root);
+
+        let account_id = create_member::<T>("voter", 0);
+        let commitment = make_commitment::<T>(&applicant, &[]);
+        let stake: BalanceOf<T> = STAKE.into();
+    }: _(RawOrigin::Signed(account_id), commitment, stake)
+    verify {
+        assert!(Module::<T>::commitments().contains(&commitment));
+    }
+
+    reveal {
+        let s in 0 .. MAX_SALT_LENGTH;
+
+        start_election::<T>();
+        let applicant = apply::<T>(0);
+        dispatch::<T>(Call::set_stage_voting(stage_ends_at::<T>()), RawOrigin::Root);
+
+        let salt = vec![b'a'; s as usize];
+        let (account_id, commitment) = vote::<T>(0, &applicant, &salt);
+        dispatch::<T>(Call::set_stage_revealing(stage_ends_at::<T>()), RawOrigin::Root);
+    }: _(RawOrigin::Signed(account_id), commitment, applicant, salt)
+    verify {
+        assert!(Module::<T>::votes(commitment).is_revealed());
+    }
+
+    set_stage_announcing {
+        let ends_at = stage_ends_at::<T>();
+    }: _(RawOrigin::Root, ends_at)
+    verify {
+        assert_eq!(Module::<T>::stage_ends_at(), Some(ends_at));
+    }
+
+    set_stage_revealing {
+        let ends_at = stage_ends_at::<T>();
+    }: _(RawOrigin::Root, ends_at)
+    verify {
+        assert_eq!(Module::<T>::stage_ends_at(), Some(ends_at));
+    }
+
+    set_stage_voting {
+        let ends_at = stage_ends_at::<T>();
+    }: _(RawOrigin::Root, ends_at)
+    verify {
+        assert_eq!(Module::<T>::stage_ends_at(), Some(ends_at));
+    }
+
+    set_election_parameters {
+        let params = election_parameters::<T>();
+    }: _(RawOrigin::Root, params)
+    verify {
+        assert_eq!(Module::<T>::council_size(), APPLICANTS);
+    }
+
+    // The stakes of all the applicants and the voters get refunded.
+    force_stop_election {
+        start_election::<T>();
+        let applicants: Vec<T::AccountId> = (0..APPLICANTS).map(apply::<T>).collect();
+        dispatch::<T>(Call::set_stage_voting(stage_ends_at::<T>()), RawOrigin::Root);
+
+        for (index, applicant) in applicants.iter().enumerate() {
+            vote::<T>(index as u32, applicant, &[]);
+        }
+    }: _(RawOrigin::Root)
+    verify {
+        assert!(!Module::<T>::is_election_running());
+    }
+
+    force_start_election {
+        dispatch::<T>(
+            Call::set_election_parameters(election_parameters::<T>()),
+            RawOrigin::Root,
+        );
+    }: _(RawOrigin::Root)
+    verify {
+        assert!(Module::<T>::is_election_running());
+    }
+
+    set_auto_start {
+    }: _(RawOrigin::Root, false)
+    verify {
+        assert!(!Module::<T>::auto_start());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{initial_test_ext, Test};
+    use frame_support::assert_ok;
+
+    #[test]
+    fn election_benchmarks_run_successfully() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_apply::<Test>());
+            assert_ok!(test_benchmark_vote::<Test>());
+            assert_ok!(test_benchmark_reveal::<Test>());
+            assert_ok!(test_benchmark_set_stage_announcing::<Test>());
+            assert_ok!(test_benchmark_set_stage_revealing::<Test>());
+            assert_ok!(test_benchmark_set_stage_voting::<Test>());
+            assert_ok!(test_benchmark_set_election_parameters::<Test>());
+            assert_ok!(test_benchmark_force_stop_election::<Test>());
+            assert_ok!(test_benchmark_force_start_election::<Test>());
+            assert_ok!(test_benchmark_set_auto_start::<Test>());
+        });
+    }
+}
@@ -0,0 +1,4 @@
+//! Governance modules benchmarking.
+
+mod council;
+mod election;
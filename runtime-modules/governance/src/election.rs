@@ -34,6 +34,7 @@ use serde::{Deserialize, Serialize};
 
 use codec::{Decode, Encode};
 use frame_support::traits::{Currency, ReservableCurrency};
+use frame_support::weights::Weight;
 use frame_support::{decl_event, decl_module, decl_storage, ensure};
 use sp_arithmetic::traits::Zero;
 use sp_runtime::traits::Hash;
@@ -52,10 +53,61 @@ pub use common::currency::{BalanceOf, GovernanceCurrency};
 
 use crate::DispatchResult;
 
+/// Extrinsic weights for the election module.
+pub trait WeightInfo {
+    fn apply() -> Weight;
+    fn vote() -> Weight;
+    fn reveal(s: u32) -> Weight;
+    fn set_stage_announcing() -> Weight;
+    fn set_stage_revealing() -> Weight;
+    fn set_stage_voting() -> Weight;
+    fn set_election_parameters() -> Weight;
+    fn force_stop_election() -> Weight;
+    fn force_start_election() -> Weight;
+    fn set_auto_start() -> Weight;
+}
+
+// Default weights, used in the tests and by the runtimes without benchmarked weights.
+impl WeightInfo for () {
+    fn apply() -> Weight {
+        10_000_000
+    }
+    fn vote() -> Weight {
+        10_000_000
+    }
+    fn reveal(_s: u32) -> Weight {
+        10_000_000
+    }
+    fn set_stage_announcing() -> Weight {
+        10_000_000
+    }
+    fn set_stage_revealing() -> Weight {
+        10_000_000
+    }
+    fn set_stage_voting() -> Weight {
+        10_000_000
+    }
+    fn set_election_parameters() -> Weight {
+        10_000_000
+    }
+    fn force_stop_election() -> Weight {
+        10_000_000
+    }
+    fn force_start_election() -> Weight {
+        10_000_000
+    }
+    fn set_auto_start() -> Weight {
+        10_000_000
+    }
+}
+
 pub trait Trait: system::Trait + council::Trait + GovernanceCurrency + membership::Trait {
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
 
     type CouncilElected: CouncilElected<Seats<Self::AccountId, BalanceOf<Self>>, Self::BlockNumber>;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
 }
 
 pub static MSG_CANNOT_CHANGE_PARAMS_DURING_ELECTION: &str = "CannotChangeParamsDuringElection";
@@ -816,7 +868,7 @@ decl_module! {
 
         // Member can apply during announcing stage only. On first call a minimum stake will need to be provided.
         // Member can make subsequent calls during announcing stage to increase their stake.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::apply()]
         pub fn apply(origin, stake: BalanceOf<T>) {
             let sender = ensure_signed(origin)?;
             ensure!(Self::can_participate(&sender), "Only members can apply to be on council");
@@ -840,7 +892,7 @@ decl_module! {
             Self::deposit_event(RawEvent::Applied(sender));
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::vote()]
         fn vote(origin, commitment: T::Hash, stake: BalanceOf<T>) {
             let sender = ensure_signed(origin)?;
             ensure!(Self::can_participate(&sender), "Only members can vote for an applicant");
@@ -859,7 +911,7 @@ decl_module! {
             Self::deposit_event(RawEvent::Voted(sender, commitment));
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::reveal(salt.len() as u32)]
         fn reveal(origin, commitment: T::Hash, vote: T::AccountId, salt: Vec<u8>) {
             let sender = ensure_signed(origin)?;
 
@@ -878,21 +930,21 @@ decl_module! {
             Self::deposit_event(RawEvent::Revealed(sender, commitment, vote));
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::set_stage_announcing()]
         fn set_stage_announcing(origin, ends_at: T::BlockNumber) {
             ensure_root(origin)?;
             ensure!(ends_at > <system::Module<T>>::block_number(), "must end at future block number");
             <Stage<T>>::put(ElectionStage::Announcing(ends_at));
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::set_stage_revealing()]
         fn set_stage_revealing(origin, ends_at: T::BlockNumber) {
             ensure_root(origin)?;
             ensure!(ends_at > <system::Module<T>>::block_number(), "must end at future block number");
             <Stage<T>>::put(ElectionStage::Revealing(ends_at));
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::set_stage_voting()]
         fn set_stage_voting(origin, ends_at: T::BlockNumber) {
             ensure_root(origin)?;
             ensure!(ends_at > <system::Module<T>>::block_number(), "must end at future block number");
@@ -904,7 +956,7 @@ decl_module! {
         /// The call will fail if an election is in progress. If a council is not being elected for some
         /// reaon after multiple rounds, force_stop_election() can be called to stop elections and followed by
         /// set_election_parameters().
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::set_election_parameters()]
         pub fn set_election_parameters(origin, params: ElectionParameters<BalanceOf<T>, T::BlockNumber>) {
             ensure_root(origin)?;
             ensure!(!Self::is_election_running(), MSG_CANNOT_CHANGE_PARAMS_DURING_ELECTION);
@@ -912,7 +964,7 @@ decl_module! {
             Self::set_verified_election_parameters(params);
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::force_stop_election()]
         fn force_stop_election(origin) {
             ensure_root(origin)?;
             ensure!(Self::is_election_running(), "only running election can be stopped");
@@ -932,13 +984,13 @@ decl_module! {
             );
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::force_start_election()]
         pub fn force_start_election(origin) {
             ensure_root(origin)?;
             Self::start_election(<council::Module<T>>::active_council())?;
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::set_auto_start()]
         fn set_auto_start (origin, flag: bool) {
             ensure_root(origin)?;
             AutoStart::put(flag);
@@ -8,6 +8,9 @@ pub mod election_params;
 mod sealed_vote;
 mod stake;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 mod mock;
 
 //TODO: Convert errors to the Substrate decl_error! macro.
@@ -63,11 +63,13 @@ impl council::Trait for Test {
     type Event = ();
 
     type CouncilTermEnded = (Election,);
+    type WeightInfo = ();
 }
 impl election::Trait for Test {
     type Event = ();
 
     type CouncilElected = (Council,);
+    type WeightInfo = ();
 }
 impl membership::Trait for Test {
     type Event = ();
@@ -75,15 +77,21 @@ impl membership::Trait for Test {
     type SubscriptionId = u32;
     type PaidTermId = u32;
     type ActorId = u32;
+    type WeightInfo = ();
 }
 impl minting::Trait for Test {
     type Currency = Balances;
     type MintId = u64;
 }
+parameter_types! {
+    pub const MaxPayoutsPerBlock: u32 = 100;
+}
 impl recurringrewards::Trait for Test {
+    type Event = ();
     type PayoutStatusHandler = ();
     type RecipientId = u64;
     type RewardRelationshipId = u64;
+    type MaxPayoutsPerBlock = MaxPayoutsPerBlock;
 }
 parameter_types! {
     pub const ExistentialDeposit: u32 = 0;
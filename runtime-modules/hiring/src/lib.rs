@@ -25,8 +25,9 @@ use mockall::predicate::*;
 use mockall::*;
 
 use codec::Codec;
-use frame_support::storage::IterableStorageMap;
-use frame_support::traits::{Currency, Imbalance};
+use frame_support::storage::{IterableStorageDoubleMap, IterableStorageMap};
+use frame_support::traits::{Currency, Get, Imbalance};
+use frame_support::weights::Weight;
 use frame_support::{decl_module, decl_storage, ensure, Parameter};
 use sp_arithmetic::traits::{BaseArithmetic, One, Zero};
 use sp_runtime::traits::{MaybeSerialize, Member};
@@ -47,6 +48,9 @@ mod test;
 
 pub use hiring::*;
 
+// Storage version with the stage transitions of the existing openings scheduled.
+const STORAGE_VERSION: u32 = 1;
+
 /// Main trait of hiring substrate module
 pub trait Trait: system::Trait + stake::Trait + Sized {
     /// OpeningId type
@@ -74,6 +78,10 @@ pub trait Trait: system::Trait + stake::Trait + Sized {
 
     /// Marker type for Stake module handler. Indicates that hiring module uses stake module mock.
     type StakeHandlerProvider: StakeHandlerProvider<Self>;
+
+    /// Number of blocks the inactive applications of a deactivated opening keep their texts for
+    /// before being archived.
+    type ApplicationsRetentionPeriod: Get<Self::BlockNumber>;
 }
 
 decl_storage! {
@@ -95,6 +103,22 @@ decl_storage! {
         /// Internal purpose of given stake, i.e. fro what application, and whether for the role or for the application.
         pub ApplicationIdByStakingId get(fn stake_purpose_by_staking_id): map hasher(blake2_128_concat)
             T::StakeId => T::ApplicationId;
+
+        /// Openings waiting to begin, indexed by the block they begin accepting applications at.
+        pub OpeningsScheduledToBegin get(fn openings_scheduled_to_begin): double_map
+            hasher(twox_64_concat) T::BlockNumber, hasher(blake2_128_concat) T::OpeningId => ();
+
+        /// Openings in the review period, indexed by the block their review period expires at.
+        pub OpeningsScheduledForReviewExpiry get(fn openings_scheduled_for_review_expiry): double_map
+            hasher(twox_64_concat) T::BlockNumber, hasher(blake2_128_concat) T::OpeningId => ();
+
+        /// Deactivated openings, indexed by the block their inactive applications are archived at.
+        pub OpeningsScheduledForApplicationsArchiving get(fn openings_scheduled_for_applications_archiving):
+            double_map hasher(twox_64_concat) T::BlockNumber, hasher(blake2_128_concat) T::OpeningId => ();
+
+        /// Version of the module storage. The older versions are updated by
+        /// `schedule_opening_stage_transitions`.
+        pub StorageVersion get(fn storage_version): u32;
     }
 }
 
@@ -156,6 +180,12 @@ decl_module! {
                     });
 
                 <OpeningById<T>>::insert(opening_id, deactivated_opening);
+                Self::schedule_applications_archiving(opening_id);
+            }
+
+            // Archive inactive applications of the deactivated openings
+            for (opening_id, _) in <OpeningsScheduledForApplicationsArchiving<T>>::drain_prefix(now) {
+                Self::archive_opening_applications(opening_id);
             }
         }
     }
@@ -205,6 +235,11 @@ impl<T: Trait> Module<T> {
         // Get Id for new opening
         let new_opening_id = <NextOpeningId<T>>::get();
 
+        // Schedule the opening activation
+        if let hiring::OpeningStage::WaitingToBegin { begins_at_block } = new_opening.stage {
+            <OpeningsScheduledToBegin<T>>::insert(begins_at_block, new_opening_id, ());
+        }
+
         // Insert opening in storage
         <OpeningById<T>>::insert(new_opening_id, new_opening);
 
@@ -275,6 +310,7 @@ impl<T: Trait> Module<T> {
         };
 
         OpeningById::<T>::insert(opening_id, new_opening);
+        Self::schedule_applications_archiving(opening_id);
 
         // Map with applications
         let applications_map = Self::application_id_iter_to_map(applications_added.iter());
@@ -355,6 +391,8 @@ impl<T: Trait> Module<T> {
         //
 
         let current_block_height = <system::Module<T>>::block_number();
+        let review_period_expires_at_block =
+            current_block_height + opening.max_review_period_length;
 
         let new_opening =
             opening.clone_with_new_active_opening_stage(hiring::ActiveOpeningStage::ReviewPeriod {
@@ -365,6 +403,13 @@ impl<T: Trait> Module<T> {
         // Update to new opening
         <OpeningById<T>>::insert(opening_id, new_opening);
 
+        // Schedule the review period expiration
+        <OpeningsScheduledForReviewExpiry<T>>::insert(
+            review_period_expires_at_block,
+            opening_id,
+            (),
+        );
+
         Ok(())
     }
 
@@ -510,6 +555,7 @@ impl<T: Trait> Module<T> {
 
         // Write back new opening
         <OpeningById<T>>::insert(opening_id, new_opening);
+        Self::schedule_applications_archiving(opening_id);
 
         // DONE
         Ok(())
@@ -817,6 +863,16 @@ impl<T: Trait> Module<T> {
                 opening.change_opening_stage_after_application_unstaked();
             });
 
+            // Applications of the deactivated opening can be archived after the last unstaking
+            if let hiring::OpeningStage::Active {
+                stage: hiring::ActiveOpeningStage::Deactivated { .. },
+                unstaking_application_count: 0,
+                ..
+            } = <OpeningById<T>>::get(application.opening_id).stage
+            {
+                Self::schedule_applications_archiving(application.opening_id);
+            }
+
             // Call handler
             T::ApplicationDeactivatedHandler::deactivated(&application_id, cause);
             return UnstakedResult::Unstaked;
@@ -824,6 +880,62 @@ impl<T: Trait> Module<T> {
 
         UnstakedResult::UnstakingInProgress
     }
+
+    /// Schedules stage transitions and applications archiving of the existing openings. Should be
+    /// called on the migration to the runtime version with the scheduled opening stage transitions.
+    /// Runs once: the transitions are already scheduled if the storage version is current.
+    /// Returns the weight of the migration.
+    pub fn schedule_opening_stage_transitions() -> Weight {
+        if Self::storage_version() >= STORAGE_VERSION {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let current_block_height = <system::Module<T>>::block_number();
+        let mut openings_count: Weight = 0;
+        let mut scheduled_count: Weight = 0;
+
+        for (opening_id, opening) in <OpeningById<T>>::iter() {
+            openings_count += 1;
+
+            match opening.stage {
+                hiring::OpeningStage::WaitingToBegin { begins_at_block } => {
+                    if begins_at_block >= current_block_height {
+                        <OpeningsScheduledToBegin<T>>::insert(begins_at_block, opening_id, ());
+                        scheduled_count += 1;
+                    }
+                }
+                hiring::OpeningStage::Active { stage, .. } => match stage {
+                    hiring::ActiveOpeningStage::ReviewPeriod {
+                        started_review_period_at_block,
+                        ..
+                    } => {
+                        let review_period_expires_at_block =
+                            started_review_period_at_block + opening.max_review_period_length;
+
+                        if review_period_expires_at_block >= current_block_height {
+                            <OpeningsScheduledForReviewExpiry<T>>::insert(
+                                review_period_expires_at_block,
+                                opening_id,
+                                (),
+                            );
+                            scheduled_count += 1;
+                        }
+                    }
+                    hiring::ActiveOpeningStage::Deactivated { .. } => {
+                        Self::schedule_applications_archiving(opening_id);
+                        scheduled_count += 1;
+                    }
+                    hiring::ActiveOpeningStage::AcceptingApplications { .. } => {}
+                },
+            }
+        }
+
+        StorageVersion::put(STORAGE_VERSION);
+
+        // Reads: the version, the block number and the openings. Writes: the version and
+        // the scheduled transitions.
+        T::DbWeight::get().reads_writes(2 + openings_count, 1 + scheduled_count)
+    }
 }
 
 /*
@@ -993,7 +1105,7 @@ impl<T: Trait> Module<T> {
             .collect::<BTreeMap<_, _>>()
     }
 
-    // Compute iterator of openings waiting to begin
+    // Compute iterator of openings waiting to begin, scheduled for the block
     fn openings_waiting_to_begin_iterator(
         now: T::BlockNumber,
     ) -> impl Iterator<
@@ -1002,7 +1114,14 @@ impl<T: Trait> Module<T> {
             Opening<BalanceOf<T>, T::BlockNumber, T::ApplicationId>,
         ),
     > {
-        <OpeningById<T>>::iter().filter_map(move |(opening_id, opening)| {
+        <OpeningsScheduledToBegin<T>>::drain_prefix(now).filter_map(move |(opening_id, _)| {
+            // Opening could have been started manually or removed
+            if !<OpeningById<T>>::contains_key(opening_id) {
+                return None;
+            }
+
+            let opening = <OpeningById<T>>::get(opening_id);
+
             if let hiring::OpeningStage::WaitingToBegin { begins_at_block } = opening.stage {
                 if begins_at_block == now {
                     Some((opening_id, opening))
@@ -1015,7 +1134,7 @@ impl<T: Trait> Module<T> {
         })
     }
 
-    // Compute iterator of openings in expired review period
+    // Compute iterator of openings in expired review period, scheduled for the block
     fn openings_expired_review_period_iterator(
         now: T::BlockNumber,
     ) -> impl Iterator<
@@ -1025,38 +1144,89 @@ impl<T: Trait> Module<T> {
             (BTreeSet<T::ApplicationId>, T::BlockNumber, T::BlockNumber),
         ),
     > {
-        <OpeningById<T>>::iter().filter_map(move |(opening_id, opening)| {
-            if let hiring::OpeningStage::Active {
-                ref stage,
-                ref applications_added,
-                ..
-            } = opening.stage
-            {
-                if let hiring::ActiveOpeningStage::ReviewPeriod {
-                    ref started_accepting_applicants_at_block,
-                    ref started_review_period_at_block,
-                } = stage
+        <OpeningsScheduledForReviewExpiry<T>>::drain_prefix(now).filter_map(
+            move |(opening_id, _)| {
+                // Opening could have been filled or cancelled
+                if !<OpeningById<T>>::contains_key(opening_id) {
+                    return None;
+                }
+
+                let opening = <OpeningById<T>>::get(opening_id);
+
+                if let hiring::OpeningStage::Active {
+                    ref stage,
+                    ref applications_added,
+                    ..
+                } = opening.stage
                 {
-                    if now == opening.max_review_period_length + *started_review_period_at_block {
-                        Some((
-                            opening_id,
-                            opening.clone(),
-                            (
-                                applications_added.clone(),
-                                *started_accepting_applicants_at_block,
-                                *started_review_period_at_block,
-                            ),
-                        ))
+                    if let hiring::ActiveOpeningStage::ReviewPeriod {
+                        ref started_accepting_applicants_at_block,
+                        ref started_review_period_at_block,
+                    } = stage
+                    {
+                        if now == opening.max_review_period_length + *started_review_period_at_block
+                        {
+                            Some((
+                                opening_id,
+                                opening.clone(),
+                                (
+                                    applications_added.clone(),
+                                    *started_accepting_applicants_at_block,
+                                    *started_review_period_at_block,
+                                ),
+                            ))
+                        } else {
+                            None
+                        }
                     } else {
                         None
                     }
                 } else {
                     None
                 }
-            } else {
-                None
+            },
+        )
+    }
+}
+
+// Applications archiving methods.
+impl<T: Trait> Module<T> {
+    // Schedules archiving of the deactivated opening applications after the retention period.
+    fn schedule_applications_archiving(opening_id: T::OpeningId) {
+        let current_block_height = <system::Module<T>>::block_number();
+
+        let archiving_block = (current_block_height + T::ApplicationsRetentionPeriod::get())
+            .max(current_block_height + One::one());
+
+        <OpeningsScheduledForApplicationsArchiving<T>>::insert(archiving_block, opening_id, ());
+    }
+
+    // Archives inactive applications of the deactivated opening: removes their texts and keeps
+    // the records, as the applications are still referenced by the working groups.
+    // Openings with unstaking applications are skipped: they are scheduled again
+    // when the last application is unstaked.
+    fn archive_opening_applications(opening_id: T::OpeningId) {
+        if !<OpeningById<T>>::contains_key(opening_id) {
+            return;
+        }
+
+        let opening = <OpeningById<T>>::get(opening_id);
+
+        if let hiring::OpeningStage::Active {
+            stage: hiring::ActiveOpeningStage::Deactivated { .. },
+            applications_added,
+            unstaking_application_count: 0,
+            ..
+        } = opening.stage
+        {
+            for application_id in applications_added {
+                <ApplicationById<T>>::mutate(application_id, |application| {
+                    if let hiring::ApplicationStage::Inactive { .. } = application.stage {
+                        application.human_readable_text = Vec::new();
+                    }
+                });
             }
-        })
+        }
     }
 }
 
@@ -71,11 +71,16 @@ impl balances::Trait for Test {
     type AccountStore = System;
 }
 
+parameter_types! {
+    pub const ApplicationsRetentionPeriod: u64 = 10;
+}
+
 impl Trait for Test {
     type OpeningId = u64;
     type ApplicationId = u64;
     type ApplicationDeactivatedHandler = TestApplicationDeactivatedHandler;
     type StakeHandlerProvider = TestStakeHandlerProvider;
+    type ApplicationsRetentionPeriod = ApplicationsRetentionPeriod;
 }
 
 impl stake::Trait for Test {
@@ -1,7 +1,7 @@
 use crate::mock::*;
 use crate::test::*;
 
-use frame_support::traits::{OnFinalize, OnInitialize};
+use frame_support::traits::{Get, OnFinalize, OnInitialize};
 
 // Recommendation from Parity on testing on_finalize
 // https://substrate.dev/docs/en/next/development/module/tests
@@ -175,3 +175,130 @@ fn on_finalize_should_deactivate_application_with_review_period_expired_cause()
         }
     });
 }
+
+#[test]
+fn on_finalize_should_skip_opening_started_before_scheduled_block() {
+    build_test_externalities().execute_with(|| {
+        let opening_activation_block = 2;
+        let mut opening_fixture = AddOpeningFixture::default();
+        opening_fixture.activate_at = ActivateOpeningAt::ExactBlock(opening_activation_block);
+        let opening_id = opening_fixture.add_opening().unwrap();
+
+        assert!(<OpeningsScheduledToBegin<Test>>::contains_key(
+            opening_activation_block,
+            opening_id
+        ));
+
+        assert!(Hiring::begin_accepting_applications(opening_id).is_ok());
+        let started_opening = <OpeningById<Test>>::get(opening_id);
+
+        run_to_block_and_finalize(opening_activation_block);
+
+        assert_eq!(<OpeningById<Test>>::get(opening_id), started_opening);
+        assert!(!<OpeningsScheduledToBegin<Test>>::contains_key(
+            opening_activation_block,
+            opening_id
+        ));
+    });
+}
+
+#[test]
+fn on_finalize_should_skip_cancelled_opening_on_review_period_expiration() {
+    build_test_externalities().execute_with(|| {
+        let mut opening_fixture = AddOpeningFixture::default();
+        opening_fixture.max_review_period_length = 2;
+        let opening_id = opening_fixture.add_opening().unwrap();
+
+        assert!(Hiring::begin_review(opening_id).is_ok());
+        assert!(<OpeningsScheduledForReviewExpiry<Test>>::contains_key(
+            opening_fixture.max_review_period_length,
+            opening_id
+        ));
+
+        assert!(Hiring::cancel_opening(opening_id, None, None).is_ok());
+        let cancelled_opening = <OpeningById<Test>>::get(opening_id);
+
+        run_to_block_and_finalize(opening_fixture.max_review_period_length + 1);
+
+        assert_eq!(<OpeningById<Test>>::get(opening_id), cancelled_opening);
+    });
+}
+
+#[test]
+fn on_finalize_should_archive_inactive_applications_after_retention_period() {
+    build_test_externalities().execute_with(|| {
+        let mut opening_fixture = AddOpeningFixture::default();
+        opening_fixture.max_review_period_length = 2;
+        let opening_id = opening_fixture.add_opening().unwrap();
+
+        let application_fixture = AddApplicationFixture::default_for_opening(opening_id);
+        let application_id = application_fixture
+            .add_application()
+            .unwrap()
+            .application_id_added;
+
+        assert!(Hiring::begin_review(opening_id).is_ok());
+
+        let deactivation_block = opening_fixture.max_review_period_length;
+        let archiving_block = deactivation_block + ApplicationsRetentionPeriod::get();
+
+        run_to_block_and_finalize(archiving_block - 1);
+        let application = <ApplicationById<Test>>::get(application_id);
+        assert_eq!(
+            application.human_readable_text,
+            application_fixture.human_readable_text
+        );
+
+        run_to_block_and_finalize(archiving_block);
+
+        // The archived application is kept without its text.
+        assert!(<ApplicationById<Test>>::contains_key(application_id));
+        let archived_application = <ApplicationById<Test>>::get(application_id);
+        assert!(archived_application.human_readable_text.is_empty());
+        assert_eq!(archived_application.stage, application.stage);
+
+        let opening = <OpeningById<Test>>::get(opening_id);
+        if let OpeningStage::Active {
+            stage,
+            applications_added,
+            ..
+        } = opening.stage
+        {
+            if let ActiveOpeningStage::Deactivated { .. } = stage {
+                // expected
+            } else {
+                panic!("should be Deactivated")
+            }
+            assert!(applications_added.contains(&application_id));
+        } else {
+            panic!("should be Active")
+        }
+    });
+}
+
+#[test]
+fn scheduling_opening_stage_transitions_runs_once() {
+    build_test_externalities().execute_with(|| {
+        let opening_activation_block = 2;
+        let mut opening_fixture = AddOpeningFixture::default();
+        opening_fixture.activate_at = ActivateOpeningAt::ExactBlock(opening_activation_block);
+        let opening_id = opening_fixture.add_opening().unwrap();
+        <OpeningsScheduledToBegin<Test>>::remove(opening_activation_block, opening_id);
+
+        Hiring::schedule_opening_stage_transitions();
+
+        assert!(<OpeningsScheduledToBegin<Test>>::contains_key(
+            opening_activation_block,
+            opening_id
+        ));
+        assert_eq!(Hiring::storage_version(), 1);
+
+        <OpeningsScheduledToBegin<Test>>::remove(opening_activation_block, opening_id);
+        Hiring::schedule_opening_stage_transitions();
+
+        assert!(!<OpeningsScheduledToBegin<Test>>::contains_key(
+            opening_activation_block,
+            opening_id
+        ));
+    });
+}
@@ -0,0 +1,172 @@
+//! Membership module benchmarking.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks};
+use system::RawOrigin;
+
+const SEED: u32 = 0;
+
+fn member_account<T: Trait>(name: &'static str, index: u32) -> T::AccountId {
+    account(name, index, SEED)
+}
+
+fn handle(index: u32) -> Vec<u8> {
+    let mut handle = b"member".to_vec();
+    handle.extend_from_slice(&index.to_be_bytes());
+    handle
+}
+
+fn insert_default_paid_terms<T: Trait>() {
+    <PaidMembershipTermsById<T>>::insert(
+        T::PaidTermId::from(DEFAULT_PAID_TERM_ID),
+        PaidMembershipTerms {
+            fee: BalanceOf::<T>::from(100u32),
+            text: Vec::new(),
+        },
+    );
+}
+
+benchmarks! {
+    _ { }
+
+    buy_membership {
+        let i in 0 .. DEFAULT_MAX_ABOUT_TEXT_LENGTH;
+
+        let caller = member_account::<T>("caller", 0);
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::from(1_000_000u32));
+        insert_default_paid_terms::<T>();
+        let about = vec![b'a'; i as usize];
+    }: _(
+        RawOrigin::Signed(caller.clone()),
+        T::PaidTermId::from(DEFAULT_PAID_TERM_ID),
+        Some(handle(0)),
+        None,
+        Some(about)
+    )
+    verify {
+        assert!(<Module<T>>::is_member_account(&caller));
+    }
+
+    change_member_about_text {
+        let i in 0 .. DEFAULT_MAX_ABOUT_TEXT_LENGTH;
+
+        let caller = member_account::<T>("caller", 0);
+        let member_id = <Module<T>>::insert_benchmark_member(&caller);
+        let text = vec![b'a'; i as usize];
+    }: _(RawOrigin::Signed(caller), member_id, text)
+    verify {
+        assert_eq!(<Module<T>>::membership(member_id).about.len() as u32, i);
+    }
+
+    change_member_avatar {
+        let i in 0 .. DEFAULT_MAX_AVATAR_URI_LENGTH;
+
+        let caller = member_account::<T>("caller", 0);
+        let member_id = <Module<T>>::insert_benchmark_member(&caller);
+        let uri = vec![b'a'; i as usize];
+    }: _(RawOrigin::Signed(caller), member_id, uri)
+    verify {
+        assert_eq!(<Module<T>>::membership(member_id).avatar_uri.len() as u32, i);
+    }
+
+    change_member_handle {
+        let i in DEFAULT_MIN_HANDLE_LENGTH .. DEFAULT_MAX_HANDLE_LENGTH;
+
+        let caller = member_account::<T>("caller", 0);
+        let member_id = <Module<T>>::insert_benchmark_member(&caller);
+        let new_handle = vec![b'a'; i as usize];
+    }: _(RawOrigin::Signed(caller), member_id, new_handle.clone())
+    verify {
+        assert_eq!(<Module<T>>::membership(member_id).handle, new_handle);
+    }
+
+    update_membership {
+        let i in 0 .. DEFAULT_MAX_ABOUT_TEXT_LENGTH;
+
+        let caller = member_account::<T>("caller", 0);
+        let member_id = <Module<T>>::insert_benchmark_member(&caller);
+        let about = vec![b'a'; i as usize];
+        let avatar_uri = vec![b'a'; DEFAULT_MAX_AVATAR_URI_LENGTH as usize];
+    }: _(
+        RawOrigin::Signed(caller),
+        member_id,
+        Some(handle(1)),
+        Some(avatar_uri),
+        Some(about)
+    )
+    verify {
+        assert_eq!(<Module<T>>::membership(member_id).about.len() as u32, i);
+    }
+
+    set_controller_account {
+        let caller = member_account::<T>("caller", 0);
+        let member_id = <Module<T>>::insert_benchmark_member(&caller);
+        let new_controller_account = member_account::<T>("controller", 0);
+    }: _(RawOrigin::Signed(caller), member_id, new_controller_account.clone())
+    verify {
+        assert_eq!(
+            <Module<T>>::membership(member_id).controller_account,
+            new_controller_account
+        );
+    }
+
+    set_root_account {
+        let caller = member_account::<T>("caller", 0);
+        let member_id = <Module<T>>::insert_benchmark_member(&caller);
+        let new_root_account = member_account::<T>("root", 0);
+    }: _(RawOrigin::Signed(caller), member_id, new_root_account.clone())
+    verify {
+        assert_eq!(<Module<T>>::membership(member_id).root_account, new_root_account);
+    }
+
+    add_screened_member {
+        let i in 0 .. DEFAULT_MAX_ABOUT_TEXT_LENGTH;
+
+        let authority = member_account::<T>("authority", 0);
+        <ScreeningAuthority<T>>::put(authority.clone());
+        let new_member_account = member_account::<T>("member", 0);
+        let about = vec![b'a'; i as usize];
+    }: _(
+        RawOrigin::Signed(authority),
+        new_member_account.clone(),
+        Some(handle(0)),
+        None,
+        Some(about)
+    )
+    verify {
+        assert!(<Module<T>>::is_member_account(&new_member_account));
+    }
+
+    set_screening_authority {
+        let authority = member_account::<T>("authority", 0);
+    }: _(RawOrigin::Root, authority.clone())
+    verify {
+        assert_eq!(<Module<T>>::screening_authority(), authority);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{Test, TestExternalitiesBuilder};
+    use frame_support::assert_ok;
+
+    fn build_test_externalities() -> sp_io::TestExternalities {
+        TestExternalitiesBuilder::<Test>::default().build()
+    }
+
+    #[test]
+    fn membership_benchmarks_run_successfully() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_buy_membership::<Test>());
+            assert_ok!(test_benchmark_change_member_about_text::<Test>());
+            assert_ok!(test_benchmark_change_member_avatar::<Test>());
+            assert_ok!(test_benchmark_change_member_handle::<Test>());
+            assert_ok!(test_benchmark_update_membership::<Test>());
+            assert_ok!(test_benchmark_set_controller_account::<Test>());
+            assert_ok!(test_benchmark_set_root_account::<Test>());
+            assert_ok!(test_benchmark_add_screened_member::<Test>());
+            assert_ok!(test_benchmark_set_screening_authority::<Test>());
+        });
+    }
+}
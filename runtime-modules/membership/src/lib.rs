@@ -5,12 +5,15 @@
 // Example:  pub PaidMembershipTermsById get(paid_membership_terms_by_id) build(|config: &GenesisConfig<T>| {}
 #![allow(clippy::redundant_closure_call)]
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 pub mod genesis;
 pub(crate) mod mock;
 mod tests;
 
 use codec::{Codec, Decode, Encode};
 use frame_support::traits::Currency;
+use frame_support::weights::Weight;
 use frame_support::{decl_event, decl_module, decl_storage, ensure, Parameter};
 use sp_arithmetic::traits::{BaseArithmetic, One};
 use sp_runtime::traits::{MaybeSerialize, Member};
@@ -25,9 +28,57 @@ use common::currency::{BalanceOf, GovernanceCurrency};
 /// Result with string error message. This exists for backward compatibility purpose.
 pub type DispatchResult = Result<(), &'static str>;
 
+/// Extrinsic weights for the membership module.
+/// `i` - total length of the provided profile fields (handle, avatar uri and about text) in bytes.
+pub trait WeightInfo {
+    fn buy_membership(i: u32) -> Weight;
+    fn change_member_about_text(i: u32) -> Weight;
+    fn change_member_avatar(i: u32) -> Weight;
+    fn change_member_handle(i: u32) -> Weight;
+    fn update_membership(i: u32) -> Weight;
+    fn set_controller_account() -> Weight;
+    fn set_root_account() -> Weight;
+    fn add_screened_member(i: u32) -> Weight;
+    fn set_screening_authority() -> Weight;
+}
+
+// Default weights, used in the tests and by the runtimes without benchmarked weights.
+impl WeightInfo for () {
+    fn buy_membership(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn change_member_about_text(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn change_member_avatar(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn change_member_handle(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn update_membership(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn set_controller_account() -> Weight {
+        10_000_000
+    }
+    fn set_root_account() -> Weight {
+        10_000_000
+    }
+    fn add_screened_member(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn set_screening_authority() -> Weight {
+        10_000_000
+    }
+}
+
 pub trait Trait: system::Trait + GovernanceCurrency + pallet_timestamp::Trait {
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
 
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
+
     type MemberId: Parameter
         + Member
         + BaseArithmetic
@@ -260,7 +311,7 @@ decl_module! {
         fn deposit_event() = default;
 
         /// Non-members can buy membership
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::buy_membership(profile_length(handle, avatar_uri, about))]
         pub fn buy_membership(
             origin,
             paid_terms_id: T::PaidTermId,
@@ -296,7 +347,7 @@ decl_module! {
         }
 
         /// Change member's about text
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::change_member_about_text(text.len() as u32)]
         pub fn change_member_about_text(origin, member_id: T::MemberId, text: Vec<u8>) {
             let sender = ensure_signed(origin)?;
 
@@ -308,7 +359,7 @@ decl_module! {
         }
 
         /// Change member's avatar
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::change_member_avatar(uri.len() as u32)]
         pub fn change_member_avatar(origin, member_id: T::MemberId, uri: Vec<u8>) {
             let sender = ensure_signed(origin)?;
 
@@ -321,7 +372,7 @@ decl_module! {
 
         /// Change member's handle. Will ensure new handle is unique and old one will be available
         /// for other members to use.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::change_member_handle(handle.len() as u32)]
         pub fn change_member_handle(origin, member_id: T::MemberId, handle: Vec<u8>) {
             let sender = ensure_signed(origin)?;
 
@@ -333,7 +384,7 @@ decl_module! {
         }
 
         /// Update member's all or some of handle, avatar and about text.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::update_membership(profile_length(handle, avatar_uri, about))]
         pub fn update_membership(
             origin,
             member_id: T::MemberId,
@@ -358,7 +409,7 @@ decl_module! {
             }
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::set_controller_account()]
         pub fn set_controller_account(origin, member_id: T::MemberId, new_controller_account: T::AccountId) {
             let sender = ensure_signed(origin)?;
 
@@ -382,7 +433,7 @@ decl_module! {
             }
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::set_root_account()]
         pub fn set_root_account(origin, member_id: T::MemberId, new_root_account: T::AccountId) {
             let sender = ensure_signed(origin)?;
 
@@ -406,7 +457,7 @@ decl_module! {
             }
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::add_screened_member(profile_length(handle, avatar_uri, about))]
         pub fn add_screened_member(
             origin,
             new_member_account: T::AccountId,
@@ -441,7 +492,7 @@ decl_module! {
             Self::deposit_event(RawEvent::MemberRegistered(member_id, new_member_account));
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::set_screening_authority()]
         pub fn set_screening_authority(origin, authority: T::AccountId) {
             ensure_root(origin)?;
             <ScreeningAuthority<T>>::put(authority);
@@ -449,6 +500,18 @@ decl_module! {
     }
 }
 
+// Total length of the optional profile fields. Used as the weight component.
+fn profile_length(
+    handle: &Option<Vec<u8>>,
+    avatar_uri: &Option<Vec<u8>>,
+    about: &Option<Vec<u8>>,
+) -> u32 {
+    [handle, avatar_uri, about]
+        .iter()
+        .map(|field| field.as_ref().map_or(0, |value| value.len() as u32))
+        .sum()
+}
+
 /// Reason why a given member id does not have a given account as the controller account.
 pub enum ControllerAccountForMemberCheckFailed {
     NotMember,
@@ -697,3 +760,26 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 }
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<T: Trait> Module<T> {
+    /// Creates a member with the account as its root and controller account.
+    /// Used by the benchmarks of this and the dependent modules.
+    pub fn insert_benchmark_member(account_id: &T::AccountId) -> T::MemberId {
+        let mut handle = b"member".to_vec();
+        handle.extend_from_slice(&Self::members_created().encode());
+
+        let user_info =
+            Self::check_user_registration_info(Some(handle), None, None).expect("valid user info");
+
+        Self::insert_member(
+            account_id,
+            account_id,
+            &user_info,
+            EntryMethod::Genesis,
+            <system::Module<T>>::block_number(),
+            <pallet_timestamp::Module<T>>::now(),
+        )
+        .expect("member created")
+    }
+}
@@ -84,6 +84,7 @@ impl Trait for Test {
     type PaidTermId = u32;
     type SubscriptionId = u32;
     type ActorId = u32;
+    type WeightInfo = ();
 }
 
 pub struct TestExternalitiesBuilder<T: Trait> {
@@ -0,0 +1,39 @@
+//! Memo module benchmarking.
+
+use super::*;
+use common::currency::BalanceOf;
+use frame_benchmarking::{account, benchmarks};
+use sp_std::vec;
+use system::RawOrigin;
+
+const SEED: u32 = 0;
+const MAX_MEMO_LENGTH: u32 = 4096;
+
+benchmarks! {
+    _ { }
+
+    update_memo {
+        let m in 0 .. MAX_MEMO_LENGTH;
+
+        let caller: T::AccountId = account("caller", 0, SEED);
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::from(100_000u32));
+        let memo = vec![0u8; m as usize];
+    }: _(RawOrigin::Signed(caller.clone()), memo)
+    verify {
+        assert_eq!(Module::<T>::memo(&caller).len() as u32, m);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{initial_test_ext, Test};
+    use frame_support::assert_ok;
+
+    #[test]
+    fn memo_benchmarks_run_successfully() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_update_memo::<Test>());
+        });
+    }
+}
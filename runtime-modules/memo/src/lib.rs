@@ -1,7 +1,12 @@
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+mod mock;
+
 use frame_support::traits::Currency;
+use frame_support::weights::Weight;
 use frame_support::{decl_event, decl_module, decl_storage, ensure};
 use sp_arithmetic::traits::Zero;
 use sp_std::vec::Vec;
@@ -9,8 +14,24 @@ use system::ensure_signed;
 
 use common::currency::GovernanceCurrency;
 
+/// Extrinsic weights for the memo module.
+pub trait WeightInfo {
+    /// `m` - memo length in bytes.
+    fn update_memo(m: u32) -> Weight;
+}
+
+// Default weights, used in the tests and by the runtimes without benchmarked weights.
+impl WeightInfo for () {
+    fn update_memo(_m: u32) -> Weight {
+        10_000_000
+    }
+}
+
 pub trait Trait: system::Trait + GovernanceCurrency {
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
 }
 
 pub type MemoText = Vec<u8>;
@@ -32,7 +53,7 @@ decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
         fn deposit_event() = default;
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::update_memo(memo.len() as u32)]
         fn update_memo(origin, memo: MemoText) {
             let sender = ensure_signed(origin)?;
 
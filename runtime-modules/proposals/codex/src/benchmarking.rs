@@ -0,0 +1,527 @@
+//! Proposals codex module benchmarking.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::StorageMap;
+use proposal_types::parameters;
+use sp_std::vec;
+
+const SEED: u32 = 0;
+// The highest required stake of the proposal types.
+const MAX_STAKE: u32 = 1_000_000;
+const RATIONALE_LENGTH: u32 = 1000;
+
+fn text(len: u32) -> Vec<u8> {
+    vec![b'a'; len as usize]
+}
+
+// Creates the proposer with the balance to stake for any proposal type.
+fn create_proposer<T: Trait>() -> (T::AccountId, MemberId<T>) {
+    let account_id: T::AccountId = account("proposer", 0, SEED);
+    let member_id = T::MembershipOriginValidator::create_benchmark_actor(&account_id);
+
+    let balance: BalanceOf<T> = MAX_STAKE.into();
+    let _ = CurrencyOf::<T>::deposit_creating(&account_id, balance + balance);
+
+    (account_id, member_id)
+}
+
+// The longest title passing both the proposal and the discussion thread checks.
+fn title<T: Trait>() -> Vec<u8> {
+    text(T::TitleMaxLength::get().min(T::ThreadTitleLengthLimit::get()))
+}
+
+fn description<T: Trait>() -> Vec<u8> {
+    text(T::DescriptionMaxLength::get())
+}
+
+fn election_parameters<T: Trait>(
+) -> ElectionParameters<BalanceOfGovernanceCurrency<T>, T::BlockNumber> {
+    ElectionParameters {
+        announcing_period: ELECTION_PARAMETERS_ANNOUNCING_PERIOD_MIN_VALUE.into(),
+        voting_period: ELECTION_PARAMETERS_VOTING_PERIOD_MIN_VALUE.into(),
+        revealing_period: ELECTION_PARAMETERS_REVEALING_PERIOD_MIN_VALUE.into(),
+        council_size: ELECTION_PARAMETERS_COUNCIL_SIZE_MIN_VALUE,
+        candidacy_limit: ELECTION_PARAMETERS_CANDIDACY_LIMIT_MIN_VALUE,
+        new_term_duration: ELECTION_PARAMETERS_NEW_TERM_DURATION_MIN_VALUE.into(),
+        min_council_stake: ELECTION_PARAMETERS_MIN_COUNCIL_STAKE_MIN_VALUE.into(),
+        min_voting_stake: ELECTION_PARAMETERS_MIN_STAKE_MIN_VALUE.into(),
+    }
+}
+
+// Creates the uploader with the balance to deposit for the wasm code of the given length.
+fn create_uploader<T: Trait>(len: u32) -> T::AccountId {
+    let account_id: T::AccountId = account("uploader", 0, SEED);
+
+    let deposit = T::RuntimeUpgradeWasmDepositPerByte::get()
+        .saturating_mul(BalanceOfGovernanceCurrency::<T>::from(len));
+    let _ = <T as GovernanceCurrency>::Currency::deposit_creating(&account_id, deposit + deposit);
+
+    account_id
+}
+
+fn assert_proposal_created<T: Trait>() {
+    assert_eq!(<proposals_engine::Module<T>>::active_proposal_count(), 1);
+}
+
+benchmarks! {
+    _ { }
+
+    create_text_proposal {
+        let t in 1 .. T::TextProposalMaxLength::get();
+
+        let (account_id, member_id) = create_proposer::<T>();
+        let stake = parameters::text_proposal::<T>().required_stake;
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        title::<T>(),
+        description::<T>(),
+        stake,
+        text(t),
+        None
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_runtime_upgrade_proposal {
+        let (account_id, member_id) = create_proposer::<T>();
+        let stake = parameters::runtime_upgrade_proposal::<T>().required_stake;
+        let runtime_upgrade_parameters = RuntimeUpgradeParameters::default();
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        title::<T>(),
+        description::<T>(),
+        stake,
+        runtime_upgrade_parameters,
+        None
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_set_election_parameters_proposal {
+        let (account_id, member_id) = create_proposer::<T>();
+        let stake = parameters::set_election_parameters_proposal::<T>().required_stake;
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        title::<T>(),
+        description::<T>(),
+        stake,
+        election_parameters::<T>(),
+        None
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_spending_proposal {
+        let (account_id, member_id) = create_proposer::<T>();
+        let stake = parameters::spending_proposal::<T>().required_stake;
+        let destination: T::AccountId = account("destination", 0, SEED);
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        title::<T>(),
+        description::<T>(),
+        stake,
+        MAX_SPENDING_PROPOSAL_VALUE.into(),
+        destination,
+        None
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_set_validator_count_proposal {
+        let (account_id, member_id) = create_proposer::<T>();
+        let stake = parameters::set_validator_count_proposal::<T>().required_stake;
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        title::<T>(),
+        description::<T>(),
+        stake,
+        MAX_VALIDATOR_COUNT,
+        None
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_add_working_group_leader_opening_proposal {
+        let (account_id, member_id) = create_proposer::<T>();
+        let stake = parameters::add_working_group_leader_opening_proposal::<T>().required_stake;
+        let add_opening_parameters = AddOpeningParameters {
+            activate_at: hiring::ActivateOpeningAt::CurrentBlock,
+            commitment: Default::default(),
+            human_readable_text: text(T::DescriptionMaxLength::get()),
+            working_group: WorkingGroup::Storage,
+        };
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        title::<T>(),
+        description::<T>(),
+        stake,
+        add_opening_parameters,
+        None
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_begin_review_working_group_leader_applications_proposal {
+        let (account_id, member_id) = create_proposer::<T>();
+        let stake =
+            parameters::begin_review_working_group_leader_applications_proposal::<T>().required_stake;
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        title::<T>(),
+        description::<T>(),
+        stake,
+        Zero::zero(),
+        WorkingGroup::Storage,
+        None
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_fill_working_group_leader_opening_proposal {
+        let (account_id, member_id) = create_proposer::<T>();
+        let stake = parameters::fill_working_group_leader_opening_proposal::<T>().required_stake;
+        let fill_opening_parameters = FillOpeningParameters {
+            opening_id: Zero::zero(),
+            successful_application_id: Zero::zero(),
+            reward_policy: None,
+            working_group: WorkingGroup::Storage,
+        };
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        title::<T>(),
+        description::<T>(),
+        stake,
+        fill_opening_parameters,
+        None
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_set_working_group_mint_capacity_proposal {
+        let (account_id, member_id) = create_proposer::<T>();
+        let stake = parameters::set_working_group_mint_capacity_proposal::<T>().required_stake;
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        title::<T>(),
+        description::<T>(),
+        stake,
+        WORKING_GROUP_MINT_CAPACITY_MAX_VALUE.into(),
+        WorkingGroup::Storage,
+        None
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_decrease_working_group_leader_stake_proposal {
+        let (account_id, member_id) = create_proposer::<T>();
+        let stake = parameters::decrease_working_group_leader_stake_proposal::<T>().required_stake;
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        title::<T>(),
+        description::<T>(),
+        stake,
+        Zero::zero(),
+        MAX_STAKE.into(),
+        WorkingGroup::Storage,
+        None
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_slash_working_group_leader_stake_proposal {
+        let (account_id, member_id) = create_proposer::<T>();
+        let stake = parameters::slash_working_group_leader_stake_proposal::<T>().required_stake;
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        title::<T>(),
+        description::<T>(),
+        stake,
+        Zero::zero(),
+        MAX_STAKE.into(),
+        WorkingGroup::Storage,
+        None
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_set_working_group_leader_reward_proposal {
+        let (account_id, member_id) = create_proposer::<T>();
+        let stake = parameters::set_working_group_leader_reward_proposal::<T>().required_stake;
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        title::<T>(),
+        description::<T>(),
+        stake,
+        Zero::zero(),
+        WORKING_GROUP_MINT_CAPACITY_MAX_VALUE.into(),
+        WorkingGroup::Storage,
+        None
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_terminate_working_group_leader_role_proposal {
+        let (account_id, member_id) = create_proposer::<T>();
+        let stake = parameters::terminate_working_group_leader_role_proposal::<T>().required_stake;
+        let terminate_role_parameters = TerminateRoleParameters {
+            worker_id: Zero::zero(),
+            rationale: text(RATIONALE_LENGTH),
+            slash: true,
+            working_group: WorkingGroup::Storage,
+        };
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        title::<T>(),
+        description::<T>(),
+        stake,
+        terminate_role_parameters,
+        None
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    // The options are the texts of the different lengths, as the options must be unique.
+    create_ranked_choice_proposal {
+        let o in 2 .. T::MaxProposalOptions::get();
+
+        let (account_id, member_id) = create_proposer::<T>();
+        let options: Vec<RankedChoiceOptionOf<T>> =
+            (1..=o).map(|index| RankedChoiceOption::Text(text(index))).collect();
+        let stake = parameters::ranked_choice_proposal::<T>(&options).required_stake;
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        title::<T>(),
+        description::<T>(),
+        stake,
+        options,
+        None
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    // The creation weights of the items are added separately: the items are the shortest texts.
+    create_batch_proposal {
+        let i in 1 .. MAX_BATCH_PROPOSAL_ITEMS;
+
+        let (account_id, member_id) = create_proposer::<T>();
+        let items: Vec<BatchProposalItemOf<T>> = vec![BatchProposalItem::Text(text(1)); i as usize];
+        let stake = parameters::batch_proposal::<T>(&items).required_stake;
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        title::<T>(),
+        description::<T>(),
+        stake,
+        items,
+        None
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_amend_constitution_proposal {
+        let c in 1 .. T::ConstitutionMaxLength::get();
+
+        let (account_id, member_id) = create_proposer::<T>();
+        let stake = parameters::amend_constitution_proposal::<T>().required_stake;
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        title::<T>(),
+        description::<T>(),
+        stake,
+        text(c),
+        None
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_set_proposal_parameters_proposal {
+        let (account_id, member_id) = create_proposer::<T>();
+        let stake = parameters::set_proposal_parameters_proposal::<T>().required_stake;
+        let proposal_parameters = parameters::text_proposal::<T>();
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        title::<T>(),
+        description::<T>(),
+        stake,
+        ProposalType::Text,
+        proposal_parameters,
+        None
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_set_default_storage_quota_limits_proposal {
+        let (account_id, member_id) = create_proposer::<T>();
+        let stake = parameters::set_default_storage_quota_limits_proposal::<T>().required_stake;
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        title::<T>(),
+        description::<T>(),
+        stake,
+        u64::max_value(),
+        u64::max_value(),
+        None
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_set_working_group_spending_limit_proposal {
+        let (account_id, member_id) = create_proposer::<T>();
+        let stake = parameters::set_working_group_spending_limit_proposal::<T>().required_stake;
+        let spending_limit = SpendingLimit {
+            amount_per_period: WORKING_GROUP_MINT_CAPACITY_MAX_VALUE.into(),
+            period_length: ELECTION_PARAMETERS_NEW_TERM_DURATION_MIN_VALUE.into(),
+        };
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        title::<T>(),
+        description::<T>(),
+        stake,
+        Some(spending_limit),
+        WorkingGroup::Storage,
+        None
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    upload_runtime_upgrade_wasm {
+        let w in 1 .. T::RuntimeUpgradeWasmProposalMaxLength::get();
+
+        let account_id = create_uploader::<T>(w);
+        let wasm = text(w);
+        let wasm_hash = blake2_256(&wasm);
+    }: _(RawOrigin::Signed(account_id), wasm)
+    verify {
+        assert!(<RuntimeUpgradeWasmByHash>::contains_key(wasm_hash));
+    }
+
+    remove_runtime_upgrade_wasm {
+        let len = T::RuntimeUpgradeWasmProposalMaxLength::get();
+        let account_id = create_uploader::<T>(len);
+        let wasm = text(len);
+        let wasm_hash = blake2_256(&wasm);
+        Module::<T>::upload_runtime_upgrade_wasm(RawOrigin::Signed(account_id.clone()).into(), wasm)
+            .expect("wasm uploaded");
+    }: _(RawOrigin::Signed(account_id), wasm_hash)
+    verify {
+        assert!(!<RuntimeUpgradeWasmByHash>::contains_key(wasm_hash));
+    }
+
+    execute_text_proposal {
+        let t in 1 .. T::TextProposalMaxLength::get();
+    }: _(RawOrigin::Root, text(t))
+
+    // The 'Runtime upgrade' proposal execution is not benchmarked: the wasm code with the runtime
+    // version cannot be built by the benchmarks.
+
+    // The dispatch weights of the calls are added separately: the calls are the executions of
+    // the shortest text proposals.
+    execute_batch_proposal {
+        let i in 1 .. MAX_BATCH_PROPOSAL_ITEMS;
+
+        let call = T::ProposalEncoder::encode_proposal(ProposalDetails::Text(text(1)));
+        let calls = vec![call; i as usize];
+    }: _(RawOrigin::Root, calls)
+
+    execute_amend_constitution_proposal {
+        let c in 1 .. T::ConstitutionMaxLength::get();
+    }: _(RawOrigin::Root, text(c))
+    verify {
+        assert_eq!(Module::<T>::constitution_version_count(), 1);
+    }
+
+    execute_set_proposal_parameters_proposal {
+        let proposal_parameters = parameters::text_proposal::<T>();
+    }: _(RawOrigin::Root, ProposalType::Text, proposal_parameters)
+    verify {
+        assert!(Module::<T>::proposal_parameters_by_type(ProposalType::Text).is_some());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{initial_test_ext, Test};
+    use frame_support::assert_ok;
+
+    #[test]
+    fn proposals_codex_benchmarks_run_successfully() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_create_text_proposal::<Test>());
+            assert_ok!(test_benchmark_create_runtime_upgrade_proposal::<Test>());
+            assert_ok!(test_benchmark_create_set_election_parameters_proposal::<Test>());
+            assert_ok!(test_benchmark_create_spending_proposal::<Test>());
+            assert_ok!(test_benchmark_create_set_validator_count_proposal::<Test>());
+            assert_ok!(test_benchmark_create_add_working_group_leader_opening_proposal::<Test>());
+            assert_ok!(
+                test_benchmark_create_begin_review_working_group_leader_applications_proposal::<
+                    Test,
+                >()
+            );
+            assert_ok!(test_benchmark_create_fill_working_group_leader_opening_proposal::<Test>());
+            assert_ok!(test_benchmark_create_set_working_group_mint_capacity_proposal::<Test>());
+            assert_ok!(
+                test_benchmark_create_decrease_working_group_leader_stake_proposal::<Test>()
+            );
+            assert_ok!(test_benchmark_create_slash_working_group_leader_stake_proposal::<Test>());
+            assert_ok!(test_benchmark_create_set_working_group_leader_reward_proposal::<Test>());
+            assert_ok!(
+                test_benchmark_create_terminate_working_group_leader_role_proposal::<Test>()
+            );
+            assert_ok!(test_benchmark_create_ranked_choice_proposal::<Test>());
+            assert_ok!(test_benchmark_create_batch_proposal::<Test>());
+            assert_ok!(test_benchmark_create_amend_constitution_proposal::<Test>());
+            assert_ok!(test_benchmark_create_set_proposal_parameters_proposal::<Test>());
+            assert_ok!(
+                test_benchmark_create_set_default_storage_quota_limits_proposal::<Test>()
+            );
+            assert_ok!(
+                test_benchmark_create_set_working_group_spending_limit_proposal::<Test>()
+            );
+            assert_ok!(test_benchmark_upload_runtime_upgrade_wasm::<Test>());
+            assert_ok!(test_benchmark_remove_runtime_upgrade_wasm::<Test>());
+            assert_ok!(test_benchmark_execute_text_proposal::<Test>());
+            assert_ok!(test_benchmark_execute_batch_proposal::<Test>());
+            assert_ok!(test_benchmark_execute_amend_constitution_proposal::<Test>());
+            assert_ok!(test_benchmark_execute_set_proposal_parameters_proposal::<Test>());
+        });
+    }
+}
@@ -16,6 +16,13 @@
 //! - [create_text_proposal](./struct.Module.html#method.create_text_proposal)
 //! - [create_runtime_upgrade_proposal](./struct.Module.html#method.create_runtime_upgrade_proposal)
 //! - [create_set_validator_count_proposal](./struct.Module.html#method.create_set_validator_count_proposal)
+//! - [create_ranked_choice_proposal](./struct.Module.html#method.create_ranked_choice_proposal)
+//! - [create_batch_proposal](./struct.Module.html#method.create_batch_proposal)
+//! - [create_amend_constitution_proposal](./struct.Module.html#method.create_amend_constitution_proposal)
+//! - [create_set_proposal_parameters_proposal](./struct.Module.html#method.create_set_proposal_parameters_proposal)
+//!
+//! ### Storage proposals
+//! - [create_set_default_storage_quota_limits_proposal](./struct.Module.html#method.create_set_default_storage_quota_limits_proposal)
 //!
 //! ### Council and election proposals
 //! - [create_set_election_parameters_proposal](./struct.Module.html#method.create_set_election_parameters_proposal)
@@ -30,10 +37,21 @@
 //! - [create_slash_working_group_leader_stake_proposal](./struct.Module.html#method.create_slash_working_group_leader_stake_proposal)
 //! - [create_set_working_group_leader_reward_proposal](./struct.Module.html#method.create_set_working_group_leader_reward_proposal)
 //! - [create_terminate_working_group_leader_role_proposal](./struct.Module.html#method.create_terminate_working_group_leader_role_proposal)
+//! - [create_set_working_group_spending_limit_proposal](./struct.Module.html#method.create_set_working_group_spending_limit_proposal)
+//!
+//! ### Runtime upgrade wasm code
+//! - [upload_runtime_upgrade_wasm](./struct.Module.html#method.upload_runtime_upgrade_wasm)
+//! - [remove_runtime_upgrade_wasm](./struct.Module.html#method.remove_runtime_upgrade_wasm)
 //!
 //! ### Proposal implementations of this module
 //! - execute_text_proposal - prints the proposal to the log
-//! - execute_runtime_upgrade_proposal - sets the runtime code
+//! - execute_runtime_upgrade_proposal - verifies the uploaded wasm code and sets the runtime code
+//! - execute_batch_proposal - dispatches the batch proposal calls atomically
+//! - execute_amend_constitution_proposal - saves the new constitution version
+//! - execute_set_proposal_parameters_proposal - updates the parameters of the proposal type
+//!
+//! ### Public API
+//! - [current_constitution](./struct.Module.html#method.current_constitution)
 //!
 //! ### Dependencies:
 //! - [proposals engine](../substrate_proposals_engine_module/index.html)
@@ -45,6 +63,22 @@
 //! ### Notes
 //! The module uses [ProposalEncoder](./trait.ProposalEncoder.html) to encode the proposal using
 //! its details. Encoded byte vector is passed to the _proposals engine_ as serialized executable code.
+//!
+//! Every proposal can be created with an optional exact execution block: the approved proposal
+//! is executed at this block instead of the grace period end (eg.: to align the validator count
+//! change with an era boundary).
+//!
+//! The 'runtime upgrade' proposal contains only the hash of the wasm code. Anyone can upload the
+//! wasm code with a deposit before the proposal execution. The deposit is returned on the proposal
+//! execution or on the wasm code removal.
+//!
+//! The platform constitution is stored with its version history. It can be amended only with the
+//! 'amend constitution' proposal: each execution saves the new text with its hash as the next
+//! version.
+//!
+//! Proposal parameters (eg.: quorum, threshold, stake) of each proposal type can be updated with
+//! the 'set proposal parameters' proposal. Updated parameters replace the default parameters of
+//! the proposal type and apply to the proposals created after the update.
 
 // `decl_module!` does a lot of recursion and requires us to increase the limit to 256.
 #![recursion_limit = "256"]
@@ -58,27 +92,42 @@
 
 mod proposal_types;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 #[cfg(test)]
 mod tests;
 
+use codec::Decode;
 use frame_support::dispatch::DispatchResult;
-use frame_support::traits::{Currency, Get};
+use frame_support::storage::with_transaction;
+use frame_support::traits::{Currency, Get, ReservableCurrency, UnfilteredDispatchable};
+use frame_support::weights::{GetDispatchInfo, Weight};
 use frame_support::{decl_error, decl_module, decl_storage, ensure, print};
-use sp_arithmetic::traits::Zero;
+use sp_arithmetic::traits::{Saturating, Zero};
+use sp_io::hashing::blake2_256;
+use sp_runtime::TransactionOutcome;
 use sp_std::clone::Clone;
 use sp_std::str::from_utf8;
 use sp_std::vec::Vec;
-use system::ensure_root;
+use sp_version::RuntimeVersion;
+use system::{ensure_root, ensure_signed, RawOrigin};
 
+use common::currency::GovernanceCurrency;
 use common::origin::ActorOriginValidator;
 use common::working_group::WorkingGroup;
 use governance::election_params::ElectionParameters;
 use proposals_engine::ProposalParameters;
+use working_group::SpendingLimit;
 
 pub use crate::proposal_types::{
-    AddOpeningParameters, FillOpeningParameters, ProposalsConfigParameters, TerminateRoleParameters,
+    AddOpeningParameters, ConstitutionVersion, FillOpeningParameters, ProposalType,
+    ProposalsConfigParameters, RuntimeUpgradeParameters, RuntimeUpgradeWasmDeposit,
+    TerminateRoleParameters,
+};
+pub use proposal_types::{
+    BatchProposalItem, BatchProposalItemOf, ProposalDetails, ProposalDetailsOf, ProposalEncoder,
+    RankedChoiceOption, RankedChoiceOptionOf,
 };
-pub use proposal_types::{ProposalDetails, ProposalDetailsOf, ProposalEncoder};
 
 // 'Set working group mint capacity' proposal limit
 const WORKING_GROUP_MINT_CAPACITY_MAX_VALUE: u32 = 5_000_000;
@@ -86,6 +135,10 @@ const WORKING_GROUP_MINT_CAPACITY_MAX_VALUE: u32 = 5_000_000;
 const MAX_SPENDING_PROPOSAL_VALUE: u32 = 5_000_000_u32;
 // Max validator count for the 'set validator count' proposal
 const MAX_VALIDATOR_COUNT: u32 = 100;
+// Storage version with the periods of the proposal types added after the genesis configured.
+const STORAGE_VERSION: u32 = 1;
+// Max items number for the 'batch' proposal
+const MAX_BATCH_PROPOSAL_ITEMS: u32 = 10;
 // council_size min value for the 'set election parameters' proposal
 const ELECTION_PARAMETERS_COUNCIL_SIZE_MIN_VALUE: u32 = 4;
 // council_size max value for the 'set election parameters' proposal
@@ -127,11 +180,127 @@ struct CreateProposalParameters<T: Trait> {
     pub title: Vec<u8>,
     pub description: Vec<u8>,
     pub stake_balance: Option<BalanceOf<T>>,
+    pub exact_execution_block: Option<T::BlockNumber>,
     pub proposal_code: Vec<u8>,
     pub proposal_parameters: ProposalParameters<T::BlockNumber, BalanceOf<T>>,
     pub proposal_details: ProposalDetailsOf<T>,
 }
 
+/// Extrinsic weights for the proposals codex module.
+/// `t` - text proposal length in bytes, `w` - runtime wasm code length in bytes,
+/// `o` - ranked choice proposal options number, `i` - batch proposal items number,
+/// `c` - constitution text length in bytes.
+pub trait WeightInfo {
+    fn create_text_proposal(t: u32) -> Weight;
+    fn create_runtime_upgrade_proposal() -> Weight;
+    fn create_set_election_parameters_proposal() -> Weight;
+    fn create_spending_proposal() -> Weight;
+    fn create_set_validator_count_proposal() -> Weight;
+    fn create_add_working_group_leader_opening_proposal() -> Weight;
+    fn create_begin_review_working_group_leader_applications_proposal() -> Weight;
+    fn create_fill_working_group_leader_opening_proposal() -> Weight;
+    fn create_set_working_group_mint_capacity_proposal() -> Weight;
+    fn create_decrease_working_group_leader_stake_proposal() -> Weight;
+    fn create_slash_working_group_leader_stake_proposal() -> Weight;
+    fn create_set_working_group_leader_reward_proposal() -> Weight;
+    fn create_terminate_working_group_leader_role_proposal() -> Weight;
+    fn create_ranked_choice_proposal(o: u32) -> Weight;
+    fn create_batch_proposal(i: u32) -> Weight;
+    fn create_amend_constitution_proposal(c: u32) -> Weight;
+    fn create_set_proposal_parameters_proposal() -> Weight;
+    fn create_set_default_storage_quota_limits_proposal() -> Weight;
+    fn create_set_working_group_spending_limit_proposal() -> Weight;
+    fn upload_runtime_upgrade_wasm(w: u32) -> Weight;
+    fn remove_runtime_upgrade_wasm() -> Weight;
+    fn execute_text_proposal(t: u32) -> Weight;
+    fn execute_runtime_upgrade_proposal() -> Weight;
+    fn execute_batch_proposal(i: u32) -> Weight;
+    fn execute_amend_constitution_proposal(c: u32) -> Weight;
+    fn execute_set_proposal_parameters_proposal() -> Weight;
+}
+
+// Default weights, used in the tests and by the runtimes without benchmarked weights.
+impl WeightInfo for () {
+    fn create_text_proposal(_t: u32) -> Weight {
+        10_000_000
+    }
+    fn create_runtime_upgrade_proposal() -> Weight {
+        10_000_000
+    }
+    fn create_set_election_parameters_proposal() -> Weight {
+        10_000_000
+    }
+    fn create_spending_proposal() -> Weight {
+        10_000_000
+    }
+    fn create_set_validator_count_proposal() -> Weight {
+        10_000_000
+    }
+    fn create_add_working_group_leader_opening_proposal() -> Weight {
+        10_000_000
+    }
+    fn create_begin_review_working_group_leader_applications_proposal() -> Weight {
+        10_000_000
+    }
+    fn create_fill_working_group_leader_opening_proposal() -> Weight {
+        10_000_000
+    }
+    fn create_set_working_group_mint_capacity_proposal() -> Weight {
+        10_000_000
+    }
+    fn create_decrease_working_group_leader_stake_proposal() -> Weight {
+        10_000_000
+    }
+    fn create_slash_working_group_leader_stake_proposal() -> Weight {
+        10_000_000
+    }
+    fn create_set_working_group_leader_reward_proposal() -> Weight {
+        10_000_000
+    }
+    fn create_terminate_working_group_leader_role_proposal() -> Weight {
+        10_000_000
+    }
+    fn create_ranked_choice_proposal(_o: u32) -> Weight {
+        10_000_000
+    }
+    fn create_batch_proposal(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn create_amend_constitution_proposal(_c: u32) -> Weight {
+        10_000_000
+    }
+    fn create_set_proposal_parameters_proposal() -> Weight {
+        10_000_000
+    }
+    fn create_set_default_storage_quota_limits_proposal() -> Weight {
+        10_000_000
+    }
+    fn create_set_working_group_spending_limit_proposal() -> Weight {
+        10_000_000
+    }
+    fn upload_runtime_upgrade_wasm(_w: u32) -> Weight {
+        10_000_000
+    }
+    fn remove_runtime_upgrade_wasm() -> Weight {
+        10_000_000
+    }
+    fn execute_text_proposal(_t: u32) -> Weight {
+        10_000_000
+    }
+    fn execute_runtime_upgrade_proposal() -> Weight {
+        10_000_000
+    }
+    fn execute_batch_proposal(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn execute_amend_constitution_proposal(_c: u32) -> Weight {
+        10_000_000
+    }
+    fn execute_set_proposal_parameters_proposal() -> Weight {
+        10_000_000
+    }
+}
+
 /// 'Proposals codex' substrate module Trait
 pub trait Trait:
     system::Trait
@@ -148,6 +317,12 @@ pub trait Trait:
     /// Defines max wasm code length of the runtime upgrade proposal.
     type RuntimeUpgradeWasmProposalMaxLength: Get<u32>;
 
+    /// Defines the deposit per byte of the uploaded runtime upgrade wasm code.
+    type RuntimeUpgradeWasmDepositPerByte: Get<BalanceOfGovernanceCurrency<Self>>;
+
+    /// Defines max allowed constitution text length.
+    type ConstitutionMaxLength: Get<u32>;
+
     /// Validates member id and origin combination
     type MembershipOriginValidator: ActorOriginValidator<
         Self::Origin,
@@ -157,6 +332,9 @@ pub trait Trait:
 
     /// Encodes the proposal usint its details
     type ProposalEncoder: ProposalEncoder<Self>;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
 }
 
 /// Balance alias for `stake` module
@@ -197,6 +375,27 @@ decl_error! {
         /// Provided WASM code for the runtime upgrade proposal is empty
         RuntimeProposalIsEmpty,
 
+        /// The WASM code with the same hash was already uploaded
+        RuntimeUpgradeWasmAlreadyUploaded,
+
+        /// The WASM code for the runtime upgrade proposal was not uploaded
+        RuntimeUpgradeWasmNotUploaded,
+
+        /// The uploaded WASM code doesn't match the runtime upgrade proposal hash
+        RuntimeUpgradeWasmHashMismatch,
+
+        /// The runtime version of the uploaded WASM code cannot be extracted
+        RuntimeUpgradeWasmVersionUnknown,
+
+        /// The spec version of the uploaded WASM code differs from the runtime upgrade proposal
+        RuntimeUpgradeSpecVersionMismatch,
+
+        /// Only the depositor can remove the uploaded WASM code
+        NotRuntimeUpgradeWasmDepositor,
+
+        /// Insufficient balance for the uploaded WASM code deposit
+        InsufficientBalanceForRuntimeUpgradeWasmDeposit,
+
         /// Invalid balance value for the spending proposal
         InvalidSpendingProposalBalance,
 
@@ -236,6 +435,9 @@ decl_error! {
         /// Invalid working group mint capacity parameter
         InvalidWorkingGroupMintCapacity,
 
+        /// Invalid 'set working group spending limit' parameter - spending period length is zero.
+        InvalidWorkingGroupSpendingPeriodLength,
+
         /// Invalid 'set lead proposal' parameter - proposed lead cannot be a councilor
         InvalidSetLeadParameterCannotBeCouncilor,
 
@@ -244,6 +446,33 @@ decl_error! {
 
         /// Invalid 'decrease stake proposal' parameter - cannot decrease by zero balance.
         DecreasingStakeIsZero,
+
+        /// Invalid 'ranked choice proposal' parameter - options must be unique.
+        DuplicateRankedChoiceOption,
+
+        /// Provided items list for the batch proposal is empty
+        BatchProposalIsEmpty,
+
+        /// The number of the batch proposal items exceeded the limit
+        BatchProposalSizeExceeded,
+
+        /// The batch proposal call cannot be decoded
+        BatchProposalCallDecodingFailed,
+
+        /// Provided constitution text is empty
+        ConstitutionIsEmpty,
+
+        /// The size of the provided constitution text exceeded the limit
+        ConstitutionSizeExceeded,
+
+        /// Invalid 'set proposal parameters' parameter - voting period cannot be zero.
+        InvalidProposalParametersVotingPeriod,
+
+        /// Invalid 'set proposal parameters' parameter - quorum percentage exceeds 100.
+        InvalidProposalParametersQuorumPercentage,
+
+        /// Invalid 'set proposal parameters' parameter - threshold percentage is zero or exceeds 100.
+        InvalidProposalParametersThresholdPercentage,
     }
 }
 
@@ -257,6 +486,27 @@ decl_storage! {
         /// Map proposal id to proposal details
         pub ProposalDetailsByProposalId: map hasher(blake2_128_concat) T::ProposalId => ProposalDetailsOf<T>;
 
+        /// Map wasm code hash to the uploaded runtime upgrade wasm code
+        pub RuntimeUpgradeWasmByHash get(fn runtime_upgrade_wasm_by_hash):
+            map hasher(identity) [u8; 32] => Vec<u8>;
+
+        /// Map wasm code hash to the deposit of the uploaded runtime upgrade wasm code
+        pub RuntimeUpgradeWasmDepositByHash get(fn runtime_upgrade_wasm_deposit_by_hash):
+            map hasher(identity) [u8; 32] =>
+                RuntimeUpgradeWasmDeposit<T::AccountId, BalanceOfGovernanceCurrency<T>>;
+
+        /// Map constitution version number to the constitution version. Versions start from 1.
+        pub ConstitutionByVersion get(fn constitution_by_version):
+            map hasher(blake2_128_concat) u32 => ConstitutionVersion<T::BlockNumber>;
+
+        /// Number of the constitution versions (the current constitution version number).
+        pub ConstitutionVersionCount get(fn constitution_version_count): u32;
+
+        /// Map proposal type to its parameters updated by the 'set proposal parameters' proposal
+        pub ProposalParametersByType get(fn proposal_parameters_by_type):
+            map hasher(blake2_128_concat) ProposalType =>
+                Option<ProposalParameters<T::BlockNumber, BalanceOf<T>>>;
+
         /// Voting period for the 'set validator count' proposal
         pub SetValidatorCountProposalVotingPeriod get(fn set_validator_count_proposal_voting_period)
             config(): T::BlockNumber;
@@ -350,6 +600,57 @@ decl_storage! {
         /// Grace period for the 'terminate working group leader role' proposal
         pub TerminateWorkingGroupLeaderRoleProposalGracePeriod get(fn terminate_working_group_leader_role_proposal_grace_period)
             config(): T::BlockNumber;
+
+        /// Voting period for the 'ranked choice' proposal
+        pub RankedChoiceProposalVotingPeriod get(fn ranked_choice_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'ranked choice' proposal
+        pub RankedChoiceProposalGracePeriod get(fn ranked_choice_proposal_grace_period)
+            config(): T::BlockNumber;
+
+        /// Voting period for the 'batch' proposal
+        pub BatchProposalVotingPeriod get(fn batch_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'batch' proposal
+        pub BatchProposalGracePeriod get(fn batch_proposal_grace_period)
+            config(): T::BlockNumber;
+
+        /// Voting period for the 'amend constitution' proposal
+        pub AmendConstitutionProposalVotingPeriod get(fn amend_constitution_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'amend constitution' proposal
+        pub AmendConstitutionProposalGracePeriod get(fn amend_constitution_proposal_grace_period)
+            config(): T::BlockNumber;
+
+        /// Voting period for the 'set proposal parameters' proposal
+        pub SetProposalParametersProposalVotingPeriod get(fn set_proposal_parameters_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'set proposal parameters' proposal
+        pub SetProposalParametersProposalGracePeriod get(fn set_proposal_parameters_proposal_grace_period)
+            config(): T::BlockNumber;
+
+        /// Voting period for the 'set default storage quota limits' proposal
+        pub SetDefaultStorageQuotaLimitsProposalVotingPeriod get(fn set_default_storage_quota_limits_proposal_voting_period) config(): T::BlockNumber;
+
+        /// Grace period for the 'set default storage quota limits' proposal
+        pub SetDefaultStorageQuotaLimitsProposalGracePeriod get(fn set_default_storage_quota_limits_proposal_grace_period) config(): T::BlockNumber;
+
+        /// Voting period for the 'set working group spending limit' proposal
+        pub SetWorkingGroupSpendingLimitProposalVotingPeriod get(fn set_working_group_spending_limit_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'set working group spending limit' proposal
+        pub SetWorkingGroupSpendingLimitProposalGracePeriod get(fn set_working_group_spending_limit_proposal_grace_period)
+            config(): T::BlockNumber;
+
+        /// Version of the module storage. New chains start with the current version, the older
+        /// versions are updated by `migrate_proposal_periods`.
+        pub StorageVersion get(fn storage_version) build(|_config: &GenesisConfig<T>|
+            STORAGE_VERSION): u32;
     }
 }
 
@@ -365,8 +666,15 @@ decl_module! {
         /// Exports max wasm code length of the runtime upgrade proposal const.
         const RuntimeUpgradeWasmProposalMaxLength: u32 = T::RuntimeUpgradeWasmProposalMaxLength::get();
 
+        /// Exports the deposit per byte of the uploaded runtime upgrade wasm code const.
+        const RuntimeUpgradeWasmDepositPerByte: BalanceOfGovernanceCurrency<T> =
+            T::RuntimeUpgradeWasmDepositPerByte::get();
+
+        /// Exports max allowed constitution text length const.
+        const ConstitutionMaxLength: u32 = T::ConstitutionMaxLength::get();
+
         /// Create 'Text (signal)' proposal type.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_text_proposal(text.len() as u32)]
         pub fn create_text_proposal(
             origin,
             member_id: MemberId<T>,
@@ -374,10 +682,9 @@ decl_module! {
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
             text: Vec<u8>,
+            exact_execution_block: Option<T::BlockNumber>,
         ) {
-            ensure!(!text.is_empty(), Error::<T>::TextProposalIsEmpty);
-            ensure!(text.len() as u32 <=  T::TextProposalMaxLength::get(),
-                Error::<T>::TextProposalSizeExceeded);
+            Self::ensure_text_proposal_valid(&text)?;
 
             let proposal_details = ProposalDetails::Text(text);
             let params = CreateProposalParameters{
@@ -386,6 +693,7 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
+                exact_execution_block,
                 proposal_details: proposal_details.clone(),
                 proposal_parameters: proposal_types::parameters::text_proposal::<T>(),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
@@ -394,28 +702,26 @@ decl_module! {
             Self::create_proposal(params)?;
         }
 
-        /// Create 'Runtime upgrade' proposal type. Runtime upgrade can be initiated only by
-        /// members from the hardcoded list `RuntimeUpgradeProposalAllowedProposers`
-        #[weight = 10_000_000] // TODO: adjust weight
+        /// Create 'Runtime upgrade' proposal type. The proposal contains the hash of the wasm code:
+        /// the wasm code should be uploaded with `upload_runtime_upgrade_wasm` before the execution.
+        #[weight = <T as Trait>::WeightInfo::create_runtime_upgrade_proposal()]
         pub fn create_runtime_upgrade_proposal(
             origin,
             member_id: MemberId<T>,
             title: Vec<u8>,
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
-            wasm: Vec<u8>,
+            runtime_upgrade_parameters: RuntimeUpgradeParameters,
+            exact_execution_block: Option<T::BlockNumber>,
         ) {
-            ensure!(!wasm.is_empty(), Error::<T>::RuntimeProposalIsEmpty);
-            ensure!(wasm.len() as u32 <= T::RuntimeUpgradeWasmProposalMaxLength::get(),
-                Error::<T>::RuntimeProposalSizeExceeded);
-
-            let proposal_details = ProposalDetails::RuntimeUpgrade(wasm);
+            let proposal_details = ProposalDetails::RuntimeUpgradeByHash(runtime_upgrade_parameters);
             let params = CreateProposalParameters{
                 origin,
                 member_id,
                 title,
                 description,
                 stake_balance,
+                exact_execution_block,
                 proposal_details: proposal_details.clone(),
                 proposal_parameters: proposal_types::parameters::runtime_upgrade_proposal::<T>(),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
@@ -426,7 +732,7 @@ decl_module! {
 
         /// Create 'Set election parameters' proposal type. This proposal uses `set_election_parameters()`
         /// extrinsic from the `governance::election module`.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_set_election_parameters_proposal()]
         pub fn create_set_election_parameters_proposal(
             origin,
             member_id: MemberId<T>,
@@ -434,6 +740,7 @@ decl_module! {
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
             election_parameters: ElectionParameters<BalanceOfGovernanceCurrency<T>, T::BlockNumber>,
+            exact_execution_block: Option<T::BlockNumber>,
         ) {
             election_parameters.ensure_valid()?;
 
@@ -446,6 +753,7 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
+                exact_execution_block,
                 proposal_details: proposal_details.clone(),
                 proposal_parameters: proposal_types::parameters::set_election_parameters_proposal::<T>(),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
@@ -456,7 +764,7 @@ decl_module! {
 
         /// Create 'Spending' proposal type.
         /// This proposal uses `spend_from_council_mint()` extrinsic from the `governance::council`  module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_spending_proposal()]
         pub fn create_spending_proposal(
             origin,
             member_id: MemberId<T>,
@@ -465,12 +773,9 @@ decl_module! {
             stake_balance: Option<BalanceOf<T>>,
             balance: BalanceOfMint<T>,
             destination: T::AccountId,
+            exact_execution_block: Option<T::BlockNumber>,
         ) {
-            ensure!(balance != BalanceOfMint::<T>::zero(), Error::<T>::InvalidSpendingProposalBalance);
-            ensure!(
-                balance <= <BalanceOfMint<T>>::from(MAX_SPENDING_PROPOSAL_VALUE),
-                Error::<T>::InvalidSpendingProposalBalance
-            );
+            Self::ensure_spending_proposal_valid(balance)?;
 
             let proposal_details = ProposalDetails::Spending(balance, destination);
             let params = CreateProposalParameters{
@@ -479,6 +784,7 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
+                exact_execution_block,
                 proposal_details: proposal_details.clone(),
                 proposal_parameters: proposal_types::parameters::spending_proposal::<T>(),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
@@ -489,7 +795,7 @@ decl_module! {
 
         /// Create 'Evict storage provider' proposal type.
         /// This proposal uses `set_validator_count()` extrinsic from the Substrate `staking`  module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_set_validator_count_proposal()]
         pub fn create_set_validator_count_proposal(
             origin,
             member_id: MemberId<T>,
@@ -497,16 +803,9 @@ decl_module! {
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
             new_validator_count: u32,
+            exact_execution_block: Option<T::BlockNumber>,
         ) {
-            ensure!(
-                new_validator_count >= <staking::Module<T>>::minimum_validator_count(),
-                Error::<T>::InvalidValidatorCount
-            );
-
-            ensure!(
-                new_validator_count <= MAX_VALIDATOR_COUNT,
-                Error::<T>::InvalidValidatorCount
-            );
+            Self::ensure_validator_count_valid(new_validator_count)?;
 
             let proposal_details = ProposalDetails::SetValidatorCount(new_validator_count);
             let params = CreateProposalParameters{
@@ -515,6 +814,7 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
+                exact_execution_block,
                 proposal_details: proposal_details.clone(),
                 proposal_parameters: proposal_types::parameters::set_validator_count_proposal::<T>(),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
@@ -525,7 +825,7 @@ decl_module! {
 
         /// Create 'Add working group leader opening' proposal type.
         /// This proposal uses `add_opening()` extrinsic from the Joystream `working group` module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_add_working_group_leader_opening_proposal()]
         pub fn create_add_working_group_leader_opening_proposal(
             origin,
             member_id: MemberId<T>,
@@ -533,6 +833,7 @@ decl_module! {
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
             add_opening_parameters: AddOpeningParameters<T::BlockNumber, BalanceOfGovernanceCurrency<T>>,
+            exact_execution_block: Option<T::BlockNumber>,
         ) {
 
             let proposal_details = ProposalDetails::AddWorkingGroupLeaderOpening(add_opening_parameters);
@@ -542,6 +843,7 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
+                exact_execution_block,
                 proposal_details: proposal_details.clone(),
                 proposal_parameters: proposal_types::parameters::add_working_group_leader_opening_proposal::<T>(),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
@@ -552,7 +854,7 @@ decl_module! {
 
         /// Create 'Begin review working group leader applications' proposal type.
         /// This proposal uses `begin_applicant_review()` extrinsic from the Joystream `working group` module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_begin_review_working_group_leader_applications_proposal()]
         pub fn create_begin_review_working_group_leader_applications_proposal(
             origin,
             member_id: MemberId<T>,
@@ -561,6 +863,7 @@ decl_module! {
             stake_balance: Option<BalanceOf<T>>,
             opening_id: working_group::OpeningId<T>,
             working_group: WorkingGroup,
+            exact_execution_block: Option<T::BlockNumber>,
         ) {
 
             let proposal_details = ProposalDetails::BeginReviewWorkingGroupLeaderApplications(opening_id, working_group);
@@ -570,6 +873,7 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
+                exact_execution_block,
                 proposal_details: proposal_details.clone(),
                 proposal_parameters: proposal_types::parameters::begin_review_working_group_leader_applications_proposal::<T>(),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
@@ -580,7 +884,7 @@ decl_module! {
 
         /// Create 'Fill working group leader opening' proposal type.
         /// This proposal uses `fill_opening()` extrinsic from the Joystream `working group` module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_fill_working_group_leader_opening_proposal()]
         pub fn create_fill_working_group_leader_opening_proposal(
             origin,
             member_id: MemberId<T>,
@@ -593,6 +897,7 @@ decl_module! {
                 working_group::OpeningId<T>,
                 working_group::ApplicationId<T>
             >
+            exact_execution_block: Option<T::BlockNumber>,
         ) {
 
             let proposal_details = ProposalDetails::FillWorkingGroupLeaderOpening(fill_opening_parameters);
@@ -602,6 +907,7 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
+                exact_execution_block,
                 proposal_details: proposal_details.clone(),
                 proposal_parameters: proposal_types::parameters::fill_working_group_leader_opening_proposal::<T>(),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
@@ -612,7 +918,7 @@ decl_module! {
 
         /// Create 'Set working group mint capacity' proposal type.
         /// This proposal uses `set_mint_capacity()` extrinsic from the `working-group`  module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_set_working_group_mint_capacity_proposal()]
         pub fn create_set_working_group_mint_capacity_proposal(
             origin,
             member_id: MemberId<T>,
@@ -621,11 +927,9 @@ decl_module! {
             stake_balance: Option<BalanceOf<T>>,
             mint_balance: BalanceOfMint<T>,
             working_group: WorkingGroup,
+            exact_execution_block: Option<T::BlockNumber>,
         ) {
-            ensure!(
-                mint_balance <= <BalanceOfMint<T>>::from(WORKING_GROUP_MINT_CAPACITY_MAX_VALUE),
-                Error::<T>::InvalidWorkingGroupMintCapacity
-            );
+            Self::ensure_working_group_mint_capacity_valid(mint_balance)?;
 
             let proposal_details = ProposalDetails::SetWorkingGroupMintCapacity(mint_balance, working_group);
             let params = CreateProposalParameters{
@@ -634,6 +938,7 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
+                exact_execution_block,
                 proposal_details: proposal_details.clone(),
                 proposal_parameters: proposal_types::parameters::set_working_group_mint_capacity_proposal::<T>(),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
@@ -644,7 +949,7 @@ decl_module! {
 
         /// Create 'decrease working group leader stake' proposal type.
         /// This proposal uses `decrease_stake()` extrinsic from the `working-group`  module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_decrease_working_group_leader_stake_proposal()]
         pub fn create_decrease_working_group_leader_stake_proposal(
             origin,
             member_id: MemberId<T>,
@@ -654,6 +959,7 @@ decl_module! {
             worker_id: working_group::WorkerId<T>,
             decreasing_stake: BalanceOf<T>,
             working_group: WorkingGroup,
+            exact_execution_block: Option<T::BlockNumber>,
         ) {
 
             ensure!(decreasing_stake != Zero::zero(), Error::<T>::DecreasingStakeIsZero);
@@ -670,6 +976,7 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
+                exact_execution_block,
                 proposal_details: proposal_details.clone(),
                 proposal_parameters: proposal_types::parameters::decrease_working_group_leader_stake_proposal::<T>(),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
@@ -680,7 +987,7 @@ decl_module! {
 
         /// Create 'slash working group leader stake' proposal type.
         /// This proposal uses `slash_stake()` extrinsic from the `working-group`  module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_slash_working_group_leader_stake_proposal()]
         pub fn create_slash_working_group_leader_stake_proposal(
             origin,
             member_id: MemberId<T>,
@@ -690,6 +997,7 @@ decl_module! {
             worker_id: working_group::WorkerId<T>,
             slashing_stake: BalanceOf<T>,
             working_group: WorkingGroup,
+            exact_execution_block: Option<T::BlockNumber>,
         ) {
 
             ensure!(slashing_stake != Zero::zero(), Error::<T>::SlashingStakeIsZero);
@@ -706,6 +1014,7 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
+                exact_execution_block,
                 proposal_details: proposal_details.clone(),
                 proposal_parameters: proposal_types::parameters::slash_working_group_leader_stake_proposal::<T>(),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
@@ -716,7 +1025,7 @@ decl_module! {
 
         /// Create 'set working group leader reward' proposal type.
         /// This proposal uses `update_reward_amount()` extrinsic from the `working-group`  module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_set_working_group_leader_reward_proposal()]
         pub fn create_set_working_group_leader_reward_proposal(
             origin,
             member_id: MemberId<T>,
@@ -726,6 +1035,7 @@ decl_module! {
             worker_id: working_group::WorkerId<T>,
             reward_amount: BalanceOfMint<T>,
             working_group: WorkingGroup,
+            exact_execution_block: Option<T::BlockNumber>,
         ) {
 
             let proposal_details = ProposalDetails::SetWorkingGroupLeaderReward(
@@ -740,6 +1050,7 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
+                exact_execution_block,
                 proposal_details: proposal_details.clone(),
                 proposal_parameters: proposal_types::parameters::set_working_group_leader_reward_proposal::<T>(),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
@@ -750,7 +1061,7 @@ decl_module! {
 
         /// Create 'terminate working group leader rolw' proposal type.
         /// This proposal uses `terminate_role()` extrinsic from the `working-group`  module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_terminate_working_group_leader_role_proposal()]
         pub fn create_terminate_working_group_leader_role_proposal(
             origin,
             member_id: MemberId<T>,
@@ -758,6 +1069,7 @@ decl_module! {
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
             terminate_role_parameters: TerminateRoleParameters<working_group::WorkerId<T>>,
+            exact_execution_block: Option<T::BlockNumber>,
         ) {
             let proposal_details = ProposalDetails::TerminateWorkingGroupLeaderRole(terminate_role_parameters);
 
@@ -767,6 +1079,7 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
+                exact_execution_block,
                 proposal_details: proposal_details.clone(),
                 proposal_parameters: proposal_types::parameters::terminate_working_group_leader_role_proposal::<T>(),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
@@ -775,11 +1088,288 @@ decl_module! {
             Self::create_proposal(params)?;
         }
 
+        /// Create 'Ranked choice' proposal type. The proposal contains several options: council
+        /// members rank them and the winning option gets executed on the proposal approval.
+        #[weight = <T as Trait>::WeightInfo::create_ranked_choice_proposal(options.len() as u32)]
+        pub fn create_ranked_choice_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            options: Vec<RankedChoiceOptionOf<T>>,
+            exact_execution_block: Option<T::BlockNumber>,
+        ) {
+            for (index, option) in options.iter().enumerate() {
+                ensure!(
+                    !options[..index].contains(option),
+                    Error::<T>::DuplicateRankedChoiceOption
+                );
+
+                Self::ensure_ranked_choice_option_valid(option)?;
+            }
+
+            let encoded_options = options
+                .iter()
+                .cloned()
+                .map(|option| T::ProposalEncoder::encode_proposal(option.into()))
+                .collect::<Vec<_>>();
+
+            let proposal_parameters =
+                proposal_types::parameters::ranked_choice_proposal::<T>(&options);
+            let proposal_details = ProposalDetails::RankedChoice(options);
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                exact_execution_block,
+                proposal_details,
+                proposal_parameters,
+                proposal_code: Vec::new(),
+            };
+
+            Self::create_multi_option_proposal(params, encoded_options)?;
+        }
+
+        /// Create 'Batch' proposal type. The proposal contains an ordered list of items: on the
+        /// proposal approval the items get executed in order. The batch execution is atomic:
+        /// any failed item reverts the changes of the whole batch.
+        /// Items are fixed on the proposal creation: an item cannot refer to the ids (e.g. opening
+        /// or application ids) created by the previous items of the same batch.
+        #[weight = Module::<T>::batch_proposal_creation_weight(items)]
+        pub fn create_batch_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            items: Vec<BatchProposalItemOf<T>>,
+            exact_execution_block: Option<T::BlockNumber>,
+        ) {
+            ensure!(!items.is_empty(), Error::<T>::BatchProposalIsEmpty);
+            ensure!(
+                items.len() as u32 <= MAX_BATCH_PROPOSAL_ITEMS,
+                Error::<T>::BatchProposalSizeExceeded
+            );
+
+            for item in items.iter() {
+                Self::ensure_batch_proposal_item_valid(item)?;
+            }
+
+            let proposal_parameters = proposal_types::parameters::batch_proposal::<T>(&items);
+            let proposal_details = ProposalDetails::Batch(items);
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                exact_execution_block,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters,
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'Amend constitution' proposal type. On the proposal execution the provided text
+        /// becomes the new version of the platform constitution.
+        #[weight = <T as Trait>::WeightInfo::create_amend_constitution_proposal(
+            constitution_text.len() as u32
+        )]
+        pub fn create_amend_constitution_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            constitution_text: Vec<u8>,
+            exact_execution_block: Option<T::BlockNumber>,
+        ) {
+            Self::ensure_constitution_valid(&constitution_text)?;
+
+            let proposal_details = ProposalDetails::AmendConstitution(constitution_text);
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                exact_execution_block,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::amend_constitution_proposal::<T>(),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'Set proposal parameters' proposal type. On the proposal execution the provided
+        /// parameters replace the parameters of the proposal type.
+        #[weight = <T as Trait>::WeightInfo::create_set_proposal_parameters_proposal()]
+        pub fn create_set_proposal_parameters_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            proposal_type: ProposalType,
+            proposal_parameters: ProposalParameters<T::BlockNumber, BalanceOf<T>>,
+            exact_execution_block: Option<T::BlockNumber>,
+        ) {
+            Self::ensure_proposal_parameters_valid(&proposal_parameters)?;
+
+            let proposal_details =
+                ProposalDetails::SetProposalParameters(proposal_type, proposal_parameters);
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                exact_execution_block,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters:
+                    proposal_types::parameters::set_proposal_parameters_proposal::<T>(),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'Set default storage quota limits' proposal type.
+        /// This proposal uses `set_default_quota_limits()` extrinsic from the Joystream
+        /// `data directory` module.
+        #[weight = <T as Trait>::WeightInfo::create_set_default_storage_quota_limits_proposal()]
+        pub fn create_set_default_storage_quota_limits_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            objects_limit: u64,
+            size_limit: u64,
+            exact_execution_block: Option<T::BlockNumber>,
+        ) {
+            let proposal_details =
+                ProposalDetails::SetDefaultStorageQuotaLimits(objects_limit, size_limit);
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                exact_execution_block,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters:
+                    proposal_types::parameters::set_default_storage_quota_limits_proposal::<T>(),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'Set working group spending limit' proposal type. Removes the limit (disabling
+        /// the one-off payments) when not provided.
+        /// This proposal uses `set_spending_limit()` extrinsic from the `working-group` module.
+        #[weight = <T as Trait>::WeightInfo::create_set_working_group_spending_limit_proposal()]
+        pub fn create_set_working_group_spending_limit_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            spending_limit: Option<SpendingLimit<BalanceOfMint<T>, T::BlockNumber>>,
+            working_group: WorkingGroup,
+            exact_execution_block: Option<T::BlockNumber>,
+        ) {
+            if let Some(ref spending_limit) = spending_limit {
+                ensure!(
+                    spending_limit.period_length != Zero::zero(),
+                    Error::<T>::InvalidWorkingGroupSpendingPeriodLength
+                );
+            }
+
+            let proposal_details =
+                ProposalDetails::SetWorkingGroupSpendingLimit(spending_limit, working_group);
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                exact_execution_block,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters:
+                    proposal_types::parameters::set_working_group_spending_limit_proposal::<T>(),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Uploads the wasm code for the 'Runtime upgrade' proposal. The wasm code is stored under
+        /// its blake2-256 hash. The deposit proportional to the code length is reserved from
+        /// the uploader account.
+        #[weight = <T as Trait>::WeightInfo::upload_runtime_upgrade_wasm(wasm.len() as u32)]
+        pub fn upload_runtime_upgrade_wasm(origin, wasm: Vec<u8>) {
+            let account_id = ensure_signed(origin)?;
+
+            ensure!(!wasm.is_empty(), Error::<T>::RuntimeProposalIsEmpty);
+            ensure!(wasm.len() as u32 <= T::RuntimeUpgradeWasmProposalMaxLength::get(),
+                Error::<T>::RuntimeProposalSizeExceeded);
+
+            let wasm_hash = blake2_256(&wasm);
+            ensure!(
+                !<RuntimeUpgradeWasmByHash>::contains_key(wasm_hash),
+                Error::<T>::RuntimeUpgradeWasmAlreadyUploaded
+            );
+
+            let deposit = T::RuntimeUpgradeWasmDepositPerByte::get()
+                .saturating_mul(BalanceOfGovernanceCurrency::<T>::from(wasm.len() as u32));
+
+            // mutation
+
+            <T as GovernanceCurrency>::Currency::reserve(&account_id, deposit)
+                .map_err(|_| Error::<T>::InsufficientBalanceForRuntimeUpgradeWasmDeposit)?;
+
+            <RuntimeUpgradeWasmByHash>::insert(wasm_hash, wasm);
+            <RuntimeUpgradeWasmDepositByHash<T>>::insert(
+                wasm_hash,
+                RuntimeUpgradeWasmDeposit {
+                    depositor: account_id,
+                    amount: deposit,
+                },
+            );
+        }
+
+        /// Removes the uploaded wasm code for the 'Runtime upgrade' proposal and returns
+        /// the deposit. Can be called only by the uploader account.
+        #[weight = <T as Trait>::WeightInfo::remove_runtime_upgrade_wasm()]
+        pub fn remove_runtime_upgrade_wasm(origin, wasm_hash: [u8; 32]) {
+            let account_id = ensure_signed(origin)?;
+
+            ensure!(
+                <RuntimeUpgradeWasmByHash>::contains_key(wasm_hash),
+                Error::<T>::RuntimeUpgradeWasmNotUploaded
+            );
+            ensure!(
+                Self::runtime_upgrade_wasm_deposit_by_hash(wasm_hash).depositor == account_id,
+                Error::<T>::NotRuntimeUpgradeWasmDepositor
+            );
+
+            // mutation
+
+            Self::remove_runtime_upgrade_wasm_with_refund(wasm_hash);
+        }
 
 // *************** Extrinsic to execute
 
         /// Text proposal extrinsic. Should be used as callable object to pass to the `engine` module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::execute_text_proposal(text.len() as u32)]
         pub fn execute_text_proposal(
             origin,
             text: Vec<u8>,
@@ -794,26 +1384,141 @@ decl_module! {
 
         /// Runtime upgrade proposal extrinsic.
         /// Should be used as callable object to pass to the `engine` module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        /// Verifies the hash and the spec version of the uploaded wasm code before setting
+        /// the runtime code. The wasm code deposit is returned on success.
+        #[weight = <T as Trait>::WeightInfo::execute_runtime_upgrade_proposal()]
         pub fn execute_runtime_upgrade_proposal(
             origin,
-            wasm: Vec<u8>,
+            runtime_upgrade_parameters: RuntimeUpgradeParameters,
         ) {
             let (cloned_origin1, cloned_origin2) = common::origin::double_origin::<T>(origin);
             ensure_root(cloned_origin1)?;
 
+            let wasm_hash = runtime_upgrade_parameters.wasm_hash;
+            ensure!(
+                <RuntimeUpgradeWasmByHash>::contains_key(wasm_hash),
+                Error::<T>::RuntimeUpgradeWasmNotUploaded
+            );
+
+            let wasm = Self::runtime_upgrade_wasm_by_hash(wasm_hash);
+            ensure!(blake2_256(&wasm) == wasm_hash, Error::<T>::RuntimeUpgradeWasmHashMismatch);
+
+            let runtime_version = sp_io::misc::runtime_version(&wasm)
+                .and_then(|version| RuntimeVersion::decode(&mut &version[..]).ok())
+                .ok_or(Error::<T>::RuntimeUpgradeWasmVersionUnknown)?;
+            ensure!(
+                runtime_version.spec_version == runtime_upgrade_parameters.spec_version,
+                Error::<T>::RuntimeUpgradeSpecVersionMismatch
+            );
+
             print("Runtime upgrade proposal execution started.");
 
             <system::Module<T>>::set_code(cloned_origin2, wasm)?;
 
+            Self::remove_runtime_upgrade_wasm_with_refund(wasm_hash);
+
             print("Runtime upgrade proposal execution finished.");
         }
+
+        /// Batch proposal extrinsic. Should be used as callable object to pass to the `engine` module.
+        /// Dispatches the encoded calls in order with the root origin. Storage changes are reverted
+        /// on the first failed call.
+        #[weight = Module::<T>::batch_proposal_execution_weight(calls)]
+        pub fn execute_batch_proposal(
+            origin,
+            calls: Vec<Vec<u8>>,
+        ) {
+            ensure_root(origin)?;
+
+            with_transaction(|| {
+                let result = Self::dispatch_batch_calls(calls);
+
+                if result.is_ok() {
+                    TransactionOutcome::Commit(result)
+                } else {
+                    TransactionOutcome::Rollback(result)
+                }
+            })?;
+        }
+
+        /// Amend constitution proposal extrinsic.
+        /// Should be used as callable object to pass to the `engine` module.
+        /// Saves the provided text with its hash as the next constitution version.
+        #[weight = <T as Trait>::WeightInfo::execute_amend_constitution_proposal(
+            constitution_text.len() as u32
+        )]
+        pub fn execute_amend_constitution_proposal(
+            origin,
+            constitution_text: Vec<u8>,
+        ) {
+            ensure_root(origin)?;
+
+            Self::ensure_constitution_valid(&constitution_text)?;
+
+            // mutation
+
+            let version = Self::constitution_version_count() + 1;
+            let constitution = ConstitutionVersion {
+                text_hash: blake2_256(&constitution_text),
+                text: constitution_text,
+                amended_at: <system::Module<T>>::block_number(),
+            };
+
+            <ConstitutionByVersion<T>>::insert(version, constitution);
+            ConstitutionVersionCount::put(version);
+        }
+
+        /// Set proposal parameters proposal extrinsic.
+        /// Should be used as callable object to pass to the `engine` module.
+        /// Updated parameters apply to the proposals of the type created after the update.
+        #[weight = <T as Trait>::WeightInfo::execute_set_proposal_parameters_proposal()]
+        pub fn execute_set_proposal_parameters_proposal(
+            origin,
+            proposal_type: ProposalType,
+            proposal_parameters: ProposalParameters<T::BlockNumber, BalanceOf<T>>,
+        ) {
+            ensure_root(origin)?;
+
+            Self::ensure_proposal_parameters_valid(&proposal_parameters)?;
+
+            // mutation
+
+            <ProposalParametersByType<T>>::insert(proposal_type, proposal_parameters);
+        }
     }
 }
 
 impl<T: Trait> Module<T> {
+    /// Returns the current constitution version if the constitution was ever amended.
+    pub fn current_constitution() -> Option<ConstitutionVersion<T::BlockNumber>> {
+        let version = Self::constitution_version_count();
+
+        if <ConstitutionByVersion<T>>::contains_key(version) {
+            Some(Self::constitution_by_version(version))
+        } else {
+            None
+        }
+    }
+
     // Generic template proposal builder
     fn create_proposal(params: CreateProposalParameters<T>) -> DispatchResult {
+        Self::create_proposal_with_options(params, None)
+    }
+
+    // Generic template multi-option proposal builder. Proposal code of the params is ignored:
+    // encoded options are executed instead.
+    fn create_multi_option_proposal(
+        params: CreateProposalParameters<T>,
+        encoded_options: Vec<Vec<u8>>,
+    ) -> DispatchResult {
+        Self::create_proposal_with_options(params, Some(encoded_options))
+    }
+
+    // Creates the binary proposal or the multi-option proposal if the encoded options provided.
+    fn create_proposal_with_options(
+        params: CreateProposalParameters<T>,
+        encoded_options: Option<Vec<Vec<u8>>>,
+    ) -> DispatchResult {
         let account_id =
             T::MembershipOriginValidator::ensure_actor_origin(params.origin, params.member_id)?;
 
@@ -822,8 +1527,13 @@ impl<T: Trait> Module<T> {
             &params.title,
             &params.description,
             params.stake_balance,
+            params.exact_execution_block,
         )?;
 
+        if let Some(ref encoded_options) = encoded_options {
+            <proposals_engine::Module<T>>::ensure_proposal_options_are_valid(encoded_options)?;
+        }
+
         <proposals_discussion::Module<T>>::ensure_can_create_thread(
             params.member_id,
             &params.title,
@@ -834,15 +1544,29 @@ impl<T: Trait> Module<T> {
             params.title.clone(),
         )?;
 
-        let proposal_id = <proposals_engine::Module<T>>::create_proposal(
-            account_id,
-            params.member_id,
-            params.proposal_parameters,
-            params.title,
-            params.description,
-            params.stake_balance,
-            params.proposal_code,
-        )?;
+        let proposal_id = if let Some(encoded_options) = encoded_options {
+            <proposals_engine::Module<T>>::create_multi_option_proposal(
+                account_id,
+                params.member_id,
+                params.proposal_parameters,
+                params.title,
+                params.description,
+                params.stake_balance,
+                params.exact_execution_block,
+                encoded_options,
+            )?
+        } else {
+            <proposals_engine::Module<T>>::create_proposal(
+                account_id,
+                params.member_id,
+                params.proposal_parameters,
+                params.title,
+                params.description,
+                params.stake_balance,
+                params.exact_execution_block,
+                params.proposal_code,
+            )?
+        };
 
         <ThreadIdByProposalId<T>>::insert(proposal_id, discussion_thread_id);
         <ProposalDetailsByProposalId<T>>::insert(proposal_id, params.proposal_details);
@@ -850,6 +1574,243 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    // Decodes and dispatches the batch proposal calls in order with the root origin.
+    // Stops on the first failed call.
+    fn dispatch_batch_calls(calls: Vec<Vec<u8>>) -> DispatchResult {
+        for call in calls {
+            let call = T::DispatchableCallCode::decode(&mut &call[..])
+                .map_err(|_| Error::<T>::BatchProposalCallDecodingFailed)?;
+
+            call.dispatch_bypass_filter(T::Origin::from(RawOrigin::Root))
+                .map_err(|e| e.error)?;
+        }
+
+        Ok(())
+    }
+
+    // Weight of the 'Batch' proposal creation: includes the creation weights of all items.
+    fn batch_proposal_creation_weight(items: &[BatchProposalItemOf<T>]) -> Weight {
+        items.iter().fold(
+            <T as Trait>::WeightInfo::create_batch_proposal(items.len() as u32),
+            |weight, item| weight.saturating_add(Self::batch_proposal_item_creation_weight(item)),
+        )
+    }
+
+    // Weight of the 'Batch' proposal execution: includes the dispatch weights of all calls.
+    // Undecodable calls fail the execution and add no weight.
+    fn batch_proposal_execution_weight(calls: &[Vec<u8>]) -> Weight {
+        calls.iter().fold(
+            <T as Trait>::WeightInfo::execute_batch_proposal(calls.len() as u32),
+            |weight, call| {
+                let call_weight = T::DispatchableCallCode::decode(&mut &call[..])
+                    .map(|call| call.get_dispatch_info().weight)
+                    .unwrap_or_default();
+
+                weight.saturating_add(call_weight)
+            },
+        )
+    }
+
+    // Weight of the 'Batch' proposal item equals the creation weight of its proposal type.
+    fn batch_proposal_item_creation_weight(item: &BatchProposalItemOf<T>) -> Weight {
+        match item {
+            BatchProposalItem::Text(text) => {
+                <T as Trait>::WeightInfo::create_text_proposal(text.len() as u32)
+            }
+            BatchProposalItem::SetElectionParameters(_) => {
+                <T as Trait>::WeightInfo::create_set_election_parameters_proposal()
+            }
+            BatchProposalItem::Spending(_, _) => {
+                <T as Trait>::WeightInfo::create_spending_proposal()
+            }
+            BatchProposalItem::SetValidatorCount(_) => {
+                <T as Trait>::WeightInfo::create_set_validator_count_proposal()
+            }
+            BatchProposalItem::AddWorkingGroupLeaderOpening(_) => {
+                <T as Trait>::WeightInfo::create_add_working_group_leader_opening_proposal()
+            }
+            BatchProposalItem::BeginReviewWorkingGroupLeaderApplications(_, _) => {
+                <T as Trait>::WeightInfo::create_begin_review_working_group_leader_applications_proposal()
+            }
+            BatchProposalItem::FillWorkingGroupLeaderOpening(_) => {
+                <T as Trait>::WeightInfo::create_fill_working_group_leader_opening_proposal()
+            }
+            BatchProposalItem::SetWorkingGroupMintCapacity(_, _) => {
+                <T as Trait>::WeightInfo::create_set_working_group_mint_capacity_proposal()
+            }
+            BatchProposalItem::DecreaseWorkingGroupLeaderStake(_, _, _) => {
+                <T as Trait>::WeightInfo::create_decrease_working_group_leader_stake_proposal()
+            }
+            BatchProposalItem::SlashWorkingGroupLeaderStake(_, _, _) => {
+                <T as Trait>::WeightInfo::create_slash_working_group_leader_stake_proposal()
+            }
+            BatchProposalItem::SetWorkingGroupLeaderReward(_, _, _) => {
+                <T as Trait>::WeightInfo::create_set_working_group_leader_reward_proposal()
+            }
+            BatchProposalItem::TerminateWorkingGroupLeaderRole(_) => {
+                <T as Trait>::WeightInfo::create_terminate_working_group_leader_role_proposal()
+            }
+        }
+    }
+
+    // Removes the uploaded runtime upgrade wasm code and unreserves its deposit.
+    fn remove_runtime_upgrade_wasm_with_refund(wasm_hash: [u8; 32]) {
+        let deposit = <RuntimeUpgradeWasmDepositByHash<T>>::take(wasm_hash);
+        <RuntimeUpgradeWasmByHash>::remove(wasm_hash);
+
+        <T as GovernanceCurrency>::Currency::unreserve(&deposit.depositor, deposit.amount);
+    }
+
+    // validates text for the 'Text' proposal
+    fn ensure_text_proposal_valid(text: &[u8]) -> DispatchResult {
+        ensure!(!text.is_empty(), Error::<T>::TextProposalIsEmpty);
+        ensure!(
+            text.len() as u32 <= T::TextProposalMaxLength::get(),
+            Error::<T>::TextProposalSizeExceeded
+        );
+
+        Ok(())
+    }
+
+    // validates text for the 'Amend constitution' proposal
+    fn ensure_constitution_valid(constitution_text: &[u8]) -> DispatchResult {
+        ensure!(
+            !constitution_text.is_empty(),
+            Error::<T>::ConstitutionIsEmpty
+        );
+        ensure!(
+            constitution_text.len() as u32 <= T::ConstitutionMaxLength::get(),
+            Error::<T>::ConstitutionSizeExceeded
+        );
+
+        Ok(())
+    }
+
+    // validates parameters for the 'Set proposal parameters' proposal
+    fn ensure_proposal_parameters_valid(
+        proposal_parameters: &ProposalParameters<T::BlockNumber, BalanceOf<T>>,
+    ) -> DispatchResult {
+        ensure!(
+            proposal_parameters.voting_period != Zero::zero(),
+            Error::<T>::InvalidProposalParametersVotingPeriod
+        );
+
+        ensure!(
+            proposal_parameters.approval_quorum_percentage <= 100
+                && proposal_parameters.slashing_quorum_percentage <= 100,
+            Error::<T>::InvalidProposalParametersQuorumPercentage
+        );
+
+        // Zero thresholds are rejected by the proposals engine on proposal creation.
+        let valid_threshold = |percentage: u32| percentage > 0 && percentage <= 100;
+        ensure!(
+            valid_threshold(proposal_parameters.approval_threshold_percentage)
+                && valid_threshold(proposal_parameters.slashing_threshold_percentage),
+            Error::<T>::InvalidProposalParametersThresholdPercentage
+        );
+
+        Ok(())
+    }
+
+    // validates balance for the 'Spending' proposal
+    fn ensure_spending_proposal_valid(balance: BalanceOfMint<T>) -> DispatchResult {
+        ensure!(
+            balance != BalanceOfMint::<T>::zero(),
+            Error::<T>::InvalidSpendingProposalBalance
+        );
+        ensure!(
+            balance <= <BalanceOfMint<T>>::from(MAX_SPENDING_PROPOSAL_VALUE),
+            Error::<T>::InvalidSpendingProposalBalance
+        );
+
+        Ok(())
+    }
+
+    // validates mint capacity for the 'Set working group mint capacity' proposal
+    fn ensure_working_group_mint_capacity_valid(mint_balance: BalanceOfMint<T>) -> DispatchResult {
+        ensure!(
+            mint_balance <= <BalanceOfMint<T>>::from(WORKING_GROUP_MINT_CAPACITY_MAX_VALUE),
+            Error::<T>::InvalidWorkingGroupMintCapacity
+        );
+
+        Ok(())
+    }
+
+    // validates validator count for the 'Set validator count' proposal
+    fn ensure_validator_count_valid(new_validator_count: u32) -> DispatchResult {
+        ensure!(
+            new_validator_count >= <staking::Module<T>>::minimum_validator_count(),
+            Error::<T>::InvalidValidatorCount
+        );
+
+        ensure!(
+            new_validator_count <= MAX_VALIDATOR_COUNT,
+            Error::<T>::InvalidValidatorCount
+        );
+
+        Ok(())
+    }
+
+    // validates the 'Batch' proposal item using the checks of its proposal type
+    fn ensure_batch_proposal_item_valid(item: &BatchProposalItemOf<T>) -> DispatchResult {
+        match item {
+            BatchProposalItem::Text(text) => Self::ensure_text_proposal_valid(text),
+            BatchProposalItem::SetElectionParameters(election_parameters) => {
+                election_parameters.ensure_valid()?;
+
+                Self::ensure_council_election_parameters_valid(election_parameters)
+            }
+            BatchProposalItem::Spending(balance, _) => {
+                Self::ensure_spending_proposal_valid(*balance)
+            }
+            BatchProposalItem::SetValidatorCount(new_validator_count) => {
+                Self::ensure_validator_count_valid(*new_validator_count)
+            }
+            BatchProposalItem::SetWorkingGroupMintCapacity(mint_balance, _) => {
+                Self::ensure_working_group_mint_capacity_valid(*mint_balance)
+            }
+            BatchProposalItem::DecreaseWorkingGroupLeaderStake(_, decreasing_stake, _) => {
+                ensure!(
+                    *decreasing_stake != Zero::zero(),
+                    Error::<T>::DecreasingStakeIsZero
+                );
+
+                Ok(())
+            }
+            BatchProposalItem::SlashWorkingGroupLeaderStake(_, slashing_stake, _) => {
+                ensure!(
+                    *slashing_stake != Zero::zero(),
+                    Error::<T>::SlashingStakeIsZero
+                );
+
+                Ok(())
+            }
+            BatchProposalItem::AddWorkingGroupLeaderOpening(_)
+            | BatchProposalItem::BeginReviewWorkingGroupLeaderApplications(_, _)
+            | BatchProposalItem::FillWorkingGroupLeaderOpening(_)
+            | BatchProposalItem::SetWorkingGroupLeaderReward(_, _, _)
+            | BatchProposalItem::TerminateWorkingGroupLeaderRole(_) => Ok(()),
+        }
+    }
+
+    // validates the 'Ranked choice' proposal option using the checks of its proposal type
+    fn ensure_ranked_choice_option_valid(option: &RankedChoiceOptionOf<T>) -> DispatchResult {
+        match option {
+            RankedChoiceOption::Text(text) => Self::ensure_text_proposal_valid(text),
+            RankedChoiceOption::SetElectionParameters(election_parameters) => {
+                election_parameters.ensure_valid()?;
+
+                Self::ensure_council_election_parameters_valid(election_parameters)
+            }
+            RankedChoiceOption::Spending(balance, _) => {
+                Self::ensure_spending_proposal_valid(*balance)
+            }
+            RankedChoiceOption::SetWorkingGroupMintCapacity(mint_balance, _) => {
+                Self::ensure_working_group_mint_capacity_valid(*mint_balance)
+            }
+        }
+    }
+
     // validates council election parameters for the 'Set election parameters' proposal
     pub(crate) fn ensure_council_election_parameters_valid(
         election_parameters: &ElectionParameters<BalanceOfGovernanceCurrency<T>, T::BlockNumber>,
@@ -1034,5 +1995,84 @@ impl<T: Trait> Module<T> {
         <TerminateWorkingGroupLeaderRoleProposalGracePeriod<T>>::put(T::BlockNumber::from(
             p.terminate_working_group_leader_role_proposal_grace_period,
         ));
+        <RankedChoiceProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.ranked_choice_proposal_voting_period,
+        ));
+        <RankedChoiceProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.ranked_choice_proposal_grace_period,
+        ));
+        <BatchProposalVotingPeriod<T>>::put(T::BlockNumber::from(p.batch_proposal_voting_period));
+        <BatchProposalGracePeriod<T>>::put(T::BlockNumber::from(p.batch_proposal_grace_period));
+        <AmendConstitutionProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.amend_constitution_proposal_voting_period,
+        ));
+        <AmendConstitutionProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.amend_constitution_proposal_grace_period,
+        ));
+        <SetProposalParametersProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.set_proposal_parameters_proposal_voting_period,
+        ));
+        <SetProposalParametersProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.set_proposal_parameters_proposal_grace_period,
+        ));
+        <SetDefaultStorageQuotaLimitsProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.set_default_storage_quota_limits_proposal_voting_period,
+        ));
+        <SetDefaultStorageQuotaLimitsProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.set_default_storage_quota_limits_proposal_grace_period,
+        ));
+        <SetWorkingGroupSpendingLimitProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.set_working_group_spending_limit_proposal_voting_period,
+        ));
+        <SetWorkingGroupSpendingLimitProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.set_working_group_spending_limit_proposal_grace_period,
+        ));
+    }
+
+    /// Sets the periods of the proposal types added after the genesis: the live chains have
+    /// the periods of these types unset. Runs once: the periods are set if the storage version is
+    /// current. Returns the weight of the migration.
+    pub fn migrate_proposal_periods(p: ProposalsConfigParameters) -> Weight {
+        if Self::storage_version() >= STORAGE_VERSION {
+            return T::DbWeight::get().reads(1);
+        }
+
+        <RankedChoiceProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.ranked_choice_proposal_voting_period,
+        ));
+        <RankedChoiceProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.ranked_choice_proposal_grace_period,
+        ));
+        <BatchProposalVotingPeriod<T>>::put(T::BlockNumber::from(p.batch_proposal_voting_period));
+        <BatchProposalGracePeriod<T>>::put(T::BlockNumber::from(p.batch_proposal_grace_period));
+        <AmendConstitutionProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.amend_constitution_proposal_voting_period,
+        ));
+        <AmendConstitutionProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.amend_constitution_proposal_grace_period,
+        ));
+        <SetProposalParametersProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.set_proposal_parameters_proposal_voting_period,
+        ));
+        <SetProposalParametersProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.set_proposal_parameters_proposal_grace_period,
+        ));
+        <SetDefaultStorageQuotaLimitsProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.set_default_storage_quota_limits_proposal_voting_period,
+        ));
+        <SetDefaultStorageQuotaLimitsProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.set_default_storage_quota_limits_proposal_grace_period,
+        ));
+        <SetWorkingGroupSpendingLimitProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.set_working_group_spending_limit_proposal_voting_period,
+        ));
+        <SetWorkingGroupSpendingLimitProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.set_working_group_spending_limit_proposal_grace_period,
+        ));
+
+        StorageVersion::put(STORAGE_VERSION);
+
+        // Reads: the version. Writes: the version and twelve periods.
+        T::DbWeight::get().reads_writes(1, 13)
     }
 }
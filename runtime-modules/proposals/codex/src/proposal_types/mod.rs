@@ -7,8 +7,9 @@ use codec::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 use sp_std::vec::Vec;
 
-use crate::ElectionParameters;
+use crate::{ElectionParameters, ProposalParameters};
 use common::working_group::WorkingGroup;
+use working_group::SpendingLimit;
 
 /// Encodes proposal using its details information.
 pub trait ProposalEncoder<T: crate::Trait> {
@@ -29,6 +30,26 @@ pub type ProposalDetailsOf<T> = ProposalDetails<
     crate::MemberId<T>,
 >;
 
+/// _RankedChoiceOption_ alias for type simplification
+pub type RankedChoiceOptionOf<T> = RankedChoiceOption<
+    crate::BalanceOfMint<T>,
+    crate::BalanceOfGovernanceCurrency<T>,
+    <T as system::Trait>::BlockNumber,
+    <T as system::Trait>::AccountId,
+>;
+
+/// _BatchProposalItem_ alias for type simplification
+pub type BatchProposalItemOf<T> = BatchProposalItem<
+    crate::BalanceOfMint<T>,
+    crate::BalanceOfGovernanceCurrency<T>,
+    <T as system::Trait>::BlockNumber,
+    <T as system::Trait>::AccountId,
+    working_group::OpeningId<T>,
+    working_group::ApplicationId<T>,
+    crate::BalanceOf<T>,
+    working_group::WorkerId<T>,
+>;
+
 /// Proposal details provide voters the information required for the perceived voting.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Debug)]
@@ -46,6 +67,8 @@ pub enum ProposalDetails<
     /// The text of the `text` proposal
     Text(Vec<u8>),
 
+    /// ********** Deprecated: replaced by the `RuntimeUpgradeByHash`.
+    /// It is kept only for backward compatibility in the Pioneer. **********
     /// The wasm code for the `runtime upgrade` proposal
     RuntimeUpgrade(Vec<u8>),
 
@@ -103,6 +126,44 @@ pub enum ProposalDetails<
 
     /// Fire the working group leader with possible slashing.
     TerminateWorkingGroupLeaderRole(TerminateRoleParameters<WorkerId>),
+
+    /// Options of the `ranked choice` proposal. The winning option gets executed.
+    RankedChoice(Vec<RankedChoiceOption<MintedBalance, CurrencyBalance, BlockNumber, AccountId>>),
+
+    /// Items of the `batch` proposal. The items get executed in order and atomically.
+    Batch(
+        Vec<
+            BatchProposalItem<
+                MintedBalance,
+                CurrencyBalance,
+                BlockNumber,
+                AccountId,
+                OpeningId,
+                ApplicationId,
+                StakeBalance,
+                WorkerId,
+            >,
+        >,
+    ),
+
+    /// Wasm code hash and spec version for the `runtime upgrade` proposal
+    RuntimeUpgradeByHash(RuntimeUpgradeParameters),
+
+    /// New constitution text for the `amend constitution` proposal
+    AmendConstitution(Vec<u8>),
+
+    /// Proposal type and its new parameters for the `set proposal parameters` proposal
+    SetProposalParameters(ProposalType, ProposalParameters<BlockNumber, StakeBalance>),
+
+    /// Objects limit and size limit for the `set default storage quota limits` proposal
+    SetDefaultStorageQuotaLimits(u64, u64),
+
+    /// Spending limit (removed when not provided) and working group for the
+    /// `set working group spending limit` proposal
+    SetWorkingGroupSpendingLimit(
+        Option<SpendingLimit<MintedBalance, BlockNumber>>,
+        WorkingGroup,
+    ),
 }
 
 impl<
@@ -133,6 +194,328 @@ impl<
     }
 }
 
+/// Option of the 'ranked choice' proposal. Contains details of the proposal type that gets
+/// executed when the option wins.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub enum RankedChoiceOption<MintedBalance, CurrencyBalance, BlockNumber, AccountId> {
+    /// The text of the `text` proposal
+    Text(Vec<u8>),
+
+    /// Election parameters for the `set election parameters` proposal
+    SetElectionParameters(ElectionParameters<CurrencyBalance, BlockNumber>),
+
+    /// Balance and destination account for the `spending` proposal
+    Spending(MintedBalance, AccountId),
+
+    /// Set working group mint capacity.
+    SetWorkingGroupMintCapacity(MintedBalance, WorkingGroup),
+}
+
+impl<
+        MintedBalance,
+        CurrencyBalance,
+        BlockNumber,
+        AccountId,
+        OpeningId,
+        ApplicationId,
+        StakeBalance,
+        WorkerId,
+        MemberId,
+    > From<RankedChoiceOption<MintedBalance, CurrencyBalance, BlockNumber, AccountId>>
+    for ProposalDetails<
+        MintedBalance,
+        CurrencyBalance,
+        BlockNumber,
+        AccountId,
+        OpeningId,
+        ApplicationId,
+        StakeBalance,
+        WorkerId,
+        MemberId,
+    >
+{
+    fn from(
+        option: RankedChoiceOption<MintedBalance, CurrencyBalance, BlockNumber, AccountId>,
+    ) -> Self {
+        match option {
+            RankedChoiceOption::Text(text) => ProposalDetails::Text(text),
+            RankedChoiceOption::SetElectionParameters(election_parameters) => {
+                ProposalDetails::SetElectionParameters(election_parameters)
+            }
+            RankedChoiceOption::Spending(balance, destination) => {
+                ProposalDetails::Spending(balance, destination)
+            }
+            RankedChoiceOption::SetWorkingGroupMintCapacity(mint_balance, working_group) => {
+                ProposalDetails::SetWorkingGroupMintCapacity(mint_balance, working_group)
+            }
+        }
+    }
+}
+
+/// Item of the 'batch' proposal. Contains details of the proposal type that gets executed
+/// as a part of the batch. All item parameters are set on the proposal creation, so an item
+/// cannot use the ids created by the previous items (e.g. fill the opening added by the same
+/// batch): such dependent steps require separate proposals.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub enum BatchProposalItem<
+    MintedBalance,
+    CurrencyBalance,
+    BlockNumber,
+    AccountId,
+    OpeningId,
+    ApplicationId,
+    StakeBalance,
+    WorkerId,
+> {
+    /// The text of the `text` proposal
+    Text(Vec<u8>),
+
+    /// Election parameters for the `set election parameters` proposal
+    SetElectionParameters(ElectionParameters<CurrencyBalance, BlockNumber>),
+
+    /// Balance and destination account for the `spending` proposal
+    Spending(MintedBalance, AccountId),
+
+    /// Validator count for the `set validator count` proposal
+    SetValidatorCount(u32),
+
+    /// Add opening for the working group leader position.
+    AddWorkingGroupLeaderOpening(AddOpeningParameters<BlockNumber, CurrencyBalance>),
+
+    /// Begin review applications for the working group leader position.
+    BeginReviewWorkingGroupLeaderApplications(OpeningId, WorkingGroup),
+
+    /// Fill opening for the working group leader position.
+    FillWorkingGroupLeaderOpening(
+        FillOpeningParameters<BlockNumber, MintedBalance, OpeningId, ApplicationId>,
+    ),
+
+    /// Set working group mint capacity.
+    SetWorkingGroupMintCapacity(MintedBalance, WorkingGroup),
+
+    /// Decrease the working group leader stake.
+    DecreaseWorkingGroupLeaderStake(WorkerId, StakeBalance, WorkingGroup),
+
+    /// Slash the working group leader stake.
+    SlashWorkingGroupLeaderStake(WorkerId, StakeBalance, WorkingGroup),
+
+    /// Set working group leader reward balance.
+    SetWorkingGroupLeaderReward(WorkerId, MintedBalance, WorkingGroup),
+
+    /// Fire the working group leader with possible slashing.
+    TerminateWorkingGroupLeaderRole(TerminateRoleParameters<WorkerId>),
+}
+
+impl<
+        MintedBalance,
+        CurrencyBalance,
+        BlockNumber,
+        AccountId,
+        OpeningId,
+        ApplicationId,
+        StakeBalance,
+        WorkerId,
+        MemberId,
+    >
+    From<
+        BatchProposalItem<
+            MintedBalance,
+            CurrencyBalance,
+            BlockNumber,
+            AccountId,
+            OpeningId,
+            ApplicationId,
+            StakeBalance,
+            WorkerId,
+        >,
+    >
+    for ProposalDetails<
+        MintedBalance,
+        CurrencyBalance,
+        BlockNumber,
+        AccountId,
+        OpeningId,
+        ApplicationId,
+        StakeBalance,
+        WorkerId,
+        MemberId,
+    >
+{
+    fn from(
+        item: BatchProposalItem<
+            MintedBalance,
+            CurrencyBalance,
+            BlockNumber,
+            AccountId,
+            OpeningId,
+            ApplicationId,
+            StakeBalance,
+            WorkerId,
+        >,
+    ) -> Self {
+        match item {
+            BatchProposalItem::Text(text) => ProposalDetails::Text(text),
+            BatchProposalItem::SetElectionParameters(election_parameters) => {
+                ProposalDetails::SetElectionParameters(election_parameters)
+            }
+            BatchProposalItem::Spending(balance, destination) => {
+                ProposalDetails::Spending(balance, destination)
+            }
+            BatchProposalItem::SetValidatorCount(new_validator_count) => {
+                ProposalDetails::SetValidatorCount(new_validator_count)
+            }
+            BatchProposalItem::AddWorkingGroupLeaderOpening(add_opening_params) => {
+                ProposalDetails::AddWorkingGroupLeaderOpening(add_opening_params)
+            }
+            BatchProposalItem::BeginReviewWorkingGroupLeaderApplications(
+                opening_id,
+                working_group,
+            ) => ProposalDetails::BeginReviewWorkingGroupLeaderApplications(
+                opening_id,
+                working_group,
+            ),
+            BatchProposalItem::FillWorkingGroupLeaderOpening(fill_opening_params) => {
+                ProposalDetails::FillWorkingGroupLeaderOpening(fill_opening_params)
+            }
+            BatchProposalItem::SetWorkingGroupMintCapacity(mint_balance, working_group) => {
+                ProposalDetails::SetWorkingGroupMintCapacity(mint_balance, working_group)
+            }
+            BatchProposalItem::DecreaseWorkingGroupLeaderStake(
+                worker_id,
+                decreasing_stake,
+                working_group,
+            ) => ProposalDetails::DecreaseWorkingGroupLeaderStake(
+                worker_id,
+                decreasing_stake,
+                working_group,
+            ),
+            BatchProposalItem::SlashWorkingGroupLeaderStake(
+                worker_id,
+                slashing_stake,
+                working_group,
+            ) => ProposalDetails::SlashWorkingGroupLeaderStake(
+                worker_id,
+                slashing_stake,
+                working_group,
+            ),
+            BatchProposalItem::SetWorkingGroupLeaderReward(
+                worker_id,
+                reward_amount,
+                working_group,
+            ) => ProposalDetails::SetWorkingGroupLeaderReward(
+                worker_id,
+                reward_amount,
+                working_group,
+            ),
+            BatchProposalItem::TerminateWorkingGroupLeaderRole(terminate_role_params) => {
+                ProposalDetails::TerminateWorkingGroupLeaderRole(terminate_role_params)
+            }
+        }
+    }
+}
+
+/// Parameters for the 'runtime upgrade' proposal. The wasm code itself is uploaded separately
+/// and is stored under its hash until the proposal execution.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, Default)]
+pub struct RuntimeUpgradeParameters {
+    /// Blake2-256 hash of the runtime wasm code.
+    pub wasm_hash: [u8; 32],
+
+    /// Spec version of the new runtime.
+    pub spec_version: u32,
+}
+
+/// Deposit reserved for the uploaded runtime upgrade wasm code.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, Default)]
+pub struct RuntimeUpgradeWasmDeposit<AccountId, Balance> {
+    /// Account that uploaded the wasm code.
+    pub depositor: AccountId,
+
+    /// Reserved balance.
+    pub amount: Balance,
+}
+
+/// Proposal types of the codex. Addresses the proposal parameters updated by
+/// the 'set proposal parameters' proposal.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProposalType {
+    /// 'Text' proposal
+    Text,
+
+    /// 'Runtime upgrade' proposal
+    RuntimeUpgrade,
+
+    /// 'Set election parameters' proposal
+    SetElectionParameters,
+
+    /// 'Spending' proposal
+    Spending,
+
+    /// 'Set validator count' proposal
+    SetValidatorCount,
+
+    /// 'Add working group leader opening' proposal
+    AddWorkingGroupLeaderOpening,
+
+    /// 'Begin review working group leader applications' proposal
+    BeginReviewWorkingGroupLeaderApplications,
+
+    /// 'Fill working group leader opening' proposal
+    FillWorkingGroupLeaderOpening,
+
+    /// 'Set working group mint capacity' proposal
+    SetWorkingGroupMintCapacity,
+
+    /// 'Decrease working group leader stake' proposal
+    DecreaseWorkingGroupLeaderStake,
+
+    /// 'Slash working group leader stake' proposal
+    SlashWorkingGroupLeaderStake,
+
+    /// 'Set working group leader reward' proposal
+    SetWorkingGroupLeaderReward,
+
+    /// 'Terminate working group leader role' proposal
+    TerminateWorkingGroupLeaderRole,
+
+    /// 'Ranked choice' proposal
+    RankedChoice,
+
+    /// 'Batch' proposal
+    Batch,
+
+    /// 'Amend constitution' proposal
+    AmendConstitution,
+
+    /// 'Set proposal parameters' proposal
+    SetProposalParameters,
+
+    /// 'Set default storage quota limits' proposal
+    SetDefaultStorageQuotaLimits,
+
+    /// 'Set working group spending limit' proposal
+    SetWorkingGroupSpendingLimit,
+}
+
+/// Version of the platform constitution saved on the 'amend constitution' proposal execution.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, Default)]
+pub struct ConstitutionVersion<BlockNumber> {
+    /// Constitution text.
+    pub text: Vec<u8>,
+
+    /// Blake2-256 hash of the constitution text.
+    pub text_hash: [u8; 32],
+
+    /// Block number of the amendment.
+    pub amended_at: BlockNumber,
+}
+
 /// Parameters for the 'terminate the leader position' proposal.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Debug)]
@@ -309,6 +692,42 @@ pub struct ProposalsConfigParameters {
 
     /// 'Terminate working group leader role' proposal grace period
     pub terminate_working_group_leader_role_proposal_grace_period: u32,
+
+    /// 'Ranked choice' proposal voting period
+    pub ranked_choice_proposal_voting_period: u32,
+
+    /// 'Ranked choice' proposal grace period
+    pub ranked_choice_proposal_grace_period: u32,
+
+    /// 'Batch' proposal voting period
+    pub batch_proposal_voting_period: u32,
+
+    /// 'Batch' proposal grace period
+    pub batch_proposal_grace_period: u32,
+
+    /// 'Amend constitution' proposal voting period
+    pub amend_constitution_proposal_voting_period: u32,
+
+    /// 'Amend constitution' proposal grace period
+    pub amend_constitution_proposal_grace_period: u32,
+
+    /// 'Set proposal parameters' proposal voting period
+    pub set_proposal_parameters_proposal_voting_period: u32,
+
+    /// 'Set proposal parameters' proposal grace period
+    pub set_proposal_parameters_proposal_grace_period: u32,
+
+    /// 'Set default storage quota limits' proposal voting period
+    pub set_default_storage_quota_limits_proposal_voting_period: u32,
+
+    /// 'Set default storage quota limits' proposal grace period
+    pub set_default_storage_quota_limits_proposal_grace_period: u32,
+
+    /// 'Set working group spending limit' proposal voting period
+    pub set_working_group_spending_limit_proposal_voting_period: u32,
+
+    /// 'Set working group spending limit' proposal grace period
+    pub set_working_group_spending_limit_proposal_grace_period: u32,
 }
 
 impl Default for ProposalsConfigParameters {
@@ -342,6 +761,18 @@ impl Default for ProposalsConfigParameters {
             set_working_group_leader_reward_proposal_grace_period: 0u32,
             terminate_working_group_leader_role_proposal_voting_period: 72200u32,
             terminate_working_group_leader_role_proposal_grace_period: 0u32,
+            ranked_choice_proposal_voting_period: 72000u32,
+            ranked_choice_proposal_grace_period: 14400u32,
+            batch_proposal_voting_period: 72000u32,
+            batch_proposal_grace_period: 14400u32,
+            amend_constitution_proposal_voting_period: 72000u32,
+            amend_constitution_proposal_grace_period: 14400u32,
+            set_proposal_parameters_proposal_voting_period: 72000u32,
+            set_proposal_parameters_proposal_grace_period: 14400u32,
+            set_default_storage_quota_limits_proposal_voting_period: 43200u32,
+            set_default_storage_quota_limits_proposal_grace_period: 0u32,
+            set_working_group_spending_limit_proposal_voting_period: 43200u32,
+            set_working_group_spending_limit_proposal_grace_period: 0u32,
         }
     }
 }
@@ -380,6 +811,18 @@ impl ProposalsConfigParameters {
             set_working_group_leader_reward_proposal_grace_period: 0,
             terminate_working_group_leader_role_proposal_voting_period: voting_period,
             terminate_working_group_leader_role_proposal_grace_period: 0,
+            ranked_choice_proposal_voting_period: voting_period,
+            ranked_choice_proposal_grace_period: grace_period,
+            batch_proposal_voting_period: voting_period,
+            batch_proposal_grace_period: grace_period,
+            amend_constitution_proposal_voting_period: voting_period,
+            amend_constitution_proposal_grace_period: grace_period,
+            set_proposal_parameters_proposal_voting_period: voting_period,
+            set_proposal_parameters_proposal_grace_period: grace_period,
+            set_default_storage_quota_limits_proposal_voting_period: voting_period,
+            set_default_storage_quota_limits_proposal_grace_period: 0,
+            set_working_group_spending_limit_proposal_voting_period: voting_period,
+            set_working_group_spending_limit_proposal_grace_period: 0,
         }
     }
 }
@@ -1,184 +1,432 @@
-use crate::{BalanceOf, Module, ProposalParameters};
+use crate::{
+    BalanceOf, BatchProposalItem, BatchProposalItemOf, Module, ProposalParameters, ProposalType,
+    RankedChoiceOption, RankedChoiceOptionOf,
+};
 
-// Proposal parameters for the 'Set validator count' proposal
-pub(crate) fn set_validator_count_proposal<T: crate::Trait>(
+// Returns the proposal parameters updated by the 'Set proposal parameters' proposal or
+// the default parameters of the proposal type if they were never updated.
+fn parameters_or_default<T: crate::Trait>(
+    proposal_type: ProposalType,
+    default_parameters: ProposalParameters<T::BlockNumber, BalanceOf<T>>,
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    <Module<T>>::proposal_parameters_by_type(proposal_type).unwrap_or(default_parameters)
+}
+
+// Combines the proposal parameters keeping the strictest values: the longest periods, the highest
+// approval percentages, the lowest slashing percentages and the highest required stake.
+fn strictest_parameters<T: crate::Trait>(
+    parameters: ProposalParameters<T::BlockNumber, BalanceOf<T>>,
+    other_parameters: ProposalParameters<T::BlockNumber, BalanceOf<T>>,
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
     ProposalParameters {
-        voting_period: <Module<T>>::set_validator_count_proposal_voting_period(),
-        grace_period: <Module<T>>::set_validator_count_proposal_grace_period(),
-        approval_quorum_percentage: 66,
-        approval_threshold_percentage: 80,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(100_000_u32)),
+        voting_period: parameters.voting_period.max(other_parameters.voting_period),
+        grace_period: parameters.grace_period.max(other_parameters.grace_period),
+        approval_quorum_percentage: parameters
+            .approval_quorum_percentage
+            .max(other_parameters.approval_quorum_percentage),
+        approval_threshold_percentage: parameters
+            .approval_threshold_percentage
+            .max(other_parameters.approval_threshold_percentage),
+        slashing_quorum_percentage: parameters
+            .slashing_quorum_percentage
+            .min(other_parameters.slashing_quorum_percentage),
+        slashing_threshold_percentage: parameters
+            .slashing_threshold_percentage
+            .min(other_parameters.slashing_threshold_percentage),
+        required_stake: parameters
+            .required_stake
+            .max(other_parameters.required_stake),
+    }
+}
+
+// Returns the current parameters of the proposal type of the 'Ranked choice' proposal option.
+fn ranked_choice_option_parameters<T: crate::Trait>(
+    option: &RankedChoiceOptionOf<T>,
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    match option {
+        RankedChoiceOption::Text(_) => text_proposal::<T>(),
+        RankedChoiceOption::SetElectionParameters(_) => set_election_parameters_proposal::<T>(),
+        RankedChoiceOption::Spending(_, _) => spending_proposal::<T>(),
+        RankedChoiceOption::SetWorkingGroupMintCapacity(_, _) => {
+            set_working_group_mint_capacity_proposal::<T>()
+        }
+    }
+}
+
+// Returns the current parameters of the proposal type of the 'Batch' proposal item.
+fn batch_proposal_item_parameters<T: crate::Trait>(
+    item: &BatchProposalItemOf<T>,
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    match item {
+        BatchProposalItem::Text(_) => text_proposal::<T>(),
+        BatchProposalItem::SetElectionParameters(_) => set_election_parameters_proposal::<T>(),
+        BatchProposalItem::Spending(_, _) => spending_proposal::<T>(),
+        BatchProposalItem::SetValidatorCount(_) => set_validator_count_proposal::<T>(),
+        BatchProposalItem::AddWorkingGroupLeaderOpening(_) => {
+            add_working_group_leader_opening_proposal::<T>()
+        }
+        BatchProposalItem::BeginReviewWorkingGroupLeaderApplications(_, _) => {
+            begin_review_working_group_leader_applications_proposal::<T>()
+        }
+        BatchProposalItem::FillWorkingGroupLeaderOpening(_) => {
+            fill_working_group_leader_opening_proposal::<T>()
+        }
+        BatchProposalItem::SetWorkingGroupMintCapacity(_, _) => {
+            set_working_group_mint_capacity_proposal::<T>()
+        }
+        BatchProposalItem::DecreaseWorkingGroupLeaderStake(_, _, _) => {
+            decrease_working_group_leader_stake_proposal::<T>()
+        }
+        BatchProposalItem::SlashWorkingGroupLeaderStake(_, _, _) => {
+            slash_working_group_leader_stake_proposal::<T>()
+        }
+        BatchProposalItem::SetWorkingGroupLeaderReward(_, _, _) => {
+            set_working_group_leader_reward_proposal::<T>()
+        }
+        BatchProposalItem::TerminateWorkingGroupLeaderRole(_) => {
+            terminate_working_group_leader_role_proposal::<T>()
+        }
     }
 }
 
+// Proposal parameters for the 'Set validator count' proposal
+pub(crate) fn set_validator_count_proposal<T: crate::Trait>(
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    parameters_or_default::<T>(
+        ProposalType::SetValidatorCount,
+        ProposalParameters {
+            voting_period: <Module<T>>::set_validator_count_proposal_voting_period(),
+            grace_period: <Module<T>>::set_validator_count_proposal_grace_period(),
+            approval_quorum_percentage: 66,
+            approval_threshold_percentage: 80,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(100_000_u32)),
+        },
+    )
+}
+
 // Proposal parameters for the upgrade runtime proposal
 pub(crate) fn runtime_upgrade_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::runtime_upgrade_proposal_voting_period(),
-        grace_period: <Module<T>>::runtime_upgrade_proposal_grace_period(),
-        approval_quorum_percentage: 80,
-        approval_threshold_percentage: 100,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(1_000_000_u32)),
-    }
+    parameters_or_default::<T>(
+        ProposalType::RuntimeUpgrade,
+        ProposalParameters {
+            voting_period: <Module<T>>::runtime_upgrade_proposal_voting_period(),
+            grace_period: <Module<T>>::runtime_upgrade_proposal_grace_period(),
+            approval_quorum_percentage: 80,
+            approval_threshold_percentage: 100,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(1_000_000_u32)),
+        },
+    )
 }
 
 // Proposal parameters for the text proposal
 pub(crate) fn text_proposal<T: crate::Trait>() -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::text_proposal_voting_period(),
-        grace_period: <Module<T>>::text_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 80,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(25000u32)),
-    }
+    parameters_or_default::<T>(
+        ProposalType::Text,
+        ProposalParameters {
+            voting_period: <Module<T>>::text_proposal_voting_period(),
+            grace_period: <Module<T>>::text_proposal_grace_period(),
+            approval_quorum_percentage: 60,
+            approval_threshold_percentage: 80,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(25000u32)),
+        },
+    )
 }
 
 // Proposal parameters for the 'Set Election Parameters' proposal
 pub(crate) fn set_election_parameters_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::set_election_parameters_proposal_voting_period(),
-        grace_period: <Module<T>>::set_election_parameters_proposal_grace_period(),
-        approval_quorum_percentage: 66,
-        approval_threshold_percentage: 80,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(200_000_u32)),
-    }
+    parameters_or_default::<T>(
+        ProposalType::SetElectionParameters,
+        ProposalParameters {
+            voting_period: <Module<T>>::set_election_parameters_proposal_voting_period(),
+            grace_period: <Module<T>>::set_election_parameters_proposal_grace_period(),
+            approval_quorum_percentage: 66,
+            approval_threshold_percentage: 80,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(200_000_u32)),
+        },
+    )
 }
 
 // Proposal parameters for the 'Spending' proposal
 pub(crate) fn spending_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::spending_proposal_voting_period(),
-        grace_period: <Module<T>>::spending_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 80,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(25000u32)),
-    }
+    parameters_or_default::<T>(
+        ProposalType::Spending,
+        ProposalParameters {
+            voting_period: <Module<T>>::spending_proposal_voting_period(),
+            grace_period: <Module<T>>::spending_proposal_grace_period(),
+            approval_quorum_percentage: 60,
+            approval_threshold_percentage: 80,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(25000u32)),
+        },
+    )
 }
 
 // Proposal parameters for the 'Add working group leader' proposal
 pub(crate) fn add_working_group_leader_opening_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::add_working_group_opening_proposal_voting_period(),
-        grace_period: <Module<T>>::add_working_group_opening_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 80,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(100_000_u32)),
-    }
+    parameters_or_default::<T>(
+        ProposalType::AddWorkingGroupLeaderOpening,
+        ProposalParameters {
+            voting_period: <Module<T>>::add_working_group_opening_proposal_voting_period(),
+            grace_period: <Module<T>>::add_working_group_opening_proposal_grace_period(),
+            approval_quorum_percentage: 60,
+            approval_threshold_percentage: 80,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(100_000_u32)),
+        },
+    )
 }
 
 // Proposal parameters for the 'Begin review working group leader applications' proposal
 pub(crate) fn begin_review_working_group_leader_applications_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period:
-            <Module<T>>::begin_review_working_group_leader_applications_proposal_voting_period(),
-        grace_period:
-            <Module<T>>::begin_review_working_group_leader_applications_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 75,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(25000u32)),
-    }
+    parameters_or_default::<T>(
+        ProposalType::BeginReviewWorkingGroupLeaderApplications,
+        ProposalParameters {
+            voting_period:
+                <Module<T>>::begin_review_working_group_leader_applications_proposal_voting_period(),
+            grace_period:
+                <Module<T>>::begin_review_working_group_leader_applications_proposal_grace_period(),
+            approval_quorum_percentage: 60,
+            approval_threshold_percentage: 75,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(25000u32)),
+        },
+    )
 }
 
 // Proposal parameters for the 'Fill working group leader opening' proposal
 pub(crate) fn fill_working_group_leader_opening_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::fill_working_group_leader_opening_proposal_voting_period(),
-        grace_period: <Module<T>>::fill_working_group_leader_opening_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 75,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(50000u32)),
-    }
+    parameters_or_default::<T>(
+        ProposalType::FillWorkingGroupLeaderOpening,
+        ProposalParameters {
+            voting_period: <Module<T>>::fill_working_group_leader_opening_proposal_voting_period(),
+            grace_period: <Module<T>>::fill_working_group_leader_opening_proposal_grace_period(),
+            approval_quorum_percentage: 60,
+            approval_threshold_percentage: 75,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(50000u32)),
+        },
+    )
 }
 
 // Proposal parameters for the 'Set working group mint capacity' proposal
 pub(crate) fn set_working_group_mint_capacity_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::set_working_group_mint_capacity_proposal_voting_period(),
-        grace_period: <Module<T>>::set_working_group_mint_capacity_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 75,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(50000u32)),
-    }
+    parameters_or_default::<T>(
+        ProposalType::SetWorkingGroupMintCapacity,
+        ProposalParameters {
+            voting_period: <Module<T>>::set_working_group_mint_capacity_proposal_voting_period(),
+            grace_period: <Module<T>>::set_working_group_mint_capacity_proposal_grace_period(),
+            approval_quorum_percentage: 60,
+            approval_threshold_percentage: 75,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(50000u32)),
+        },
+    )
 }
 
 // Proposal parameters for the 'Decrease working group leader stake' proposal
 pub(crate) fn decrease_working_group_leader_stake_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::decrease_working_group_leader_stake_proposal_voting_period(),
-        grace_period: <Module<T>>::decrease_working_group_leader_stake_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 75,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(50000u32)),
-    }
+    parameters_or_default::<T>(
+        ProposalType::DecreaseWorkingGroupLeaderStake,
+        ProposalParameters {
+            voting_period: <Module<T>>::decrease_working_group_leader_stake_proposal_voting_period(
+            ),
+            grace_period: <Module<T>>::decrease_working_group_leader_stake_proposal_grace_period(),
+            approval_quorum_percentage: 60,
+            approval_threshold_percentage: 75,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(50000u32)),
+        },
+    )
 }
 
 // Proposal parameters for the 'Slash working group leader stake' proposal
 pub(crate) fn slash_working_group_leader_stake_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::slash_working_group_leader_stake_proposal_voting_period(),
-        grace_period: <Module<T>>::slash_working_group_leader_stake_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 75,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(50000u32)),
-    }
+    parameters_or_default::<T>(
+        ProposalType::SlashWorkingGroupLeaderStake,
+        ProposalParameters {
+            voting_period: <Module<T>>::slash_working_group_leader_stake_proposal_voting_period(),
+            grace_period: <Module<T>>::slash_working_group_leader_stake_proposal_grace_period(),
+            approval_quorum_percentage: 60,
+            approval_threshold_percentage: 75,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(50000u32)),
+        },
+    )
 }
 
 // Proposal parameters for the 'Set working group leader reward' proposal
 pub(crate) fn set_working_group_leader_reward_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::set_working_group_leader_reward_proposal_voting_period(),
-        grace_period: <Module<T>>::set_working_group_leader_reward_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 75,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(50000u32)),
-    }
+    parameters_or_default::<T>(
+        ProposalType::SetWorkingGroupLeaderReward,
+        ProposalParameters {
+            voting_period: <Module<T>>::set_working_group_leader_reward_proposal_voting_period(),
+            grace_period: <Module<T>>::set_working_group_leader_reward_proposal_grace_period(),
+            approval_quorum_percentage: 60,
+            approval_threshold_percentage: 75,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(50000u32)),
+        },
+    )
 }
 
 // Proposal parameters for the 'Terminate working group leader role' proposal
 pub(crate) fn terminate_working_group_leader_role_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::terminate_working_group_leader_role_proposal_voting_period(),
-        grace_period: <Module<T>>::terminate_working_group_leader_role_proposal_grace_period(),
-        approval_quorum_percentage: 66,
-        approval_threshold_percentage: 80,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(100_000_u32)),
-    }
+    parameters_or_default::<T>(
+        ProposalType::TerminateWorkingGroupLeaderRole,
+        ProposalParameters {
+            voting_period: <Module<T>>::terminate_working_group_leader_role_proposal_voting_period(
+            ),
+            grace_period: <Module<T>>::terminate_working_group_leader_role_proposal_grace_period(),
+            approval_quorum_percentage: 66,
+            approval_threshold_percentage: 80,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(100_000_u32)),
+        },
+    )
+}
+
+// Proposal parameters for the 'Ranked choice' proposal. The parameters are the strictest among
+// the 'Ranked choice' parameters and the current parameters of the proposal types of the options,
+// so the options can't bypass the parameters of their proposal types.
+pub(crate) fn ranked_choice_proposal<T: crate::Trait>(
+    options: &[RankedChoiceOptionOf<T>],
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    let ranked_choice_parameters = parameters_or_default::<T>(
+        ProposalType::RankedChoice,
+        ProposalParameters {
+            voting_period: <Module<T>>::ranked_choice_proposal_voting_period(),
+            grace_period: <Module<T>>::ranked_choice_proposal_grace_period(),
+            approval_quorum_percentage: 66,
+            approval_threshold_percentage: 80,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(200_000_u32)),
+        },
+    );
+
+    options
+        .iter()
+        .map(ranked_choice_option_parameters::<T>)
+        .fold(ranked_choice_parameters, strictest_parameters::<T>)
+}
+
+// Proposal parameters for the 'Batch' proposal. The parameters are the strictest among the 'Batch'
+// parameters and the current parameters of the proposal types of the items, so the items can't
+// bypass the parameters of their proposal types.
+pub(crate) fn batch_proposal<T: crate::Trait>(
+    items: &[BatchProposalItemOf<T>],
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    let batch_parameters = parameters_or_default::<T>(
+        ProposalType::Batch,
+        ProposalParameters {
+            voting_period: <Module<T>>::batch_proposal_voting_period(),
+            grace_period: <Module<T>>::batch_proposal_grace_period(),
+            approval_quorum_percentage: 66,
+            approval_threshold_percentage: 80,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(200_000_u32)),
+        },
+    );
+
+    items
+        .iter()
+        .map(batch_proposal_item_parameters::<T>)
+        .fold(batch_parameters, strictest_parameters::<T>)
+}
+
+// Proposal parameters for the 'Amend constitution' proposal
+pub(crate) fn amend_constitution_proposal<T: crate::Trait>(
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    parameters_or_default::<T>(
+        ProposalType::AmendConstitution,
+        ProposalParameters {
+            voting_period: <Module<T>>::amend_constitution_proposal_voting_period(),
+            grace_period: <Module<T>>::amend_constitution_proposal_grace_period(),
+            approval_quorum_percentage: 80,
+            approval_threshold_percentage: 100,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(200_000_u32)),
+        },
+    )
+}
+
+// Proposal parameters for the 'Set proposal parameters' proposal
+pub(crate) fn set_proposal_parameters_proposal<T: crate::Trait>(
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    parameters_or_default::<T>(
+        ProposalType::SetProposalParameters,
+        ProposalParameters {
+            voting_period: <Module<T>>::set_proposal_parameters_proposal_voting_period(),
+            grace_period: <Module<T>>::set_proposal_parameters_proposal_grace_period(),
+            approval_quorum_percentage: 80,
+            approval_threshold_percentage: 100,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(200_000_u32)),
+        },
+    )
+}
+
+// Proposal parameters for the 'Set default storage quota limits' proposal
+pub(crate) fn set_default_storage_quota_limits_proposal<T: crate::Trait>(
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    parameters_or_default::<T>(
+        ProposalType::SetDefaultStorageQuotaLimits,
+        ProposalParameters {
+            voting_period: <Module<T>>::set_default_storage_quota_limits_proposal_voting_period(),
+            grace_period: <Module<T>>::set_default_storage_quota_limits_proposal_grace_period(),
+            approval_quorum_percentage: 66,
+            approval_threshold_percentage: 80,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(50000u32)),
+        },
+    )
+}
+
+// Proposal parameters for the 'Set working group spending limit' proposal
+pub(crate) fn set_working_group_spending_limit_proposal<T: crate::Trait>(
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    parameters_or_default::<T>(
+        ProposalType::SetWorkingGroupSpendingLimit,
+        ProposalParameters {
+            voting_period: <Module<T>>::set_working_group_spending_limit_proposal_voting_period(),
+            grace_period: <Module<T>>::set_working_group_spending_limit_proposal_grace_period(),
+            approval_quorum_percentage: 60,
+            approval_threshold_percentage: 75,
+            slashing_quorum_percentage: 60,
+            slashing_threshold_percentage: 80,
+            required_stake: Some(<BalanceOf<T>>::from(50000u32)),
+        },
+    )
 }
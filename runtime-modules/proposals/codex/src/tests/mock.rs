@@ -1,5 +1,6 @@
 #![cfg(test)]
 
+use codec::Encode;
 use frame_support::{impl_outer_dispatch, impl_outer_origin, parameter_types};
 use sp_core::H256;
 use sp_runtime::curve::PiecewiseLinear;
@@ -11,7 +12,7 @@ use sp_runtime::{
 use sp_staking::SessionIndex;
 pub use system;
 
-use crate::{ProposalDetailsOf, ProposalEncoder};
+use crate::{ProposalDetails, ProposalDetailsOf, ProposalEncoder};
 use proposals_engine::VotersParameters;
 use sp_runtime::testing::TestXt;
 
@@ -50,6 +51,7 @@ impl membership::Trait for Test {
     type PaidTermId = u64;
     type SubscriptionId = u64;
     type ActorId = u64;
+    type WeightInfo = ();
 }
 
 parameter_types! {
@@ -78,6 +80,9 @@ parameter_types! {
     pub const TitleMaxLength: u32 = 100;
     pub const DescriptionMaxLength: u32 = 10000;
     pub const MaxActiveProposalLimit: u32 = 100;
+    pub const MaxProposalOptions: u32 = 5;
+    pub const VoteRationaleMaxLength: u32 = 1000;
+    pub const MaxExactExecutionBlockDelay: u64 = 100;
 }
 
 impl proposals_engine::Trait for Test {
@@ -92,7 +97,11 @@ impl proposals_engine::Trait for Test {
     type TitleMaxLength = TitleMaxLength;
     type DescriptionMaxLength = DescriptionMaxLength;
     type MaxActiveProposalLimit = MaxActiveProposalLimit;
+    type MaxProposalOptions = MaxProposalOptions;
+    type VoteRationaleMaxLength = VoteRationaleMaxLength;
+    type MaxExactExecutionBlockDelay = MaxExactExecutionBlockDelay;
     type DispatchableCallCode = crate::Call<Test>;
+    type WeightInfo = ();
 }
 
 impl Default for crate::Call<Test> {
@@ -109,6 +118,7 @@ impl minting::Trait for Test {
 impl governance::council::Trait for Test {
     type Event = ();
     type CouncilTermEnded = ();
+    type WeightInfo = ();
 }
 
 impl common::origin::ActorOriginValidator<Origin, u64, u64> for () {
@@ -117,6 +127,11 @@ impl common::origin::ActorOriginValidator<Origin, u64, u64> for () {
 
         Ok(account_id)
     }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn create_benchmark_actor(account_id: &u64) -> u64 {
+        *account_id
+    }
 }
 
 parameter_types! {
@@ -135,6 +150,7 @@ impl proposals_discussion::Trait for Test {
     type ThreadTitleLengthLimit = ThreadTitleLengthLimit;
     type PostLengthLimit = PostLengthLimit;
     type MaxThreadInARowNumber = MaxThreadInARowNumber;
+    type WeightInfo = ();
 }
 
 pub struct MockVotersParameters;
@@ -147,11 +163,14 @@ impl VotersParameters for MockVotersParameters {
 parameter_types! {
     pub const TextProposalMaxLength: u32 = 20_000;
     pub const RuntimeUpgradeWasmProposalMaxLength: u32 = 20_000;
+    pub const RuntimeUpgradeWasmDepositPerByte: u64 = 10;
+    pub const ConstitutionMaxLength: u32 = 20_000;
 }
 
 impl governance::election::Trait for Test {
     type Event = ();
     type CouncilElected = ();
+    type WeightInfo = ();
 }
 
 // The content directory working group instance alias.
@@ -167,17 +186,31 @@ parameter_types! {
 impl working_group::Trait<ContentDirectoryWorkingGroupInstance> for Test {
     type Event = ();
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WeightInfo = ();
+    type WorkerExitHandler = ();
 }
 
 impl working_group::Trait<StorageWorkingGroupInstance> for Test {
     type Event = ();
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WeightInfo = ();
+    type WorkerExitHandler = ();
+}
+
+parameter_types! {
+    pub const MaxPayoutsPerBlock: u32 = 100;
 }
 
 impl recurring_rewards::Trait for Test {
+    type Event = ();
     type PayoutStatusHandler = ();
     type RecipientId = u64;
     type RewardRelationshipId = u64;
+    type MaxPayoutsPerBlock = MaxPayoutsPerBlock;
+}
+
+parameter_types! {
+    pub const ApplicationsRetentionPeriod: u64 = 100;
 }
 
 impl hiring::Trait for Test {
@@ -185,6 +218,7 @@ impl hiring::Trait for Test {
     type ApplicationId = u64;
     type ApplicationDeactivatedHandler = ();
     type StakeHandlerProvider = hiring::Module<Self>;
+    type ApplicationsRetentionPeriod = ApplicationsRetentionPeriod;
 }
 
 pallet_staking_reward_curve::build! {
@@ -253,13 +287,22 @@ impl staking::SessionInterface<u64> for Test {
 impl crate::Trait for Test {
     type TextProposalMaxLength = TextProposalMaxLength;
     type RuntimeUpgradeWasmProposalMaxLength = RuntimeUpgradeWasmProposalMaxLength;
+    type RuntimeUpgradeWasmDepositPerByte = RuntimeUpgradeWasmDepositPerByte;
+    type ConstitutionMaxLength = ConstitutionMaxLength;
     type MembershipOriginValidator = ();
     type ProposalEncoder = ();
+    type WeightInfo = ();
 }
 
 impl ProposalEncoder<Test> for () {
-    fn encode_proposal(_proposal_details: ProposalDetailsOf<Test>) -> Vec<u8> {
-        Vec::new()
+    fn encode_proposal(proposal_details: ProposalDetailsOf<Test>) -> Vec<u8> {
+        match proposal_details {
+            // The text proposal calls get dispatched by the batch proposal benchmarks.
+            ProposalDetails::Text(text) => {
+                crate::Call::<Test>::execute_text_proposal(text).encode()
+            }
+            _ => Vec::new(),
+        }
     }
 }
 
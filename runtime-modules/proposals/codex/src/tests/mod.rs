@@ -1,15 +1,17 @@
 mod mock;
 
+use codec::Encode;
 use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::storage::StorageMap;
-use frame_support::traits::Currency;
+use frame_support::traits::{Currency, Get};
+use frame_support::weights::GetDispatchInfo;
 use system::RawOrigin;
 
 use common::working_group::WorkingGroup;
 use governance::election_params::ElectionParameters;
 use hiring::ActivateOpeningAt;
 use proposals_engine::ProposalParameters;
-use working_group::OpeningPolicyCommitment;
+use working_group::{OpeningPolicyCommitment, SpendingLimit};
 
 use crate::proposal_types::ProposalsConfigParameters;
 use crate::*;
@@ -113,6 +115,7 @@ fn create_text_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     None,
                     b"text".to_vec(),
+                    None,
                 )
             },
             empty_stake_call: || {
@@ -123,6 +126,7 @@ fn create_text_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     None,
                     b"text".to_vec(),
+                    None,
                 )
             },
             invalid_stake_call: || {
@@ -133,6 +137,7 @@ fn create_text_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
                     b"text".to_vec(),
+                    None,
                 )
             },
             successful_call: || {
@@ -143,6 +148,7 @@ fn create_text_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(25000u32)),
                     b"text".to_vec(),
+                    None,
                 )
             },
             proposal_parameters: crate::proposal_types::parameters::text_proposal::<Test>(),
@@ -166,6 +172,7 @@ fn create_text_proposal_codex_call_fails_with_incorrect_text_size() {
                 b"body".to_vec(),
                 None,
                 long_text,
+                None,
             ),
             Err(Error::<Test>::TextProposalSizeExceeded.into())
         );
@@ -178,12 +185,62 @@ fn create_text_proposal_codex_call_fails_with_incorrect_text_size() {
                 b"body".to_vec(),
                 None,
                 Vec::new(),
+                None,
             ),
             Err(Error::<Test>::TextProposalIsEmpty.into())
         );
     });
 }
 
+#[test]
+fn create_text_proposal_with_exact_execution_block_succeeds() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let max_execution_block =
+            <Test as proposals_engine::Trait>::MaxExactExecutionBlockDelay::get();
+
+        assert_eq!(
+            ProposalCodex::create_text_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                b"text".to_vec(),
+                Some(max_execution_block + 1),
+            ),
+            Err(proposals_engine::Error::<Test>::InvalidExactExecutionBlockMaximum.into())
+        );
+
+        assert_eq!(
+            ProposalCodex::create_text_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                b"text".to_vec(),
+                Some(max_execution_block),
+            ),
+            Ok(())
+        );
+
+        let proposal_id = 1;
+        assert_eq!(
+            ProposalsEngine::exact_execution_block(proposal_id),
+            Some(max_execution_block)
+        );
+    });
+}
+
+fn get_runtime_upgrade_parameters() -> RuntimeUpgradeParameters {
+    RuntimeUpgradeParameters {
+        wasm_hash: sp_io::hashing::blake2_256(b"wasm"),
+        spec_version: 1,
+    }
+}
+
 #[test]
 fn create_runtime_upgrade_common_checks_succeed() {
     initial_test_ext().execute_with(|| {
@@ -197,7 +254,8 @@ fn create_runtime_upgrade_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
-                    b"wasm".to_vec(),
+                    get_runtime_upgrade_parameters(),
+                    None,
                 )
             },
             empty_stake_call: || {
@@ -207,7 +265,8 @@ fn create_runtime_upgrade_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
-                    b"wasm".to_vec(),
+                    get_runtime_upgrade_parameters(),
+                    None,
                 )
             },
             invalid_stake_call: || {
@@ -217,7 +276,8 @@ fn create_runtime_upgrade_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(500u32)),
-                    b"wasm".to_vec(),
+                    get_runtime_upgrade_parameters(),
+                    None,
                 )
             },
             successful_call: || {
@@ -227,44 +287,141 @@ fn create_runtime_upgrade_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(1_000_000_u32)),
-                    b"wasm".to_vec(),
+                    get_runtime_upgrade_parameters(),
+                    None,
                 )
             },
             proposal_parameters: crate::proposal_types::parameters::runtime_upgrade_proposal::<Test>(),
-            proposal_details: ProposalDetails::RuntimeUpgrade(b"wasm".to_vec()),
+            proposal_details: ProposalDetails::RuntimeUpgradeByHash(
+                get_runtime_upgrade_parameters(),
+            ),
         };
         proposal_fixture.check_all();
     });
 }
 
 #[test]
-fn create_upgrade_runtime_proposal_codex_call_fails_with_incorrect_wasm_size() {
+fn upload_runtime_upgrade_wasm_fails_with_incorrect_wasm_size() {
     initial_test_ext().execute_with(|| {
-        let origin = RawOrigin::Signed(1).into();
-
         let long_wasm = [0u8; 30000].to_vec();
         assert_eq!(
-            ProposalCodex::create_runtime_upgrade_proposal(
-                origin,
-                1,
-                b"title".to_vec(),
-                b"body".to_vec(),
-                None,
-                long_wasm,
-            ),
+            ProposalCodex::upload_runtime_upgrade_wasm(RawOrigin::Signed(1).into(), long_wasm),
             Err(Error::<Test>::RuntimeProposalSizeExceeded.into())
         );
 
         assert_eq!(
-            ProposalCodex::create_runtime_upgrade_proposal(
+            ProposalCodex::upload_runtime_upgrade_wasm(RawOrigin::Signed(1).into(), Vec::new()),
+            Err(Error::<Test>::RuntimeProposalIsEmpty.into())
+        );
+    });
+}
+
+#[test]
+fn upload_runtime_upgrade_wasm_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let account_id = 1;
+        increase_total_balance_issuance_using_account_id(account_id, 500);
+
+        let wasm = b"wasm".to_vec();
+        let wasm_hash = sp_io::hashing::blake2_256(&wasm);
+
+        assert_eq!(
+            ProposalCodex::upload_runtime_upgrade_wasm(
+                RawOrigin::Signed(account_id).into(),
+                wasm.clone()
+            ),
+            Ok(())
+        );
+
+        let deposit = RuntimeUpgradeWasmDepositPerByte::get() * wasm.len() as u64;
+        assert_eq!(ProposalCodex::runtime_upgrade_wasm_by_hash(wasm_hash), wasm);
+        assert_eq!(
+            ProposalCodex::runtime_upgrade_wasm_deposit_by_hash(wasm_hash),
+            RuntimeUpgradeWasmDeposit {
+                depositor: account_id,
+                amount: deposit,
+            }
+        );
+        assert_eq!(Balances::reserved_balance(account_id), deposit);
+
+        assert_eq!(
+            ProposalCodex::upload_runtime_upgrade_wasm(RawOrigin::Signed(2).into(), wasm),
+            Err(Error::<Test>::RuntimeUpgradeWasmAlreadyUploaded.into())
+        );
+    });
+}
+
+#[test]
+fn upload_runtime_upgrade_wasm_fails_with_insufficient_balance() {
+    initial_test_ext().execute_with(|| {
+        assert_eq!(
+            ProposalCodex::upload_runtime_upgrade_wasm(
                 RawOrigin::Signed(1).into(),
-                1,
-                b"title".to_vec(),
-                b"body".to_vec(),
-                None,
-                Vec::new(),
+                b"wasm".to_vec()
             ),
-            Err(Error::<Test>::RuntimeProposalIsEmpty.into())
+            Err(Error::<Test>::InsufficientBalanceForRuntimeUpgradeWasmDeposit.into())
+        );
+    });
+}
+
+#[test]
+fn remove_runtime_upgrade_wasm_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let account_id = 1;
+        increase_total_balance_issuance_using_account_id(account_id, 500);
+
+        let wasm = b"wasm".to_vec();
+        let wasm_hash = sp_io::hashing::blake2_256(&wasm);
+
+        assert_eq!(
+            ProposalCodex::remove_runtime_upgrade_wasm(
+                RawOrigin::Signed(account_id).into(),
+                wasm_hash
+            ),
+            Err(Error::<Test>::RuntimeUpgradeWasmNotUploaded.into())
+        );
+
+        assert_eq!(
+            ProposalCodex::upload_runtime_upgrade_wasm(RawOrigin::Signed(account_id).into(), wasm),
+            Ok(())
+        );
+
+        assert_eq!(
+            ProposalCodex::remove_runtime_upgrade_wasm(RawOrigin::Signed(2).into(), wasm_hash),
+            Err(Error::<Test>::NotRuntimeUpgradeWasmDepositor.into())
+        );
+
+        assert_eq!(
+            ProposalCodex::remove_runtime_upgrade_wasm(
+                RawOrigin::Signed(account_id).into(),
+                wasm_hash
+            ),
+            Ok(())
+        );
+
+        assert!(!<crate::RuntimeUpgradeWasmByHash>::contains_key(wasm_hash));
+        assert!(!<crate::RuntimeUpgradeWasmDepositByHash<Test>>::contains_key(wasm_hash));
+        assert_eq!(Balances::reserved_balance(account_id), 0);
+    });
+}
+
+#[test]
+fn execute_runtime_upgrade_proposal_fails_without_uploaded_wasm() {
+    initial_test_ext().execute_with(|| {
+        assert_eq!(
+            ProposalCodex::execute_runtime_upgrade_proposal(
+                RawOrigin::Signed(1).into(),
+                get_runtime_upgrade_parameters()
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+
+        assert_eq!(
+            ProposalCodex::execute_runtime_upgrade_proposal(
+                RawOrigin::Root.into(),
+                get_runtime_upgrade_parameters()
+            ),
+            Err(Error::<Test>::RuntimeUpgradeWasmNotUploaded.into())
         );
     });
 }
@@ -283,6 +440,7 @@ fn create_set_election_parameters_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     None,
                     get_valid_election_parameters(),
+                    None,
                 )
             },
             empty_stake_call: || {
@@ -293,6 +451,7 @@ fn create_set_election_parameters_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     None,
                     get_valid_election_parameters(),
+                    None,
                 )
             },
             invalid_stake_call: || {
@@ -303,6 +462,7 @@ fn create_set_election_parameters_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(50000u32)),
                     get_valid_election_parameters(),
+                    None,
                 )
             },
             successful_call: || {
@@ -313,6 +473,7 @@ fn create_set_election_parameters_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(200_000_u32)),
                     get_valid_election_parameters(),
+                    None,
                 )
             },
             proposal_parameters:
@@ -337,6 +498,7 @@ fn assert_failed_election_parameters_call(
             b"body".to_vec(),
             Some(<BalanceOf<Test>>::from(3750u32)),
             election_parameters,
+            None,
         ),
         Err(error)
     );
@@ -488,6 +650,7 @@ fn create_spending_proposal_common_checks_succeed() {
                     None,
                     20,
                     10,
+                    None,
                 )
             },
             empty_stake_call: || {
@@ -499,6 +662,7 @@ fn create_spending_proposal_common_checks_succeed() {
                     None,
                     20,
                     10,
+                    None,
                 )
             },
             invalid_stake_call: || {
@@ -510,6 +674,7 @@ fn create_spending_proposal_common_checks_succeed() {
                     Some(<BalanceOf<Test>>::from(5000u32)),
                     20,
                     10,
+                    None,
                 )
             },
             successful_call: || {
@@ -521,6 +686,7 @@ fn create_spending_proposal_common_checks_succeed() {
                     Some(<BalanceOf<Test>>::from(25000u32)),
                     100,
                     2,
+                    None,
                 )
             },
             proposal_parameters: crate::proposal_types::parameters::spending_proposal::<Test>(),
@@ -544,6 +710,7 @@ fn create_spending_proposal_call_fails_with_incorrect_balance() {
                 Some(<BalanceOf<Test>>::from(1250u32)),
                 0,
                 2,
+                None,
             ),
             Err(Error::<Test>::InvalidSpendingProposalBalance.into())
         );
@@ -557,6 +724,7 @@ fn create_spending_proposal_call_fails_with_incorrect_balance() {
                 Some(<BalanceOf<Test>>::from(1250u32)),
                 5000001,
                 2,
+                None,
             ),
             Err(Error::<Test>::InvalidSpendingProposalBalance.into())
         );
@@ -577,6 +745,7 @@ fn create_set_validator_count_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     None,
                     4,
+                    None,
                 )
             },
             empty_stake_call: || {
@@ -587,6 +756,7 @@ fn create_set_validator_count_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     None,
                     4,
+                    None,
                 )
             },
             invalid_stake_call: || {
@@ -597,6 +767,7 @@ fn create_set_validator_count_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
                     4,
+                    None,
                 )
             },
             successful_call: || {
@@ -607,6 +778,7 @@ fn create_set_validator_count_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(100_000_u32)),
                     4,
+                    None,
                 )
             },
             proposal_parameters: crate::proposal_types::parameters::set_validator_count_proposal::<
@@ -629,6 +801,7 @@ fn create_set_validator_count_proposal_failed_with_invalid_validator_count() {
                 b"body".to_vec(),
                 Some(<BalanceOf<Test>>::from(500u32)),
                 3,
+                None,
             ),
             Err(Error::<Test>::InvalidValidatorCount.into())
         );
@@ -641,6 +814,7 @@ fn create_set_validator_count_proposal_failed_with_invalid_validator_count() {
                 b"body".to_vec(),
                 Some(<BalanceOf<Test>>::from(1001u32)),
                 3,
+                None,
             ),
             Err(Error::<Test>::InvalidValidatorCount.into())
         );
@@ -761,6 +935,177 @@ fn set_default_proposal_parameters_succeeded() {
             <TerminateWorkingGroupLeaderRoleProposalGracePeriod<Test>>::get(),
             p.terminate_working_group_leader_role_proposal_grace_period as u64
         );
+        assert_eq!(
+            <RankedChoiceProposalVotingPeriod<Test>>::get(),
+            p.ranked_choice_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <RankedChoiceProposalGracePeriod<Test>>::get(),
+            p.ranked_choice_proposal_grace_period as u64
+        );
+        assert_eq!(
+            <BatchProposalVotingPeriod<Test>>::get(),
+            p.batch_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <BatchProposalGracePeriod<Test>>::get(),
+            p.batch_proposal_grace_period as u64
+        );
+        assert_eq!(
+            <AmendConstitutionProposalVotingPeriod<Test>>::get(),
+            p.amend_constitution_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <AmendConstitutionProposalGracePeriod<Test>>::get(),
+            p.amend_constitution_proposal_grace_period as u64
+        );
+        assert_eq!(
+            <SetProposalParametersProposalVotingPeriod<Test>>::get(),
+            p.set_proposal_parameters_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <SetProposalParametersProposalGracePeriod<Test>>::get(),
+            p.set_proposal_parameters_proposal_grace_period as u64
+        );
+        assert_eq!(
+            <SetDefaultStorageQuotaLimitsProposalVotingPeriod<Test>>::get(),
+            p.set_default_storage_quota_limits_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <SetDefaultStorageQuotaLimitsProposalGracePeriod<Test>>::get(),
+            p.set_default_storage_quota_limits_proposal_grace_period as u64
+        );
+        assert_eq!(
+            <SetWorkingGroupSpendingLimitProposalVotingPeriod<Test>>::get(),
+            p.set_working_group_spending_limit_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <SetWorkingGroupSpendingLimitProposalGracePeriod<Test>>::get(),
+            p.set_working_group_spending_limit_proposal_grace_period as u64
+        );
+    });
+}
+
+#[test]
+fn migrate_proposal_periods_sets_the_periods_of_the_new_proposal_types() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 1_000_000);
+
+        let p = ProposalsConfigParameters::default();
+
+        // the live chains have the periods of the new proposal types unset
+        <StorageVersion>::put(0);
+        ProposalCodex::migrate_proposal_periods(p);
+
+        assert_eq!(ProposalCodex::storage_version(), STORAGE_VERSION);
+
+        // the members alternate to stay below the threads in a row limit
+        let create_calls: Vec<(DispatchResult, u32)> = vec![
+            (
+                ProposalCodex::create_ranked_choice_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(200_000_u32)),
+                    get_valid_ranked_choice_options(),
+                    None,
+                ),
+                p.ranked_choice_proposal_voting_period,
+            ),
+            (
+                ProposalCodex::create_batch_proposal(
+                    RawOrigin::Signed(1).into(),
+                    2,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(200_000_u32)),
+                    get_valid_batch_proposal_items(),
+                    None,
+                ),
+                p.batch_proposal_voting_period,
+            ),
+            (
+                ProposalCodex::create_amend_constitution_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(200_000_u32)),
+                    b"constitution".to_vec(),
+                    None,
+                ),
+                p.amend_constitution_proposal_voting_period,
+            ),
+            (
+                ProposalCodex::create_set_proposal_parameters_proposal(
+                    RawOrigin::Signed(1).into(),
+                    2,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(200_000_u32)),
+                    ProposalType::Text,
+                    get_valid_proposal_parameters(),
+                    None,
+                ),
+                p.set_proposal_parameters_proposal_voting_period,
+            ),
+            (
+                ProposalCodex::create_set_default_storage_quota_limits_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(50000u32)),
+                    1000,
+                    50_000_000,
+                    None,
+                ),
+                p.set_default_storage_quota_limits_proposal_voting_period,
+            ),
+            (
+                ProposalCodex::create_set_working_group_spending_limit_proposal(
+                    RawOrigin::Signed(1).into(),
+                    2,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(50000u32)),
+                    Some(SpendingLimit {
+                        amount_per_period: 100,
+                        period_length: 10,
+                    }),
+                    WorkingGroup::Storage,
+                    None,
+                ),
+                p.set_working_group_spending_limit_proposal_voting_period,
+            ),
+        ];
+
+        for (index, (create_result, voting_period)) in create_calls.into_iter().enumerate() {
+            assert_eq!(create_result, Ok(()));
+
+            let proposal_id = index as u64 + 1;
+            let proposal = ProposalsEngine::proposals(proposal_id);
+
+            assert_ne!(voting_period, 0);
+            assert_eq!(proposal.parameters.voting_period, voting_period as u64);
+        }
+    });
+}
+
+#[test]
+fn migrate_proposal_periods_runs_once() {
+    initial_test_ext().execute_with(|| {
+        <StorageVersion>::put(0);
+        ProposalCodex::migrate_proposal_periods(ProposalsConfigParameters::default());
+
+        let p = ProposalsConfigParameters::with_grace_and_voting_periods(20, 30);
+        ProposalCodex::migrate_proposal_periods(p);
+
+        assert_eq!(
+            <BatchProposalVotingPeriod<Test>>::get(),
+            ProposalsConfigParameters::default().batch_proposal_voting_period as u64
+        );
     });
 }
 
@@ -794,6 +1139,7 @@ fn run_create_add_working_group_leader_opening_proposal_common_checks_succeed(
                     b"body".to_vec(),
                     None,
                     add_opening_parameters.clone(),
+                    None,
                 )
             },
             empty_stake_call: || {
@@ -804,6 +1150,7 @@ fn run_create_add_working_group_leader_opening_proposal_common_checks_succeed(
                     b"body".to_vec(),
                     None,
                     add_opening_parameters.clone(),
+                    None,
                 )
             },
             invalid_stake_call: || {
@@ -814,6 +1161,7 @@ fn run_create_add_working_group_leader_opening_proposal_common_checks_succeed(
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
                     add_opening_parameters.clone(),
+                    None,
                 )
             },
             successful_call: || {
@@ -824,6 +1172,7 @@ fn run_create_add_working_group_leader_opening_proposal_common_checks_succeed(
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(100_000_u32)),
                     add_opening_parameters.clone(),
+                    None,
                 )
             },
             proposal_parameters: crate::proposal_types::parameters::add_working_group_leader_opening_proposal::<
@@ -862,7 +1211,8 @@ fn run_create_begin_review_working_group_leader_applications_proposal_common_che
                     b"body".to_vec(),
                     None,
                     opening_id,
-                    working_group
+                    working_group,
+                    None,
                 )
             },
             empty_stake_call: || {
@@ -873,7 +1223,8 @@ fn run_create_begin_review_working_group_leader_applications_proposal_common_che
                     b"body".to_vec(),
                     None,
                     opening_id,
-                    working_group
+                    working_group,
+                    None,
                 )
             },
             invalid_stake_call: || {
@@ -884,7 +1235,8 @@ fn run_create_begin_review_working_group_leader_applications_proposal_common_che
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
                     opening_id,
-                    working_group
+                    working_group,
+                    None,
                 )
             },
             successful_call: || {
@@ -895,7 +1247,8 @@ fn run_create_begin_review_working_group_leader_applications_proposal_common_che
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(25000u32)),
                     opening_id,
-                    working_group
+                    working_group,
+                    None,
                 )
             },
             proposal_parameters: crate::proposal_types::parameters::begin_review_working_group_leader_applications_proposal::<
@@ -939,7 +1292,8 @@ fn run_create_fill_working_group_leader_opening_proposal_common_checks_succeed(
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
-                    fill_opening_parameters.clone()
+                    fill_opening_parameters.clone(),
+                    None,
                 )
             },
             empty_stake_call: || {
@@ -949,7 +1303,8 @@ fn run_create_fill_working_group_leader_opening_proposal_common_checks_succeed(
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
-                    fill_opening_parameters.clone()
+                    fill_opening_parameters.clone(),
+                    None,
                 )
             },
             invalid_stake_call: || {
@@ -959,7 +1314,8 @@ fn run_create_fill_working_group_leader_opening_proposal_common_checks_succeed(
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
-                    fill_opening_parameters.clone()
+                    fill_opening_parameters.clone(),
+                    None,
                 )
             },
             successful_call: || {
@@ -969,7 +1325,8 @@ fn run_create_fill_working_group_leader_opening_proposal_common_checks_succeed(
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(50000u32)),
-                    fill_opening_parameters.clone()
+                    fill_opening_parameters.clone(),
+                    None,
                 )
             },
             proposal_parameters: crate::proposal_types::parameters::fill_working_group_leader_opening_proposal::<
@@ -1004,6 +1361,7 @@ fn run_create_working_group_mint_capacity_proposal_fails_with_invalid_parameters
                 Some(<BalanceOf<Test>>::from(50000u32)),
                 (crate::WORKING_GROUP_MINT_CAPACITY_MAX_VALUE + 1) as u64,
                 working_group,
+                None,
             ),
             Err(Error::<Test>::InvalidWorkingGroupMintCapacity.into())
         );
@@ -1034,6 +1392,7 @@ fn run_create_set_working_group_mint_capacity_proposal_common_checks_succeed(
                     None,
                     0,
                     working_group,
+                    None,
                 )
             },
             empty_stake_call: || {
@@ -1045,6 +1404,7 @@ fn run_create_set_working_group_mint_capacity_proposal_common_checks_succeed(
                     None,
                     0,
                     working_group,
+                    None,
                 )
             },
             invalid_stake_call: || {
@@ -1056,6 +1416,7 @@ fn run_create_set_working_group_mint_capacity_proposal_common_checks_succeed(
                     Some(<BalanceOf<Test>>::from(5000u32)),
                     0,
                     working_group,
+                    None,
                 )
             },
             successful_call: || {
@@ -1067,6 +1428,7 @@ fn run_create_set_working_group_mint_capacity_proposal_common_checks_succeed(
                     Some(<BalanceOf<Test>>::from(50000u32)),
                     10,
                     working_group,
+                    None,
                 )
             },
             proposal_parameters:
@@ -1079,30 +1441,139 @@ fn run_create_set_working_group_mint_capacity_proposal_common_checks_succeed(
 }
 
 #[test]
-fn create_decrease_working_group_leader_stake_proposal_common_checks_succeed() {
+fn create_set_working_group_spending_limit_proposal_common_checks_succeed() {
     // This uses strum crate for enum iteration
     for group in WorkingGroup::iter() {
-        run_create_decrease_working_group_leader_stake_proposal_common_checks_succeed(group);
+        run_create_set_working_group_spending_limit_proposal_common_checks_succeed(group);
     }
 }
 
-fn run_create_decrease_working_group_leader_stake_proposal_common_checks_succeed(
+fn run_create_set_working_group_spending_limit_proposal_common_checks_succeed(
     working_group: WorkingGroup,
 ) {
     initial_test_ext().execute_with(|| {
         increase_total_balance_issuance(500000);
 
+        let spending_limit = SpendingLimit {
+            amount_per_period: 100,
+            period_length: 10,
+        };
+
         let proposal_fixture = ProposalTestFixture {
             insufficient_rights_call: || {
-                ProposalCodex::create_decrease_working_group_leader_stake_proposal(
+                ProposalCodex::create_set_working_group_spending_limit_proposal(
                     RawOrigin::None.into(),
                     1,
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
-                    0,
-                    10,
+                    None,
+                    working_group,
+                    None,
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_set_working_group_spending_limit_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    None,
+                    working_group,
+                    None,
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_set_working_group_spending_limit_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    None,
+                    working_group,
+                    None,
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_set_working_group_spending_limit_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(50000u32)),
+                    Some(SpendingLimit {
+                        amount_per_period: 100,
+                        period_length: 10,
+                    }),
+                    working_group,
+                    None,
+                )
+            },
+            proposal_parameters:
+                crate::proposal_types::parameters::set_working_group_spending_limit_proposal::<
+                    Test,
+                >(),
+            proposal_details: ProposalDetails::SetWorkingGroupSpendingLimit(
+                Some(spending_limit),
+                working_group,
+            ),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_set_working_group_spending_limit_proposal_fails_with_zero_period_length() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        assert_eq!(
+            ProposalCodex::create_set_working_group_spending_limit_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(50000u32)),
+                Some(SpendingLimit {
+                    amount_per_period: 100,
+                    period_length: 0,
+                }),
+                WorkingGroup::Storage,
+                None,
+            ),
+            Err(Error::<Test>::InvalidWorkingGroupSpendingPeriodLength.into())
+        );
+    });
+}
+
+#[test]
+fn create_decrease_working_group_leader_stake_proposal_common_checks_succeed() {
+    // This uses strum crate for enum iteration
+    for group in WorkingGroup::iter() {
+        run_create_decrease_working_group_leader_stake_proposal_common_checks_succeed(group);
+    }
+}
+
+fn run_create_decrease_working_group_leader_stake_proposal_common_checks_succeed(
+    working_group: WorkingGroup,
+) {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance(500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_decrease_working_group_leader_stake_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    0,
+                    10,
                     working_group,
+                    None,
                 )
             },
             empty_stake_call: || {
@@ -1115,6 +1586,7 @@ fn run_create_decrease_working_group_leader_stake_proposal_common_checks_succeed
                     0,
                     10,
                     working_group,
+                    None,
                 )
             },
             invalid_stake_call: || {
@@ -1127,6 +1599,7 @@ fn run_create_decrease_working_group_leader_stake_proposal_common_checks_succeed
                     0,
                     10,
                     working_group,
+                    None,
                 )
             },
             successful_call: || {
@@ -1139,6 +1612,7 @@ fn run_create_decrease_working_group_leader_stake_proposal_common_checks_succeed
                     10,
                     10,
                     working_group,
+                    None,
                 )
             },
             proposal_parameters:
@@ -1180,6 +1654,7 @@ fn run_create_slash_working_group_leader_stake_proposal_common_checks_succeed(
                     0,
                     10,
                     working_group,
+                    None,
                 )
             },
             empty_stake_call: || {
@@ -1192,6 +1667,7 @@ fn run_create_slash_working_group_leader_stake_proposal_common_checks_succeed(
                     0,
                     10,
                     working_group,
+                    None,
                 )
             },
             invalid_stake_call: || {
@@ -1204,6 +1680,7 @@ fn run_create_slash_working_group_leader_stake_proposal_common_checks_succeed(
                     0,
                     10,
                     working_group,
+                    None,
                 )
             },
             successful_call: || {
@@ -1216,6 +1693,7 @@ fn run_create_slash_working_group_leader_stake_proposal_common_checks_succeed(
                     10,
                     10,
                     working_group,
+                    None,
                 )
             },
             proposal_parameters:
@@ -1261,6 +1739,7 @@ fn run_slash_stake_with_zero_staking_balance_fails(working_group: WorkingGroup)
                 10,
                 0,
                 working_group,
+                None,
             ),
             Err(Error::<Test>::SlashingStakeIsZero.into())
         );
@@ -1296,6 +1775,7 @@ fn run_decrease_stake_with_zero_staking_balance_fails(working_group: WorkingGrou
                 10,
                 0,
                 working_group,
+                None,
             ),
             Err(Error::<Test>::DecreasingStakeIsZero.into())
         );
@@ -1325,6 +1805,7 @@ fn run_create_set_working_group_leader_reward_proposal_common_checks_succeed(
                     0,
                     10,
                     working_group,
+                    None,
                 )
             },
             empty_stake_call: || {
@@ -1337,6 +1818,7 @@ fn run_create_set_working_group_leader_reward_proposal_common_checks_succeed(
                     0,
                     10,
                     working_group,
+                    None,
                 )
             },
             invalid_stake_call: || {
@@ -1349,6 +1831,7 @@ fn run_create_set_working_group_leader_reward_proposal_common_checks_succeed(
                     0,
                     10,
                     working_group,
+                    None,
                 )
             },
             successful_call: || {
@@ -1361,6 +1844,7 @@ fn run_create_set_working_group_leader_reward_proposal_common_checks_succeed(
                     10,
                     10,
                     working_group,
+                    None,
                 )
             },
             proposal_parameters:
@@ -1402,6 +1886,7 @@ fn run_create_terminate_working_group_leader_role_proposal_common_checks_succeed
                     b"body".to_vec(),
                     None,
                     terminate_role_parameters.clone(),
+                    None,
                 )
             },
             empty_stake_call: || {
@@ -1412,6 +1897,7 @@ fn run_create_terminate_working_group_leader_role_proposal_common_checks_succeed
                     b"body".to_vec(),
                     None,
                     terminate_role_parameters.clone(),
+                    None,
                 )
             },
             invalid_stake_call: || {
@@ -1422,6 +1908,7 @@ fn run_create_terminate_working_group_leader_role_proposal_common_checks_succeed
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
                     terminate_role_parameters.clone(),
+                    None,
                 )
             },
             successful_call: || {
@@ -1432,6 +1919,7 @@ fn run_create_terminate_working_group_leader_role_proposal_common_checks_succeed
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(100_000_u32)),
                     terminate_role_parameters.clone(),
+                    None,
                 )
             },
             proposal_parameters:
@@ -1445,3 +1933,869 @@ fn run_create_terminate_working_group_leader_role_proposal_common_checks_succeed
         proposal_fixture.check_all();
     });
 }
+
+fn get_valid_ranked_choice_options() -> Vec<RankedChoiceOption<u64, u64, u64, u64>> {
+    vec![
+        RankedChoiceOption::Text(b"text".to_vec()),
+        RankedChoiceOption::Spending(100, 2),
+        RankedChoiceOption::SetElectionParameters(get_valid_election_parameters()),
+        RankedChoiceOption::SetWorkingGroupMintCapacity(10, WorkingGroup::Storage),
+    ]
+}
+
+#[test]
+fn create_ranked_choice_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_ranked_choice_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    get_valid_ranked_choice_options(),
+                    None,
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_ranked_choice_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    get_valid_ranked_choice_options(),
+                    None,
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_ranked_choice_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    get_valid_ranked_choice_options(),
+                    None,
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_ranked_choice_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(200_000_u32)),
+                    get_valid_ranked_choice_options(),
+                    None,
+                )
+            },
+            proposal_parameters: crate::proposal_types::parameters::ranked_choice_proposal::<Test>(
+                &get_valid_ranked_choice_options(),
+            ),
+            proposal_details: ProposalDetails::RankedChoice(get_valid_ranked_choice_options()),
+        };
+        proposal_fixture.check_all();
+
+        // options were passed to the engine
+        assert_eq!(
+            <proposals_engine::ProposalOptionsCode<Test>>::get(1).len(),
+            get_valid_ranked_choice_options().len()
+        );
+    });
+}
+
+fn assert_failed_ranked_choice_call(
+    options: Vec<RankedChoiceOption<u64, u64, u64, u64>>,
+    error: DispatchError,
+) {
+    assert_eq!(
+        ProposalCodex::create_ranked_choice_proposal(
+            RawOrigin::Signed(1).into(),
+            1,
+            b"title".to_vec(),
+            b"body".to_vec(),
+            Some(<BalanceOf<Test>>::from(200_000_u32)),
+            options,
+            None,
+        ),
+        Err(error)
+    );
+}
+
+#[test]
+fn create_ranked_choice_proposal_fails_with_invalid_options() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        assert_failed_ranked_choice_call(
+            vec![RankedChoiceOption::Text(b"text".to_vec())],
+            proposals_engine::Error::<Test>::InvalidProposalOptionsNumber.into(),
+        );
+
+        assert_failed_ranked_choice_call(
+            vec![
+                RankedChoiceOption::Text(b"text".to_vec()),
+                RankedChoiceOption::Text(b"text".to_vec()),
+            ],
+            Error::<Test>::DuplicateRankedChoiceOption.into(),
+        );
+
+        assert_failed_ranked_choice_call(
+            vec![
+                RankedChoiceOption::Text(b"text".to_vec()),
+                RankedChoiceOption::Text(Vec::new()),
+            ],
+            Error::<Test>::TextProposalIsEmpty.into(),
+        );
+
+        assert_failed_ranked_choice_call(
+            vec![
+                RankedChoiceOption::Text(b"text".to_vec()),
+                RankedChoiceOption::Spending(0, 2),
+            ],
+            Error::<Test>::InvalidSpendingProposalBalance.into(),
+        );
+
+        let mut election_parameters = get_valid_election_parameters();
+        election_parameters.council_size = 2;
+        assert_failed_ranked_choice_call(
+            vec![
+                RankedChoiceOption::Text(b"text".to_vec()),
+                RankedChoiceOption::SetElectionParameters(election_parameters),
+            ],
+            Error::<Test>::InvalidCouncilElectionParameterCouncilSize.into(),
+        );
+
+        assert_failed_ranked_choice_call(
+            vec![
+                RankedChoiceOption::Text(b"text".to_vec()),
+                RankedChoiceOption::SetWorkingGroupMintCapacity(5_000_001, WorkingGroup::Content),
+            ],
+            Error::<Test>::InvalidWorkingGroupMintCapacity.into(),
+        );
+    });
+}
+
+fn get_valid_batch_proposal_items() -> Vec<BatchProposalItem<u64, u64, u64, u64, u64, u64, u64, u64>>
+{
+    vec![
+        BatchProposalItem::BeginReviewWorkingGroupLeaderApplications(1, WorkingGroup::Storage),
+        BatchProposalItem::FillWorkingGroupLeaderOpening(FillOpeningParameters {
+            opening_id: 1,
+            successful_application_id: 1,
+            reward_policy: None,
+            working_group: WorkingGroup::Storage,
+        }),
+        BatchProposalItem::SetWorkingGroupMintCapacity(10, WorkingGroup::Storage),
+    ]
+}
+
+#[test]
+fn create_batch_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_batch_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    get_valid_batch_proposal_items(),
+                    None,
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_batch_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    get_valid_batch_proposal_items(),
+                    None,
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_batch_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    get_valid_batch_proposal_items(),
+                    None,
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_batch_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(200_000_u32)),
+                    get_valid_batch_proposal_items(),
+                    None,
+                )
+            },
+            proposal_parameters: crate::proposal_types::parameters::batch_proposal::<Test>(
+                &get_valid_batch_proposal_items(),
+            ),
+            proposal_details: ProposalDetails::Batch(get_valid_batch_proposal_items()),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+fn assert_failed_batch_proposal_call(
+    items: Vec<BatchProposalItem<u64, u64, u64, u64, u64, u64, u64, u64>>,
+    error: DispatchError,
+) {
+    assert_eq!(
+        ProposalCodex::create_batch_proposal(
+            RawOrigin::Signed(1).into(),
+            1,
+            b"title".to_vec(),
+            b"body".to_vec(),
+            Some(<BalanceOf<Test>>::from(200_000_u32)),
+            items,
+            None,
+        ),
+        Err(error)
+    );
+}
+
+#[test]
+fn create_batch_proposal_fails_with_invalid_items() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        assert_failed_batch_proposal_call(Vec::new(), Error::<Test>::BatchProposalIsEmpty.into());
+
+        assert_failed_batch_proposal_call(
+            vec![BatchProposalItem::Text(b"text".to_vec()); 11],
+            Error::<Test>::BatchProposalSizeExceeded.into(),
+        );
+
+        assert_failed_batch_proposal_call(
+            vec![
+                BatchProposalItem::Text(b"text".to_vec()),
+                BatchProposalItem::Text(Vec::new()),
+            ],
+            Error::<Test>::TextProposalIsEmpty.into(),
+        );
+
+        assert_failed_batch_proposal_call(
+            vec![BatchProposalItem::SetValidatorCount(1000)],
+            Error::<Test>::InvalidValidatorCount.into(),
+        );
+
+        assert_failed_batch_proposal_call(
+            vec![BatchProposalItem::SlashWorkingGroupLeaderStake(
+                10,
+                0,
+                WorkingGroup::Storage,
+            )],
+            Error::<Test>::SlashingStakeIsZero.into(),
+        );
+
+        assert_failed_batch_proposal_call(
+            vec![BatchProposalItem::DecreaseWorkingGroupLeaderStake(
+                10,
+                0,
+                WorkingGroup::Content,
+            )],
+            Error::<Test>::DecreasingStakeIsZero.into(),
+        );
+    });
+}
+
+#[test]
+fn execute_batch_proposal_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let calls = vec![
+            crate::Call::<Test>::execute_text_proposal(b"text".to_vec()).encode(),
+            crate::Call::<Test>::execute_text_proposal(b"text".to_vec()).encode(),
+        ];
+
+        assert_eq!(
+            ProposalCodex::execute_batch_proposal(RawOrigin::Root.into(), calls.clone()),
+            Ok(())
+        );
+
+        assert_eq!(
+            ProposalCodex::execute_batch_proposal(RawOrigin::Signed(1).into(), calls),
+            Err(DispatchError::BadOrigin)
+        );
+    });
+}
+
+#[test]
+fn execute_batch_proposal_fails_with_invalid_call() {
+    initial_test_ext().execute_with(|| {
+        let text_call = crate::Call::<Test>::execute_text_proposal(b"text".to_vec()).encode();
+
+        assert_eq!(
+            ProposalCodex::execute_batch_proposal(
+                RawOrigin::Root.into(),
+                vec![text_call, b"invalid call".to_vec()]
+            ),
+            Err(Error::<Test>::BatchProposalCallDecodingFailed.into())
+        );
+    });
+}
+
+#[test]
+fn batch_proposal_weights_include_item_weights() {
+    initial_test_ext().execute_with(|| {
+        let create_call = crate::Call::<Test>::create_batch_proposal(
+            1,
+            b"title".to_vec(),
+            b"body".to_vec(),
+            Some(<BalanceOf<Test>>::from(1250u32)),
+            get_valid_batch_proposal_items(),
+            None,
+        );
+
+        // Default weights: the batch proposal creation and the creation of its three items.
+        assert_eq!(create_call.get_dispatch_info().weight, 4 * 10_000_000);
+
+        let text_call = crate::Call::<Test>::execute_text_proposal(b"text".to_vec());
+        let execute_call = crate::Call::<Test>::execute_batch_proposal(vec![
+            text_call.encode(),
+            text_call.encode(),
+        ]);
+        let expected_execute_weight = <() as crate::WeightInfo>::execute_batch_proposal(2)
+            + 2 * text_call.get_dispatch_info().weight;
+
+        assert_eq!(
+            execute_call.get_dispatch_info().weight,
+            expected_execute_weight
+        );
+    });
+}
+
+#[test]
+fn create_amend_constitution_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_amend_constitution_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    b"constitution".to_vec(),
+                    None,
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_amend_constitution_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    b"constitution".to_vec(),
+                    None,
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_amend_constitution_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    b"constitution".to_vec(),
+                    None,
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_amend_constitution_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(200_000_u32)),
+                    b"constitution".to_vec(),
+                    None,
+                )
+            },
+            proposal_parameters: crate::proposal_types::parameters::amend_constitution_proposal::<
+                Test,
+            >(),
+            proposal_details: ProposalDetails::AmendConstitution(b"constitution".to_vec()),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_amend_constitution_proposal_fails_with_incorrect_text_size() {
+    initial_test_ext().execute_with(|| {
+        let long_text = [0u8; 30000].to_vec();
+        assert_eq!(
+            ProposalCodex::create_amend_constitution_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                None,
+                long_text,
+                None,
+            ),
+            Err(Error::<Test>::ConstitutionSizeExceeded.into())
+        );
+
+        assert_eq!(
+            ProposalCodex::create_amend_constitution_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                None,
+                Vec::new(),
+                None,
+            ),
+            Err(Error::<Test>::ConstitutionIsEmpty.into())
+        );
+    });
+}
+
+#[test]
+fn execute_amend_constitution_proposal_saves_constitution_versions() {
+    initial_test_ext().execute_with(|| {
+        assert_eq!(ProposalCodex::current_constitution(), None);
+
+        assert_eq!(
+            ProposalCodex::execute_amend_constitution_proposal(
+                RawOrigin::Signed(1).into(),
+                b"constitution".to_vec()
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+
+        System::set_block_number(1);
+        assert_eq!(
+            ProposalCodex::execute_amend_constitution_proposal(
+                RawOrigin::Root.into(),
+                b"constitution".to_vec()
+            ),
+            Ok(())
+        );
+
+        System::set_block_number(10);
+        assert_eq!(
+            ProposalCodex::execute_amend_constitution_proposal(
+                RawOrigin::Root.into(),
+                b"amended constitution".to_vec()
+            ),
+            Ok(())
+        );
+
+        let first_version = ConstitutionVersion {
+            text: b"constitution".to_vec(),
+            text_hash: sp_io::hashing::blake2_256(b"constitution"),
+            amended_at: 1,
+        };
+        let second_version = ConstitutionVersion {
+            text: b"amended constitution".to_vec(),
+            text_hash: sp_io::hashing::blake2_256(b"amended constitution"),
+            amended_at: 10,
+        };
+
+        assert_eq!(ProposalCodex::constitution_version_count(), 2);
+        assert_eq!(ProposalCodex::constitution_by_version(1), first_version);
+        assert_eq!(ProposalCodex::constitution_by_version(2), second_version);
+        assert_eq!(ProposalCodex::current_constitution(), Some(second_version));
+    });
+}
+
+fn get_valid_proposal_parameters() -> ProposalParameters<u64, u64> {
+    ProposalParameters {
+        voting_period: 100,
+        grace_period: 10,
+        approval_quorum_percentage: 50,
+        approval_threshold_percentage: 60,
+        slashing_quorum_percentage: 50,
+        slashing_threshold_percentage: 60,
+        required_stake: Some(50_000),
+    }
+}
+
+#[test]
+fn create_set_proposal_parameters_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_set_proposal_parameters_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    ProposalType::Text,
+                    get_valid_proposal_parameters(),
+                    None,
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_set_proposal_parameters_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    ProposalType::Text,
+                    get_valid_proposal_parameters(),
+                    None,
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_set_proposal_parameters_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    ProposalType::Text,
+                    get_valid_proposal_parameters(),
+                    None,
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_set_proposal_parameters_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(200_000_u32)),
+                    ProposalType::Text,
+                    get_valid_proposal_parameters(),
+                    None,
+                )
+            },
+            proposal_parameters:
+                crate::proposal_types::parameters::set_proposal_parameters_proposal::<Test>(),
+            proposal_details: ProposalDetails::SetProposalParameters(
+                ProposalType::Text,
+                get_valid_proposal_parameters(),
+            ),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+fn assert_failed_set_proposal_parameters_call(
+    proposal_parameters: ProposalParameters<u64, u64>,
+    error: DispatchError,
+) {
+    assert_eq!(
+        ProposalCodex::create_set_proposal_parameters_proposal(
+            RawOrigin::Signed(1).into(),
+            1,
+            b"title".to_vec(),
+            b"body".to_vec(),
+            Some(<BalanceOf<Test>>::from(200_000_u32)),
+            ProposalType::Text,
+            proposal_parameters,
+            None,
+        ),
+        Err(error)
+    );
+}
+
+#[test]
+fn create_set_proposal_parameters_proposal_fails_with_invalid_parameters() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        assert_failed_set_proposal_parameters_call(
+            ProposalParameters {
+                voting_period: 0,
+                ..get_valid_proposal_parameters()
+            },
+            Error::<Test>::InvalidProposalParametersVotingPeriod.into(),
+        );
+
+        assert_failed_set_proposal_parameters_call(
+            ProposalParameters {
+                approval_quorum_percentage: 101,
+                ..get_valid_proposal_parameters()
+            },
+            Error::<Test>::InvalidProposalParametersQuorumPercentage.into(),
+        );
+
+        assert_failed_set_proposal_parameters_call(
+            ProposalParameters {
+                slashing_quorum_percentage: 101,
+                ..get_valid_proposal_parameters()
+            },
+            Error::<Test>::InvalidProposalParametersQuorumPercentage.into(),
+        );
+
+        assert_failed_set_proposal_parameters_call(
+            ProposalParameters {
+                approval_threshold_percentage: 101,
+                ..get_valid_proposal_parameters()
+            },
+            Error::<Test>::InvalidProposalParametersThresholdPercentage.into(),
+        );
+
+        assert_failed_set_proposal_parameters_call(
+            ProposalParameters {
+                slashing_threshold_percentage: 101,
+                ..get_valid_proposal_parameters()
+            },
+            Error::<Test>::InvalidProposalParametersThresholdPercentage.into(),
+        );
+
+        assert_failed_set_proposal_parameters_call(
+            ProposalParameters {
+                approval_threshold_percentage: 0,
+                ..get_valid_proposal_parameters()
+            },
+            Error::<Test>::InvalidProposalParametersThresholdPercentage.into(),
+        );
+
+        assert_failed_set_proposal_parameters_call(
+            ProposalParameters {
+                slashing_threshold_percentage: 0,
+                ..get_valid_proposal_parameters()
+            },
+            Error::<Test>::InvalidProposalParametersThresholdPercentage.into(),
+        );
+    });
+}
+
+#[test]
+fn execute_set_proposal_parameters_proposal_updates_proposal_parameters() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let new_parameters = get_valid_proposal_parameters();
+
+        assert_eq!(
+            ProposalCodex::execute_set_proposal_parameters_proposal(
+                RawOrigin::Signed(1).into(),
+                ProposalType::Text,
+                new_parameters
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+
+        assert_eq!(
+            ProposalCodex::execute_set_proposal_parameters_proposal(
+                RawOrigin::Root.into(),
+                ProposalType::Text,
+                ProposalParameters {
+                    voting_period: 0,
+                    ..new_parameters
+                }
+            ),
+            Err(Error::<Test>::InvalidProposalParametersVotingPeriod.into())
+        );
+
+        assert_eq!(
+            ProposalCodex::execute_set_proposal_parameters_proposal(
+                RawOrigin::Root.into(),
+                ProposalType::Text,
+                new_parameters
+            ),
+            Ok(())
+        );
+
+        assert_eq!(
+            crate::proposal_types::parameters::text_proposal::<Test>(),
+            new_parameters
+        );
+
+        // Parameters of other proposal types are not affected.
+        assert_eq!(
+            ProposalCodex::proposal_parameters_by_type(ProposalType::Spending),
+            None
+        );
+
+        // The old text proposal stake is no longer valid.
+        assert_eq!(
+            ProposalCodex::create_text_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                b"text".to_vec(),
+                None,
+            ),
+            Err(proposals_engine::Error::<Test>::StakeDiffersFromRequired.into())
+        );
+
+        assert_eq!(
+            ProposalCodex::create_text_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                new_parameters.required_stake,
+                b"text".to_vec(),
+                None,
+            ),
+            Ok(())
+        );
+
+        let proposal = ProposalsEngine::proposals(1);
+        assert_eq!(proposal.parameters, new_parameters);
+    });
+}
+
+#[test]
+fn batch_and_ranked_choice_proposals_use_updated_parameters_of_their_items() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let spending_parameters = ProposalParameters {
+            approval_quorum_percentage: 90,
+            approval_threshold_percentage: 100,
+            required_stake: Some(300_000),
+            ..crate::proposal_types::parameters::spending_proposal::<Test>()
+        };
+
+        assert_eq!(
+            ProposalCodex::execute_set_proposal_parameters_proposal(
+                RawOrigin::Root.into(),
+                ProposalType::Spending,
+                spending_parameters
+            ),
+            Ok(())
+        );
+
+        let items = vec![
+            BatchProposalItem::Text(b"text".to_vec()),
+            BatchProposalItem::Spending(100, 2),
+        ];
+
+        // The 'Batch' stake no longer covers the updated 'Spending' stake.
+        assert_eq!(
+            ProposalCodex::create_batch_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(200_000_u32)),
+                items.clone(),
+                None,
+            ),
+            Err(proposals_engine::Error::<Test>::StakeDiffersFromRequired.into())
+        );
+
+        assert_eq!(
+            ProposalCodex::create_batch_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                spending_parameters.required_stake,
+                items,
+                None,
+            ),
+            Ok(())
+        );
+
+        let proposal = ProposalsEngine::proposals(1);
+        assert_eq!(proposal.parameters.approval_quorum_percentage, 90);
+        assert_eq!(proposal.parameters.approval_threshold_percentage, 100);
+        assert_eq!(proposal.parameters.required_stake, Some(300_000));
+
+        // Ranked choice options are checked the same way.
+        let ranked_choice_parameters = crate::proposal_types::parameters::ranked_choice_proposal::<
+            Test,
+        >(&[RankedChoiceOption::Spending(100, 2)]);
+        assert_eq!(ranked_choice_parameters.approval_threshold_percentage, 100);
+        assert_eq!(ranked_choice_parameters.required_stake, Some(300_000));
+
+        // Proposals without the updated items keep their own parameters.
+        assert_eq!(
+            crate::proposal_types::parameters::batch_proposal::<Test>(&[BatchProposalItem::Text(
+                b"text".to_vec()
+            )])
+            .approval_threshold_percentage,
+            80
+        );
+    });
+}
+
+#[test]
+fn create_set_default_storage_quota_limits_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_set_default_storage_quota_limits_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    1000,
+                    50_000_000,
+                    None,
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_set_default_storage_quota_limits_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    1000,
+                    50_000_000,
+                    None,
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_set_default_storage_quota_limits_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    1000,
+                    50_000_000,
+                    None,
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_set_default_storage_quota_limits_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(50000u32)),
+                    1000,
+                    50_000_000,
+                    None,
+                )
+            },
+            proposal_parameters:
+                crate::proposal_types::parameters::set_default_storage_quota_limits_proposal::<
+                    Test,
+                >(),
+            proposal_details: ProposalDetails::SetDefaultStorageQuotaLimits(1000, 50_000_000),
+        };
+        proposal_fixture.check_all();
+    });
+}
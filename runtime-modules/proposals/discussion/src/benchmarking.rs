@@ -0,0 +1,72 @@
+//! Proposals discussion module benchmarking.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::StorageDoubleMap;
+use sp_std::vec;
+use system::RawOrigin;
+
+const SEED: u32 = 0;
+
+fn text(len: u32) -> Vec<u8> {
+    vec![b'a'; len as usize]
+}
+
+fn create_author<T: Trait>() -> (T::AccountId, MemberId<T>) {
+    let account_id: T::AccountId = account("author", 0, SEED);
+    let member_id = T::PostAuthorOriginValidator::create_benchmark_actor(&account_id);
+
+    (account_id, member_id)
+}
+
+fn create_thread<T: Trait>(author_id: MemberId<T>) -> T::ThreadId {
+    Module::<T>::create_thread(author_id, text(T::ThreadTitleLengthLimit::get()))
+        .expect("thread created")
+}
+
+benchmarks! {
+    _ { }
+
+    add_post {
+        let i in 1 .. T::PostLengthLimit::get();
+
+        let (account_id, author_id) = create_author::<T>();
+        let thread_id = create_thread::<T>(author_id);
+    }: _(RawOrigin::Signed(account_id), author_id, thread_id, text(i))
+    verify {
+        assert_eq!(Module::<T>::post_count(), 1);
+    }
+
+    update_post {
+        let i in 1 .. T::PostLengthLimit::get();
+
+        let (account_id, author_id) = create_author::<T>();
+        let thread_id = create_thread::<T>(author_id);
+        Module::<T>::add_post(
+            RawOrigin::Signed(account_id.clone()).into(),
+            author_id,
+            thread_id,
+            text(1),
+        )
+        .expect("post added");
+        let post_id = T::PostId::from(Module::<T>::post_count());
+    }: _(RawOrigin::Signed(account_id), author_id, thread_id, post_id, text(i))
+    verify {
+        assert_eq!(<PostThreadIdByPostId<T>>::get(thread_id, post_id).text, text(i));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::mock::{initial_test_ext, Test};
+    use frame_support::assert_ok;
+
+    #[test]
+    fn proposals_discussion_benchmarks_run_successfully() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_add_post::<Test>());
+            assert_ok!(test_benchmark_update_post::<Test>());
+        });
+    }
+}
@@ -44,12 +44,15 @@
 // Do not delete! Cannot be uncommented by default, because of Parity decl_module! issue.
 //#![warn(missing_docs)]
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 #[cfg(test)]
 mod tests;
 mod types;
 
 use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::traits::Get;
+use frame_support::weights::Weight;
 use frame_support::{decl_error, decl_event, decl_module, decl_storage, ensure, Parameter};
 use sp_std::clone::Clone;
 use sp_std::vec::Vec;
@@ -78,6 +81,23 @@ decl_event!(
     }
 );
 
+/// Extrinsic weights for the proposals discussion module.
+/// `i` - post text length in bytes.
+pub trait WeightInfo {
+    fn add_post(i: u32) -> Weight;
+    fn update_post(i: u32) -> Weight;
+}
+
+// Default weights, used in the tests and by the runtimes without benchmarked weights.
+impl WeightInfo for () {
+    fn add_post(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn update_post(_i: u32) -> Weight {
+        10_000_000
+    }
+}
+
 /// 'Proposal discussion' substrate module Trait
 pub trait Trait: system::Trait + membership::Trait {
     /// Discussion event type.
@@ -107,6 +127,9 @@ pub trait Trait: system::Trait + membership::Trait {
 
     /// Defines max thread by same author in a row number limit.
     type MaxThreadInARowNumber: Get<u32>;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
 }
 
 decl_error! {
@@ -190,7 +213,7 @@ decl_module! {
         const MaxThreadInARowNumber: u32 = T::MaxThreadInARowNumber::get();
 
         /// Adds a post with author origin check.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::add_post(text.len() as u32)]
         pub fn add_post(
             origin,
             post_author_id: MemberId<T>,
@@ -230,7 +253,7 @@ decl_module! {
        }
 
         /// Updates a post with author origin check. Update attempts number is limited.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::update_post(text.len() as u32)]
         pub fn update_post(
             origin,
             post_author_id: MemberId<T>,
@@ -77,6 +77,7 @@ impl membership::Trait for Test {
     type PaidTermId = u64;
     type SubscriptionId = u64;
     type ActorId = u64;
+    type WeightInfo = ();
 }
 
 impl crate::Trait for Test {
@@ -88,6 +89,7 @@ impl crate::Trait for Test {
     type ThreadTitleLengthLimit = ThreadTitleLengthLimit;
     type PostLengthLimit = PostLengthLimit;
     type MaxThreadInARowNumber = MaxThreadInARowNumber;
+    type WeightInfo = ();
 }
 
 impl ActorOriginValidator<Origin, u64, u64> for () {
@@ -102,6 +104,11 @@ impl ActorOriginValidator<Origin, u64, u64> for () {
 
         Err("Invalid author")
     }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn create_benchmark_actor(_: &u64) -> u64 {
+        1
+    }
 }
 
 impl system::Trait for Test {
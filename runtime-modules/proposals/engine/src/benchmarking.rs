@@ -0,0 +1,152 @@
+//! Proposals engine module benchmarking.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks};
+use sp_std::vec;
+use system::RawOrigin;
+
+const SEED: u32 = 0;
+const STAKE: u32 = 1000;
+
+fn text(len: u32) -> Vec<u8> {
+    vec![b'a'; len as usize]
+}
+
+fn create_proposer<T: Trait>() -> (T::AccountId, MemberId<T>) {
+    let account_id: T::AccountId = account("proposer", 0, SEED);
+    let member_id = T::ProposerOriginValidator::create_benchmark_actor(&account_id);
+
+    (account_id, member_id)
+}
+
+fn create_voter<T: Trait>() -> (T::AccountId, MemberId<T>) {
+    let account_id: T::AccountId = account("voter", 0, SEED);
+    let member_id = T::VoterOriginValidator::create_benchmark_actor(&account_id);
+
+    (account_id, member_id)
+}
+
+// Creates the staked proposal: the stake gets slashed or refunded on the proposal finalization.
+// Creates the multi-option proposal if the options number is provided.
+fn create_proposal<T: Trait>(options: Option<u32>) -> (MemberId<T>, T::ProposalId) {
+    let (account_id, proposer_id) = create_proposer::<T>();
+
+    let stake: BalanceOf<T> = STAKE.into();
+    let _ = CurrencyOf::<T>::deposit_creating(&account_id, stake + stake);
+
+    let parameters = ProposalParameters {
+        voting_period: 1000.into(),
+        grace_period: Zero::zero(),
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 60,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 60,
+        required_stake: Some(stake),
+    };
+
+    let proposal_id = match options {
+        Some(options) => Module::<T>::create_multi_option_proposal(
+            account_id,
+            proposer_id,
+            parameters,
+            text(1),
+            text(1),
+            Some(stake),
+            None,
+            vec![Vec::new(); options as usize],
+        ),
+        None => Module::<T>::create_proposal(
+            account_id,
+            proposer_id,
+            parameters,
+            text(1),
+            text(1),
+            Some(stake),
+            None,
+            Vec::new(),
+        ),
+    }
+    .expect("proposal created");
+
+    (proposer_id, proposal_id)
+}
+
+fn is_active<T: Trait>(proposal_id: T::ProposalId) -> bool {
+    <ActiveProposalIds<T>>::contains_key(proposal_id)
+}
+
+benchmarks! {
+    _ { }
+
+    // The voter replaces the previous vote.
+    vote {
+        let r in 0 .. T::VoteRationaleMaxLength::get();
+
+        let (_, proposal_id) = create_proposal::<T>(None);
+        let (account_id, voter_id) = create_voter::<T>();
+        Module::<T>::vote(
+            RawOrigin::Signed(account_id.clone()).into(),
+            voter_id,
+            proposal_id,
+            VoteKind::Reject,
+            text(T::VoteRationaleMaxLength::get()),
+        )
+        .expect("voted");
+    }: _(RawOrigin::Signed(account_id), voter_id, proposal_id, VoteKind::Approve, text(r))
+    verify {
+        assert_eq!(Module::<T>::vote_by_proposal_by_voter(proposal_id, voter_id), VoteKind::Approve);
+    }
+
+    // The voter replaces the previous ranked ballot, which ranks all the options.
+    vote_ranked {
+        let o in 1 .. T::MaxProposalOptions::get();
+        let r in 0 .. T::VoteRationaleMaxLength::get();
+
+        let (_, proposal_id) = create_proposal::<T>(Some(T::MaxProposalOptions::get()));
+        let (account_id, voter_id) = create_voter::<T>();
+        Module::<T>::vote_ranked(
+            RawOrigin::Signed(account_id.clone()).into(),
+            voter_id,
+            proposal_id,
+            (0..T::MaxProposalOptions::get()).collect(),
+            text(T::VoteRationaleMaxLength::get()),
+        )
+        .expect("voted");
+        let ballot: RankedBallot = (0..o).rev().collect();
+    }: _(RawOrigin::Signed(account_id), voter_id, proposal_id, ballot.clone(), text(r))
+    verify {
+        assert_eq!(Module::<T>::ranked_ballot_by_proposal_by_voter(proposal_id, voter_id), ballot);
+    }
+
+    cancel_proposal {
+        let (proposer_id, proposal_id) = create_proposal::<T>(None);
+        let (account_id, _) = create_proposer::<T>();
+    }: _(RawOrigin::Signed(account_id), proposer_id, proposal_id)
+    verify {
+        assert!(!is_active::<T>(proposal_id));
+    }
+
+    veto_proposal {
+        let (_, proposal_id) = create_proposal::<T>(None);
+    }: _(RawOrigin::Root, proposal_id)
+    verify {
+        assert!(!is_active::<T>(proposal_id));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::mock::{initial_test_ext, Test};
+    use frame_support::assert_ok;
+
+    #[test]
+    fn proposals_engine_benchmarks_run_successfully() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_vote::<Test>());
+            assert_ok!(test_benchmark_vote_ranked::<Test>());
+            assert_ok!(test_benchmark_cancel_proposal::<Test>());
+            assert_ok!(test_benchmark_veto_proposal::<Test>());
+        });
+    }
+}
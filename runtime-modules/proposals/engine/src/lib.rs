@@ -13,9 +13,13 @@
 //! When a proposal passes [checks](./struct.Module.html#method.ensure_create_proposal_parameters_are_valid)
 //! for its [parameters](./struct.ProposalParameters.html) - it can be [created](./struct.Module.html#method.create_proposal).
 //! The newly created proposal has _Active_ status. The proposal can be voted on or canceled during its
-//! _voting period_. Votes can be [different](./enum.VoteKind.html). When the proposal gets enough votes
+//! _voting period_. Votes can be [different](./enum.VoteKind.html) and can be accompanied by
+//! a rationale text. A vote can be changed until the proposal voting is finished. When the proposal gets enough votes
 //! to be slashed or approved or _voting period_ ends - the proposal becomes _Finalized_. If the proposal
 //! got approved and _grace period_ passed - the  `engine` module tries to execute the proposal.
+//! A proposal can be created with an _exact execution block_: the approved proposal would be executed
+//! at this block instead of the _grace period_ end. The exact execution block cannot precede
+//! the _voting period_ and the _grace period_ end and is limited by the max execution delay.
 //! The final [approved status](./enum.ApprovedProposalStatus.html) of the proposal defines
 //! an overall proposal outcome.
 //!
@@ -27,6 +31,10 @@
 //! different statuses should be accomplished from the external handler from the _stake module_
 //! (_StakingEventsHandler_). Such a handler should call
 //! [refund_proposal_stake](./struct.Module.html#method.refund_proposal_stake) callback function.
+//! - The proposal can contain several executable options. Council members vote for such a proposal
+//! with [ranked ballots](./type.RankedBallot.html). A ranked ballot counts as an approval, and
+//! when the proposal gets approved - the option with the best
+//! [ranked choice result](./struct.RankedChoiceVotingResults.html) (Borda count) is executed.
 //! - If the _council_ got reelected during the proposal _voting period_ the external handler calls
 //! [reset_active_proposals](./trait.Module.html#method.reset_active_proposals) function and
 //! all voting results get cleared.
@@ -44,15 +52,20 @@
 //! A full list of the abstractions can be found [here](./trait.Trait.html).
 //!
 //! ### Supported extrinsics
-//! - [vote](./struct.Module.html#method.vote) - registers a vote for the proposal
+//! - [vote](./struct.Module.html#method.vote) - registers or changes a vote for the proposal
+//! - [vote_ranked](./struct.Module.html#method.vote_ranked) - registers or changes a ranked ballot for the multi-option proposal
 //! - [cancel_proposal](./struct.Module.html#method.cancel_proposal) - cancels the proposal (can be canceled only by owner)
 //! - [veto_proposal](./struct.Module.html#method.veto_proposal) - vetoes the proposal
 //!
 //! ### Public API
 //! - [create_proposal](./struct.Module.html#method.create_proposal) - creates proposal using provided parameters
+//! - [create_multi_option_proposal](./struct.Module.html#method.create_multi_option_proposal) - creates ranked choice proposal with several executable options
+//! - [ensure_proposal_options_are_valid](./struct.Module.html#method.ensure_proposal_options_are_valid) - ensures that multi-option proposal options are valid
 //! - [ensure_create_proposal_parameters_are_valid](./struct.Module.html#method.ensure_create_proposal_parameters_are_valid) - ensures that we can create the proposal
 //! - [refund_proposal_stake](./struct.Module.html#method.refund_proposal_stake) - a callback for _StakingHandlerEvents_
 //! - [reset_active_proposals](./trait.Module.html#method.reset_active_proposals) - resets voting results for active proposals
+//! - [get_active_proposals](./struct.Module.html#method.get_active_proposals) - returns proposals open for voting
+//! - [get_pending_execution_proposals](./struct.Module.html#method.get_pending_execution_proposals) - returns approved proposals with the grace period in effect
 //!
 //! ## Usage
 //!
@@ -87,6 +100,7 @@
 //!                 &parameters,
 //!                 &title,
 //!                 &description,
+//!                 None,
 //!                 None
 //!             )?;
 //!             <engine::Module<T>>::create_proposal(
@@ -96,6 +110,7 @@
 //!                 title,
 //!                 description,
 //!                 None,
+//!                 None,
 //!                 encoded_proposal_code
 //!             )?;
 //!         }
@@ -115,7 +130,7 @@ use types::FinalizedProposalData;
 use types::ProposalStakeManager;
 pub use types::{
     ActiveStake, ApprovedProposalStatus, FinalizationData, Proposal, ProposalDecisionStatus,
-    ProposalParameters, ProposalStatus, VotingResults,
+    ProposalParameters, ProposalStatus, RankedBallot, RankedChoiceVotingResults, VotingResults,
 };
 pub use types::{BalanceOf, CurrencyOf, NegativeImbalance};
 pub use types::{DefaultStakeHandlerProvider, StakeHandler, StakeHandlerProvider};
@@ -124,6 +139,8 @@ pub use types::{VoteKind, VotersParameters};
 
 pub(crate) mod types;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 #[cfg(test)]
 mod tests;
 
@@ -131,10 +148,11 @@ use codec::Decode;
 use frame_support::dispatch::{DispatchError, DispatchResult, UnfilteredDispatchable};
 use frame_support::storage::IterableStorageMap;
 use frame_support::traits::{Currency, Get};
+use frame_support::weights::{GetDispatchInfo, Weight};
 use frame_support::{
     decl_error, decl_event, decl_module, decl_storage, ensure, print, Parameter, StorageDoubleMap,
 };
-use sp_arithmetic::traits::Zero;
+use sp_arithmetic::traits::{Saturating, Zero};
 use sp_std::vec::Vec;
 use system::{ensure_root, RawOrigin};
 
@@ -142,6 +160,31 @@ use common::origin::ActorOriginValidator;
 
 type MemberId<T> = <T as membership::Trait>::MemberId;
 
+/// Extrinsic weights for the proposals engine module.
+/// `o` - ranked ballot length, `r` - vote rationale length in bytes.
+pub trait WeightInfo {
+    fn vote(r: u32) -> Weight;
+    fn vote_ranked(o: u32, r: u32) -> Weight;
+    fn cancel_proposal() -> Weight;
+    fn veto_proposal() -> Weight;
+}
+
+// Default weights, used in the tests and by the runtimes without benchmarked weights.
+impl WeightInfo for () {
+    fn vote(_r: u32) -> Weight {
+        10_000_000
+    }
+    fn vote_ranked(_o: u32, _r: u32) -> Weight {
+        10_000_000
+    }
+    fn cancel_proposal() -> Weight {
+        10_000_000
+    }
+    fn veto_proposal() -> Weight {
+        10_000_000
+    }
+}
+
 /// Proposals engine trait.
 pub trait Trait:
     system::Trait + pallet_timestamp::Trait + stake::Trait + membership::Trait
@@ -183,8 +226,23 @@ pub trait Trait:
     /// Defines max simultaneous active proposals number.
     type MaxActiveProposalLimit: Get<u32>;
 
+    /// Defines max options number for the multi-option (ranked choice) proposal.
+    type MaxProposalOptions: Get<u32>;
+
+    /// Defines max allowed vote rationale length.
+    type VoteRationaleMaxLength: Get<u32>;
+
+    /// Defines max allowed delay between the proposal creation and its exact execution block.
+    type MaxExactExecutionBlockDelay: Get<Self::BlockNumber>;
+
     /// Proposals executable code. Can be instantiated by external module Call enum members.
-    type DispatchableCallCode: Parameter + UnfilteredDispatchable<Origin = Self::Origin> + Default;
+    type DispatchableCallCode: Parameter
+        + UnfilteredDispatchable<Origin = Self::Origin>
+        + GetDispatchInfo
+        + Default;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
 }
 
 decl_event!(
@@ -209,12 +267,34 @@ decl_event!(
         /// - New proposal status
         ProposalStatusUpdated(ProposalId, ProposalStatus<BlockNumber, StakeId, AccountId>),
 
-        /// Emits on voting for the proposal
+        /// Emits on voting for the proposal or changing the vote
         /// Params:
         /// - Voter - member id of a voter.
         /// - Id of a proposal.
         /// - Kind of vote.
-        Voted(MemberId, ProposalId, VoteKind),
+        /// - Vote rationale (can be empty).
+        Voted(MemberId, ProposalId, VoteKind, Vec<u8>),
+
+        /// Emits on ranked voting for the multi-option proposal or changing the ranked vote
+        /// Params:
+        /// - Voter - member id of a voter.
+        /// - Id of a proposal.
+        /// - Ranked ballot: option indices, the most preferred first.
+        /// - Vote rationale (can be empty).
+        RankedVoted(MemberId, ProposalId, RankedBallot, Vec<u8>),
+
+        /// Emits on the winning option selection for the approved multi-option proposal
+        /// Params:
+        /// - Id of a proposal.
+        /// - Index of the selected option.
+        ProposalOptionSelected(ProposalId, u32),
+
+        /// Emits when the approved proposal execution is deferred beyond its grace period
+        /// until the exact execution block.
+        /// Params:
+        /// - Id of a proposal.
+        /// - Exact execution block.
+        ProposalExecutionDeferred(ProposalId, BlockNumber),
     }
 );
 
@@ -239,7 +319,8 @@ decl_error! {
         /// Proposal is finalized already
         ProposalFinalized,
 
-        /// The proposal have been already voted on
+        /// Deprecated and unused: the votes can be changed. Kept to preserve the indices of
+        /// the following errors.
         AlreadyVoted,
 
         /// Not an author
@@ -265,6 +346,27 @@ decl_error! {
 
         /// Require root origin in extrinsics
         RequireRootOrigin,
+
+        /// Multi-option proposal options number is out of the allowed range
+        InvalidProposalOptionsNumber,
+
+        /// Ranked ballot is allowed only for the multi-option proposal
+        NotMultiOptionProposal,
+
+        /// Multi-option proposal requires the ranked ballot
+        RankedBallotRequired,
+
+        /// Ranked ballot must be non-empty and contain existing options without duplicates
+        InvalidRankedBallot,
+
+        /// Vote rationale is too long
+        VoteRationaleIsTooLong,
+
+        /// Exact execution block cannot precede the end of the voting and grace periods
+        InvalidExactExecutionBlockMinimum,
+
+        /// Exact execution block exceeds the max allowed execution delay
+        InvalidExactExecutionBlockMaximum,
     }
 }
 
@@ -289,17 +391,40 @@ decl_storage! {
         pub ActiveProposalIds get(fn active_proposal_ids): map hasher(blake2_128_concat)
             T::ProposalId=> ();
 
-        /// Ids of proposals that were approved and theirs grace period was not expired.
+        /// Ids of proposals that were approved and theirs grace period was not expired or
+        /// exact execution block was not reached.
         pub PendingExecutionProposalIds get(fn pending_proposal_ids): map hasher(blake2_128_concat)
             T::ProposalId=> ();
 
-        /// Double map for preventing duplicate votes. Should be cleaned after usage.
+        /// Double map for the votes of the proposal voters. Used for the vote changing.
+        /// Should be cleaned after usage.
         pub VoteExistsByProposalByVoter get(fn vote_by_proposal_by_voter):
             double_map hasher(blake2_128_concat)  T::ProposalId, hasher(blake2_128_concat) MemberId<T> => VoteKind;
 
         /// Map proposal id by stake id. Required by StakingEventsHandler callback call
         pub StakesProposals get(fn stakes_proposals): map hasher(blake2_128_concat)
             T::StakeId =>  T::ProposalId;
+
+        /// Map multi-option proposal executable option codes by proposal id.
+        pub ProposalOptionsCode get(fn proposal_options_code): map hasher(blake2_128_concat)
+            T::ProposalId => Vec<Vec<u8>>;
+
+        /// Map ranked choice voting results by multi-option proposal id.
+        pub RankedChoiceResultsByProposal get(fn ranked_choice_results):
+            map hasher(blake2_128_concat) T::ProposalId => RankedChoiceVotingResults;
+
+        /// Double map for ranked ballots of the multi-option proposals. Should be cleaned after usage.
+        pub RankedBallotByProposalByVoter get(fn ranked_ballot_by_proposal_by_voter):
+            double_map hasher(blake2_128_concat)  T::ProposalId, hasher(blake2_128_concat) MemberId<T> => RankedBallot;
+
+        /// Double map for the vote rationales of the proposal voters. Should be cleaned after usage.
+        pub VoteRationaleByProposalByVoter get(fn vote_rationale_by_proposal_by_voter):
+            double_map hasher(blake2_128_concat)  T::ProposalId, hasher(blake2_128_concat) MemberId<T> => Vec<u8>;
+
+        /// Map exact execution block by proposal id. Proposals without the exact execution block
+        /// are executed right after the grace period.
+        pub ExactExecutionBlockByProposal get(fn exact_execution_block):
+            map hasher(blake2_128_concat) T::ProposalId => Option<T::BlockNumber>;
     }
 }
 
@@ -327,9 +452,25 @@ decl_module! {
         /// Exports const -  max simultaneous active proposals number.
         const MaxActiveProposalLimit: u32 = T::MaxActiveProposalLimit::get();
 
-        /// Vote extrinsic. Conditions:  origin must allow votes.
-        #[weight = 10_000_000] // TODO: adjust weight
-        pub fn vote(origin, voter_id: MemberId<T>, proposal_id: T::ProposalId, vote: VoteKind)  {
+        /// Exports const -  max options number for the multi-option proposal.
+        const MaxProposalOptions: u32 = T::MaxProposalOptions::get();
+
+        /// Exports const -  max allowed vote rationale length.
+        const VoteRationaleMaxLength: u32 = T::VoteRationaleMaxLength::get();
+
+        /// Exports const -  max allowed delay between the proposal creation and its exact execution block.
+        const MaxExactExecutionBlockDelay: T::BlockNumber = T::MaxExactExecutionBlockDelay::get();
+
+        /// Vote extrinsic. Conditions:  origin must allow votes. Repeated vote replaces
+        /// the previous vote of the voter while the proposal is active.
+        #[weight = <T as Trait>::WeightInfo::vote(rationale.len() as u32)]
+        pub fn vote(
+            origin,
+            voter_id: MemberId<T>,
+            proposal_id: T::ProposalId,
+            vote: VoteKind,
+            rationale: Vec<u8>
+        )  {
             T::VoterOriginValidator::ensure_actor_origin(
                 origin,
                 voter_id,
@@ -340,24 +481,78 @@ decl_module! {
 
             ensure!(matches!(proposal.status, ProposalStatus::Active{..}), Error::<T>::ProposalFinalized);
 
-            let did_not_vote_before = !<VoteExistsByProposalByVoter<T>>::contains_key(
-                proposal_id,
-                voter_id,
+            Self::ensure_vote_rationale_is_valid(&rationale)?;
+
+            // Approval of the multi-option proposal must define the preferred options.
+            ensure!(
+                vote != VoteKind::Approve || !<ProposalOptionsCode<T>>::contains_key(proposal_id),
+                Error::<T>::RankedBallotRequired
             );
 
-            ensure!(did_not_vote_before, Error::<T>::AlreadyVoted);
+            // mutation
 
+            Self::revoke_previous_vote(proposal_id, voter_id, &mut proposal);
             proposal.voting_results.add_vote(vote.clone());
 
+            <Proposals<T>>::insert(proposal_id, proposal);
+            <VoteExistsByProposalByVoter<T>>::insert(proposal_id, voter_id, vote.clone());
+            Self::save_vote_rationale(proposal_id, voter_id, rationale.clone());
+            Self::deposit_event(RawEvent::Voted(voter_id, proposal_id, vote, rationale));
+        }
+
+        /// Ranked vote extrinsic for the multi-option proposal. The ranked ballot counts as an approval.
+        /// Conditions: origin must allow votes. Repeated vote replaces the previous vote of the voter
+        /// while the proposal is active.
+        #[weight = <T as Trait>::WeightInfo::vote_ranked(
+            ballot.len() as u32,
+            rationale.len() as u32,
+        )]
+        pub fn vote_ranked(
+            origin,
+            voter_id: MemberId<T>,
+            proposal_id: T::ProposalId,
+            ballot: RankedBallot,
+            rationale: Vec<u8>
+        ) {
+            T::VoterOriginValidator::ensure_actor_origin(
+                origin,
+                voter_id,
+            )?;
+
+            ensure!(<Proposals<T>>::contains_key(proposal_id), Error::<T>::ProposalNotFound);
+            let mut proposal = Self::proposals(proposal_id);
+
+            ensure!(matches!(proposal.status, ProposalStatus::Active{..}), Error::<T>::ProposalFinalized);
+
+            ensure!(
+                <ProposalOptionsCode<T>>::contains_key(proposal_id),
+                Error::<T>::NotMultiOptionProposal
+            );
+
+            ensure!(
+                Self::ranked_choice_results(proposal_id).is_valid_ballot(&ballot),
+                Error::<T>::InvalidRankedBallot
+            );
+
+            Self::ensure_vote_rationale_is_valid(&rationale)?;
+
             // mutation
 
+            Self::revoke_previous_vote(proposal_id, voter_id, &mut proposal);
+            proposal.voting_results.add_vote(VoteKind::Approve);
+
             <Proposals<T>>::insert(proposal_id, proposal);
-            <VoteExistsByProposalByVoter<T>>::insert(proposal_id, voter_id, vote.clone());
-            Self::deposit_event(RawEvent::Voted(voter_id, proposal_id, vote));
+            <RankedChoiceResultsByProposal<T>>::mutate(proposal_id, |ranked_choice_results| {
+                ranked_choice_results.add_ballot(&ballot);
+            });
+            <VoteExistsByProposalByVoter<T>>::insert(proposal_id, voter_id, VoteKind::Approve);
+            <RankedBallotByProposalByVoter<T>>::insert(proposal_id, voter_id, ballot.clone());
+            Self::save_vote_rationale(proposal_id, voter_id, rationale.clone());
+            Self::deposit_event(RawEvent::RankedVoted(voter_id, proposal_id, ballot, rationale));
         }
 
         /// Cancel a proposal by its original proposer.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::cancel_proposal()]
         pub fn cancel_proposal(origin, proposer_id: MemberId<T>, proposal_id: T::ProposalId) {
             T::ProposerOriginValidator::ensure_actor_origin(
                 origin,
@@ -376,7 +571,7 @@ decl_module! {
         }
 
         /// Veto a proposal. Must be root.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::veto_proposal()]
         pub fn veto_proposal(origin, proposal_id: T::ProposalId) {
             ensure_root(origin)?;
 
@@ -394,7 +589,7 @@ decl_module! {
         }
 
         /// Block finalization. Perform voting period check, vote result tally, approved proposals
-        /// grace period checks, and proposal execution.
+        /// grace period and exact execution block checks, and proposal execution.
         fn on_finalize(_n: T::BlockNumber) {
             let finalized_proposals = Self::get_finalized_proposals();
 
@@ -407,10 +602,9 @@ decl_module! {
                 Self::finalize_proposal(proposal_data.proposal_id, proposal_data.status);
             }
 
-            let executable_proposals =
-                Self::get_approved_proposal_with_expired_grace_period();
+            let executable_proposals = Self::get_approved_proposals_ready_for_execution();
 
-            // Execute approved proposals with expired grace period
+            // Execute approved proposals in the order of their execution blocks
             for approved_proosal in executable_proposals {
                 Self::execute_proposal(approved_proosal);
             }
@@ -420,6 +614,8 @@ decl_module! {
 
 impl<T: Trait> Module<T> {
     /// Create proposal. Requires 'proposal origin' membership.
+    /// The approved proposal is executed at the exact execution block if provided.
+    #[allow(clippy::too_many_arguments)]
     pub fn create_proposal(
         account_id: T::AccountId,
         proposer_id: MemberId<T>,
@@ -427,6 +623,7 @@ impl<T: Trait> Module<T> {
         title: Vec<u8>,
         description: Vec<u8>,
         stake_balance: Option<types::BalanceOf<T>>,
+        exact_execution_block: Option<T::BlockNumber>,
         encoded_dispatchable_call_code: Vec<u8>,
     ) -> Result<T::ProposalId, DispatchError> {
         Self::ensure_create_proposal_parameters_are_valid(
@@ -434,64 +631,102 @@ impl<T: Trait> Module<T> {
             &title,
             &description,
             stake_balance,
+            exact_execution_block,
         )?;
 
         // checks passed
         // mutation
 
-        let next_proposal_count_value = Self::proposal_count() + 1;
-        let new_proposal_id = next_proposal_count_value;
-        let proposal_id = T::ProposalId::from(new_proposal_id);
+        let proposal_id = Self::insert_proposal(
+            account_id,
+            proposer_id,
+            parameters,
+            title,
+            description,
+            stake_balance,
+            exact_execution_block,
+        )?;
 
-        // Check stake_balance for value and create stake if value exists, else take None
-        // If create_stake() returns error - return error from extrinsic
-        let stake_id_result = stake_balance
-            .map(|stake_amount| {
-                ProposalStakeManager::<T>::create_stake(stake_amount, account_id.clone())
-            })
-            .transpose()?;
+        <DispatchableCallCode<T>>::insert(proposal_id, encoded_dispatchable_call_code);
 
-        let mut stake_data = None;
-        if let Some(stake_id) = stake_id_result {
-            stake_data = Some(ActiveStake {
-                stake_id,
-                source_account_id: account_id,
-            });
+        Self::deposit_event(RawEvent::ProposalCreated(proposer_id, proposal_id));
 
-            <StakesProposals<T>>::insert(stake_id, proposal_id);
-        }
+        Ok(proposal_id)
+    }
 
-        let new_proposal = Proposal {
-            created_at: Self::current_block(),
+    /// Create multi-option (ranked choice) proposal. Requires 'proposal origin' membership.
+    /// Council members vote for the proposal with ranked ballots, the winning option code
+    /// gets executed on the proposal approval.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_multi_option_proposal(
+        account_id: T::AccountId,
+        proposer_id: MemberId<T>,
+        parameters: ProposalParameters<T::BlockNumber, types::BalanceOf<T>>,
+        title: Vec<u8>,
+        description: Vec<u8>,
+        stake_balance: Option<types::BalanceOf<T>>,
+        exact_execution_block: Option<T::BlockNumber>,
+        encoded_options: Vec<Vec<u8>>,
+    ) -> Result<T::ProposalId, DispatchError> {
+        Self::ensure_create_proposal_parameters_are_valid(
+            &parameters,
+            &title,
+            &description,
+            stake_balance,
+            exact_execution_block,
+        )?;
+
+        Self::ensure_proposal_options_are_valid(&encoded_options)?;
+
+        // checks passed
+        // mutation
+
+        let proposal_id = Self::insert_proposal(
+            account_id,
+            proposer_id,
             parameters,
             title,
             description,
-            proposer_id,
-            status: ProposalStatus::Active(stake_data),
-            voting_results: VotingResults::default(),
-        };
+            stake_balance,
+            exact_execution_block,
+        )?;
 
-        <Proposals<T>>::insert(proposal_id, new_proposal);
-        <DispatchableCallCode<T>>::insert(proposal_id, encoded_dispatchable_call_code);
-        <ActiveProposalIds<T>>::insert(proposal_id, ());
-        ProposalCount::put(next_proposal_count_value);
-        Self::increase_active_proposal_counter();
+        <RankedChoiceResultsByProposal<T>>::insert(
+            proposal_id,
+            RankedChoiceVotingResults::new(encoded_options.len() as u32),
+        );
+        <ProposalOptionsCode<T>>::insert(proposal_id, encoded_options);
 
         Self::deposit_event(RawEvent::ProposalCreated(proposer_id, proposal_id));
 
         Ok(proposal_id)
     }
 
+    /// Ensures that the multi-option proposal has from two to 'MaxProposalOptions' options.
+    pub fn ensure_proposal_options_are_valid(encoded_options: &[Vec<u8>]) -> DispatchResult {
+        let options_number = encoded_options.len() as u32;
+
+        ensure!(
+            options_number >= 2 && options_number <= T::MaxProposalOptions::get(),
+            Error::<T>::InvalidProposalOptionsNumber
+        );
+
+        Ok(())
+    }
+
     /// Performs all checks for the proposal creation:
     /// - title, body lengths
     /// - max active proposal
     /// - provided parameters: approval_threshold_percentage and slashing_threshold_percentage > 0
     /// - provided stake balance and parameters.required_stake are valid
+    /// - provided exact execution block follows the voting and grace periods and doesn't exceed
+    /// the max execution delay
     pub fn ensure_create_proposal_parameters_are_valid(
         parameters: &ProposalParameters<T::BlockNumber, types::BalanceOf<T>>,
         title: &[u8],
         description: &[u8],
         stake_balance: Option<types::BalanceOf<T>>,
+        exact_execution_block: Option<T::BlockNumber>,
     ) -> DispatchResult {
         ensure!(!title.is_empty(), Error::<T>::EmptyTitleProvided);
         ensure!(
@@ -539,6 +774,24 @@ impl<T: Trait> Module<T> {
             return Err(Error::<T>::StakeShouldBeEmpty.into());
         }
 
+        // check exact execution block
+        if let Some(exact_execution_block) = exact_execution_block {
+            let now = Self::current_block();
+
+            ensure!(
+                exact_execution_block
+                    >= now
+                        .saturating_add(parameters.voting_period)
+                        .saturating_add(parameters.grace_period),
+                Error::<T>::InvalidExactExecutionBlockMinimum
+            );
+
+            ensure!(
+                exact_execution_block <= now.saturating_add(T::MaxExactExecutionBlockDelay::get()),
+                Error::<T>::InvalidExactExecutionBlockMaximum
+            );
+        }
+
         Ok(())
     }
 
@@ -574,6 +827,20 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    /// Returns ids and data of the proposals open for voting.
+    pub fn get_active_proposals() -> Vec<(T::ProposalId, ProposalOf<T>)> {
+        <ActiveProposalIds<T>>::iter()
+            .map(|(proposal_id, _)| (proposal_id, Self::proposals(proposal_id)))
+            .collect()
+    }
+
+    /// Returns ids and data of the approved proposals waiting for the execution.
+    pub fn get_pending_execution_proposals() -> Vec<(T::ProposalId, ProposalOf<T>)> {
+        <PendingExecutionProposalIds<T>>::iter()
+            .map(|(proposal_id, _)| (proposal_id, Self::proposals(proposal_id)))
+            .collect()
+    }
+
     /// Resets voting results for active proposals.
     /// Possible application includes new council elections.
     pub fn reset_active_proposals() {
@@ -581,7 +848,17 @@ impl<T: Trait> Module<T> {
             <Proposals<T>>::mutate(proposal_id, |proposal| {
                 proposal.reset_proposal();
                 <VoteExistsByProposalByVoter<T>>::remove_prefix(&proposal_id);
+                <VoteRationaleByProposalByVoter<T>>::remove_prefix(&proposal_id);
             });
+
+            if <ProposalOptionsCode<T>>::contains_key(proposal_id) {
+                let options_number = Self::proposal_options_code(proposal_id).len() as u32;
+                <RankedChoiceResultsByProposal<T>>::insert(
+                    proposal_id,
+                    RankedChoiceVotingResults::new(options_number),
+                );
+                <RankedBallotByProposalByVoter<T>>::remove_prefix(&proposal_id);
+            }
         });
     }
 }
@@ -592,6 +869,99 @@ impl<T: Trait> Module<T> {
         <system::Module<T>>::block_number()
     }
 
+    // Saves a new active proposal and creates its stake if required. Returns the new proposal id.
+    // Proposal code and the creation event are handled by the callers.
+    fn insert_proposal(
+        account_id: T::AccountId,
+        proposer_id: MemberId<T>,
+        parameters: ProposalParameters<T::BlockNumber, types::BalanceOf<T>>,
+        title: Vec<u8>,
+        description: Vec<u8>,
+        stake_balance: Option<types::BalanceOf<T>>,
+        exact_execution_block: Option<T::BlockNumber>,
+    ) -> Result<T::ProposalId, DispatchError> {
+        let next_proposal_count_value = Self::proposal_count() + 1;
+        let new_proposal_id = next_proposal_count_value;
+        let proposal_id = T::ProposalId::from(new_proposal_id);
+
+        // Check stake_balance for value and create stake if value exists, else take None
+        // If create_stake() returns error - return error from extrinsic
+        let stake_id_result = stake_balance
+            .map(|stake_amount| {
+                ProposalStakeManager::<T>::create_stake(stake_amount, account_id.clone())
+            })
+            .transpose()?;
+
+        let mut stake_data = None;
+        if let Some(stake_id) = stake_id_result {
+            stake_data = Some(ActiveStake {
+                stake_id,
+                source_account_id: account_id,
+            });
+
+            <StakesProposals<T>>::insert(stake_id, proposal_id);
+        }
+
+        let new_proposal = Proposal {
+            created_at: Self::current_block(),
+            parameters,
+            title,
+            description,
+            proposer_id,
+            status: ProposalStatus::Active(stake_data),
+            voting_results: VotingResults::default(),
+        };
+
+        <Proposals<T>>::insert(proposal_id, new_proposal);
+        <ActiveProposalIds<T>>::insert(proposal_id, ());
+        if let Some(exact_execution_block) = exact_execution_block {
+            <ExactExecutionBlockByProposal<T>>::insert(proposal_id, exact_execution_block);
+        }
+        ProposalCount::put(next_proposal_count_value);
+        Self::increase_active_proposal_counter();
+
+        Ok(proposal_id)
+    }
+
+    // Ensures the vote rationale length is within the limit.
+    fn ensure_vote_rationale_is_valid(rationale: &[u8]) -> DispatchResult {
+        ensure!(
+            rationale.len() as u32 <= T::VoteRationaleMaxLength::get(),
+            Error::<T>::VoteRationaleIsTooLong
+        );
+
+        Ok(())
+    }
+
+    // Removes the previous vote of the voter (if any) from the proposal voting results and
+    // the ranked choice results. Proposal changes should be saved by the caller.
+    fn revoke_previous_vote(
+        proposal_id: T::ProposalId,
+        voter_id: MemberId<T>,
+        proposal: &mut ProposalOf<T>,
+    ) {
+        if <VoteExistsByProposalByVoter<T>>::contains_key(proposal_id, voter_id) {
+            let previous_vote = Self::vote_by_proposal_by_voter(proposal_id, voter_id);
+            proposal.voting_results.remove_vote(previous_vote);
+        }
+
+        if <RankedBallotByProposalByVoter<T>>::contains_key(proposal_id, voter_id) {
+            let previous_ballot = <RankedBallotByProposalByVoter<T>>::take(proposal_id, voter_id);
+            <RankedChoiceResultsByProposal<T>>::mutate(proposal_id, |ranked_choice_results| {
+                ranked_choice_results.remove_ballot(&previous_ballot);
+            });
+        }
+    }
+
+    // Saves the vote rationale. Empty rationale removes the rationale of the previous vote.
+    fn save_vote_rationale(proposal_id: T::ProposalId, voter_id: MemberId<T>, rationale: Vec<u8>) {
+        if rationale.is_empty() {
+            <VoteRationaleByProposalByVoter<T>>::remove(proposal_id, voter_id);
+        } else {
+            <VoteRationaleByProposalByVoter<T>>::insert(proposal_id, voter_id, rationale);
+        }
+    }
+
     // Enumerates through active proposals. Tally Voting results.
     // Returns proposals with finalized status and id
     fn get_finalized_proposals() -> Vec<FinalizedProposal<T>> {
@@ -643,11 +1013,9 @@ impl<T: Trait> Module<T> {
 
     // Executes approved proposal code
     fn execute_proposal(approved_proposal: ApprovedProposal<T>) {
-        let proposal_code = Self::proposal_codes(approved_proposal.proposal_id);
-
-        let proposal_code_result = T::DispatchableCallCode::decode(&mut &proposal_code[..]);
+        let executable_call = Self::get_executable_call(approved_proposal.proposal_id);
 
-        let approved_proposal_status = match proposal_code_result {
+        let approved_proposal_status = match executable_call {
             Ok(proposal_code) => {
                 if let Err(dispatch_error) =
                     proposal_code.dispatch_bypass_filter(T::Origin::from(RawOrigin::Root))
@@ -659,7 +1027,7 @@ impl<T: Trait> Module<T> {
                     ApprovedProposalStatus::Executed
                 }
             }
-            Err(error) => ApprovedProposalStatus::failed_execution(error.what()),
+            Err(error) => ApprovedProposalStatus::failed_execution(error),
         };
 
         let proposal_execution_status = approved_proposal
@@ -676,12 +1044,53 @@ impl<T: Trait> Module<T> {
         ));
 
         <PendingExecutionProposalIds<T>>::remove(&approved_proposal.proposal_id);
+        <ExactExecutionBlockByProposal<T>>::remove(&approved_proposal.proposal_id);
+        Self::remove_proposal_options(approved_proposal.proposal_id);
+    }
+
+    // Removes the options and the ranked choice results of the multi-option proposal.
+    fn remove_proposal_options(proposal_id: T::ProposalId) {
+        <ProposalOptionsCode<T>>::remove(proposal_id);
+        <RankedChoiceResultsByProposal<T>>::remove(proposal_id);
+    }
+
+    // Decodes the executable code of the approved proposal. The multi-option proposal returns
+    // the code of the ranked choice winner option.
+    fn get_executable_call(
+        proposal_id: T::ProposalId,
+    ) -> Result<T::DispatchableCallCode, &'static str> {
+        let proposal_code = if <ProposalOptionsCode<T>>::contains_key(proposal_id) {
+            Self::get_winner_option_code(proposal_id)?
+        } else {
+            Self::proposal_codes(proposal_id)
+        };
+
+        T::DispatchableCallCode::decode(&mut &proposal_code[..]).map_err(|error| error.what())
+    }
+
+    // Returns the code of the ranked choice winner option for the multi-option proposal.
+    fn get_winner_option_code(proposal_id: T::ProposalId) -> Result<Vec<u8>, &'static str> {
+        let winner = Self::ranked_choice_results(proposal_id)
+            .winner()
+            .ok_or("No proposal option was selected")?;
+
+        let option_code = Self::proposal_options_code(proposal_id)
+            .get(winner as usize)
+            .cloned()
+            .ok_or("Selected proposal option doesn't exist")?;
+
+        Self::deposit_event(RawEvent::ProposalOptionSelected(proposal_id, winner));
+
+        Ok(option_code)
     }
 
     // Performs all actions on proposal finalization:
     // - clean active proposal cache
     // - update proposal status fields (status, finalized_at)
     // - add to pending execution proposal cache if approved
+    // - fire an event if the approved proposal execution is deferred until the exact execution block
+    // - clean ranked ballots and vote rationales, and proposal options and exact execution block
+    //   if not approved
     // - slash and unstake proposal stake if stake exists
     // - decrease active proposal counter
     // - fire an event
@@ -693,7 +1102,8 @@ impl<T: Trait> Module<T> {
         let mut proposal = Self::proposals(proposal_id);
 
         if let ProposalStatus::Active(active_stake) = proposal.status.clone() {
-            if let ProposalDecisionStatus::Approved { .. } = decision_status {
+            let approved = matches!(decision_status, ProposalDecisionStatus::Approved { .. });
+            if approved {
                 <PendingExecutionProposalIds<T>>::insert(proposal_id, ());
             }
 
@@ -711,18 +1121,45 @@ impl<T: Trait> Module<T> {
                 Self::current_block(),
             );
 
+            let grace_period = proposal.parameters.grace_period;
             proposal.status = new_proposal_status.clone();
             <Proposals<T>>::insert(proposal_id, proposal);
 
+            <RankedBallotByProposalByVoter<T>>::remove_prefix(&proposal_id);
+            <VoteRationaleByProposalByVoter<T>>::remove_prefix(&proposal_id);
+            if !approved {
+                <ExactExecutionBlockByProposal<T>>::remove(&proposal_id);
+                Self::remove_proposal_options(proposal_id);
+            }
+
             Self::deposit_event(RawEvent::ProposalStatusUpdated(
                 proposal_id,
                 new_proposal_status,
             ));
+
+            if approved {
+                Self::deposit_execution_deferred_event(proposal_id, grace_period);
+            }
         } else {
             print("Broken invariant: proposal cannot be non-active during the finalisation");
         }
     }
 
+    // Fires an event if the approved proposal execution is deferred beyond its grace period
+    // until the exact execution block.
+    fn deposit_execution_deferred_event(proposal_id: T::ProposalId, grace_period: T::BlockNumber) {
+        if let Some(exact_execution_block) = Self::exact_execution_block(proposal_id) {
+            let grace_period_end = Self::current_block().saturating_add(grace_period);
+
+            if exact_execution_block > grace_period_end {
+                Self::deposit_event(RawEvent::ProposalExecutionDeferred(
+                    proposal_id,
+                    exact_execution_block,
+                ));
+            }
+        }
+    }
+
     // Slashes the stake and perform unstake only in case of existing stake
     fn slash_and_unstake(
         current_stake_data: Option<ActiveStake<T::StakeId, T::AccountId>>,
@@ -761,27 +1198,41 @@ impl<T: Trait> Module<T> {
         }
     }
 
-    // Enumerates approved proposals and checks their grace period expiration
-    fn get_approved_proposal_with_expired_grace_period() -> Vec<ApprovedProposal<T>> {
-        <PendingExecutionProposalIds<T>>::iter()
+    // Enumerates approved proposals and checks their grace period expiration and exact execution
+    // block. Returns proposals ready for the execution ordered by their execution blocks.
+    fn get_approved_proposals_ready_for_execution() -> Vec<ApprovedProposal<T>> {
+        let mut ready_proposals = <PendingExecutionProposalIds<T>>::iter()
             .filter_map(|(proposal_id, _)| {
                 let proposal = Self::proposals(proposal_id);
 
-                if proposal.is_grace_period_expired(Self::current_block()) {
-                    // this should be true, because it was tested inside is_grace_period_expired()
-                    if let ProposalStatus::Finalized(finalisation_data) = proposal.status.clone() {
-                        Some(ApprovedProposalData {
+                let execution_block =
+                    proposal.execution_block(Self::exact_execution_block(proposal_id))?;
+
+                if execution_block > Self::current_block() {
+                    return None;
+                }
+
+                // this should be true, because it was tested inside execution_block()
+                if let ProposalStatus::Finalized(finalisation_data) = proposal.status.clone() {
+                    Some((
+                        execution_block,
+                        ApprovedProposalData {
                             proposal_id,
                             proposal,
                             finalisation_status_data: finalisation_data,
-                        })
-                    } else {
-                        None
-                    }
+                        },
+                    ))
                 } else {
                     None
                 }
             })
+            .collect::<Vec<_>>();
+
+        ready_proposals.sort_by_key(|(execution_block, _)| *execution_block);
+
+        ready_proposals
+            .into_iter()
+            .map(|(_, approved_proposal)| approved_proposal)
             .collect()
     }
 
@@ -836,8 +1287,8 @@ type ApprovedProposal<T> = ApprovedProposalData<
     <T as system::Trait>::AccountId,
 >;
 
-// Simplification of the 'Proposal' type
-type ProposalOf<T> = Proposal<
+/// Simplification of the 'Proposal' type
+pub type ProposalOf<T> = Proposal<
     <T as system::Trait>::BlockNumber,
     MemberId<T>,
     types::BalanceOf<T>,
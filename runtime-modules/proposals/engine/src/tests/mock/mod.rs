@@ -82,6 +82,9 @@ parameter_types! {
     pub const TitleMaxLength: u32 = 100;
     pub const DescriptionMaxLength: u32 = 10000;
     pub const MaxActiveProposalLimit: u32 = 100;
+    pub const MaxProposalOptions: u32 = 5;
+    pub const VoteRationaleMaxLength: u32 = 1000;
+    pub const MaxExactExecutionBlockDelay: u64 = 100;
 }
 
 impl membership::Trait for Test {
@@ -90,6 +93,7 @@ impl membership::Trait for Test {
     type PaidTermId = u64;
     type SubscriptionId = u64;
     type ActorId = u64;
+    type WeightInfo = ();
 }
 
 impl crate::Trait for Test {
@@ -104,7 +108,11 @@ impl crate::Trait for Test {
     type TitleMaxLength = TitleMaxLength;
     type DescriptionMaxLength = DescriptionMaxLength;
     type MaxActiveProposalLimit = MaxActiveProposalLimit;
+    type MaxProposalOptions = MaxProposalOptions;
+    type VoteRationaleMaxLength = VoteRationaleMaxLength;
+    type MaxExactExecutionBlockDelay = MaxExactExecutionBlockDelay;
     type DispatchableCallCode = proposals::Call<Test>;
+    type WeightInfo = ();
 }
 
 impl Default for proposals::Call<Test> {
@@ -119,6 +127,11 @@ impl common::origin::ActorOriginValidator<Origin, u64, u64> for () {
 
         Ok(signed_account_id)
     }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn create_benchmark_actor(account_id: &u64) -> u64 {
+        *account_id
+    }
 }
 
 // If changing count is required, we can upgrade the implementation as shown here:
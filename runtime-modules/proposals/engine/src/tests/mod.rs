@@ -5,7 +5,7 @@ use mock::*;
 
 use codec::Encode;
 use frame_support::dispatch::DispatchResult;
-use frame_support::traits::{Currency, OnFinalize, OnInitialize};
+use frame_support::traits::{Currency, Get, OnFinalize, OnInitialize};
 use frame_support::{StorageDoubleMap, StorageMap, StorageValue};
 use sp_std::rc::Rc;
 use system::RawOrigin;
@@ -71,6 +71,7 @@ struct DummyProposalFixture {
     title: Vec<u8>,
     description: Vec<u8>,
     stake_balance: Option<BalanceOf<Test>>,
+    exact_execution_block: Option<u64>,
 }
 
 impl Default for DummyProposalFixture {
@@ -96,6 +97,7 @@ impl Default for DummyProposalFixture {
             title,
             description,
             stake_balance: None,
+            exact_execution_block: None,
         }
     }
 }
@@ -131,6 +133,13 @@ impl DummyProposalFixture {
         }
     }
 
+    fn with_exact_execution_block(self, exact_execution_block: u64) -> Self {
+        DummyProposalFixture {
+            exact_execution_block: Some(exact_execution_block),
+            ..self
+        }
+    }
+
     fn create_proposal_and_assert(self, result: Result<u32, DispatchError>) -> Option<u32> {
         let proposal_id_result = ProposalsEngine::create_proposal(
             self.account_id,
@@ -139,12 +148,45 @@ impl DummyProposalFixture {
             self.title,
             self.description,
             self.stake_balance,
+            self.exact_execution_block,
             self.proposal_code,
         );
         assert_eq!(proposal_id_result, result);
 
         proposal_id_result.ok()
     }
+
+    fn create_multi_option_proposal_and_assert(
+        self,
+        encoded_options: Vec<Vec<u8>>,
+        result: Result<u32, DispatchError>,
+    ) -> Option<u32> {
+        let proposal_id_result = ProposalsEngine::create_multi_option_proposal(
+            self.account_id,
+            self.proposer_id,
+            self.parameters,
+            self.title,
+            self.description,
+            self.stake_balance,
+            self.exact_execution_block,
+            encoded_options,
+        );
+        assert_eq!(proposal_id_result, result);
+
+        proposal_id_result.ok()
+    }
+}
+
+fn dummy_proposal_options(options_number: usize) -> Vec<Vec<u8>> {
+    (0..options_number)
+        .map(|_| {
+            mock::proposals::Call::<Test>::dummy_proposal(
+                b"title".to_vec(),
+                b"description".to_vec(),
+            )
+            .encode()
+        })
+        .collect()
 }
 
 struct CancelProposalFixture {
@@ -214,6 +256,7 @@ struct VoteGenerator {
     current_account_id: u64,
     current_voter_id: u64,
     pub auto_increment_voter_id: bool,
+    pub rationale: Vec<u8>,
 }
 
 impl VoteGenerator {
@@ -223,6 +266,7 @@ impl VoteGenerator {
             current_voter_id: 0,
             current_account_id: 0,
             auto_increment_voter_id: true,
+            rationale: Vec::new(),
         }
     }
     fn vote_and_assert_ok(&mut self, vote_kind: VoteKind) {
@@ -233,6 +277,24 @@ impl VoteGenerator {
         assert_eq!(self.vote(vote_kind.clone()), expected_result);
     }
 
+    fn vote_ranked_and_assert(&mut self, ballot: RankedBallot, expected_result: DispatchResult) {
+        if self.auto_increment_voter_id {
+            self.current_account_id += 1;
+            self.current_voter_id += 1;
+        }
+
+        assert_eq!(
+            ProposalsEngine::vote_ranked(
+                system::RawOrigin::Signed(self.current_account_id).into(),
+                self.current_voter_id,
+                self.proposal_id,
+                ballot,
+                self.rationale.clone(),
+            ),
+            expected_result
+        );
+    }
+
     fn vote(&mut self, vote_kind: VoteKind) -> DispatchResult {
         if self.auto_increment_voter_id {
             self.current_account_id += 1;
@@ -244,6 +306,7 @@ impl VoteGenerator {
             self.current_voter_id,
             self.proposal_id,
             vote_kind,
+            self.rationale.clone(),
         )
     }
 }
@@ -305,7 +368,13 @@ fn vote_succeeds() {
 fn vote_fails_with_insufficient_rights() {
     initial_test_ext().execute_with(|| {
         assert_eq!(
-            ProposalsEngine::vote(system::RawOrigin::None.into(), 1, 1, VoteKind::Approve),
+            ProposalsEngine::vote(
+                system::RawOrigin::None.into(),
+                1,
+                1,
+                VoteKind::Approve,
+                Vec::new()
+            ),
             Err(DispatchError::Other("Bad origin"))
         );
     });
@@ -560,16 +629,115 @@ fn vote_fails_with_absent_proposal() {
 }
 
 #[test]
-fn vote_fails_on_double_voting() {
+fn vote_change_succeeds() {
     initial_test_ext().execute_with(|| {
         let dummy_proposal = DummyProposalFixture::default();
         let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
 
         let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
         vote_generator.auto_increment_voter_id = false;
 
         vote_generator.vote_and_assert_ok(VoteKind::Approve);
-        vote_generator.vote_and_assert(VoteKind::Approve, Err(Error::<Test>::AlreadyVoted.into()));
+        vote_generator.vote_and_assert_ok(VoteKind::Slash);
+
+        assert_eq!(
+            <Proposals<Test>>::get(proposal_id).voting_results,
+            VotingResults {
+                abstentions: 0,
+                approvals: 0,
+                rejections: 0,
+                slashes: 1,
+            }
+        );
+        assert_eq!(
+            <VoteExistsByProposalByVoter<Test>>::get(&proposal_id, &1),
+            VoteKind::Slash
+        );
+
+        // the same vote can be repeated
+        vote_generator.vote_and_assert_ok(VoteKind::Slash);
+
+        let voting_results = <Proposals<Test>>::get(proposal_id).voting_results;
+        assert_eq!(voting_results.votes_number(), 1);
+    });
+}
+
+#[test]
+fn vote_change_fails_for_finalized_proposal() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+
+        run_to_block_and_finalize(2);
+
+        vote_generator.auto_increment_voter_id = false;
+        vote_generator.vote_and_assert(
+            VoteKind::Approve,
+            Err(Error::<Test>::ProposalFinalized.into()),
+        );
+    });
+}
+
+#[test]
+fn vote_rationale_is_saved_and_emitted() {
+    initial_test_ext().execute_with(|| {
+        // Events start only from 1 first block. No events on block zero.
+        run_to_block_and_finalize(1);
+
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.rationale = b"rationale".to_vec();
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+
+        assert_eq!(
+            <VoteRationaleByProposalByVoter<Test>>::get(&proposal_id, &1),
+            b"rationale".to_vec()
+        );
+
+        // vote change without rationale clears the previous rationale
+        vote_generator.auto_increment_voter_id = false;
+        vote_generator.rationale = Vec::new();
+        vote_generator.vote_and_assert_ok(VoteKind::Abstain);
+
+        assert!(!<VoteRationaleByProposalByVoter<Test>>::contains_key(
+            &proposal_id,
+            &1
+        ));
+
+        EventFixture::assert_events(vec![
+            RawEvent::ProposalCreated(1, 1),
+            RawEvent::Voted(1, 1, VoteKind::Reject, b"rationale".to_vec()),
+            RawEvent::Voted(1, 1, VoteKind::Abstain, Vec::new()),
+        ]);
+    });
+}
+
+#[test]
+fn vote_fails_with_too_long_rationale() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let max_length = <Test as crate::Trait>::VoteRationaleMaxLength::get() as usize;
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.rationale = vec![0u8; max_length + 1];
+        vote_generator.vote_and_assert(
+            VoteKind::Reject,
+            Err(Error::<Test>::VoteRationaleIsTooLong.into()),
+        );
+
+        vote_generator.rationale = vec![0u8; max_length];
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
     });
 }
 
@@ -786,7 +954,7 @@ fn vote_proposal_event_emitted() {
 
         EventFixture::assert_events(vec![
             RawEvent::ProposalCreated(1, 1),
-            RawEvent::Voted(1, 1, VoteKind::Approve),
+            RawEvent::Voted(1, 1, VoteKind::Approve, Vec::new()),
         ]);
     });
 }
@@ -991,6 +1159,142 @@ fn proposal_execution_succeeds_after_the_grace_period() {
     });
 }
 
+#[test]
+fn create_proposal_fails_with_invalid_exact_execution_block() {
+    initial_test_ext().execute_with(|| {
+        // voting period = 3, grace period = 0
+        DummyProposalFixture::default()
+            .with_exact_execution_block(2)
+            .create_proposal_and_assert(Err(
+                Error::<Test>::InvalidExactExecutionBlockMinimum.into()
+            ));
+
+        let max_execution_block = <Test as crate::Trait>::MaxExactExecutionBlockDelay::get();
+        DummyProposalFixture::default()
+            .with_exact_execution_block(max_execution_block + 1)
+            .create_proposal_and_assert(Err(
+                Error::<Test>::InvalidExactExecutionBlockMaximum.into()
+            ));
+
+        let proposal_id = DummyProposalFixture::default()
+            .with_exact_execution_block(max_execution_block)
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
+
+        assert_eq!(
+            <ExactExecutionBlockByProposal<Test>>::get(proposal_id),
+            Some(max_execution_block)
+        );
+    });
+}
+
+#[test]
+fn proposal_execution_deferred_until_exact_execution_block() {
+    initial_test_ext().execute_with(|| {
+        // Events start only from 1 first block. No events on block zero.
+        run_to_block_and_finalize(1);
+
+        let parameters_fixture = ProposalParametersFixture::default().with_grace_period(2);
+        let dummy_proposal = DummyProposalFixture::default()
+            .with_parameters(parameters_fixture.params())
+            .with_exact_execution_block(10);
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block_and_finalize(2);
+
+        assert!(System::events().iter().any(|record| record.event
+            == TestEvent::engine(RawEvent::ProposalExecutionDeferred(proposal_id, 10))));
+
+        // grace period expired, exact execution block is not reached
+        run_to_block_and_finalize(9);
+
+        assert!(<PendingExecutionProposalIds<Test>>::contains_key(
+            proposal_id
+        ));
+        assert_eq!(
+            <Proposals<Test>>::get(proposal_id).status,
+            ProposalStatus::approved(ApprovedProposalStatus::PendingExecution, 1)
+        );
+
+        run_to_block_and_finalize(10);
+
+        assert!(!<PendingExecutionProposalIds<Test>>::contains_key(
+            proposal_id
+        ));
+        assert_eq!(
+            <Proposals<Test>>::get(proposal_id).status,
+            ProposalStatus::approved(ApprovedProposalStatus::Executed, 1)
+        );
+        assert_eq!(ProposalsEngine::exact_execution_block(proposal_id), None);
+    });
+}
+
+#[test]
+fn exact_execution_block_is_removed_for_rejected_proposal() {
+    initial_test_ext().execute_with(|| {
+        let proposal_id = DummyProposalFixture::default()
+            .with_exact_execution_block(10)
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+
+        run_to_block_and_finalize(2);
+
+        assert_eq!(ProposalsEngine::exact_execution_block(proposal_id), None);
+    });
+}
+
+#[test]
+fn approved_proposals_are_executed_in_the_order_of_execution_blocks() {
+    initial_test_ext().execute_with(|| {
+        let later_proposal_id = DummyProposalFixture::default()
+            .with_exact_execution_block(6)
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
+        let earlier_proposal_id = DummyProposalFixture::default()
+            .with_exact_execution_block(5)
+            .create_proposal_and_assert(Ok(2))
+            .unwrap();
+
+        for &proposal_id in &[later_proposal_id, earlier_proposal_id] {
+            let mut vote_generator = VoteGenerator::new(proposal_id);
+            vote_generator.vote_and_assert_ok(VoteKind::Approve);
+            vote_generator.vote_and_assert_ok(VoteKind::Approve);
+            vote_generator.vote_and_assert_ok(VoteKind::Approve);
+            vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        }
+
+        run_to_block_and_finalize(5);
+
+        assert_eq!(
+            <Proposals<Test>>::get(earlier_proposal_id).status,
+            ProposalStatus::approved(ApprovedProposalStatus::Executed, 0)
+        );
+        assert_eq!(
+            <Proposals<Test>>::get(later_proposal_id).status,
+            ProposalStatus::approved(ApprovedProposalStatus::PendingExecution, 0)
+        );
+
+        run_to_block_and_finalize(6);
+
+        assert_eq!(
+            <Proposals<Test>>::get(later_proposal_id).status,
+            ProposalStatus::approved(ApprovedProposalStatus::Executed, 0)
+        );
+    });
+}
+
 #[test]
 fn create_proposal_fails_on_exceeding_max_active_proposals_count() {
     initial_test_ext().execute_with(|| {
@@ -1602,3 +1906,277 @@ fn slash_balance_is_calculated_correctly() {
         assert_eq!(slash_balance_with_stake, stake);
     });
 }
+
+#[test]
+fn create_multi_option_proposal_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let options = dummy_proposal_options(3);
+
+        let proposal_id = DummyProposalFixture::default()
+            .create_multi_option_proposal_and_assert(options.clone(), Ok(1))
+            .unwrap();
+
+        assert_eq!(<ProposalOptionsCode<Test>>::get(proposal_id), options);
+        assert_eq!(
+            <RankedChoiceResultsByProposal<Test>>::get(proposal_id),
+            RankedChoiceVotingResults::new(3)
+        );
+        assert_eq!(<ActiveProposalCount>::get(), 1);
+    });
+}
+
+#[test]
+fn create_multi_option_proposal_fails_with_invalid_options_number() {
+    initial_test_ext().execute_with(|| {
+        DummyProposalFixture::default().create_multi_option_proposal_and_assert(
+            dummy_proposal_options(1),
+            Err(Error::<Test>::InvalidProposalOptionsNumber.into()),
+        );
+
+        let too_many_options = <Test as crate::Trait>::MaxProposalOptions::get() as usize + 1;
+        DummyProposalFixture::default().create_multi_option_proposal_and_assert(
+            dummy_proposal_options(too_many_options),
+            Err(Error::<Test>::InvalidProposalOptionsNumber.into()),
+        );
+    });
+}
+
+#[test]
+fn vote_ranked_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let proposal_id = DummyProposalFixture::default()
+            .create_multi_option_proposal_and_assert(dummy_proposal_options(3), Ok(1))
+            .unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_ranked_and_assert(vec![2, 0], Ok(()));
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+
+        assert_eq!(
+            <RankedBallotByProposalByVoter<Test>>::get(&proposal_id, &1),
+            vec![2, 0]
+        );
+        assert_eq!(
+            <VoteExistsByProposalByVoter<Test>>::get(&proposal_id, &1),
+            VoteKind::Approve
+        );
+        assert_eq!(
+            <RankedChoiceResultsByProposal<Test>>::get(proposal_id),
+            RankedChoiceVotingResults {
+                option_scores: vec![1, 0, 2],
+                ballots: 1,
+            }
+        );
+        assert_eq!(
+            <Proposals<Test>>::get(proposal_id).voting_results,
+            VotingResults {
+                abstentions: 0,
+                approvals: 1,
+                rejections: 1,
+                slashes: 0,
+            }
+        );
+    });
+}
+
+#[test]
+fn vote_ranked_fails_with_invalid_ballot() {
+    initial_test_ext().execute_with(|| {
+        let proposal_id = DummyProposalFixture::default()
+            .create_multi_option_proposal_and_assert(dummy_proposal_options(3), Ok(1))
+            .unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.auto_increment_voter_id = false;
+        vote_generator.current_account_id = 1;
+        vote_generator.current_voter_id = 1;
+
+        let invalid_ballot_error = Err(Error::<Test>::InvalidRankedBallot.into());
+        vote_generator.vote_ranked_and_assert(Vec::new(), invalid_ballot_error);
+        vote_generator.vote_ranked_and_assert(vec![0, 3], invalid_ballot_error);
+        vote_generator.vote_ranked_and_assert(vec![1, 1], invalid_ballot_error);
+
+        vote_generator.vote_ranked_and_assert(vec![1], Ok(()));
+    });
+}
+
+#[test]
+fn ranked_vote_change_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let proposal_id = DummyProposalFixture::default()
+            .create_multi_option_proposal_and_assert(dummy_proposal_options(3), Ok(1))
+            .unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_ranked_and_assert(vec![0, 1, 2], Ok(()));
+        vote_generator.auto_increment_voter_id = false;
+
+        // ranked ballot replaces the previous ballot
+        vote_generator.vote_ranked_and_assert(vec![2, 1], Ok(()));
+
+        assert_eq!(
+            <RankedChoiceResultsByProposal<Test>>::get(proposal_id),
+            RankedChoiceVotingResults {
+                option_scores: vec![0, 1, 2],
+                ballots: 1,
+            }
+        );
+        assert_eq!(
+            <RankedBallotByProposalByVoter<Test>>::get(&proposal_id, &1),
+            vec![2, 1]
+        );
+
+        // rejection revokes the ranked ballot
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+
+        assert_eq!(
+            <RankedChoiceResultsByProposal<Test>>::get(proposal_id),
+            RankedChoiceVotingResults {
+                option_scores: vec![0, 0, 0],
+                ballots: 0,
+            }
+        );
+        assert!(!<RankedBallotByProposalByVoter<Test>>::contains_key(
+            &proposal_id,
+            &1
+        ));
+        assert_eq!(
+            <Proposals<Test>>::get(proposal_id).voting_results,
+            VotingResults {
+                abstentions: 0,
+                approvals: 0,
+                rejections: 1,
+                slashes: 0,
+            }
+        );
+    });
+}
+
+#[test]
+fn approve_vote_fails_for_multi_option_proposal() {
+    initial_test_ext().execute_with(|| {
+        let proposal_id = DummyProposalFixture::default()
+            .create_multi_option_proposal_and_assert(dummy_proposal_options(2), Ok(1))
+            .unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert(
+            VoteKind::Approve,
+            Err(Error::<Test>::RankedBallotRequired.into()),
+        );
+    });
+}
+
+#[test]
+fn vote_ranked_fails_for_binary_proposal() {
+    initial_test_ext().execute_with(|| {
+        let proposal_id = DummyProposalFixture::default()
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator
+            .vote_ranked_and_assert(vec![0], Err(Error::<Test>::NotMultiOptionProposal.into()));
+    });
+}
+
+#[test]
+fn multi_option_proposal_executes_winner_option() {
+    initial_test_ext().execute_with(|| {
+        let faulty_proposal = mock::proposals::Call::<Test>::faulty_proposal(
+            b"title".to_vec(),
+            b"description".to_vec(),
+        );
+        let dummy_proposal = mock::proposals::Call::<Test>::dummy_proposal(
+            b"title".to_vec(),
+            b"description".to_vec(),
+        );
+
+        let options = vec![faulty_proposal.encode(), dummy_proposal.encode()];
+        let proposal_id = DummyProposalFixture::default()
+            .create_multi_option_proposal_and_assert(options, Ok(1))
+            .unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_ranked_and_assert(vec![0, 1], Ok(()));
+        vote_generator.vote_ranked_and_assert(vec![1, 0], Ok(()));
+        vote_generator.vote_ranked_and_assert(vec![1], Ok(()));
+        vote_generator.vote_ranked_and_assert(vec![1, 0], Ok(()));
+
+        run_to_block_and_finalize(2);
+
+        assert_eq!(
+            <Proposals<Test>>::get(proposal_id).status,
+            ProposalStatus::approved(ApprovedProposalStatus::Executed, 0)
+        );
+        assert!(System::events().iter().any(|record| record.event
+            == TestEvent::engine(RawEvent::ProposalOptionSelected(proposal_id, 1))));
+
+        assert!(!<ProposalOptionsCode<Test>>::contains_key(proposal_id));
+        assert!(!<RankedChoiceResultsByProposal<Test>>::contains_key(
+            proposal_id
+        ));
+        assert!(!<RankedBallotByProposalByVoter<Test>>::contains_key(
+            &proposal_id,
+            &1
+        ));
+    });
+}
+
+#[test]
+fn rejected_multi_option_proposal_voting_data_is_removed() {
+    initial_test_ext().execute_with(|| {
+        let proposal_id = DummyProposalFixture::default()
+            .create_multi_option_proposal_and_assert(dummy_proposal_options(2), Ok(1))
+            .unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.rationale = b"rationale".to_vec();
+        vote_generator.vote_ranked_and_assert(vec![1, 0], Ok(()));
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+
+        run_to_block_and_finalize(2);
+
+        assert!(!<ProposalOptionsCode<Test>>::contains_key(proposal_id));
+        assert!(!<RankedChoiceResultsByProposal<Test>>::contains_key(
+            proposal_id
+        ));
+        assert!(!<RankedBallotByProposalByVoter<Test>>::contains_key(
+            &proposal_id,
+            &1
+        ));
+        assert!(!<VoteRationaleByProposalByVoter<Test>>::contains_key(
+            &proposal_id,
+            &1
+        ));
+    });
+}
+
+#[test]
+fn multi_option_proposal_reset_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let proposal_id = DummyProposalFixture::default()
+            .create_multi_option_proposal_and_assert(dummy_proposal_options(2), Ok(1))
+            .unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_ranked_and_assert(vec![1, 0], Ok(()));
+
+        ProposalsEngine::reset_active_proposals();
+
+        assert_eq!(
+            <RankedChoiceResultsByProposal<Test>>::get(proposal_id),
+            RankedChoiceVotingResults::new(2)
+        );
+        assert!(!<RankedBallotByProposalByVoter<Test>>::contains_key(
+            &proposal_id,
+            &1
+        ));
+        assert_eq!(
+            <Proposals<Test>>::get(proposal_id).voting_results,
+            VotingResults::default()
+        );
+    });
+}
@@ -8,9 +8,11 @@ use frame_support::dispatch::DispatchResult;
 use frame_support::traits::Currency;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
+use sp_runtime::traits::Saturating;
 use sp_runtime::Perbill;
 use sp_std::boxed::Box;
 use sp_std::cmp::PartialOrd;
+use sp_std::collections::btree_set::BTreeSet;
 use sp_std::ops::Add;
 use sp_std::vec::Vec;
 
@@ -108,12 +110,118 @@ impl VotingResults {
         }
     }
 
+    /// Remove previously added vote from the related counter. Used on the vote change.
+    pub fn remove_vote(&mut self, vote: VoteKind) {
+        match vote {
+            VoteKind::Abstain => self.abstentions = self.abstentions.saturating_sub(1),
+            VoteKind::Approve => self.approvals = self.approvals.saturating_sub(1),
+            VoteKind::Reject => self.rejections = self.rejections.saturating_sub(1),
+            VoteKind::Slash => self.slashes = self.slashes.saturating_sub(1),
+        }
+    }
+
     /// Calculates number of votes so far
     pub fn votes_number(&self) -> u32 {
         self.abstentions + self.approvals + self.rejections + self.slashes
     }
 }
 
+/// Ranked ballot for the multi-option proposal: option indices ordered by the voter preference,
+/// the most preferred option goes first.
+pub type RankedBallot = Vec<u32>;
+
+/// Contains current ranked choice voting results (Borda count) for the multi-option proposal
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct RankedChoiceVotingResults {
+    /// Borda scores of the proposal options, indexed by the option index
+    pub option_scores: Vec<u32>,
+
+    /// Ranked ballots counter
+    pub ballots: u32,
+}
+
+impl RankedChoiceVotingResults {
+    /// Creates empty voting results for the provided options number
+    pub fn new(options_number: u32) -> Self {
+        let mut option_scores = Vec::new();
+        option_scores.resize(options_number as usize, 0);
+
+        RankedChoiceVotingResults {
+            option_scores,
+            ballots: 0,
+        }
+    }
+
+    /// Checks the ballot: it must be non-empty and contain only existing options without
+    /// duplicates. Not all options must be ranked.
+    pub fn is_valid_ballot(&self, ballot: &[u32]) -> bool {
+        let options_number = self.option_scores.len();
+
+        let unique_options = ballot.iter().collect::<BTreeSet<_>>();
+
+        !ballot.is_empty()
+            && unique_options.len() == ballot.len()
+            && ballot
+                .iter()
+                .all(|option| (*option as usize) < options_number)
+    }
+
+    /// Adds the ballot to the Borda count. With N options, the option ranked at the position
+    /// `i` (starting from zero) receives `N - 1 - i` points. Unranked options receive no points.
+    pub fn add_ballot(&mut self, ballot: &[u32]) {
+        for (option, points) in Self::ballot_points(self.option_scores.len(), ballot) {
+            if let Some(score) = self.option_scores.get_mut(option) {
+                *score = score.saturating_add(points);
+            }
+        }
+
+        self.ballots += 1;
+    }
+
+    /// Removes previously added ballot from the Borda count. Used on the vote change.
+    pub fn remove_ballot(&mut self, ballot: &[u32]) {
+        for (option, points) in Self::ballot_points(self.option_scores.len(), ballot) {
+            if let Some(score) = self.option_scores.get_mut(option) {
+                *score = score.saturating_sub(points);
+            }
+        }
+
+        self.ballots = self.ballots.saturating_sub(1);
+    }
+
+    // Returns Borda points of the ballot options: (option index, points) pairs.
+    fn ballot_points(
+        options_number: usize,
+        ballot: &[u32],
+    ) -> impl Iterator<Item = (usize, u32)> + '_ {
+        ballot.iter().enumerate().map(move |(position, option)| {
+            let points = (options_number as u32).saturating_sub(position as u32 + 1);
+
+            (*option as usize, points)
+        })
+    }
+
+    /// Returns the option with the highest score. A tie is resolved in favor of the option
+    /// with the lowest index. Returns None if no ballots were cast.
+    pub fn winner(&self) -> Option<u32> {
+        if self.ballots == 0 {
+            return None;
+        }
+
+        let mut winner = None;
+        let mut max_score = 0;
+        for (option, score) in self.option_scores.iter().enumerate() {
+            if winner.is_none() || *score > max_score {
+                winner = Some(option as u32);
+                max_score = *score;
+            }
+        }
+
+        winner
+    }
+}
+
 /// Contains created stake id and source account for the stake balance
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, Copy, PartialEq, Eq, Debug)]
@@ -176,6 +284,69 @@ where
         false
     }
 
+    /// Returns the execution block of the approved proposal: the exact execution block if provided,
+    /// or the grace period end otherwise.
+    /// Returns None if the proposal is not finalized with Approved status.
+    pub fn execution_block(
+        &self,
+        exact_execution_block: Option<BlockNumber>,
+    ) -> Option<BlockNumber> {
+        if let ProposalStatus::Finalized(finalized_status) = self.status.clone() {
+            if let ProposalDecisionStatus::Approved(_) = finalized_status.proposal_status {
+                return Some(exact_execution_block.unwrap_or_else(|| {
+                    finalized_status.finalized_at + self.parameters.grace_period
+                }));
+            }
+        }
+
+        None
+    }
+
+    /// Returns the number of blocks left until the voting period expiration.
+    /// Returns None if the proposal is not active.
+    pub fn remaining_voting_period(&self, now: BlockNumber) -> Option<BlockNumber>
+    where
+        BlockNumber: Saturating,
+    {
+        if let ProposalStatus::Active(_) = self.status {
+            let voting_period_end = self
+                .created_at
+                .saturating_add(self.parameters.voting_period);
+
+            return Some(voting_period_end.saturating_sub(now));
+        }
+
+        None
+    }
+
+    /// Returns the number of blocks left until the grace period expiration or until the exact
+    /// execution block if provided.
+    /// Returns None if the proposal is not approved and pending execution.
+    pub fn remaining_grace_period(
+        &self,
+        now: BlockNumber,
+        exact_execution_block: Option<BlockNumber>,
+    ) -> Option<BlockNumber>
+    where
+        BlockNumber: Saturating,
+    {
+        if let ProposalStatus::Finalized(finalized_status) = self.status.clone() {
+            if let ProposalDecisionStatus::Approved(ApprovedProposalStatus::PendingExecution) =
+                finalized_status.proposal_status
+            {
+                let grace_period_end = exact_execution_block.unwrap_or_else(|| {
+                    finalized_status
+                        .finalized_at
+                        .saturating_add(self.parameters.grace_period)
+                });
+
+                return Some(grace_period_end.saturating_sub(now));
+            }
+        }
+
+        None
+    }
+
     /// Determines the finalized proposal status using voting results tally for current proposal.
     /// Calculates votes, takes in account voting period expiration.
     /// If voting process is in progress, then decision status is None.
@@ -440,6 +611,154 @@ mod tests {
         assert!(!proposal.is_grace_period_expired(3));
     }
 
+    #[test]
+    fn proposal_execution_block_calculated() {
+        let mut proposal = ProposalObject::default();
+
+        proposal.parameters.grace_period = 3;
+
+        assert_eq!(proposal.execution_block(None), None);
+        assert_eq!(proposal.execution_block(Some(10)), None);
+
+        proposal.status = ProposalStatus::finalized_successfully(
+            ProposalDecisionStatus::Approved(ApprovedProposalStatus::PendingExecution),
+            2,
+        );
+
+        assert_eq!(proposal.execution_block(None), Some(5));
+        assert_eq!(proposal.execution_block(Some(10)), Some(10));
+    }
+
+    #[test]
+    fn proposal_remaining_voting_period_calculated() {
+        let mut proposal = ProposalObject::default();
+
+        proposal.created_at = 1;
+        proposal.parameters.voting_period = 3;
+
+        assert_eq!(proposal.remaining_voting_period(2), Some(2));
+        assert_eq!(proposal.remaining_voting_period(4), Some(0));
+        assert_eq!(proposal.remaining_voting_period(10), Some(0));
+    }
+
+    #[test]
+    fn proposal_remaining_voting_period_is_none_for_finalized_proposal() {
+        let mut proposal = ProposalObject::default();
+
+        proposal.parameters.voting_period = 3;
+        proposal.status =
+            ProposalStatus::finalized_successfully(ProposalDecisionStatus::Rejected, 1);
+
+        assert_eq!(proposal.remaining_voting_period(2), None);
+    }
+
+    #[test]
+    fn proposal_remaining_grace_period_calculated() {
+        let mut proposal = ProposalObject::default();
+
+        proposal.parameters.grace_period = 3;
+        proposal.status = ProposalStatus::finalized_successfully(
+            ProposalDecisionStatus::Approved(ApprovedProposalStatus::PendingExecution),
+            2,
+        );
+
+        assert_eq!(proposal.remaining_grace_period(3, None), Some(2));
+        assert_eq!(proposal.remaining_grace_period(7, None), Some(0));
+        assert_eq!(proposal.remaining_grace_period(3, Some(10)), Some(7));
+    }
+
+    #[test]
+    fn proposal_remaining_grace_period_is_none_for_not_pending_proposal() {
+        let mut proposal = ProposalObject::default();
+
+        proposal.parameters.grace_period = 3;
+
+        assert_eq!(proposal.remaining_grace_period(1, None), None);
+
+        proposal.status = ProposalStatus::approved(ApprovedProposalStatus::Executed, 1);
+
+        assert_eq!(proposal.remaining_grace_period(1, None), None);
+    }
+
+    #[test]
+    fn ranked_choice_ballot_validation_succeeds() {
+        let results = RankedChoiceVotingResults::new(3);
+
+        assert!(results.is_valid_ballot(&[2, 0, 1]));
+        assert!(results.is_valid_ballot(&[1]));
+        assert!(!results.is_valid_ballot(&[]));
+        assert!(!results.is_valid_ballot(&[0, 3]));
+        assert!(!results.is_valid_ballot(&[1, 1]));
+    }
+
+    #[test]
+    fn ranked_choice_borda_count_succeeds() {
+        let mut results = RankedChoiceVotingResults::new(3);
+
+        assert_eq!(results.winner(), None);
+
+        results.add_ballot(&[0, 1, 2]);
+        results.add_ballot(&[1, 2, 0]);
+        results.add_ballot(&[1, 0]);
+        results.add_ballot(&[2]);
+
+        assert_eq!(
+            results,
+            RankedChoiceVotingResults {
+                option_scores: vec![3, 5, 3],
+                ballots: 4,
+            }
+        );
+        assert_eq!(results.winner(), Some(1));
+    }
+
+    #[test]
+    fn ranked_choice_ballot_removal_succeeds() {
+        let mut results = RankedChoiceVotingResults::new(3);
+
+        results.add_ballot(&[0, 1, 2]);
+        results.add_ballot(&[2, 1]);
+        results.remove_ballot(&[0, 1, 2]);
+
+        assert_eq!(
+            results,
+            RankedChoiceVotingResults {
+                option_scores: vec![0, 1, 2],
+                ballots: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn voting_results_vote_removal_succeeds() {
+        let mut voting_results = VotingResults::default();
+
+        voting_results.add_vote(VoteKind::Approve);
+        voting_results.add_vote(VoteKind::Reject);
+        voting_results.remove_vote(VoteKind::Approve);
+        voting_results.add_vote(VoteKind::Slash);
+
+        assert_eq!(
+            voting_results,
+            VotingResults {
+                abstentions: 0,
+                approvals: 0,
+                rejections: 1,
+                slashes: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn ranked_choice_tie_resolved_with_lowest_option_index() {
+        let mut results = RankedChoiceVotingResults::new(3);
+
+        results.add_ballot(&[2, 1]);
+        results.add_ballot(&[1, 2]);
+
+        assert_eq!(results.winner(), Some(1));
+    }
+
     #[test]
     fn define_proposal_decision_status_returns_expired() {
         let mut proposal = ProposalObject::default();
@@ -9,9 +9,11 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::{Codec, Decode, Encode};
-use frame_support::storage::IterableStorageMap;
-use frame_support::{decl_module, decl_storage, ensure, Parameter};
-use sp_arithmetic::traits::{BaseArithmetic, One, Zero};
+use frame_support::storage::{IterableStorageDoubleMap, IterableStorageMap};
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+use frame_support::{decl_event, decl_module, decl_storage, ensure, Parameter};
+use sp_arithmetic::traits::{BaseArithmetic, One, Saturating, Zero};
 use sp_runtime::traits::{MaybeSerialize, Member};
 
 use minting::BalanceOf;
@@ -19,7 +21,13 @@ use minting::BalanceOf;
 mod mock;
 mod tests;
 
+// Storage version with the payouts of the active reward relationships scheduled.
+const STORAGE_VERSION: u32 = 1;
+
 pub trait Trait: system::Trait + minting::Trait {
+    /// Recurring reward event type.
+    type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
+
     type PayoutStatusHandler: PayoutStatusHandler<Self>;
 
     /// Type of identifier for recipients.
@@ -41,6 +49,10 @@ pub trait Trait: system::Trait + minting::Trait {
         + Copy
         + MaybeSerialize
         + PartialEq;
+
+    /// Maximum number of scheduled payouts processed per block. Due payouts exceeding the limit
+    /// are left in place and processed on the next blocks.
+    type MaxPayoutsPerBlock: Get<u32>;
 }
 
 /// Handler for aftermath of a payout attempt
@@ -86,6 +98,26 @@ pub struct Recipient<Balance> {
     total_reward_missed: Balance,
 }
 
+/// Policy applied to the missed payouts of a reward relationship
+#[derive(Encode, Decode, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MissedPayoutPolicy {
+    /// Missed payouts are only tracked in the reward stats.
+    Discard,
+
+    /// Missed payouts are accrued as debt, which is settled with the next payouts
+    /// when the mint regains capacity. A relationship without upcoming payouts (deactivated
+    /// or after its one-off payout) keeps the debt until it is activated again. The debt is
+    /// written off on switching to the `Discard` policy. On removing the relationship the debt
+    /// is settled as far as the mint capacity allows and the rest is written off.
+    AccrueDebt,
+}
+
+impl Default for MissedPayoutPolicy {
+    fn default() -> Self {
+        MissedPayoutPolicy::Discard
+    }
+}
+
 #[derive(Encode, Decode, Copy, Clone, Debug, Default)]
 pub struct RewardRelationship<AccountId, Balance, BlockNumber, MintId, RecipientId> {
     /// Identifier for receiver
@@ -124,6 +156,11 @@ impl<AccountId: Clone, Balance: Clone, BlockNumber: Clone, MintId: Clone, Recipi
         self.next_payment_at_block.is_some()
     }
 
+    /// Total payout received in this relationship
+    pub fn total_reward_received(&self) -> Balance {
+        self.total_reward_received.clone()
+    }
+
     /// Make clone which is activated.
     pub fn clone_activated(&self, start_at: &BlockNumber) -> Self {
         Self {
@@ -152,11 +189,62 @@ decl_storage! {
             T::RewardRelationshipId => RewardRelationship<T::AccountId, BalanceOf<T>, T::BlockNumber, T::MintId, T::RecipientId>;
 
         RewardRelationshipsCreated get(fn reward_relationships_created): T::RewardRelationshipId;
+
+        /// Reward relationships indexed by the block their payout is due at. The value is the
+        /// block the payout was due at. Entries not matching the relationship next payment block
+        /// are stale and skipped on processing.
+        pub ScheduledPayouts get(fn scheduled_payouts): double_map
+            hasher(twox_64_concat) T::BlockNumber, hasher(blake2_128_concat) T::RewardRelationshipId
+            => T::BlockNumber;
+
+        /// The oldest block with the scheduled payouts not processed yet.
+        pub NextPayoutsBlock get(fn next_payouts_block): Option<T::BlockNumber>;
+
+        /// Missed payout policy of the reward relationships. Missed payouts are discarded by default.
+        pub MissedPayoutPolicies get(fn missed_payout_policy): map hasher(blake2_128_concat)
+            T::RewardRelationshipId => MissedPayoutPolicy;
+
+        /// Missed payouts debt of the reward relationships accruing debt.
+        pub PayoutDebts get(fn payout_debt): map hasher(blake2_128_concat)
+            T::RewardRelationshipId => BalanceOf<T>;
+
+        /// Version of the module storage. The older versions are updated by
+        /// `schedule_active_reward_relationships`.
+        pub StorageVersion get(fn storage_version): u32;
     }
 }
 
+decl_event!(
+    /// Recurring reward events
+    pub enum Event<T>
+    where
+        <T as Trait>::RewardRelationshipId,
+        Balance = BalanceOf<T>,
+    {
+        /// Emits on adding a missed payout to the reward relationship debt.
+        /// Params:
+        /// - Reward relationship id
+        /// - Missed payout amount
+        PayoutDebtAccrued(RewardRelationshipId, Balance),
+
+        /// Emits on settling (fully or partially) the reward relationship debt.
+        /// Params:
+        /// - Reward relationship id
+        /// - Settled debt amount
+        PayoutDebtSettled(RewardRelationshipId, Balance),
+
+        /// Emits on writing off the reward relationship debt.
+        /// Params:
+        /// - Reward relationship id
+        /// - Written off debt amount
+        PayoutDebtWrittenOff(RewardRelationshipId, Balance),
+    }
+);
+
 decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+        /// Default deposit_event() handler
+        fn deposit_event() = default;
 
         fn on_finalize(now: T::BlockNumber) {
             Self::do_payouts(now);
@@ -218,14 +306,44 @@ impl<T: Trait> Module<T> {
                 total_reward_missed: Zero::zero(),
             },
         );
+        Self::schedule_payout(relationship_id, next_payment_at_block);
         Ok(relationship_id)
     }
 
-    /// Removes a relationship from RewardRelashionships and its recipient.
+    /// Removes a relationship from RewardRelashionships and its recipient. The accrued debt is
+    /// settled as far as the mint capacity allows, the rest is written off.
     pub fn remove_reward_relationship(id: T::RewardRelationshipId) {
         if <RewardRelationships<T>>::contains_key(&id) {
-            <Recipients<T>>::remove(<RewardRelationships<T>>::take(&id).recipient);
+            let relationship = <RewardRelationships<T>>::take(&id);
+
+            Self::unschedule_payout(id, relationship.next_payment_at_block);
+            Self::settle_payout_debt_on_removal(id, &relationship);
+            <MissedPayoutPolicies<T>>::remove(id);
+            <Recipients<T>>::remove(relationship.recipient);
+        }
+    }
+
+    /// Settles the accrued debt of the deactivated relationship as far as the mint capacity
+    /// allows and writes off the rest: the deactivated relationship has no scheduled payouts to
+    /// settle the debt with.
+    pub fn settle_payout_debt(id: T::RewardRelationshipId) -> Result<(), ()> {
+        let mut relationship = Self::ensure_reward_relationship_exists(&id)?;
+
+        let debt_settled = Self::settle_payout_debt_on_removal(id, &relationship);
+
+        if !debt_settled.is_zero() {
+            // settled debt is not missed anymore
+            relationship.total_reward_missed = relationship
+                .total_reward_missed
+                .saturating_sub(debt_settled);
+            <Recipients<T>>::mutate(relationship.recipient, |recipient| {
+                recipient.total_reward_missed =
+                    recipient.total_reward_missed.saturating_sub(debt_settled);
+            });
+            <RewardRelationships<T>>::insert(id, relationship);
         }
+
+        Ok(())
     }
 
     /// Will attempt to activat a deactivated reward relationship.
@@ -245,6 +363,7 @@ impl<T: Trait> Module<T> {
                 reward_relationship.clone_activated(&next_payment_at_block);
 
             RewardRelationships::<T>::insert(id, activated_relationship);
+            Self::schedule_payout(id, next_payment_at_block);
 
             // We activated
             true
@@ -262,6 +381,7 @@ impl<T: Trait> Module<T> {
             let deactivated_relationship = reward_relationship.clone_deactivated();
 
             RewardRelationships::<T>::insert(id, deactivated_relationship);
+            Self::unschedule_payout(id, reward_relationship.next_payment_at_block);
 
             // Was deactivated
             true
@@ -304,6 +424,12 @@ impl<T: Trait> Module<T> {
                     RewardsError::NextPaymentNotInFuture
                 );
             }
+
+            Self::unschedule_payout(id, relationship.next_payment_at_block);
+            if let Some(blocknumber) = next_payout_at_block {
+                Self::schedule_payout(id, blocknumber);
+            }
+
             relationship.next_payment_at_block = next_payout_at_block;
         }
         if let Some(payout_interval) = new_payout_interval {
@@ -314,78 +440,298 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    /// Sets the policy applied to the missed payouts of the reward relationship.
+    /// Switching to the `Discard` policy writes off the accrued debt.
+    pub fn set_missed_payout_policy(
+        id: T::RewardRelationshipId,
+        policy: MissedPayoutPolicy,
+    ) -> Result<(), RewardsError> {
+        ensure!(
+            <RewardRelationships<T>>::contains_key(&id),
+            RewardsError::RewardRelationshipNotFound
+        );
+
+        match policy {
+            MissedPayoutPolicy::AccrueDebt => <MissedPayoutPolicies<T>>::insert(id, policy),
+            MissedPayoutPolicy::Discard => {
+                <MissedPayoutPolicies<T>>::remove(id);
+                Self::write_off_payout_debt(id, <PayoutDebts<T>>::take(id));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Schedules payouts of all active relationships with the upcoming next payment. Should be
+    /// called on the migration to the runtime version with the scheduled payouts. Runs once: the
+    /// payouts are already scheduled if the storage version is current. Returns the weight of
+    /// the migration.
+    pub fn schedule_active_reward_relationships() -> Weight {
+        if Self::storage_version() >= STORAGE_VERSION {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let now = <system::Module<T>>::block_number();
+        let mut relationships_count: Weight = 0;
+        let mut scheduled_count: Weight = 0;
+
+        for (relationship_id, relationship) in <RewardRelationships<T>>::iter() {
+            relationships_count += 1;
+
+            if let Some(next_payment_at_block) = relationship.next_payment_at_block {
+                if next_payment_at_block >= now {
+                    Self::schedule_payout(relationship_id, next_payment_at_block);
+                    scheduled_count += 1;
+                }
+            }
+        }
+
+        StorageVersion::put(STORAGE_VERSION);
+
+        // Reads: the version, the block number and the relationships. Writes: the version and
+        // the scheduled payouts.
+        T::DbWeight::get().reads_writes(2 + relationships_count, 1 + scheduled_count)
+    }
+
     /*
-    For all relationships where next_payment_at_block is set and matches current block height,
-    a call to pay_reward is made for the suitable amount, recipient and source.
+    For all relationships scheduled up to the current block height, a call to pay_reward is made for
+    the suitable amount, recipient and source. Only T::MaxPayoutsPerBlock scheduled payouts are
+    processed per block, the rest of due payouts is left in place and processed on the next blocks
+    in the order of their due blocks.
     The next_payment_in_block is updated based on payout_interval.
     If the call succeeds, total_reward_received is incremented on both
     recipient and dependency with amount_per_payout, and a call to T::PayoutStatusHandler is made.
     Otherwise, analogous steps for failure.
     */
     fn do_payouts(now: T::BlockNumber) {
-        for (relationship_id, ref mut relationship) in <RewardRelationships<T>>::iter() {
-            assert!(<Recipients<T>>::contains_key(&relationship.recipient));
+        let max_payouts_per_block = T::MaxPayoutsPerBlock::get();
+        let mut processed_count = 0u32;
 
-            let mut recipient = Self::recipients(relationship.recipient);
+        // start with the payouts left from the previous blocks
+        let mut block = Self::next_payouts_block().map_or(now, |block| block.min(now));
 
-            if let Some(next_payment_at_block) = relationship.next_payment_at_block {
-                if next_payment_at_block != now {
+        while block <= now && processed_count < max_payouts_per_block {
+            let limit = (max_payouts_per_block - processed_count) as usize;
+            let scheduled_payouts = <ScheduledPayouts<T>>::iter_prefix(block)
+                .take(limit)
+                .collect::<Vec<_>>();
+            let block_processed = scheduled_payouts.len() < limit;
+
+            for (relationship_id, payment_due_at_block) in scheduled_payouts {
+                <ScheduledPayouts<T>>::remove(block, relationship_id);
+                processed_count += 1;
+
+                if !<RewardRelationships<T>>::contains_key(relationship_id) {
                     continue;
                 }
 
-                // Add the missed payout and try to pay those in addition to scheduled payout?
-                // let payout = relationship.total_reward_missed + relationship.amount_per_payout;
-                let payout = relationship.amount_per_payout;
-
-                // try to make payment
-                if <minting::Module<T>>::transfer_tokens(
-                    relationship.mint_id,
-                    payout,
-                    &relationship.account,
-                )
-                .is_err()
-                {
-                    // add only newly scheduled payout to total missed payout
-                    relationship.total_reward_missed += relationship.amount_per_payout;
-
-                    // update recipient stats
-                    recipient.total_reward_missed += relationship.amount_per_payout;
-
-                    T::PayoutStatusHandler::payout_failed(
-                        relationship_id,
-                        &relationship.account,
-                        payout,
-                    );
-                } else {
-                    // update payout received stats
-                    relationship.total_reward_received += payout;
-                    recipient.total_reward_received += payout;
-
-                    // update missed payout stats
-                    // if relationship.total_reward_missed != Zero::zero() {
-                    //     // update recipient stats
-                    //     recipient.total_reward_missed -= relationship.total_reward_missed;
-
-                    //     // clear missed reward on relationship
-                    //     relationship.total_reward_missed = Zero::zero();
-                    // }
-                    T::PayoutStatusHandler::payout_succeeded(
-                        relationship_id,
-                        &relationship.account,
-                        payout,
-                    );
-                }
+                let relationship = Self::reward_relationships(relationship_id);
 
-                // update next payout blocknumber at interval if set
-                if let Some(payout_interval) = relationship.payout_interval {
-                    relationship.next_payment_at_block = Some(now + payout_interval);
-                } else {
-                    relationship.next_payment_at_block = None;
+                // relationship was deactivated or rescheduled after the payout was scheduled
+                if relationship.next_payment_at_block != Some(payment_due_at_block) {
+                    continue;
                 }
 
-                <Recipients<T>>::insert(relationship.recipient, recipient);
-                <RewardRelationships<T>>::insert(relationship_id, relationship);
+                Self::do_payout(relationship_id, relationship, payment_due_at_block);
             }
+
+            if block_processed {
+                block += One::one();
+            }
+        }
+
+        <NextPayoutsBlock<T>>::put(block);
+    }
+
+    fn do_payout(
+        relationship_id: T::RewardRelationshipId,
+        mut relationship: RewardRelationship<
+            T::AccountId,
+            BalanceOf<T>,
+            T::BlockNumber,
+            T::MintId,
+            T::RecipientId,
+        >,
+        payment_due_at_block: T::BlockNumber,
+    ) {
+        assert!(<Recipients<T>>::contains_key(&relationship.recipient));
+
+        let mut recipient = Self::recipients(relationship.recipient);
+
+        let payout = relationship.amount_per_payout;
+
+        // try to make payment
+        if Self::missed_payout_policy(relationship_id) == MissedPayoutPolicy::AccrueDebt {
+            Self::make_payout_accruing_debt(relationship_id, &mut relationship, &mut recipient);
+        } else if <minting::Module<T>>::transfer_tokens(
+            relationship.mint_id,
+            payout,
+            &relationship.account,
+        )
+        .is_err()
+        {
+            // add only newly scheduled payout to total missed payout
+            relationship.total_reward_missed += relationship.amount_per_payout;
+
+            // update recipient stats
+            recipient.total_reward_missed += relationship.amount_per_payout;
+
+            T::PayoutStatusHandler::payout_failed(relationship_id, &relationship.account, payout);
+        } else {
+            // update payout received stats
+            relationship.total_reward_received += payout;
+            recipient.total_reward_received += payout;
+
+            T::PayoutStatusHandler::payout_succeeded(
+                relationship_id,
+                &relationship.account,
+                payout,
+            );
+        }
+
+        // update next payout blocknumber at interval if set
+        if let Some(payout_interval) = relationship.payout_interval {
+            let next_payment_at_block = payment_due_at_block + payout_interval;
+            relationship.next_payment_at_block = Some(next_payment_at_block);
+            Self::schedule_payout(relationship_id, next_payment_at_block);
+        } else {
+            relationship.next_payment_at_block = None;
+        }
+
+        <Recipients<T>>::insert(relationship.recipient, recipient);
+        <RewardRelationships<T>>::insert(relationship_id, relationship);
+    }
+
+    // Pays the scheduled payout together with the accrued debt as far as the mint capacity allows.
+    // The debt is settled first, the missed part of the scheduled payout is added to the debt.
+    fn make_payout_accruing_debt(
+        relationship_id: T::RewardRelationshipId,
+        relationship: &mut RewardRelationship<
+            T::AccountId,
+            BalanceOf<T>,
+            T::BlockNumber,
+            T::MintId,
+            T::RecipientId,
+        >,
+        recipient: &mut Recipient<BalanceOf<T>>,
+    ) {
+        let payout = relationship.amount_per_payout;
+        let debt = Self::payout_debt(relationship_id);
+
+        let mint_capacity = <minting::Module<T>>::get_mint_capacity(relationship.mint_id)
+            .unwrap_or_else(|_| Zero::zero());
+        let mut amount_paid = (payout + debt).min(mint_capacity);
+
+        if <minting::Module<T>>::transfer_tokens(
+            relationship.mint_id,
+            amount_paid,
+            &relationship.account,
+        )
+        .is_err()
+        {
+            amount_paid = Zero::zero();
+        }
+
+        let debt_settled = amount_paid.min(debt);
+        let payout_missed = payout - (amount_paid - debt_settled);
+
+        <PayoutDebts<T>>::insert(relationship_id, debt - debt_settled + payout_missed);
+
+        // update payout received stats
+        relationship.total_reward_received += amount_paid;
+        recipient.total_reward_received += amount_paid;
+
+        // settled debt is not missed anymore
+        relationship.total_reward_missed = relationship
+            .total_reward_missed
+            .saturating_sub(debt_settled);
+        recipient.total_reward_missed = recipient.total_reward_missed.saturating_sub(debt_settled);
+
+        if !debt_settled.is_zero() {
+            Self::deposit_event(RawEvent::PayoutDebtSettled(relationship_id, debt_settled));
+        }
+
+        if payout_missed.is_zero() {
+            T::PayoutStatusHandler::payout_succeeded(
+                relationship_id,
+                &relationship.account,
+                amount_paid,
+            );
+        } else {
+            // update missed payout stats
+            relationship.total_reward_missed += payout_missed;
+            recipient.total_reward_missed += payout_missed;
+
+            Self::deposit_event(RawEvent::PayoutDebtAccrued(relationship_id, payout_missed));
+
+            T::PayoutStatusHandler::payout_failed(
+                relationship_id,
+                &relationship.account,
+                payout_missed,
+            );
+        }
+    }
+
+    // Pays the accrued debt of the relationship being removed or deactivated as far as the mint
+    // capacity allows and writes off the rest. Returns the settled debt.
+    fn settle_payout_debt_on_removal(
+        id: T::RewardRelationshipId,
+        relationship: &RewardRelationship<
+            T::AccountId,
+            BalanceOf<T>,
+            T::BlockNumber,
+            T::MintId,
+            T::RecipientId,
+        >,
+    ) -> BalanceOf<T> {
+        let debt = <PayoutDebts<T>>::take(id);
+
+        if debt.is_zero() {
+            return Zero::zero();
+        }
+
+        let mint_capacity = <minting::Module<T>>::get_mint_capacity(relationship.mint_id)
+            .unwrap_or_else(|_| Zero::zero());
+        let mut debt_settled = debt.min(mint_capacity);
+
+        if <minting::Module<T>>::transfer_tokens(
+            relationship.mint_id,
+            debt_settled,
+            &relationship.account,
+        )
+        .is_err()
+        {
+            debt_settled = Zero::zero();
+        }
+
+        if !debt_settled.is_zero() {
+            Self::deposit_event(RawEvent::PayoutDebtSettled(id, debt_settled));
+        }
+
+        Self::write_off_payout_debt(id, debt - debt_settled);
+
+        debt_settled
+    }
+
+    // Records the written off debt.
+    fn write_off_payout_debt(id: T::RewardRelationshipId, debt: BalanceOf<T>) {
+        if !debt.is_zero() {
+            Self::deposit_event(RawEvent::PayoutDebtWrittenOff(id, debt));
+        }
+    }
+
+    // Schedules the payout due at the block.
+    fn schedule_payout(id: T::RewardRelationshipId, payment_due_at_block: T::BlockNumber) {
+        <ScheduledPayouts<T>>::insert(payment_due_at_block, id, payment_due_at_block);
+    }
+
+    // Removes the scheduled payout if any.
+    fn unschedule_payout(
+        id: T::RewardRelationshipId,
+        next_payment_at_block: Option<T::BlockNumber>,
+    ) {
+        if let Some(next_payment_at_block) = next_payment_at_block {
+            <ScheduledPayouts<T>>::remove(next_payment_at_block, id);
         }
     }
 }
@@ -3,7 +3,7 @@
 use crate::{Module, Trait};
 
 use balances;
-use frame_support::{impl_outer_origin, parameter_types};
+use frame_support::{impl_outer_event, impl_outer_origin, parameter_types};
 use minting;
 use sp_core::H256;
 use sp_runtime::{
@@ -19,6 +19,18 @@ impl_outer_origin! {
     pub enum Origin for Test {}
 }
 
+mod recurring_rewards {
+    pub use crate::Event;
+}
+
+impl_outer_event! {
+    pub enum TestEvent for Test {
+        recurring_rewards<T>,
+        balances<T>,
+        system<T>,
+    }
+}
+
 // Workaround for https://github.com/rust-lang/rust/issues/26925 . Remove when sorted.
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct Test;
@@ -41,7 +53,7 @@ impl system::Trait for Test {
     type AccountId = u64;
     type Lookup = IdentityLookup<Self::AccountId>;
     type Header = Header;
-    type Event = ();
+    type Event = TestEvent;
     type BlockHashCount = BlockHashCount;
     type MaximumBlockWeight = MaximumBlockWeight;
     type DbWeight = ();
@@ -64,15 +76,21 @@ parameter_types! {
 impl balances::Trait for Test {
     type Balance = u64;
     type DustRemoval = ();
-    type Event = ();
+    type Event = TestEvent;
     type ExistentialDeposit = ExistentialDeposit;
     type AccountStore = System;
 }
 
+parameter_types! {
+    pub const MaxPayoutsPerBlock: u32 = 3;
+}
+
 impl Trait for Test {
+    type Event = TestEvent;
     type PayoutStatusHandler = MockStatusHandler;
     type RecipientId = u64;
     type RewardRelationshipId = u64;
+    type MaxPayoutsPerBlock = MaxPayoutsPerBlock;
 }
 
 impl minting::Trait for Test {
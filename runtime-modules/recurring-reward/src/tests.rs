@@ -2,7 +2,7 @@
 
 use super::*;
 use crate::mock::*;
-use frame_support::traits::Currency;
+use frame_support::traits::{Currency, Get};
 
 fn create_new_mint_with_capacity(capacity: u64) -> u64 {
     let mint_id = Minting::add_mint(capacity, None).ok().unwrap();
@@ -11,6 +11,16 @@ fn create_new_mint_with_capacity(capacity: u64) -> u64 {
     mint_id
 }
 
+fn recurring_reward_events() -> Vec<RawEvent<u64, u64>> {
+    System::events()
+        .into_iter()
+        .filter_map(|record| match record.event {
+            TestEvent::recurring_rewards(event) => Some(event),
+            _ => None,
+        })
+        .collect()
+}
+
 #[test]
 fn adding_recipients() {
     build_test_externalities().execute_with(|| {
@@ -257,3 +267,442 @@ fn activate_and_deactivate_relationship() {
         assert!(!Rewards::try_to_activate_relationship(relationship_id, next_payout_at).unwrap());
     });
 }
+
+#[test]
+fn only_due_payouts_are_made() {
+    build_test_externalities().execute_with(|| {
+        let starting_block: u64 = 10000;
+        System::set_block_number(starting_block);
+        let mint_id = create_new_mint_with_capacity(1000000);
+        let payout: u64 = 1000;
+
+        let add_relationship = |account: u64, next_payout_at: u64, interval: Option<u64>| {
+            let recipient_id = Rewards::add_recipient();
+            Rewards::add_reward_relationship(
+                mint_id,
+                recipient_id,
+                account,
+                payout,
+                next_payout_at,
+                interval,
+            )
+            .unwrap()
+        };
+
+        let recurring_relationship_id = add_relationship(1, starting_block + 2, Some(3));
+        let other_recurring_relationship_id = add_relationship(2, starting_block + 3, Some(5));
+        let one_off_relationship_id = add_relationship(3, starting_block + 4, None);
+        let deactivated_relationship_id = add_relationship(4, starting_block + 5, Some(1));
+        let rescheduled_relationship_id = add_relationship(5, starting_block + 6, None);
+
+        assert!(Rewards::try_to_deactivate_relationship(deactivated_relationship_id).unwrap());
+        assert!(Rewards::set_reward_relationship(
+            rescheduled_relationship_id,
+            None,
+            None,
+            Some(Some(starting_block + 10)),
+            None,
+        )
+        .is_ok());
+
+        for block in starting_block + 1..=starting_block + 20 {
+            System::set_block_number(block);
+            Rewards::do_payouts(block);
+
+            // processed payouts are removed from the schedule
+            assert_eq!(<ScheduledPayouts<Test>>::iter_prefix(block).count(), 0);
+        }
+
+        // payouts at blocks: 10002, 10005, 10008, 10011, 10014, 10017, 10020
+        assert_eq!(Balances::free_balance(&1), payout * 7);
+        // payouts at blocks: 10003, 10008, 10013, 10018
+        assert_eq!(Balances::free_balance(&2), payout * 4);
+        assert_eq!(Balances::free_balance(&3), payout);
+        assert_eq!(Balances::free_balance(&4), 0);
+        assert_eq!(Balances::free_balance(&5), payout);
+        assert_eq!(MockStatusHandler::successes(), 13);
+
+        assert_eq!(
+            Rewards::reward_relationships(&recurring_relationship_id).next_payment_at_block,
+            Some(starting_block + 23)
+        );
+        assert_eq!(
+            Rewards::reward_relationships(&other_recurring_relationship_id).next_payment_at_block,
+            Some(starting_block + 23)
+        );
+        assert_eq!(
+            Rewards::reward_relationships(&one_off_relationship_id).next_payment_at_block,
+            None
+        );
+        assert_eq!(
+            Rewards::reward_relationships(&rescheduled_relationship_id).total_reward_received,
+            payout
+        );
+    });
+}
+
+#[test]
+fn payouts_exceeding_block_limit_are_left_for_next_blocks() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let mint_id = create_new_mint_with_capacity(1000000);
+        let payout: u64 = 1000;
+        let next_payout_at: u64 = 12222;
+        let interval: u64 = 600;
+
+        let max_payouts_per_block = MaxPayoutsPerBlock::get() as u64;
+        let relationships_number = max_payouts_per_block + 2;
+
+        let relationship_ids = (0..relationships_number)
+            .map(|account| {
+                let recipient_id = Rewards::add_recipient();
+                Rewards::add_reward_relationship(
+                    mint_id,
+                    recipient_id,
+                    account,
+                    payout,
+                    next_payout_at,
+                    Some(interval),
+                )
+                .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        Rewards::do_payouts(next_payout_at);
+        assert_eq!(
+            MockStatusHandler::successes(),
+            max_payouts_per_block as usize
+        );
+        assert_eq!(
+            <ScheduledPayouts<Test>>::iter_prefix(next_payout_at).count(),
+            2
+        );
+        assert_eq!(Rewards::next_payouts_block(), Some(next_payout_at));
+
+        Rewards::do_payouts(next_payout_at + 1);
+        assert_eq!(
+            MockStatusHandler::successes(),
+            relationships_number as usize
+        );
+        assert_eq!(
+            <ScheduledPayouts<Test>>::iter_prefix(next_payout_at).count(),
+            0
+        );
+        assert_eq!(Rewards::next_payouts_block(), Some(next_payout_at + 2));
+
+        // payouts left for the next blocks keep the payout schedule
+        for relationship_id in relationship_ids.iter() {
+            let relationship = Rewards::reward_relationships(relationship_id);
+            assert_eq!(relationship.total_reward_received, payout);
+            assert_eq!(
+                relationship.next_payment_at_block,
+                Some(next_payout_at + interval)
+            );
+        }
+
+        Rewards::do_payouts(next_payout_at + interval);
+        Rewards::do_payouts(next_payout_at + interval + 1);
+        assert_eq!(
+            MockStatusHandler::successes(),
+            2 * relationships_number as usize
+        );
+
+        for account in 0..relationships_number {
+            assert_eq!(Balances::free_balance(&account), 2 * payout);
+        }
+    });
+}
+
+#[test]
+fn missed_payouts_are_accrued_as_debt_and_settled() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let recipient_account: u64 = 1;
+        let mint_id = create_new_mint_with_capacity(0);
+        let recipient_id = Rewards::add_recipient();
+        let payout: u64 = 1000;
+        let next_payout_at: u64 = 12222;
+        let interval: u64 = 600;
+        let relationship_id = Rewards::add_reward_relationship(
+            mint_id,
+            recipient_id,
+            recipient_account,
+            payout,
+            next_payout_at,
+            Some(interval),
+        )
+        .unwrap();
+
+        assert_eq!(
+            Rewards::missed_payout_policy(relationship_id),
+            MissedPayoutPolicy::Discard
+        );
+        assert!(
+            Rewards::set_missed_payout_policy(relationship_id, MissedPayoutPolicy::AccrueDebt)
+                .is_ok()
+        );
+
+        // mint has no capacity: the payout is accrued as debt
+        Rewards::do_payouts(next_payout_at);
+        assert_eq!(Balances::free_balance(&recipient_account), 0);
+        assert_eq!(Rewards::payout_debt(relationship_id), payout);
+        assert_eq!(MockStatusHandler::failures(), 1);
+        assert_eq!(
+            recurring_reward_events(),
+            vec![RawEvent::PayoutDebtAccrued(relationship_id, payout)]
+        );
+
+        // mint capacity is enough to settle the debt and pay the half of the payout
+        assert!(Minting::set_mint_capacity(mint_id, 1500).is_ok());
+        Rewards::do_payouts(next_payout_at + interval);
+        assert_eq!(Balances::free_balance(&recipient_account), 1500);
+        assert_eq!(Rewards::payout_debt(relationship_id), 500);
+        assert_eq!(MockStatusHandler::failures(), 2);
+        assert_eq!(
+            recurring_reward_events(),
+            vec![
+                RawEvent::PayoutDebtAccrued(relationship_id, payout),
+                RawEvent::PayoutDebtSettled(relationship_id, payout),
+                RawEvent::PayoutDebtAccrued(relationship_id, 500),
+            ]
+        );
+
+        // mint regains capacity: the rest of the debt is settled
+        assert!(Minting::set_mint_capacity(mint_id, 1000000).is_ok());
+        Rewards::do_payouts(next_payout_at + 2 * interval);
+        assert_eq!(Balances::free_balance(&recipient_account), 3 * payout);
+        assert_eq!(Rewards::payout_debt(relationship_id), 0);
+        assert_eq!(MockStatusHandler::successes(), 1);
+        assert_eq!(
+            recurring_reward_events().last(),
+            Some(&RawEvent::PayoutDebtSettled(relationship_id, 500))
+        );
+
+        // settled debt is not counted as missed
+        let relationship = Rewards::reward_relationships(&relationship_id);
+        assert_eq!(relationship.total_reward_received, 3 * payout);
+        assert_eq!(relationship.total_reward_missed, 0);
+
+        let recipient = Rewards::recipients(&recipient_id);
+        assert_eq!(recipient.total_reward_received, 3 * payout);
+        assert_eq!(recipient.total_reward_missed, 0);
+    });
+}
+
+#[test]
+fn debt_without_upcoming_payouts_is_kept_until_activation() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let recipient_account: u64 = 1;
+        let mint_id = create_new_mint_with_capacity(0);
+        let recipient_id = Rewards::add_recipient();
+        let payout: u64 = 1000;
+        let next_payout_at: u64 = 12222;
+        let relationship_id = Rewards::add_reward_relationship(
+            mint_id,
+            recipient_id,
+            recipient_account,
+            payout,
+            next_payout_at,
+            None,
+        )
+        .unwrap();
+
+        assert!(
+            Rewards::set_missed_payout_policy(relationship_id, MissedPayoutPolicy::AccrueDebt)
+                .is_ok()
+        );
+
+        // the one-off payout is missed: the debt is kept without upcoming payouts
+        Rewards::do_payouts(next_payout_at);
+        assert!(!Rewards::reward_relationships(&relationship_id).is_active());
+        assert_eq!(Rewards::payout_debt(relationship_id), payout);
+
+        // the debt is settled with the first payout after the activation
+        assert!(Minting::set_mint_capacity(mint_id, 1000000).is_ok());
+        let activated_at = next_payout_at + 100;
+        assert_eq!(
+            Rewards::try_to_activate_relationship(relationship_id, activated_at),
+            Ok(true)
+        );
+        Rewards::do_payouts(activated_at);
+        assert_eq!(Balances::free_balance(&recipient_account), 2 * payout);
+        assert_eq!(Rewards::payout_debt(relationship_id), 0);
+
+        let relationship = Rewards::reward_relationships(&relationship_id);
+        assert_eq!(relationship.total_reward_received, 2 * payout);
+        assert_eq!(relationship.total_reward_missed, 0);
+    });
+}
+
+#[test]
+fn removing_relationship_settles_debt_and_writes_off_the_rest() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let recipient_account: u64 = 1;
+        let mint_id = create_new_mint_with_capacity(0);
+        let recipient_id = Rewards::add_recipient();
+        let next_payout_at: u64 = 12222;
+        let relationship_id = Rewards::add_reward_relationship(
+            mint_id,
+            recipient_id,
+            recipient_account,
+            1000,
+            next_payout_at,
+            None,
+        )
+        .unwrap();
+
+        assert!(
+            Rewards::set_missed_payout_policy(relationship_id, MissedPayoutPolicy::AccrueDebt)
+                .is_ok()
+        );
+        Rewards::do_payouts(next_payout_at);
+        assert_eq!(Rewards::payout_debt(relationship_id), 1000);
+
+        assert!(Minting::set_mint_capacity(mint_id, 300).is_ok());
+        Rewards::remove_reward_relationship(relationship_id);
+        assert!(!<PayoutDebts<Test>>::contains_key(relationship_id));
+        assert!(!<MissedPayoutPolicies<Test>>::contains_key(relationship_id));
+
+        assert_eq!(Balances::free_balance(&recipient_account), 300);
+        assert_eq!(
+            recurring_reward_events(),
+            vec![
+                RawEvent::PayoutDebtAccrued(relationship_id, 1000),
+                RawEvent::PayoutDebtSettled(relationship_id, 300),
+                RawEvent::PayoutDebtWrittenOff(relationship_id, 700),
+            ]
+        );
+    });
+}
+
+#[test]
+fn settling_deactivated_relationship_debt_writes_off_the_rest() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let recipient_account: u64 = 1;
+        let mint_id = create_new_mint_with_capacity(0);
+        let recipient_id = Rewards::add_recipient();
+        let next_payout_at: u64 = 12222;
+        let relationship_id = Rewards::add_reward_relationship(
+            mint_id,
+            recipient_id,
+            recipient_account,
+            1000,
+            next_payout_at,
+            Some(600),
+        )
+        .unwrap();
+
+        assert!(
+            Rewards::set_missed_payout_policy(relationship_id, MissedPayoutPolicy::AccrueDebt)
+                .is_ok()
+        );
+        Rewards::do_payouts(next_payout_at);
+        assert_eq!(Rewards::payout_debt(relationship_id), 1000);
+
+        assert_eq!(
+            Rewards::try_to_deactivate_relationship(relationship_id),
+            Ok(true)
+        );
+        assert!(Minting::set_mint_capacity(mint_id, 300).is_ok());
+        assert_eq!(Rewards::settle_payout_debt(relationship_id), Ok(()));
+        assert!(!<PayoutDebts<Test>>::contains_key(relationship_id));
+
+        assert_eq!(Balances::free_balance(&recipient_account), 300);
+        assert_eq!(
+            Rewards::reward_relationships(&relationship_id).total_reward_missed,
+            700
+        );
+        assert_eq!(
+            recurring_reward_events(),
+            vec![
+                RawEvent::PayoutDebtAccrued(relationship_id, 1000),
+                RawEvent::PayoutDebtSettled(relationship_id, 300),
+                RawEvent::PayoutDebtWrittenOff(relationship_id, 700),
+            ]
+        );
+    });
+}
+
+#[test]
+fn discarding_missed_payouts_writes_off_debt() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let recipient_account: u64 = 1;
+        let mint_id = create_new_mint_with_capacity(0);
+        let recipient_id = Rewards::add_recipient();
+        let payout: u64 = 1000;
+        let next_payout_at: u64 = 12222;
+        let interval: u64 = 600;
+        let relationship_id = Rewards::add_reward_relationship(
+            mint_id,
+            recipient_id,
+            recipient_account,
+            payout,
+            next_payout_at,
+            Some(interval),
+        )
+        .unwrap();
+
+        assert_eq!(
+            Rewards::set_missed_payout_policy(relationship_id + 1, MissedPayoutPolicy::AccrueDebt),
+            Err(RewardsError::RewardRelationshipNotFound)
+        );
+
+        assert!(
+            Rewards::set_missed_payout_policy(relationship_id, MissedPayoutPolicy::AccrueDebt)
+                .is_ok()
+        );
+        Rewards::do_payouts(next_payout_at);
+        assert_eq!(Rewards::payout_debt(relationship_id), payout);
+
+        assert!(
+            Rewards::set_missed_payout_policy(relationship_id, MissedPayoutPolicy::Discard).is_ok()
+        );
+        assert_eq!(Rewards::payout_debt(relationship_id), 0);
+
+        // only the scheduled payout is made
+        assert!(Minting::set_mint_capacity(mint_id, 1000000).is_ok());
+        Rewards::do_payouts(next_payout_at + interval);
+        assert_eq!(Balances::free_balance(&recipient_account), payout);
+        assert_eq!(
+            recurring_reward_events(),
+            vec![
+                RawEvent::PayoutDebtAccrued(relationship_id, payout),
+                RawEvent::PayoutDebtWrittenOff(relationship_id, payout),
+            ]
+        );
+    });
+}
+
+#[test]
+fn scheduling_active_reward_relationships_runs_once() {
+    build_test_externalities().execute_with(|| {
+        let mint_id = create_new_mint_with_capacity(1000000);
+        let recipient_id = Rewards::add_recipient();
+        let next_payment_at: u64 = 2222;
+
+        let relationship_id =
+            Rewards::add_reward_relationship(mint_id, recipient_id, 1, 100, next_payment_at, None)
+                .unwrap();
+        <ScheduledPayouts<Test>>::remove(next_payment_at, relationship_id);
+
+        Rewards::schedule_active_reward_relationships();
+
+        assert!(<ScheduledPayouts<Test>>::contains_key(
+            next_payment_at,
+            relationship_id
+        ));
+        assert_eq!(Rewards::storage_version(), STORAGE_VERSION);
+
+        <ScheduledPayouts<Test>>::remove(next_payment_at, relationship_id);
+        Rewards::schedule_active_reward_relationships();
+
+        assert!(!<ScheduledPayouts<Test>>::contains_key(
+            next_payment_at,
+            relationship_id
+        ));
+    });
+}
@@ -0,0 +1,98 @@
+//! Service discovery module benchmarking.
+
+use super::*;
+use common::constraints::InputValidationLengthConstraint;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::{StorageMap, StorageValue};
+use sp_std::vec;
+use system::RawOrigin;
+
+const SEED: u32 = 0;
+const MAX_TEXT_LENGTH: u32 = 1000;
+const MAX_BOOTSTRAP_ENDPOINTS: u32 = 100;
+const URL_LENGTH: u32 = 100;
+
+fn text(len: u32) -> Vec<u8> {
+    vec![b'a'; len as usize]
+}
+
+// Relaxes the text constraints of the storage working group to the texts of the benchmark hiring,
+// hires the leader and the storage provider.
+fn create_storage_provider<T: Trait>() -> (T::AccountId, StorageProviderId<T>) {
+    let constraint = InputValidationLengthConstraint::new(1, MAX_TEXT_LENGTH as u16);
+
+    <working_group::OpeningHumanReadableText<StorageWorkingGroupInstance>>::put(constraint);
+    <working_group::WorkerApplicationHumanReadableText<StorageWorkingGroupInstance>>::put(
+        constraint,
+    );
+
+    let lead_account_id: T::AccountId = account("lead", 0, SEED);
+    StorageWorkingGroup::<T>::insert_benchmark_lead(&lead_account_id);
+
+    let account_id: T::AccountId = account("storage_provider", 0, SEED);
+    let storage_provider_id = StorageWorkingGroup::<T>::insert_benchmark_worker(&account_id);
+
+    (account_id, storage_provider_id)
+}
+
+benchmarks! {
+    _ { }
+
+    set_ipns_id {
+        let i in 1 .. MAX_TEXT_LENGTH;
+
+        let (account_id, storage_provider_id) = create_storage_provider::<T>();
+    }: _(RawOrigin::Signed(account_id), storage_provider_id, text(i))
+    verify {
+        assert_eq!(
+            Module::<T>::account_info_by_storage_provider_id(storage_provider_id).identity,
+            text(i)
+        );
+    }
+
+    unset_ipns_id {
+        let (account_id, storage_provider_id) = create_storage_provider::<T>();
+        Module::<T>::set_ipns_id(
+            RawOrigin::Signed(account_id.clone()).into(),
+            storage_provider_id,
+            text(MAX_TEXT_LENGTH),
+        )
+        .expect("IPNS id set");
+    }: _(RawOrigin::Signed(account_id), storage_provider_id)
+    verify {
+        assert!(!<AccountInfoByStorageProviderId<T>>::contains_key(storage_provider_id));
+    }
+
+    set_default_lifetime {
+        let lifetime = T::BlockNumber::from(MINIMUM_LIFETIME);
+    }: _(RawOrigin::Root, lifetime)
+    verify {
+        assert_eq!(Module::<T>::default_lifetime(), lifetime);
+    }
+
+    set_bootstrap_endpoints {
+        let e in 1 .. MAX_BOOTSTRAP_ENDPOINTS;
+
+        let endpoints = vec![text(URL_LENGTH); e as usize];
+    }: _(RawOrigin::Root, endpoints.clone())
+    verify {
+        assert_eq!(Module::<T>::bootstrap_endpoints(), endpoints);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{initial_test_ext, Test};
+    use frame_support::assert_ok;
+
+    #[test]
+    fn service_discovery_benchmarks_run_successfully() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_set_ipns_id::<Test>());
+            assert_ok!(test_benchmark_unset_ipns_id::<Test>());
+            assert_ok!(test_benchmark_set_default_lifetime::<Test>());
+            assert_ok!(test_benchmark_set_bootstrap_endpoints::<Test>());
+        });
+    }
+}
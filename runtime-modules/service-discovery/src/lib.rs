@@ -19,6 +19,8 @@
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 mod mock;
 mod tests;
 
@@ -26,6 +28,7 @@ use codec::{Decode, Encode};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 
+use frame_support::weights::Weight;
 use frame_support::{decl_event, decl_module, decl_storage, ensure};
 use sp_std::vec::Vec;
 use system::ensure_root;
@@ -70,10 +73,37 @@ pub struct ServiceProviderRecord<BlockNumber> {
     pub expires_at: BlockNumber,
 }
 
+/// Extrinsic weights for the _Service discovery_ module.
+pub trait WeightInfo {
+    fn set_ipns_id(i: u32) -> Weight;
+    fn unset_ipns_id() -> Weight;
+    fn set_default_lifetime() -> Weight;
+    fn set_bootstrap_endpoints(e: u32) -> Weight;
+}
+
+// Default weights, used in the tests and by the runtimes without benchmarked weights.
+impl WeightInfo for () {
+    fn set_ipns_id(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn unset_ipns_id() -> Weight {
+        10_000_000
+    }
+    fn set_default_lifetime() -> Weight {
+        10_000_000
+    }
+    fn set_bootstrap_endpoints(_e: u32) -> Weight {
+        10_000_000
+    }
+}
+
 /// The _Service discovery_ main _Trait_.
 pub trait Trait: system::Trait + working_group::Trait<StorageWorkingGroupInstance> {
     /// _Service discovery_ event type.
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
 }
 
 decl_storage! {
@@ -117,7 +147,7 @@ decl_module! {
 
         /// Creates the ServiceProviderRecord to save an IPNS identity for the storage provider.
         /// Requires signed storage provider credentials.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::set_ipns_id(id.len() as u32)]
         pub fn set_ipns_id(
             origin,
             storage_provider_id: StorageProviderId<T>,
@@ -141,7 +171,7 @@ decl_module! {
 
         /// Deletes the ServiceProviderRecord with the IPNS identity for the storage provider.
         /// Requires signed storage provider credentials.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::unset_ipns_id()]
         pub fn unset_ipns_id(origin, storage_provider_id: StorageProviderId<T>) {
             <StorageWorkingGroup<T>>::ensure_worker_signed(origin, &storage_provider_id)?;
 
@@ -156,7 +186,7 @@ decl_module! {
         // Privileged methods
 
         /// Sets default lifetime for storage providers accounts info. Requires root privileges.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::set_default_lifetime()]
         pub fn set_default_lifetime(origin, lifetime: T::BlockNumber) {
             ensure_root(origin)?;
             ensure!(lifetime >= T::BlockNumber::from(MINIMUM_LIFETIME),
@@ -168,7 +198,7 @@ decl_module! {
         }
 
         /// Sets bootstrap endpoints for the Colossus. Requires root privileges.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::set_bootstrap_endpoints(endpoints.len() as u32)]
         pub fn set_bootstrap_endpoints(origin, endpoints: Vec<Url>) {
             ensure_root(origin)?;
 
@@ -37,6 +37,7 @@ impl_outer_event! {
         balances<T>,
         membership_mod<T>,
         working_group_mod StorageWorkingGroupInstance <T>,
+        recurringrewards<T>,
         system<T>,
     }
 }
@@ -83,6 +84,11 @@ impl system::Trait for Test {
 
 impl Trait for Test {
     type Event = MetaEvent;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const ApplicationsRetentionPeriod: u64 = 100;
 }
 
 impl hiring::Trait for Test {
@@ -90,6 +96,7 @@ impl hiring::Trait for Test {
     type ApplicationId = u64;
     type ApplicationDeactivatedHandler = ();
     type StakeHandlerProvider = hiring::Module<Self>;
+    type ApplicationsRetentionPeriod = ApplicationsRetentionPeriod;
 }
 
 impl minting::Trait for Test {
@@ -111,6 +118,7 @@ impl membership::Trait for Test {
     type PaidTermId = u64;
     type SubscriptionId = u64;
     type ActorId = u64;
+    type WeightInfo = ();
 }
 
 impl common::currency::GovernanceCurrency for Test {
@@ -125,10 +133,16 @@ impl balances::Trait for Test {
     type AccountStore = System;
 }
 
+parameter_types! {
+    pub const MaxPayoutsPerBlock: u32 = 100;
+}
+
 impl recurringrewards::Trait for Test {
+    type Event = MetaEvent;
     type PayoutStatusHandler = ();
     type RecipientId = u64;
     type RewardRelationshipId = u64;
+    type MaxPayoutsPerBlock = MaxPayoutsPerBlock;
 }
 
 parameter_types! {
@@ -138,6 +152,8 @@ parameter_types! {
 impl working_group::Trait<StorageWorkingGroupInstance> for Test {
     type Event = MetaEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WeightInfo = ();
+    type WorkerExitHandler = ();
 }
 
 impl pallet_timestamp::Trait for Test {
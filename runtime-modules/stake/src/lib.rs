@@ -2,13 +2,15 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::{Codec, Decode, Encode};
-use frame_support::storage::IterableStorageMap;
+use frame_support::storage::{IterableStorageDoubleMap, IterableStorageMap};
 use frame_support::traits::{Currency, ExistenceRequirement, Get, Imbalance, WithdrawReasons};
+use frame_support::weights::Weight;
 use frame_support::{decl_module, decl_storage, ensure, Parameter};
-use sp_arithmetic::traits::{BaseArithmetic, One, Zero};
+use sp_arithmetic::traits::{BaseArithmetic, One, Saturating, Zero};
 use sp_runtime::traits::{AccountIdConversion, MaybeSerialize, Member};
 use sp_runtime::ModuleId;
 use sp_std::collections::btree_map::BTreeMap;
+use sp_std::collections::btree_set::BTreeSet;
 use sp_std::prelude::*;
 
 mod errors;
@@ -17,6 +19,9 @@ mod macroes;
 mod mock;
 mod tests;
 
+// Storage version with the stakes with active timers scheduled.
+const STORAGE_VERSION: u32 = 1;
+
 pub type BalanceOf<T> =
     <<T as Trait>::Currency as Currency<<T as system::Trait>::AccountId>>::Balance;
 
@@ -318,6 +323,27 @@ where
         self.staking_status == StakingStatus::NotStaked
     }
 
+    /// Returns true if the stake has at least one active slashing or unstaking timer, which
+    /// should be advanced on block finalization.
+    fn has_active_timers(&self) -> bool {
+        match self.staking_status {
+            StakingStatus::Staked(ref staked_state) => {
+                let has_active_slashes = staked_state
+                    .ongoing_slashes
+                    .values()
+                    .any(|slash| slash.is_active);
+
+                let is_actively_unstaking = match staked_state.staked_status {
+                    StakedStatus::Unstaking(ref unstaking_state) => unstaking_state.is_active,
+                    StakedStatus::Normal => false,
+                };
+
+                has_active_slashes || is_actively_unstaking
+            }
+            StakingStatus::NotStaked => false,
+        }
+    }
+
     /// If staking status is Staked and not currently Unstaking it will increase the staked amount by value.
     /// On success returns new total staked value.
     /// Increasing stake by zero is an error.
@@ -715,6 +741,16 @@ decl_storage! {
         /// Identifier value for next stake, and count of total stakes created (not necessarily the number of current
         /// stakes in the Stakes map as stakes can be removed.)
         pub StakesCreated get(fn stakes_created): T::StakeId;
+
+        /// Queue of the stakes with active slashing or unstaking timers, indexed by the block
+        /// where the timers of the stake are advanced. Entries of the stakes which timers were
+        /// paused are skipped on processing.
+        pub ScheduledStakeTimers get(fn scheduled_stake_timers): double_map
+            hasher(twox_64_concat) T::BlockNumber, hasher(blake2_128_concat) T::StakeId => ();
+
+        /// Version of the module storage. The older versions are updated by
+        /// `schedule_stakes_with_active_timers`.
+        pub StorageVersion get(fn storage_version): u32;
     }
 }
 
@@ -1040,9 +1076,46 @@ impl<T: Trait> Module<T> {
         )?;
 
         <Stakes<T>>::insert(stake_id, stake);
+        Self::schedule_stake_timers(stake_id);
+
         Ok(slash_id)
     }
 
+    /// Schedules advancing the stake timers starting from the current block finalization.
+    /// Scheduling the same stake more than once for the block has no effect.
+    fn schedule_stake_timers(stake_id: &T::StakeId) {
+        let now = <system::Module<T>>::block_number();
+
+        <ScheduledStakeTimers<T>>::insert(now, stake_id, ());
+    }
+
+    /// Schedules all stakes with active timers. Should be called on the migration to
+    /// the runtime version with the scheduled stake timers. Runs once: the stakes are already
+    /// scheduled if the storage version is current. Returns the weight of the migration.
+    pub fn schedule_stakes_with_active_timers() -> Weight {
+        if Self::storage_version() >= STORAGE_VERSION {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut stakes_count: Weight = 0;
+        let mut scheduled_count: Weight = 0;
+
+        for (stake_id, stake) in <Stakes<T>>::iter() {
+            stakes_count += 1;
+
+            if stake.has_active_timers() {
+                Self::schedule_stake_timers(&stake_id);
+                scheduled_count += 1;
+            }
+        }
+
+        StorageVersion::put(STORAGE_VERSION);
+
+        // Reads: the version, the block number and the stakes. Writes: the version and
+        // the scheduled timers.
+        T::DbWeight::get().reads_writes(2 + stakes_count, 1 + scheduled_count)
+    }
+
     /// Pause an ongoing slashing
     pub fn pause_slashing(
         stake_id: &T::StakeId,
@@ -1067,6 +1140,8 @@ impl<T: Trait> Module<T> {
         stake.resume_slashing(slash_id)?;
 
         <Stakes<T>>::insert(stake_id, stake);
+        Self::schedule_stake_timers(stake_id);
+
         Ok(())
     }
 
@@ -1079,8 +1154,14 @@ impl<T: Trait> Module<T> {
 
         stake.cancel_slashing(slash_id)?;
 
+        let has_active_timers = stake.has_active_timers();
         <Stakes<T>>::insert(stake_id, stake);
 
+        // cancelling the last slash resumes unstaking
+        if has_active_timers {
+            Self::schedule_stake_timers(stake_id);
+        }
+
         Ok(())
     }
 
@@ -1094,6 +1175,7 @@ impl<T: Trait> Module<T> {
         if let Some(unstaking_period) = unstaking_period {
             stake.initiate_unstaking(unstaking_period, <system::Module<T>>::block_number())?;
             <Stakes<T>>::insert(stake_id, stake);
+            Self::schedule_stake_timers(stake_id);
         } else {
             let staked_amount = stake.unstake()?;
             <Stakes<T>>::insert(stake_id, stake);
@@ -1127,6 +1209,7 @@ impl<T: Trait> Module<T> {
         stake.resume_unstaking()?;
 
         <Stakes<T>>::insert(stake_id, stake);
+        Self::schedule_stake_timers(stake_id);
 
         Ok(())
     }
@@ -1136,11 +1219,37 @@ impl<T: Trait> Module<T> {
     /// imbalance is provided to the unstaked() hook in the StakingEventsHandler.
     /// Finalised slashing results in the staked_balance in the given stake being correspondingly reduced, and the imbalance
     /// is provided to the slashed() hook in the StakingEventsHandler.
+    /// Only the scheduled stakes are processed: the stakes scheduled on the current block and
+    /// the stakes which timers remained active on the previous block finalization. Stakes which
+    /// timers remain active are kept scheduled on the current block.
     fn finalize_slashing_and_unstaking() {
-        for (stake_id, ref mut stake) in <Stakes<T>>::iter() {
+        let now = <system::Module<T>>::block_number();
+        let previous_block = now.saturating_sub(One::one());
+
+        let scheduled_stake_ids = <ScheduledStakeTimers<T>>::drain_prefix(previous_block)
+            .chain(<ScheduledStakeTimers<T>>::drain_prefix(now))
+            .map(|(stake_id, _)| stake_id)
+            .collect::<BTreeSet<_>>();
+
+        for stake_id in scheduled_stake_ids {
+            if !<Stakes<T>>::contains_key(stake_id) {
+                continue;
+            }
+
+            let mut stake = Self::stakes(stake_id);
+
+            // timers were paused after the stake was scheduled
+            if !stake.has_active_timers() {
+                continue;
+            }
+
             let (updated, slashed, unstaked) =
                 stake.finalize_slashing_and_unstaking(T::Currency::minimum_balance());
 
+            if stake.has_active_timers() {
+                <ScheduledStakeTimers<T>>::insert(now, stake_id, ());
+            }
+
             // update the state before making external calls to StakingEventsHandler
             if updated {
                 <Stakes<T>>::insert(stake_id, stake)
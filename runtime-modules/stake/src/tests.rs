@@ -705,6 +705,127 @@ fn initiating_pausing_resuming_unstaking() {
     });
 }
 
+#[test]
+fn paused_unstaking_is_not_advanced_and_resumed_unstaking_is_rescheduled() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(1);
+
+        let staked_amount = Balances::minimum_balance() + 10000;
+
+        let _ = Balances::deposit_creating(&StakePool::stake_pool_account_id(), staked_amount);
+
+        let stake_id = StakePool::create_stake();
+        <Stakes<Test>>::insert(
+            &stake_id,
+            Stake {
+                created: System::block_number(),
+                staking_status: StakingStatus::Staked(StakedState {
+                    staked_amount,
+                    ongoing_slashes: BTreeMap::new(),
+                    next_slash_id: 0,
+                    staked_status: StakedStatus::Normal,
+                }),
+            },
+        );
+
+        assert_ok!(StakePool::initiate_unstaking(&stake_id, Some(2)));
+        assert!(<ScheduledStakeTimers<Test>>::contains_key(1, stake_id));
+
+        assert_ok!(StakePool::pause_unstaking(&stake_id));
+
+        // the queue entry is consumed but the paused timer is not advanced
+        StakePool::on_finalize(System::block_number());
+        assert!(!<ScheduledStakeTimers<Test>>::contains_key(1, stake_id));
+
+        let expected_unstaking_state = |blocks_remaining, is_active| Stake {
+            created: 1,
+            staking_status: StakingStatus::Staked(StakedState {
+                staked_amount,
+                ongoing_slashes: BTreeMap::new(),
+                next_slash_id: 0,
+                staked_status: StakedStatus::Unstaking(UnstakingState {
+                    started_at_block: 1,
+                    blocks_remaining_in_active_period_for_unstaking: blocks_remaining,
+                    is_active,
+                }),
+            }),
+        };
+
+        assert_eq!(
+            StakePool::stakes(&stake_id),
+            expected_unstaking_state(2, false)
+        );
+
+        System::set_block_number(2);
+        StakePool::on_finalize(System::block_number());
+        assert_eq!(
+            StakePool::stakes(&stake_id),
+            expected_unstaking_state(2, false)
+        );
+
+        assert_ok!(StakePool::resume_unstaking(&stake_id));
+        assert!(<ScheduledStakeTimers<Test>>::contains_key(2, stake_id));
+
+        StakePool::on_finalize(System::block_number());
+        assert_eq!(
+            StakePool::stakes(&stake_id),
+            expected_unstaking_state(1, true)
+        );
+        assert!(<ScheduledStakeTimers<Test>>::contains_key(2, stake_id));
+
+        System::set_block_number(3);
+        StakePool::on_finalize(System::block_number());
+        assert_eq!(
+            StakePool::stakes(&stake_id),
+            Stake {
+                created: 1,
+                staking_status: StakingStatus::NotStaked
+            }
+        );
+        assert!(!<ScheduledStakeTimers<Test>>::contains_key(2, stake_id));
+        assert!(!<ScheduledStakeTimers<Test>>::contains_key(3, stake_id));
+    });
+}
+
+#[test]
+fn scheduling_stakes_with_active_timers_runs_once() {
+    build_test_externalities().execute_with(|| {
+        let stake_id = StakePool::create_stake();
+        <Stakes<Test>>::insert(
+            &stake_id,
+            Stake {
+                created: System::block_number(),
+                staking_status: StakingStatus::Staked(StakedState {
+                    staked_amount: 10000,
+                    ongoing_slashes: BTreeMap::new(),
+                    next_slash_id: 0,
+                    staked_status: StakedStatus::Unstaking(UnstakingState {
+                        started_at_block: System::block_number(),
+                        blocks_remaining_in_active_period_for_unstaking: 2,
+                        is_active: true,
+                    }),
+                }),
+            },
+        );
+
+        StakePool::schedule_stakes_with_active_timers();
+
+        assert!(<ScheduledStakeTimers<Test>>::contains_key(
+            System::block_number(),
+            stake_id
+        ));
+        assert_eq!(StakePool::storage_version(), STORAGE_VERSION);
+
+        <ScheduledStakeTimers<Test>>::remove(System::block_number(), stake_id);
+        StakePool::schedule_stakes_with_active_timers();
+
+        assert!(!<ScheduledStakeTimers<Test>>::contains_key(
+            System::block_number(),
+            stake_id
+        ));
+    });
+}
+
 #[test]
 fn unstake() {
     build_test_externalities().execute_with(|| {
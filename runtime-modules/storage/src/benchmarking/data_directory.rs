@@ -0,0 +1,209 @@
+//! Data directory module benchmarking.
+
+use super::{
+    content_id_from_index, create_data_object_type, create_lead, create_member,
+    create_storage_provider, data_object, insert_data_object, text, MAX_TEXT_LENGTH,
+};
+use crate::data_directory::*;
+use crate::{MemberId, StorageProviderId};
+use frame_benchmarking::benchmarks;
+use frame_support::traits::Get;
+use frame_support::StorageValue;
+use system::RawOrigin;
+
+const MIME_TYPE_LENGTH: u32 = 10;
+const EXTENSION_LENGTH: u32 = 3;
+
+// Inserts the data object and the storage provider accepts it, so the content id is known.
+// Returns the content id.
+fn create_accepted_content<T: Trait>(
+    index: u32,
+    owner: MemberId<T>,
+    storage_provider: &(T::AccountId, StorageProviderId<T>),
+) -> T::ContentId {
+    let (account_id, storage_provider_id) = storage_provider;
+    let content_id = content_id_from_index::<T>(index);
+
+    insert_data_object::<T>(content_id, owner, *storage_provider_id);
+    Module::<T>::accept_content(
+        RawOrigin::Signed(account_id.clone()).into(),
+        *storage_provider_id,
+        content_id,
+    )
+    .expect("content accepted");
+
+    content_id
+}
+
+benchmarks! {
+    _ { }
+
+    add_content {
+        let i in 1 .. MAX_TEXT_LENGTH;
+
+        create_lead::<T>();
+        create_storage_provider::<T>();
+        let (account_id, member_id) = create_member::<T>();
+        let type_id = create_data_object_type::<T>();
+        DefaultQuotaLimits::put(default_storage_quota_limits());
+        let content_id = content_id_from_index::<T>(1);
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        content_id,
+        type_id,
+        1,
+        text(i),
+        text(MIME_TYPE_LENGTH),
+        text(EXTENSION_LENGTH)
+    )
+    verify {
+        assert!(Module::<T>::data_object_by_content_id(content_id).is_some());
+    }
+
+    accept_content {
+        create_lead::<T>();
+        let (account_id, storage_provider_id) = create_storage_provider::<T>();
+        let (_, member_id) = create_member::<T>();
+        let content_id = content_id_from_index::<T>(1);
+        insert_data_object::<T>(content_id, member_id, storage_provider_id);
+    }: _(RawOrigin::Signed(account_id), storage_provider_id, content_id)
+    verify {
+        assert!(Module::<T>::known_content_index_by_id(content_id).is_some());
+    }
+
+    reject_content {
+        create_lead::<T>();
+        let (account_id, storage_provider_id) = create_storage_provider::<T>();
+        let (_, member_id) = create_member::<T>();
+        let content_id = content_id_from_index::<T>(1);
+        insert_data_object::<T>(content_id, member_id, storage_provider_id);
+    }: _(RawOrigin::Signed(account_id), storage_provider_id, content_id)
+    verify {
+        assert_eq!(
+            Module::<T>::data_object_by_content_id(content_id).map(|data| data.liaison_judgement),
+            Some(LiaisonJudgement::Rejected)
+        );
+    }
+
+    // The last known content id takes the position of the removed one.
+    remove_known_content_id {
+        create_lead::<T>();
+        let storage_provider = create_storage_provider::<T>();
+        let (_, member_id) = create_member::<T>();
+        let content_id = create_accepted_content::<T>(1, member_id, &storage_provider);
+        create_accepted_content::<T>(2, member_id, &storage_provider);
+    }: _(RawOrigin::Root, content_id)
+    verify {
+        assert!(Module::<T>::known_content_index_by_id(content_id).is_none());
+    }
+
+    // The injected data objects overwrite the counted ones.
+    inject_data_objects {
+        let o in 1 .. T::MaxObjectsPerInjection::get();
+
+        create_lead::<T>();
+        let (_, storage_provider_id) = create_storage_provider::<T>();
+        let (_, member_id) = create_member::<T>();
+        for index in 1..=o {
+            insert_data_object::<T>(content_id_from_index::<T>(index), member_id, storage_provider_id);
+        }
+        let objects: DataObjectsMap<T> = (1..=o)
+            .map(|index| {
+                (content_id_from_index::<T>(index), data_object::<T>(member_id, storage_provider_id))
+            })
+            .collect();
+    }: _(RawOrigin::Root, objects)
+    verify {
+        assert_eq!(Module::<T>::known_content_ids_count(), o as u64);
+    }
+
+    delete_content {
+        create_lead::<T>();
+        let storage_provider = create_storage_provider::<T>();
+        let (account_id, member_id) = create_member::<T>();
+        let content_id = create_accepted_content::<T>(1, member_id, &storage_provider);
+    }: _(RawOrigin::Signed(account_id), member_id, content_id)
+    verify {
+        assert!(Module::<T>::data_object_by_content_id(content_id).is_none());
+    }
+
+    // The storage working group leader takes down the content: the content curators are checked
+    // first.
+    takedown_content {
+        let lead_account_id = create_lead::<T>();
+        let storage_provider = create_storage_provider::<T>();
+        let (_, member_id) = create_member::<T>();
+        let content_id = create_accepted_content::<T>(1, member_id, &storage_provider);
+    }: _(RawOrigin::Signed(lead_account_id), content_id)
+    verify {
+        assert!(Module::<T>::data_object_by_content_id(content_id).is_none());
+    }
+
+    add_channel_content {
+        let i in 1 .. MAX_TEXT_LENGTH;
+
+        create_lead::<T>();
+        create_storage_provider::<T>();
+        let (account_id, member_id) = create_member::<T>();
+        let channel_id = T::ChannelOwners::create_benchmark_channel(&member_id);
+        let type_id = create_data_object_type::<T>();
+        DefaultQuotaLimits::put(default_storage_quota_limits());
+        let content_id = content_id_from_index::<T>(1);
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        channel_id,
+        content_id,
+        type_id,
+        1,
+        text(i),
+        text(MIME_TYPE_LENGTH),
+        text(EXTENSION_LENGTH)
+    )
+    verify {
+        assert_eq!(Module::<T>::channel_id_by_content_id(content_id), Some(channel_id));
+    }
+
+    // The leader sets the limits: the root origin is checked first.
+    set_quota_limits {
+        let lead_account_id = create_lead::<T>();
+        let (_, member_id) = create_member::<T>();
+        let owner = StorageObjectOwner::<T>::Member(member_id);
+        let limits = default_storage_quota_limits();
+    }: _(RawOrigin::Signed(lead_account_id), owner.clone(), Some(limits))
+    verify {
+        assert_eq!(Module::<T>::quota_limits_by_owner(owner), Some(limits));
+    }
+
+    set_default_quota_limits {
+        let lead_account_id = create_lead::<T>();
+        let limits = default_storage_quota_limits();
+    }: _(RawOrigin::Signed(lead_account_id), limits)
+    verify {
+        assert_eq!(Module::<T>::default_quota_limits(), limits);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::mock::{with_default_mock_builder, Test};
+    use frame_support::assert_ok;
+
+    #[test]
+    fn data_directory_benchmarks_run_successfully() {
+        with_default_mock_builder(|| {
+            assert_ok!(test_benchmark_add_content::<Test>());
+            assert_ok!(test_benchmark_accept_content::<Test>());
+            assert_ok!(test_benchmark_reject_content::<Test>());
+            assert_ok!(test_benchmark_remove_known_content_id::<Test>());
+            assert_ok!(test_benchmark_inject_data_objects::<Test>());
+            assert_ok!(test_benchmark_delete_content::<Test>());
+            assert_ok!(test_benchmark_takedown_content::<Test>());
+            assert_ok!(test_benchmark_add_channel_content::<Test>());
+            assert_ok!(test_benchmark_set_quota_limits::<Test>());
+            assert_ok!(test_benchmark_set_default_quota_limits::<Test>());
+        });
+    }
+}
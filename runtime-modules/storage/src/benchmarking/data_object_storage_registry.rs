@@ -0,0 +1,91 @@
+//! Data object storage registry module benchmarking.
+
+use super::{
+    content_id_from_index, create_lead, create_member, create_storage_provider, insert_data_object,
+};
+use crate::data_object_storage_registry::*;
+use crate::StorageProviderId;
+use frame_benchmarking::benchmarks;
+use system::RawOrigin;
+
+// Adds the content stored by the storage provider. Returns the relationship id.
+fn create_relationship<T: Trait>(
+    account_id: &T::AccountId,
+    storage_provider_id: StorageProviderId<T>,
+) -> T::DataObjectStorageRelationshipId {
+    let (_, member_id) = create_member::<T>();
+    let content_id = content_id_from_index::<T>(1);
+    insert_data_object::<T>(content_id, member_id, storage_provider_id);
+
+    let relationship_id = Module::<T>::next_relationship_id();
+    Module::<T>::add_relationship(
+        RawOrigin::Signed(account_id.clone()).into(),
+        storage_provider_id,
+        content_id,
+    )
+    .expect("relationship added");
+
+    relationship_id
+}
+
+benchmarks! {
+    _ { }
+
+    add_relationship {
+        create_lead::<T>();
+        let (account_id, storage_provider_id) = create_storage_provider::<T>();
+        let (_, member_id) = create_member::<T>();
+        let content_id = content_id_from_index::<T>(1);
+        insert_data_object::<T>(content_id, member_id, storage_provider_id);
+        let relationship_id = Module::<T>::next_relationship_id();
+    }: _(RawOrigin::Signed(account_id), storage_provider_id, content_id)
+    verify {
+        assert!(Module::<T>::relationships(relationship_id).is_some());
+    }
+
+    set_relationship_ready {
+        create_lead::<T>();
+        let (account_id, storage_provider_id) = create_storage_provider::<T>();
+        let relationship_id = create_relationship::<T>(&account_id, storage_provider_id);
+    }: _(RawOrigin::Signed(account_id), storage_provider_id, relationship_id)
+    verify {
+        assert_eq!(
+            Module::<T>::relationships(relationship_id).map(|dosr| dosr.ready),
+            Some(true)
+        );
+    }
+
+    unset_relationship_ready {
+        create_lead::<T>();
+        let (account_id, storage_provider_id) = create_storage_provider::<T>();
+        let relationship_id = create_relationship::<T>(&account_id, storage_provider_id);
+        Module::<T>::set_relationship_ready(
+            RawOrigin::Signed(account_id.clone()).into(),
+            storage_provider_id,
+            relationship_id,
+        )
+        .expect("relationship ready");
+    }: _(RawOrigin::Signed(account_id), storage_provider_id, relationship_id)
+    verify {
+        assert_eq!(
+            Module::<T>::relationships(relationship_id).map(|dosr| dosr.ready),
+            Some(false)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::mock::{with_default_mock_builder, Test};
+    use frame_support::assert_ok;
+
+    #[test]
+    fn data_object_storage_registry_benchmarks_run_successfully() {
+        with_default_mock_builder(|| {
+            assert_ok!(test_benchmark_add_relationship::<Test>());
+            assert_ok!(test_benchmark_set_relationship_ready::<Test>());
+            assert_ok!(test_benchmark_unset_relationship_ready::<Test>());
+        });
+    }
+}
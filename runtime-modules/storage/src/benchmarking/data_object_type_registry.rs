@@ -0,0 +1,84 @@
+//! Data object type registry module benchmarking.
+
+use super::{create_data_object_type, create_lead, text, MAX_TEXT_LENGTH};
+use crate::data_object_type_registry::*;
+use frame_benchmarking::benchmarks;
+use sp_std::vec;
+use system::RawOrigin;
+
+const MAX_ALLOWED_VALUES: usize = 10;
+
+// Creates the data object type with the description of the given length and the MIME type and
+// extension constraints.
+fn data_object_type(description_length: u32) -> DataObjectType {
+    DataObjectType {
+        description: text(description_length),
+        active: true,
+        max_size: Some(u64::max_value()),
+        allowed_mime_types: vec![text(10); MAX_ALLOWED_VALUES],
+        allowed_extensions: vec![text(3); MAX_ALLOWED_VALUES],
+        replication_factor: 1,
+    }
+}
+
+benchmarks! {
+    _ { }
+
+    register_data_object_type {
+        let i in 1 .. MAX_TEXT_LENGTH;
+
+        let lead_account_id = create_lead::<T>();
+        let type_id = Module::<T>::next_data_object_type_id();
+    }: _(RawOrigin::Signed(lead_account_id), data_object_type(i))
+    verify {
+        assert!(Module::<T>::data_object_types(type_id).is_some());
+    }
+
+    update_data_object_type {
+        let i in 1 .. MAX_TEXT_LENGTH;
+
+        let lead_account_id = create_lead::<T>();
+        let type_id = create_data_object_type::<T>();
+    }: _(RawOrigin::Signed(lead_account_id), type_id, data_object_type(i))
+    verify {
+        assert_eq!(Module::<T>::data_object_types(type_id), Some(data_object_type(i)));
+    }
+
+    activate_data_object_type {
+        let lead_account_id = create_lead::<T>();
+        let type_id = create_data_object_type::<T>();
+        Module::<T>::deactivate_data_object_type(
+            RawOrigin::Signed(lead_account_id.clone()).into(),
+            type_id,
+        )
+        .expect("data object type deactivated");
+    }: _(RawOrigin::Signed(lead_account_id), type_id)
+    verify {
+        assert!(Module::<T>::is_active_data_object_type(&type_id));
+    }
+
+    deactivate_data_object_type {
+        let lead_account_id = create_lead::<T>();
+        let type_id = create_data_object_type::<T>();
+    }: _(RawOrigin::Signed(lead_account_id), type_id)
+    verify {
+        assert!(!Module::<T>::is_active_data_object_type(&type_id));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::mock::{with_default_mock_builder, Test};
+    use frame_support::assert_ok;
+
+    #[test]
+    fn data_object_type_registry_benchmarks_run_successfully() {
+        with_default_mock_builder(|| {
+            assert_ok!(test_benchmark_register_data_object_type::<Test>());
+            assert_ok!(test_benchmark_update_data_object_type::<Test>());
+            assert_ok!(test_benchmark_activate_data_object_type::<Test>());
+            assert_ok!(test_benchmark_deactivate_data_object_type::<Test>());
+        });
+    }
+}
@@ -0,0 +1,105 @@
+//! Storage modules benchmarking.
+
+mod data_directory;
+mod data_object_storage_registry;
+mod data_object_type_registry;
+
+use codec::Decode;
+use common::constraints::InputValidationLengthConstraint;
+use common::origin::ActorOriginValidator;
+use frame_benchmarking::account;
+use frame_support::{StorageMap, StorageValue};
+use sp_std::vec;
+use sp_std::vec::Vec;
+
+use crate::data_directory::{
+    DataObject, DataObjectByContentId, DataObjectInternal, LiaisonJudgement, QuotaOwnerByContentId,
+    StorageObjectOwner, StorageProviderHelper,
+};
+use crate::data_object_type_registry::{DataObjectType, DataObjectTypes};
+use crate::{MemberId, StorageProviderId, StorageWorkingGroup, StorageWorkingGroupInstance};
+
+const SEED: u32 = 0;
+const MAX_TEXT_LENGTH: u32 = 1000;
+
+fn text(len: u32) -> Vec<u8> {
+    vec![b'a'; len as usize]
+}
+
+// Relaxes the text constraints of the storage working group to the texts of the benchmark hiring
+// and hires the leader. Returns the leader account.
+fn create_lead<T: working_group::Trait<StorageWorkingGroupInstance>>() -> T::AccountId {
+    let constraint = InputValidationLengthConstraint::new(1, MAX_TEXT_LENGTH as u16);
+
+    <working_group::OpeningHumanReadableText<StorageWorkingGroupInstance>>::put(constraint);
+    <working_group::WorkerApplicationHumanReadableText<StorageWorkingGroupInstance>>::put(
+        constraint,
+    );
+
+    let lead_account_id: T::AccountId = account("lead", 0, SEED);
+    let leader_worker_id = StorageWorkingGroup::<T>::insert_benchmark_lead(&lead_account_id);
+
+    StorageWorkingGroup::<T>::worker_by_id(leader_worker_id).role_account_id
+}
+
+// Hires the live storage provider. Requires the leader.
+fn create_storage_provider<T: crate::data_directory::Trait>() -> (T::AccountId, StorageProviderId<T>)
+{
+    let account_id: T::AccountId = account("storage_provider", 0, SEED);
+    let storage_provider_id =
+        T::StorageProviderHelper::create_benchmark_storage_provider(&account_id);
+
+    (account_id, storage_provider_id)
+}
+
+fn create_member<T: crate::data_directory::Trait>() -> (T::AccountId, MemberId<T>) {
+    let account_id: T::AccountId = account("member", 0, SEED);
+    let member_id = T::MemberOriginValidator::create_benchmark_actor(&account_id);
+
+    (account_id, member_id)
+}
+
+// Registers the active data object type without the constraints.
+fn create_data_object_type<T: crate::data_object_type_registry::Trait>() -> T::DataObjectTypeId {
+    let type_id = <crate::data_object_type_registry::Module<T>>::first_data_object_type_id();
+    <DataObjectTypes<T>>::insert(type_id, DataObjectType::default());
+
+    type_id
+}
+
+// Decodes the content id from the index padded with zeros, as the content id types can't be
+// converted from the integers.
+fn content_id_from_index<T: crate::data_directory::Trait>(index: u32) -> T::ContentId {
+    let mut bytes = [0u8; 32];
+    bytes[..4].copy_from_slice(&index.to_le_bytes());
+
+    T::ContentId::decode(&mut &bytes[..]).expect("content id decoded")
+}
+
+// Creates the data object awaiting the judgement of the liaison.
+fn data_object<T: crate::data_directory::Trait>(
+    owner: MemberId<T>,
+    liaison: StorageProviderId<T>,
+) -> DataObject<T> {
+    DataObjectInternal {
+        owner,
+        added_at: common::current_block_time::<T>(),
+        type_id: create_data_object_type::<T>(),
+        size: 1,
+        liaison,
+        liaison_judgement: LiaisonJudgement::Pending,
+        ipfs_content_id: text(MAX_TEXT_LENGTH),
+        mime_type: Vec::new(),
+        extension: Vec::new(),
+    }
+}
+
+// Inserts the data object counted in the storage quota of its owner.
+fn insert_data_object<T: crate::data_directory::Trait>(
+    content_id: T::ContentId,
+    owner: MemberId<T>,
+    liaison: StorageProviderId<T>,
+) {
+    <DataObjectByContentId<T>>::insert(content_id, data_object::<T>(owner, liaison));
+    <QuotaOwnerByContentId<T>>::insert(content_id, StorageObjectOwner::<T>::Member(owner));
+}
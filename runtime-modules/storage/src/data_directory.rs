@@ -10,25 +10,44 @@
 //!
 //! ### Public extrinsic
 //! - [add_content](./struct.Module.html#method.add_content) - Adds the content to the system.
+//! - [add_channel_content](./struct.Module.html#method.add_channel_content) - Adds the content of the channel to the system.
+//! - [delete_content](./struct.Module.html#method.delete_content) - Deletes the content by its owner.
+//!
+//! ### Storage working group leader extrinsics
+//! - [set_quota_limits](./struct.Module.html#method.set_quota_limits) - Sets the storage quota limits of the member or the channel.
+//! - [set_default_quota_limits](./struct.Module.html#method.set_default_quota_limits) - Sets the default storage quota limits.
 //!
 //! ### Private extrinsics
 //! - accept_content - Storage provider accepts a content.
 //! - reject_content - Storage provider rejects a content.
-//! - remove_known_content_id - Removes the content id from the list of known content ids. Requires root privileges.
+//! - takedown_content - Content curator or storage working group leader removes a content.
+//! - remove_known_content_id - Removes the content id from the set of known content ids. Requires root privileges.
 //! - set_known_content_id - Sets the content id from the list of known content ids. Requires root privileges.
 //!
+//! ### Storage quotas
+//! Each member and each channel has a storage quota: the limits of the data objects number and of
+//! their total size. Added content is counted in the quota of the member or the channel until
+//! it is deleted or taken down. The owners without the explicit limits get the default limits.
+//! The quota limits can be set by the storage working group leader or by the root origin
+//! (eg.: the proposal). Injected data objects are not counted in the quotas: deleting them
+//! doesn't release any quota usage.
+//!
 
 // Do not delete! Cannot be uncommented by default, because of Parity decl_module! issue.
 //#![warn(missing_docs)]
 
 use codec::{Decode, Encode};
-use frame_support::dispatch::DispatchResult;
+use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::traits::Get;
-use frame_support::{decl_error, decl_event, decl_module, decl_storage, ensure, Parameter};
+use frame_support::weights::Weight;
+use frame_support::{
+    decl_error, decl_event, decl_module, decl_storage, ensure, IterableStorageMap, Parameter,
+};
 use sp_runtime::traits::{MaybeSerialize, Member};
+use sp_std::cell::Cell;
 use sp_std::collections::btree_map::BTreeMap;
 use sp_std::vec::Vec;
-use system::ensure_root;
+use system::{ensure_root, ensure_signed};
 
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
@@ -38,7 +57,63 @@ pub(crate) use common::BlockAndTime;
 
 use crate::data_object_type_registry;
 use crate::data_object_type_registry::IsActiveDataObjectType;
-use crate::{MemberId, StorageProviderId, StorageWorkingGroup, StorageWorkingGroupInstance};
+use crate::{
+    ChannelId, MemberId, StorageProviderId, StorageWorkingGroup, StorageWorkingGroupInstance,
+};
+
+// Storage versions of the module. Each version is reached by its migration.
+const STORAGE_VERSION_DATA_OBJECT_MEDIA_TYPES: u32 = 1;
+const STORAGE_VERSION_STORAGE_QUOTAS: u32 = 2;
+const STORAGE_VERSION_KNOWN_CONTENT_IDS_MIGRATION: u32 = 3;
+const STORAGE_VERSION: u32 = STORAGE_VERSION_KNOWN_CONTENT_IDS_MIGRATION;
+
+/// Extrinsic weights for the _Data directory_ module.
+pub trait WeightInfo {
+    fn add_content(i: u32) -> Weight;
+    fn accept_content() -> Weight;
+    fn reject_content() -> Weight;
+    fn remove_known_content_id() -> Weight;
+    fn inject_data_objects(o: u32) -> Weight;
+    fn delete_content() -> Weight;
+    fn takedown_content() -> Weight;
+    fn add_channel_content(i: u32) -> Weight;
+    fn set_quota_limits() -> Weight;
+    fn set_default_quota_limits() -> Weight;
+}
+
+// Default weights, used in the tests and by the runtimes without benchmarked weights.
+impl WeightInfo for () {
+    fn add_content(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn accept_content() -> Weight {
+        10_000_000
+    }
+    fn reject_content() -> Weight {
+        10_000_000
+    }
+    fn remove_known_content_id() -> Weight {
+        10_000_000
+    }
+    fn inject_data_objects(_o: u32) -> Weight {
+        10_000_000
+    }
+    fn delete_content() -> Weight {
+        10_000_000
+    }
+    fn takedown_content() -> Weight {
+        10_000_000
+    }
+    fn add_channel_content(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn set_quota_limits() -> Weight {
+        10_000_000
+    }
+    fn set_default_quota_limits() -> Weight {
+        10_000_000
+    }
+}
 
 /// The _Data directory_ main _Trait_.
 pub trait Trait:
@@ -64,6 +139,21 @@ pub trait Trait:
     type MemberOriginValidator: ActorOriginValidator<Self::Origin, MemberId<Self>, Self::AccountId>;
 
     type MaxObjectsPerInjection: Get<u32>;
+
+    /// Maximum number of the ids moved from the deprecated list of known content ids per block.
+    type MaxKnownContentIdsMigratedPerBlock: Get<u32>;
+
+    /// Authorizes content curators to take down any content.
+    type ContentCurators: ContentCurators<Self>;
+
+    /// Cleans up the data associated with the removed content.
+    type ContentRemovedHandler: ContentRemovedHandler<Self>;
+
+    /// Authorizes channel owners to add the channel content.
+    type ChannelOwners: ChannelOwners<Self>;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
 }
 
 decl_error! {
@@ -85,7 +175,19 @@ decl_error! {
         RequireRootOrigin,
 
         /// DataObject Injection Failed. Too Many DataObjects.
-        DataObjectsInjectionExceededLimit
+        DataObjectsInjectionExceededLimit,
+
+        /// Only the owner of the content may delete it.
+        OwnerRequired,
+
+        /// Only the channel owner may add the channel content.
+        ChannelOwnerRequired,
+
+        /// The storage quota objects limit of the owner exceeded.
+        QuotaObjectsLimitExceeded,
+
+        /// The storage quota size limit of the owner exceeded.
+        QuotaSizeLimitExceeded,
     }
 }
 
@@ -142,19 +244,142 @@ pub struct DataObjectInternal<MemberId, BlockNumber, Moment, DataObjectTypeId, S
 
     /// IPFS content id.
     pub ipfs_content_id: Vec<u8>,
+
+    /// MIME type of the content declared by the owner. Storage providers may reject the content
+    /// of the different type.
+    pub mime_type: Vec<u8>,
+
+    /// File extension of the content declared by the owner, without the leading dot.
+    pub extension: Vec<u8>,
+}
+
+// Data object encoding before the MIME type and the extension were stored.
+#[derive(Decode)]
+struct DataObjectWithoutMediaType<
+    MemberId,
+    BlockNumber,
+    Moment,
+    DataObjectTypeId,
+    StorageProviderId,
+> {
+    owner: MemberId,
+    added_at: BlockAndTime<BlockNumber, Moment>,
+    type_id: DataObjectTypeId,
+    size: u64,
+    liaison: StorageProviderId,
+    liaison_judgement: LiaisonJudgement,
+    ipfs_content_id: Vec<u8>,
 }
 
+type DataObjectWithoutMediaTypeOf<T> = DataObjectWithoutMediaType<
+    MemberId<T>,
+    <T as system::Trait>::BlockNumber,
+    <T as pallet_timestamp::Trait>::Moment,
+    <T as data_object_type_registry::Trait>::DataObjectTypeId,
+    StorageProviderId<T>,
+>;
+
 /// A map collection of unique DataObjects keyed by the ContentId
 pub type DataObjectsMap<T> = BTreeMap<<T as Trait>::ContentId, DataObject<T>>;
 
+/// Alias for StorageObjectOwnerInternal
+pub type StorageObjectOwner<T> = StorageObjectOwnerInternal<MemberId<T>, ChannelId<T>>;
+
+/// Owner of the storage quota.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Encode, Decode, PartialEq, Eq, Debug)]
+pub enum StorageObjectOwnerInternal<MemberId, ChannelId> {
+    /// Member adding the content.
+    Member(MemberId),
+
+    /// Channel the content is added to.
+    Channel(ChannelId),
+}
+
+/// Storage quota limits.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Encode, Decode, Default, PartialEq, Eq, Debug)]
+pub struct QuotaLimits {
+    /// Max number of the data objects.
+    pub objects_limit: u64,
+
+    /// Max total size of the data objects in bytes.
+    pub size_limit: u64,
+}
+
+/// Storage quota usage.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Encode, Decode, Default, PartialEq, Eq, Debug)]
+pub struct QuotaUsage {
+    /// Number of the data objects.
+    pub objects: u64,
+
+    /// Total size of the data objects in bytes.
+    pub size: u64,
+}
+
 decl_storage! {
     trait Store for Module<T: Trait> as DataDirectory {
-        /// List of ids known to the system.
-        pub KnownContentIds get(fn known_content_ids) config(): Vec<T::ContentId> = Vec::new();
+        /// Deprecated list of ids known to the system. Moved to the indexed set by
+        /// `migrate_known_content_ids` over several blocks and removed afterwards.
+        pub KnownContentIds: Vec<T::ContentId>;
+
+        /// Position of the next id of the deprecated list of known content ids to move to the
+        /// indexed set. Set by `migrate_storage`, removed with the deprecated list.
+        pub KnownContentIdsMigrationPosition get(fn known_content_ids_migration_position):
+            Option<u64>;
+
+        /// Ids removed from the known content ids while the deprecated list is moved. They are
+        /// not moved to the indexed set, and are cleared after the move over several blocks.
+        pub RemovedUnmigratedContentIds: map hasher(blake2_128_concat) T::ContentId => ();
+
+        /// Number of ids known to the system.
+        pub KnownContentIdsCount get(fn known_content_ids_count): u64;
+
+        /// Maps positions in the indexed set to the ids known to the system.
+        pub KnownContentIdByIndex get(fn known_content_id_by_index):
+            map hasher(blake2_128_concat) u64 => T::ContentId;
+
+        /// Maps ids known to the system to their positions in the indexed set.
+        pub KnownContentIndexById get(fn known_content_index_by_id):
+            map hasher(blake2_128_concat) T::ContentId => Option<u64>;
 
         /// Maps data objects by their content id.
         pub DataObjectByContentId get(fn data_object_by_content_id) config():
             map hasher(blake2_128_concat) T::ContentId => Option<DataObject<T>>;
+
+        /// Maps the channel content ids to their channels.
+        pub ChannelIdByContentId get(fn channel_id_by_content_id):
+            map hasher(blake2_128_concat) T::ContentId => Option<ChannelId<T>>;
+
+        /// Storage quota limits of the owners without the explicit limits.
+        pub DefaultQuotaLimits get(fn default_quota_limits) config(): QuotaLimits;
+
+        /// Maps the owners to their explicit storage quota limits.
+        pub QuotaLimitsByOwner get(fn quota_limits_by_owner):
+            map hasher(blake2_128_concat) StorageObjectOwner<T> => Option<QuotaLimits>;
+
+        /// Maps the owners to their storage quota usage.
+        pub QuotaUsageByOwner get(fn quota_usage_by_owner):
+            map hasher(blake2_128_concat) StorageObjectOwner<T> => QuotaUsage;
+
+        /// Maps the content ids of the data objects counted in the storage quotas to their
+        /// owners. The injected data objects are not counted.
+        pub QuotaOwnerByContentId get(fn quota_owner_by_content_id):
+            map hasher(blake2_128_concat) T::ContentId => Option<StorageObjectOwner<T>>;
+
+        /// Version of the module storage. New chains start with the current version, the older
+        /// versions are updated by `migrate_storage`.
+        pub StorageVersion get(fn storage_version) build(|_config: &GenesisConfig<T>|
+            STORAGE_VERSION): u32;
+    }
+    add_extra_genesis {
+        config(known_content_ids): Vec<T::ContentId>;
+        build(|config: &GenesisConfig<T>| {
+            for content_id in config.known_content_ids.iter() {
+                <Module<T>>::add_known_content_id(*content_id);
+            }
+        });
     }
 }
 
@@ -163,7 +388,8 @@ decl_event! {
     pub enum Event<T> where
         <T as Trait>::ContentId,
         MemberId = MemberId<T>,
-        StorageProviderId = StorageProviderId<T>
+        StorageProviderId = StorageProviderId<T>,
+        StorageObjectOwner = StorageObjectOwner<T>
     {
         /// Emits on adding of the content.
         /// Params:
@@ -182,6 +408,30 @@ decl_event! {
         /// - Id of the relationship.
         /// - Id of the storage provider.
         ContentRejected(ContentId, StorageProviderId),
+
+        /// Emits when the owner deletes a content.
+        /// Params:
+        /// - Id of the content.
+        /// - Id of the member.
+        /// - IPFS content id to be garbage-collected by the storage providers.
+        ContentDeleted(ContentId, MemberId, Vec<u8>),
+
+        /// Emits when a content curator or the storage working group leader takes down a content.
+        /// Params:
+        /// - Id of the content.
+        /// - IPFS content id to be garbage-collected by the storage providers.
+        ContentTakenDown(ContentId, Vec<u8>),
+
+        /// Emits on updating of the storage quota limits of the owner.
+        /// Params:
+        /// - Owner of the storage quota.
+        /// - New limits, none if the default limits apply.
+        QuotaLimitsUpdated(StorageObjectOwner, Option<QuotaLimits>),
+
+        /// Emits on updating of the default storage quota limits.
+        /// Params:
+        /// - New default limits.
+        DefaultQuotaLimitsUpdated(QuotaLimits),
     }
 }
 
@@ -197,52 +447,185 @@ decl_module! {
         /// Maximum objects allowed per inject_data_objects() transaction
         const MaxObjectsPerInjection: u32 = T::MaxObjectsPerInjection::get();
 
-        /// Adds the content to the system. Member id should match its origin. The created DataObject
-        /// awaits liaison to accept or reject it.
-        #[weight = 10_000_000] // TODO: adjust weight
+        /// Maximum number of the ids moved from the deprecated list of known content ids per block.
+        const MaxKnownContentIdsMigratedPerBlock: u32 = T::MaxKnownContentIdsMigratedPerBlock::get();
+
+        fn on_initialize() -> Weight {
+            Self::migrate_known_content_ids()
+        }
+
+        /// Adds the content to the system. Member id should match its origin. The content size,
+        /// MIME type and extension should satisfy the constraints of its data object type. The
+        /// created DataObject keeps the MIME type and extension and awaits liaison to accept or
+        /// reject it. The content is counted in the storage quota of the member.
+        #[weight = <T as Trait>::WeightInfo::add_content(ipfs_content_id.len() as u32)]
         pub fn add_content(
             origin,
             member_id: MemberId<T>,
             content_id: T::ContentId,
             type_id: <T as data_object_type_registry::Trait>::DataObjectTypeId,
             size: u64,
-            ipfs_content_id: Vec<u8>
+            ipfs_content_id: Vec<u8>,
+            mime_type: Vec<u8>,
+            extension: Vec<u8>
         ) {
             T::MemberOriginValidator::ensure_actor_origin(
                 origin,
                 member_id,
             )?;
 
-            ensure!(T::IsActiveDataObjectType::is_active_data_object_type(&type_id),
-                Error::<T>::DataObjectTypeMustBeActive);
+            let quota_owner = StorageObjectOwner::<T>::Member(member_id);
+
+            let data = Self::ensure_data_object_can_be_added(
+                &quota_owner,
+                member_id,
+                content_id,
+                type_id,
+                size,
+                ipfs_content_id,
+                mime_type,
+                extension,
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::insert_data_object(quota_owner, content_id, data);
+
+            Self::deposit_event(RawEvent::ContentAdded(content_id, member_id));
+        }
+
+        /// Adds the content of the channel to the system. Member id should match its origin and
+        /// the channel owner. The content is counted in the storage quota of the channel.
+        #[weight = <T as Trait>::WeightInfo::add_channel_content(ipfs_content_id.len() as u32)]
+        pub fn add_channel_content(
+            origin,
+            member_id: MemberId<T>,
+            channel_id: ChannelId<T>,
+            content_id: T::ContentId,
+            type_id: <T as data_object_type_registry::Trait>::DataObjectTypeId,
+            size: u64,
+            ipfs_content_id: Vec<u8>,
+            mime_type: Vec<u8>,
+            extension: Vec<u8>
+        ) {
+            T::MemberOriginValidator::ensure_actor_origin(
+                origin,
+                member_id,
+            )?;
 
-            ensure!(!<DataObjectByContentId<T>>::contains_key(content_id),
-                Error::<T>::DataObjectAlreadyAdded);
+            ensure!(
+                T::ChannelOwners::is_channel_owner(&member_id, &channel_id),
+                Error::<T>::ChannelOwnerRequired
+            );
 
-            let liaison = T::StorageProviderHelper::get_random_storage_provider()?;
+            let quota_owner = StorageObjectOwner::<T>::Channel(channel_id);
 
-            // Let's create the entry then
-            let data: DataObject<T> = DataObjectInternal {
+            let data = Self::ensure_data_object_can_be_added(
+                &quota_owner,
+                member_id,
+                content_id,
                 type_id,
                 size,
-                added_at: common::current_block_time::<T>(),
-                owner: member_id,
-                liaison,
-                liaison_judgement: LiaisonJudgement::Pending,
                 ipfs_content_id,
-            };
+                mime_type,
+                extension,
+            )?;
 
             //
             // == MUTATION SAFE ==
             //
 
-            <DataObjectByContentId<T>>::insert(&content_id, data);
+            Self::insert_data_object(quota_owner, content_id, data);
+            <ChannelIdByContentId<T>>::insert(content_id, channel_id);
+
             Self::deposit_event(RawEvent::ContentAdded(content_id, member_id));
         }
 
+        /// Deletes the content from the system. Member id should match its origin and
+        /// the content owner.
+        #[weight = <T as Trait>::WeightInfo::delete_content()]
+        pub fn delete_content(
+            origin,
+            member_id: MemberId<T>,
+            content_id: T::ContentId
+        ) {
+            T::MemberOriginValidator::ensure_actor_origin(
+                origin,
+                member_id,
+            )?;
+
+            let data = Self::data_object_by_content_id(&content_id).ok_or(Error::<T>::CidNotFound)?;
+
+            ensure!(data.owner == member_id, Error::<T>::OwnerRequired);
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::remove_content(content_id, &data);
+
+            Self::deposit_event(RawEvent::ContentDeleted(content_id, member_id, data.ipfs_content_id));
+        }
+
+        /// Content curator or the storage working group leader removes the content from the system.
+        #[weight = <T as Trait>::WeightInfo::takedown_content()]
+        pub(crate) fn takedown_content(origin, content_id: T::ContentId) {
+            let account_id = ensure_signed(origin.clone())?;
+
+            if !T::ContentCurators::is_content_curator(&account_id) {
+                <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
+            }
+
+            let data = Self::data_object_by_content_id(&content_id).ok_or(Error::<T>::CidNotFound)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::remove_content(content_id, &data);
+
+            Self::deposit_event(RawEvent::ContentTakenDown(content_id, data.ipfs_content_id));
+        }
+
+        /// Sets the storage quota limits of the member or the channel. `None` restores
+        /// the default limits. Requires leader privileges or the root origin.
+        #[weight = <T as Trait>::WeightInfo::set_quota_limits()]
+        pub fn set_quota_limits(
+            origin,
+            owner: StorageObjectOwner<T>,
+            limits: Option<QuotaLimits>
+        ) {
+            Self::ensure_root_or_storage_lead(origin)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <QuotaLimitsByOwner<T>>::mutate(&owner, |owner_limits| *owner_limits = limits);
+
+            Self::deposit_event(RawEvent::QuotaLimitsUpdated(owner, limits));
+        }
+
+        /// Sets the storage quota limits of the owners without the explicit limits.
+        /// Requires leader privileges or the root origin.
+        #[weight = <T as Trait>::WeightInfo::set_default_quota_limits()]
+        pub fn set_default_quota_limits(origin, limits: QuotaLimits) {
+            Self::ensure_root_or_storage_lead(origin)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            DefaultQuotaLimits::put(limits);
+
+            Self::deposit_event(RawEvent::DefaultQuotaLimitsUpdated(limits));
+        }
+
         /// Storage provider accepts a content. Requires signed storage provider account and its id.
         /// The LiaisonJudgement can be updated, but only by the liaison.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::accept_content()]
         pub(crate) fn accept_content(
             origin,
             storage_provider_id: StorageProviderId<T>,
@@ -254,14 +637,14 @@ decl_module! {
 
             Self::update_content_judgement(&storage_provider_id, content_id, LiaisonJudgement::Accepted)?;
 
-            <KnownContentIds<T>>::mutate(|ids| ids.push(content_id));
+            Self::add_known_content_id(content_id);
 
             Self::deposit_event(RawEvent::ContentAccepted(content_id, storage_provider_id));
         }
 
         /// Storage provider rejects a content. Requires signed storage provider account and its id.
         /// The LiaisonJudgement can be updated, but only by the liaison.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::reject_content()]
         pub(crate) fn reject_content(
             origin,
             storage_provider_id: StorageProviderId<T>,
@@ -277,26 +660,23 @@ decl_module! {
 
         // Sudo methods
 
-        /// Removes the content id from the list of known content ids. Requires root privileges.
-        #[weight = 10_000_000] // TODO: adjust weight
-        fn remove_known_content_id(origin, content_id: T::ContentId) {
+        /// Removes the content id from the set of known content ids. Requires root privileges.
+        #[weight = <T as Trait>::WeightInfo::remove_known_content_id()]
+        pub(crate) fn remove_known_content_id(origin, content_id: T::ContentId) {
             ensure_root(origin)?;
 
             // == MUTATION SAFE ==
 
-            let upd_content_ids: Vec<T::ContentId> = Self::known_content_ids()
-                .into_iter()
-                .filter(|&id| id != content_id)
-                .collect();
-            <KnownContentIds<T>>::put(upd_content_ids);
+            Self::delete_known_content_id(content_id);
         }
 
         /// Injects a set of data objects and their corresponding content id into the directory.
         /// The operation is "silent" - no events will be emitted as objects are added.
         /// The number of objects that can be added per call is limited to prevent the dispatch
         /// from causing the block production to fail if it takes too much time to process.
-        /// Existing data objects will be overwritten.
-        #[weight = 10_000_000] // TODO: adjust weight
+        /// Existing data objects will be overwritten: their storage quota usage is released and
+        /// their channels are unset.
+        #[weight = <T as Trait>::WeightInfo::inject_data_objects(objects.len() as u32)]
         pub(crate) fn inject_data_objects(origin, objects: DataObjectsMap<T>) {
             ensure_root(origin)?;
 
@@ -304,22 +684,330 @@ decl_module! {
             ensure!(objects.len() <= T::MaxObjectsPerInjection::get() as usize, Error::<T>::DataObjectsInjectionExceededLimit);
 
             for (id, object) in objects.into_iter() {
-                // append to known content ids
-                // duplicates will be removed at the end
-                <KnownContentIds<T>>::mutate(|ids| ids.push(id));
+                if let Some(data) = Self::data_object_by_content_id(id) {
+                    Self::release_storage_quota(id, &data);
+                    <ChannelIdByContentId<T>>::remove(id);
+                }
+
+                // add to known content ids, already known ids are skipped
+                Self::add_known_content_id(id);
                 <DataObjectByContentId<T>>::insert(id, object);
             }
-
-            // remove duplicate ids
-            <KnownContentIds<T>>::mutate(|ids| {
-                ids.sort();
-                ids.dedup();
-            });
         }
     }
 }
 
 impl<T: Trait> Module<T> {
+    /// Returns the storage quota limits of the owner: its explicit limits or the default limits.
+    pub fn quota_limits(owner: &StorageObjectOwner<T>) -> QuotaLimits {
+        Self::quota_limits_by_owner(owner).unwrap_or_else(Self::default_quota_limits)
+    }
+
+    /// Returns a page of the ids known to the system, starting from the provided position.
+    pub fn known_content_ids(start: u64, limit: u64) -> Vec<T::ContentId> {
+        let end = start
+            .saturating_add(limit)
+            .min(Self::known_content_ids_count());
+
+        (start..end).map(<KnownContentIdByIndex<T>>::get).collect()
+    }
+
+    /// Moves the next ids of the deprecated list of known content ids to the indexed set, at
+    /// most `MaxKnownContentIdsMigratedPerBlock` per call, starting from the migration position.
+    /// The list is removed after its last id is moved. Then the ids removed during the move are
+    /// cleared, at most `MaxKnownContentIdsMigratedPerBlock` per call. Returns the weight of the
+    /// migration step.
+    pub fn migrate_known_content_ids() -> Weight {
+        let max_content_ids = T::MaxKnownContentIdsMigratedPerBlock::get();
+
+        let position = match Self::known_content_ids_migration_position() {
+            Some(position) => position,
+            None => return Self::clear_removed_unmigrated_content_ids(max_content_ids),
+        };
+
+        let content_ids = <KnownContentIds<T>>::get();
+        let next_content_ids = content_ids
+            .iter()
+            .skip(position as usize)
+            .take(max_content_ids as usize);
+
+        let mut content_ids_count: Weight = 0;
+        for content_id in next_content_ids {
+            if !<RemovedUnmigratedContentIds<T>>::contains_key(content_id) {
+                Self::add_known_content_id(*content_id);
+            }
+
+            content_ids_count += 1;
+        }
+
+        let next_position = position.saturating_add(content_ids_count);
+
+        if next_position >= content_ids.len() as u64 {
+            <KnownContentIds<T>>::kill();
+            KnownContentIdsMigrationPosition::kill();
+        } else {
+            KnownContentIdsMigrationPosition::put(next_position);
+        }
+
+        // Reads: the position, the deprecated list and per id: the removed entry, its index and
+        // the count. Writes: the position, the deprecated list and per id: both index entries
+        // and the count.
+        T::DbWeight::get().reads_writes(2 + 3 * content_ids_count, 2 + 3 * content_ids_count)
+    }
+
+    // Clears at most `max_content_ids` ids removed while the deprecated list of known content
+    // ids was moved. Returns the weight of the step.
+    fn clear_removed_unmigrated_content_ids(max_content_ids: u32) -> Weight {
+        let content_ids_count = <RemovedUnmigratedContentIds<T>>::drain()
+            .take(max_content_ids as usize)
+            .count() as Weight;
+
+        // Reads: the position, the next removed id and the next removed id per id. Writes: the
+        // removed entry per id.
+        T::DbWeight::get().reads_writes(2 + content_ids_count, content_ids_count)
+    }
+
+    // Starts moving the deprecated list of known content ids to the indexed set over several
+    // blocks, unless the list is absent. Returns the weight of the migration.
+    pub(crate) fn start_known_content_ids_migration() -> Weight {
+        if !<KnownContentIds<T>>::exists() {
+            return T::DbWeight::get().reads(1);
+        }
+
+        KnownContentIdsMigrationPosition::put(0);
+
+        T::DbWeight::get().reads_writes(1, 1)
+    }
+
+    /// Migrates the module storage from its version to the current version. Each migration runs
+    /// once. Returns the weight of the migrations.
+    pub fn migrate_storage() -> Weight {
+        let version = Self::storage_version();
+        let mut weight = T::DbWeight::get().reads(1);
+
+        if version >= STORAGE_VERSION {
+            return weight;
+        }
+
+        if version < STORAGE_VERSION_DATA_OBJECT_MEDIA_TYPES {
+            weight = weight.saturating_add(Self::migrate_data_objects());
+        }
+
+        if version < STORAGE_VERSION_STORAGE_QUOTAS {
+            weight = weight.saturating_add(Self::migrate_storage_quotas());
+        }
+
+        if version < STORAGE_VERSION_KNOWN_CONTENT_IDS_MIGRATION {
+            weight = weight.saturating_add(Self::start_known_content_ids_migration());
+        }
+
+        StorageVersion::put(STORAGE_VERSION);
+
+        weight.saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    // Sets the default storage quota limits unless they are set, and counts the existing data
+    // objects in the storage quotas of their owners. Returns the weight of the migration.
+    pub(crate) fn migrate_storage_quotas() -> Weight {
+        let mut writes: Weight = 0;
+
+        if !DefaultQuotaLimits::exists() {
+            DefaultQuotaLimits::put(default_storage_quota_limits());
+            writes += 1;
+        }
+
+        let mut data_objects_count: Weight = 0;
+
+        for (content_id, data) in <DataObjectByContentId<T>>::iter() {
+            let quota_owner = match Self::channel_id_by_content_id(content_id) {
+                Some(channel_id) => StorageObjectOwner::<T>::Channel(channel_id),
+                None => StorageObjectOwner::<T>::Member(data.owner),
+            };
+
+            Self::count_storage_quota(quota_owner, content_id, &data);
+
+            data_objects_count += 1;
+        }
+
+        // Reads: the default limits and per data object: the data object, its channel and
+        // the usage of its owner. Writes: the default limits and per data object: the usage
+        // of its owner and its quota owner.
+        T::DbWeight::get().reads_writes(1 + 3 * data_objects_count, writes + 2 * data_objects_count)
+    }
+
+    // Adds the empty MIME type and extension to the existing data objects. Returns the weight
+    // of the migration.
+    pub(crate) fn migrate_data_objects() -> Weight {
+        // Counted in a cell, as the translation closure can't mutate the captured variables.
+        let data_objects_count = Cell::new(0 as Weight);
+
+        <DataObjectByContentId<T>>::translate(
+            |_content_id, data: DataObjectWithoutMediaTypeOf<T>| {
+                data_objects_count.set(data_objects_count.get() + 1);
+
+                Some(DataObjectInternal {
+                    owner: data.owner,
+                    added_at: data.added_at,
+                    type_id: data.type_id,
+                    size: data.size,
+                    liaison: data.liaison,
+                    liaison_judgement: data.liaison_judgement,
+                    ipfs_content_id: data.ipfs_content_id,
+                    mime_type: Vec::new(),
+                    extension: Vec::new(),
+                })
+            },
+        );
+
+        T::DbWeight::get().reads_writes(data_objects_count.get(), data_objects_count.get())
+    }
+
+    // Adds the id to the indexed set of known content ids, unless it is already known.
+    fn add_known_content_id(content_id: T::ContentId) {
+        if <KnownContentIndexById<T>>::contains_key(content_id) {
+            return;
+        }
+
+        let index = Self::known_content_ids_count();
+
+        <KnownContentIdByIndex<T>>::insert(index, content_id);
+        <KnownContentIndexById<T>>::insert(content_id, index);
+        KnownContentIdsCount::put(index + 1);
+    }
+
+    // Removes the id from the indexed set of known content ids. The last id takes its position.
+    // While the deprecated list is moved, the id is recorded as removed, so it is not moved to
+    // the indexed set later.
+    fn delete_known_content_id(content_id: T::ContentId) {
+        if KnownContentIdsMigrationPosition::exists() {
+            <RemovedUnmigratedContentIds<T>>::insert(content_id, ());
+        }
+
+        if let Some(index) = <KnownContentIndexById<T>>::take(content_id) {
+            let last_index = Self::known_content_ids_count() - 1;
+
+            if index != last_index {
+                let last_content_id = <KnownContentIdByIndex<T>>::get(last_index);
+
+                <KnownContentIdByIndex<T>>::insert(index, last_content_id);
+                <KnownContentIndexById<T>>::insert(last_content_id, index);
+            }
+
+            <KnownContentIdByIndex<T>>::remove(last_index);
+            KnownContentIdsCount::put(last_index);
+        }
+    }
+
+    // Removes the data object, its content id and the associated data. Releases the storage
+    // quota of the data object owner.
+    fn remove_content(content_id: T::ContentId, data: &DataObject<T>) {
+        Self::release_storage_quota(content_id, data);
+
+        <ChannelIdByContentId<T>>::remove(content_id);
+        <DataObjectByContentId<T>>::remove(content_id);
+        Self::delete_known_content_id(content_id);
+
+        T::ContentRemovedHandler::content_removed(&content_id);
+    }
+
+    // Validates the new data object and the storage quota of its owner.
+    fn ensure_data_object_can_be_added(
+        quota_owner: &StorageObjectOwner<T>,
+        member_id: MemberId<T>,
+        content_id: T::ContentId,
+        type_id: <T as data_object_type_registry::Trait>::DataObjectTypeId,
+        size: u64,
+        ipfs_content_id: Vec<u8>,
+        mime_type: Vec<u8>,
+        extension: Vec<u8>,
+    ) -> Result<DataObject<T>, DispatchError> {
+        ensure!(
+            T::IsActiveDataObjectType::is_active_data_object_type(&type_id),
+            Error::<T>::DataObjectTypeMustBeActive
+        );
+
+        T::IsActiveDataObjectType::ensure_data_object_constraints(
+            &type_id, size, &mime_type, &extension,
+        )?;
+
+        ensure!(
+            !<DataObjectByContentId<T>>::contains_key(content_id),
+            Error::<T>::DataObjectAlreadyAdded
+        );
+
+        let limits = Self::quota_limits(quota_owner);
+        let usage = Self::quota_usage_by_owner(quota_owner);
+
+        ensure!(
+            usage.objects < limits.objects_limit,
+            Error::<T>::QuotaObjectsLimitExceeded
+        );
+
+        ensure!(
+            usage.size.saturating_add(size) <= limits.size_limit,
+            Error::<T>::QuotaSizeLimitExceeded
+        );
+
+        let liaison = T::StorageProviderHelper::get_random_storage_provider()?;
+
+        // Let's create the entry then
+        Ok(DataObjectInternal {
+            type_id,
+            size,
+            added_at: common::current_block_time::<T>(),
+            owner: member_id,
+            liaison,
+            liaison_judgement: LiaisonJudgement::Pending,
+            ipfs_content_id,
+            mime_type,
+            extension,
+        })
+    }
+
+    // Saves the data object and counts it in the storage quota of its owner.
+    fn insert_data_object(
+        quota_owner: StorageObjectOwner<T>,
+        content_id: T::ContentId,
+        data: DataObject<T>,
+    ) {
+        Self::count_storage_quota(quota_owner, content_id, &data);
+
+        <DataObjectByContentId<T>>::insert(content_id, data);
+    }
+
+    // Counts the data object in the storage quota usage of the owner.
+    fn count_storage_quota(
+        quota_owner: StorageObjectOwner<T>,
+        content_id: T::ContentId,
+        data: &DataObject<T>,
+    ) {
+        <QuotaUsageByOwner<T>>::mutate(&quota_owner, |usage| {
+            usage.objects = usage.objects.saturating_add(1);
+            usage.size = usage.size.saturating_add(data.size);
+        });
+
+        <QuotaOwnerByContentId<T>>::insert(content_id, quota_owner);
+    }
+
+    // Releases the storage quota usage of the data object, if it was counted.
+    fn release_storage_quota(content_id: T::ContentId, data: &DataObject<T>) {
+        if let Some(quota_owner) = <QuotaOwnerByContentId<T>>::take(content_id) {
+            <QuotaUsageByOwner<T>>::mutate(quota_owner, |usage| {
+                usage.objects = usage.objects.saturating_sub(1);
+                usage.size = usage.size.saturating_sub(data.size);
+            });
+        }
+    }
+
+    // Ensures the origin is root or the storage working group leader.
+    fn ensure_root_or_storage_lead(origin: T::Origin) -> DispatchResult {
+        if ensure_root(origin.clone()).is_ok() {
+            return Ok(());
+        }
+
+        <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)
+    }
+
     fn update_content_judgement(
         storage_provider_id: &StorageProviderId<T>,
         content_id: T::ContentId,
@@ -341,10 +1029,60 @@ impl<T: Trait> Module<T> {
     }
 }
 
+/// Creates default storage quota limits: 10 000 data objects of 100 GB total size.
+pub fn default_storage_quota_limits() -> QuotaLimits {
+    QuotaLimits {
+        objects_limit: 10_000,
+        size_limit: 100_000_000_000,
+    }
+}
+
 /// Provides random storage provider id. We use it when assign the content to the storage provider.
 pub trait StorageProviderHelper<T: Trait> {
     /// Provides random storage provider id.
     fn get_random_storage_provider() -> Result<StorageProviderId<T>, &'static str>;
+
+    /// Provides up to `count` live storage provider ids to store the additional replicas of the
+    /// content. Excludes the storage providers storing the content. Provides fewer ids if there
+    /// are not enough live storage providers.
+    fn get_storage_providers_for_replication(
+        content_id: &T::ContentId,
+        excluded_ids: &[StorageProviderId<T>],
+        count: usize,
+    ) -> Vec<StorageProviderId<T>>;
+
+    /// Weight of the `get_storage_providers_for_replication` call, registered by the callers
+    /// processing the content in the block hooks.
+    fn get_storage_providers_for_replication_weight() -> Weight;
+
+    /// Hires the live storage provider with the account as its role account. Requires the storage
+    /// working group leader. Returns the storage provider id.
+    /// Used by the benchmarks only.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn create_benchmark_storage_provider(account_id: &T::AccountId) -> StorageProviderId<T>;
+}
+
+/// Authorizes content curators. We use it when the content is taken down.
+pub trait ContentCurators<T: Trait> {
+    /// Verifies that the account belongs to a content curator.
+    fn is_content_curator(account_id: &T::AccountId) -> bool;
+}
+
+/// Authorizes channel owners. We use it when the channel content is added.
+pub trait ChannelOwners<T: Trait> {
+    /// Verifies that the member owns the channel.
+    fn is_channel_owner(member_id: &MemberId<T>, channel_id: &ChannelId<T>) -> bool;
+
+    /// Creates the channel owned by the member. Returns the channel id.
+    /// Used by the benchmarks only.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn create_benchmark_channel(member_id: &MemberId<T>) -> ChannelId<T>;
+}
+
+/// Content removal handler. We use it to clean up the data associated with the removed content.
+pub trait ContentRemovedHandler<T: Trait> {
+    /// Cleans up the data associated with the removed content.
+    fn content_removed(content_id: &T::ContentId);
 }
 
 /// Content access helper.
@@ -13,6 +13,15 @@
 //! - [set_relationship_ready](./struct.Module.html#method.set_relationship_ready)- Activates storage provider-to-content relationship.
 //! - [unset_relationship_ready](./struct.Module.html#method.unset_relationship_ready) - Deactivates storage provider-to-content relationship.
 //!
+//! ## Replication
+//!
+//! Each data object should be stored by the number of storage providers defined by the replication
+//! factor of its data object type. When a storage provider leaves the storage working group, its
+//! relationships are removed and the content is assigned to the additional live storage providers.
+//! The relationships are processed in `on_initialize` in batches limited per block, the weight of
+//! the processed batch is registered for the block. The content is left under-replicated if there
+//! are not enough live storage providers.
+//!
 
 // Clippy linter requirement.
 // Disable it because of the substrate lib design. Example:
@@ -24,16 +33,49 @@
 
 use codec::{Codec, Decode, Encode};
 use frame_support::dispatch::DispatchResult;
-use frame_support::{decl_error, decl_event, decl_module, decl_storage, ensure, Parameter};
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+use frame_support::{
+    decl_error, decl_event, decl_module, decl_storage, ensure, IterableStorageDoubleMap,
+    IterableStorageMap, Parameter,
+};
 use sp_arithmetic::traits::BaseArithmetic;
 use sp_runtime::traits::{MaybeSerialize, Member};
 use sp_std::vec::Vec;
 
-use crate::data_directory::{self, ContentIdExists};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use crate::data_directory::{self, ContentIdExists, ContentRemovedHandler, StorageProviderHelper};
+use crate::data_object_type_registry;
 use crate::{StorageProviderId, StorageWorkingGroup, StorageWorkingGroupInstance};
+use working_group::WorkerExitHandler;
 
 const DEFAULT_FIRST_RELATIONSHIP_ID: u8 = 1;
 
+// Storage version with the relationships indexed by the storage providers.
+const STORAGE_VERSION: u32 = 1;
+
+/// Extrinsic weights for the _Data object storage registry_ module.
+pub trait WeightInfo {
+    fn add_relationship() -> Weight;
+    fn set_relationship_ready() -> Weight;
+    fn unset_relationship_ready() -> Weight;
+}
+
+// Default weights, used in the tests and by the runtimes without benchmarked weights.
+impl WeightInfo for () {
+    fn add_relationship() -> Weight {
+        10_000_000
+    }
+    fn set_relationship_ready() -> Weight {
+        10_000_000
+    }
+    fn unset_relationship_ready() -> Weight {
+        10_000_000
+    }
+}
+
 /// The _Data object storage registry_ main _Trait_.
 pub trait Trait:
     pallet_timestamp::Trait
@@ -56,6 +98,12 @@ pub trait Trait:
 
     /// Ensures that a content exists
     type ContentIdExists: data_directory::ContentIdExists<Self>;
+
+    /// Maximum number of the relationships of the exited storage providers reassigned per block.
+    type MaxReassignmentsPerBlock: Get<u32>;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
 }
 
 decl_error! {
@@ -88,6 +136,20 @@ pub struct DataObjectStorageRelationship<T: Trait> {
     pub ready: bool,
 }
 
+/// Replication state of the data object.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Encode, Decode, PartialEq, Eq, Debug)]
+pub struct ObjectReplication<ContentId> {
+    /// Content id.
+    pub content_id: ContentId,
+
+    /// Number of storage providers with the ready relationship.
+    pub ready_replicas: u32,
+
+    /// Number of storage providers required by the data object type.
+    pub replication_factor: u32,
+}
+
 decl_storage! {
     trait Store for Module<T: Trait> as DataObjectStorageRegistry {
 
@@ -105,6 +167,18 @@ decl_storage! {
         /// Keeps a list of storage relationships per content id.
         pub RelationshipsByContentId get(fn relationships_by_content_id): map hasher(blake2_128_concat)
             T::ContentId => Vec<T::DataObjectStorageRelationshipId>;
+
+        /// Keeps a set of storage relationships per storage provider id.
+        pub RelationshipsByStorageProviderId: double_map hasher(blake2_128_concat)
+            StorageProviderId<T>, hasher(blake2_128_concat) T::DataObjectStorageRelationshipId => ();
+
+        /// Exited storage providers with the relationships waiting to be reassigned.
+        pub ExitedStorageProviderIds get(fn exited_storage_provider_ids): Vec<StorageProviderId<T>>;
+
+        /// Version of the module storage. New chains start with the current version, the older
+        /// versions are updated by `migrate_relationships_by_storage_provider_id`.
+        pub StorageVersion get(fn storage_version) build(|_config: &GenesisConfig<T>|
+            STORAGE_VERSION): u32;
     }
 }
 
@@ -127,6 +201,13 @@ decl_event! {
         /// - Id of the relationship.
         /// - Current state of the relationship (True=Active).
         DataObjectStorageRelationshipReadyUpdated(DataObjectStorageRelationshipId, bool),
+
+        /// Emits when there are not enough live storage providers to replace the exited storage
+        /// provider of the content.
+        /// Params:
+        /// - Id of the content.
+        /// - Number of the missing storage providers.
+        ContentUnderReplicated(ContentId, u32),
     }
 }
 
@@ -139,9 +220,13 @@ decl_module! {
         /// Predefined errors.
         type Error = Error<T>;
 
+        fn on_initialize(_now: T::BlockNumber) -> Weight {
+            Self::reassign_exited_storage_providers_content()
+        }
+
         /// Add storage provider-to-content relationship. The storage provider should be registered
         /// in the storage working group.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::add_relationship()]
         pub fn add_relationship(origin, storage_provider_id: StorageProviderId<T>, cid: T::ContentId) {
             // Origin should match storage provider.
             <StorageWorkingGroup<T>>::ensure_worker_signed(origin, &storage_provider_id)?;
@@ -149,38 +234,16 @@ decl_module! {
             // Content ID must exist
             ensure!(T::ContentIdExists::has_content(&cid), Error::<T>::CidNotFound);
 
-            // Create new ID, data.
-            let new_id = Self::next_relationship_id();
-            let dosr: DataObjectStorageRelationship<T> = DataObjectStorageRelationship {
-                content_id: cid,
-                storage_provider_id,
-                ready: false,
-            };
-
             //
             // == MUTATION SAFE ==
             //
 
-            <Relationships<T>>::insert(new_id, dosr);
-            <NextRelationshipId<T>>::mutate(|n| {
-                *n += T::DataObjectStorageRelationshipId::from(1);
-            });
-
-            // Also add the DOSR to the list of DOSRs for the CID. Uniqueness is guaranteed
-            // by the map, so we can just append the new_id to the list.
-            let mut dosr_list = Self::relationships_by_content_id(cid);
-            dosr_list.push(new_id);
-            <RelationshipsByContentId<T>>::insert(cid, dosr_list);
-
-            // Emit event
-            Self::deposit_event(
-                RawEvent::DataObjectStorageRelationshipAdded(new_id, cid, storage_provider_id)
-            );
+            Self::insert_relationship(cid, storage_provider_id);
         }
 
         /// Activates storage provider-to-content relationship. The storage provider should be registered
         /// in the storage working group. A storage provider may flip their own ready state, but nobody else.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::set_relationship_ready()]
         pub fn set_relationship_ready(
             origin,
             storage_provider_id: StorageProviderId<T>,
@@ -191,7 +254,7 @@ decl_module! {
 
         /// Deactivates storage provider-to-content relationship. The storage provider should be registered
         /// in the storage working group. A storage provider may flip their own ready state, but nobody else.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::unset_relationship_ready()]
         pub fn unset_relationship_ready(
             origin,
             storage_provider_id: StorageProviderId<T>,
@@ -231,4 +294,234 @@ impl<T: Trait> Module<T> {
 
         Ok(())
     }
+
+    // Creates the relationship which is not ready yet and emits the event.
+    fn insert_relationship(content_id: T::ContentId, storage_provider_id: StorageProviderId<T>) {
+        // Create new ID, data.
+        let new_id = Self::next_relationship_id();
+        let dosr: DataObjectStorageRelationship<T> = DataObjectStorageRelationship {
+            content_id,
+            storage_provider_id,
+            ready: false,
+        };
+
+        <Relationships<T>>::insert(new_id, dosr);
+        <NextRelationshipId<T>>::mutate(|n| {
+            *n += T::DataObjectStorageRelationshipId::from(1);
+        });
+
+        // Also add the DOSR to the list of DOSRs for the CID and to the set of the storage provider.
+        // Uniqueness is guaranteed by the map, so we can just append the new_id to the list.
+        <RelationshipsByContentId<T>>::mutate(content_id, |ids| ids.push(new_id));
+        <RelationshipsByStorageProviderId<T>>::insert(storage_provider_id, new_id, ());
+
+        // Emit event
+        Self::deposit_event(RawEvent::DataObjectStorageRelationshipAdded(
+            new_id,
+            content_id,
+            storage_provider_id,
+        ));
+    }
+
+    /// Returns the number of storage providers required by the data object type of the content.
+    pub fn replication_factor(content_id: &T::ContentId) -> u32 {
+        let do_type = T::ContentIdExists::get_data_object(content_id)
+            .ok()
+            .and_then(|data| {
+                <data_object_type_registry::Module<T>>::data_object_types(data.type_id)
+            })
+            .unwrap_or_default();
+
+        do_type.replication_factor
+    }
+
+    /// Returns the replication state of the content.
+    pub fn object_replication(content_id: T::ContentId) -> ObjectReplication<T::ContentId> {
+        let ready_replicas = Self::relationships_by_content_id(content_id)
+            .into_iter()
+            .filter_map(Self::relationships)
+            .filter(|dosr| dosr.ready)
+            .count() as u32;
+
+        ObjectReplication {
+            content_id,
+            ready_replicas,
+            replication_factor: Self::replication_factor(&content_id),
+        }
+    }
+
+    /// Returns the under-replicated objects from the page of the content ids known to the system.
+    /// The page is defined by the positions of the ids in the data directory.
+    pub fn under_replicated_objects(
+        start: u64,
+        limit: u64,
+    ) -> Vec<ObjectReplication<T::ContentId>> {
+        <data_directory::Module<T>>::known_content_ids(start, limit)
+            .into_iter()
+            .map(Self::object_replication)
+            .filter(|replication| replication.ready_replicas < replication.replication_factor)
+            .collect()
+    }
+
+    // Removes the relationships of the exited storage providers and reassigns their content.
+    // Only T::MaxReassignmentsPerBlock relationships are processed per block, the rest is carried
+    // over to the next blocks. Returns the weight of the reassignment.
+    fn reassign_exited_storage_providers_content() -> Weight {
+        let db_weight = T::DbWeight::get();
+
+        let mut exited_ids = Self::exited_storage_provider_ids();
+        if exited_ids.is_empty() {
+            return db_weight.reads(1);
+        }
+
+        // Reads and writes the exited storage provider ids.
+        let mut weight = db_weight.reads_writes(1, 1);
+        let mut reassignments_left = T::MaxReassignmentsPerBlock::get() as usize;
+
+        while reassignments_left > 0 && !exited_ids.is_empty() {
+            let storage_provider_id = exited_ids[0];
+
+            // Only the processed relationships are read, the rest stays in place.
+            let batch = <RelationshipsByStorageProviderId<T>>::iter_prefix(storage_provider_id)
+                .take(reassignments_left)
+                .map(|(id, _)| id)
+                .collect::<Vec<_>>();
+            weight = weight.saturating_add(db_weight.reads(1 + batch.len() as Weight));
+
+            if batch.len() < reassignments_left {
+                exited_ids.remove(0);
+            }
+            reassignments_left -= batch.len();
+
+            for id in batch {
+                <RelationshipsByStorageProviderId<T>>::remove(storage_provider_id, id);
+                weight = weight
+                    .saturating_add(db_weight.writes(1))
+                    .saturating_add(Self::reassign_relationship(id));
+            }
+        }
+
+        <ExitedStorageProviderIds<T>>::put(exited_ids);
+
+        weight
+    }
+
+    // Removes the relationship of the exited storage provider and assigns its content to
+    // the additional storage providers if required. Returns the weight of the reassignment.
+    fn reassign_relationship(id: T::DataObjectStorageRelationshipId) -> Weight {
+        let db_weight = T::DbWeight::get();
+
+        // Takes the relationship.
+        let mut weight = db_weight.reads_writes(1, 1);
+
+        if let Some(dosr) = <Relationships<T>>::take(id) {
+            <RelationshipsByContentId<T>>::mutate(dosr.content_id, |ids| {
+                ids.retain(|dosr_id| *dosr_id != id)
+            });
+
+            weight = weight
+                .saturating_add(db_weight.reads_writes(1, 1))
+                .saturating_add(Self::assign_additional_storage_providers(dosr.content_id));
+        }
+
+        weight
+    }
+
+    // Assigns the content to the additional storage providers, if it is stored by fewer
+    // storage providers than required by the replication factor. Returns the weight of
+    // the assignment.
+    fn assign_additional_storage_providers(content_id: T::ContentId) -> Weight {
+        let db_weight = T::DbWeight::get();
+
+        let relationship_ids = Self::relationships_by_content_id(content_id);
+
+        // Reads the relationships of the content, the data object and its type.
+        let mut weight = db_weight.reads(3 + relationship_ids.len() as Weight);
+
+        let assigned_ids: Vec<StorageProviderId<T>> = relationship_ids
+            .into_iter()
+            .filter_map(Self::relationships)
+            .map(|dosr| dosr.storage_provider_id)
+            .collect();
+
+        let replication_factor = Self::replication_factor(&content_id) as usize;
+        if assigned_ids.len() >= replication_factor {
+            return weight;
+        }
+
+        let storage_provider_ids = T::StorageProviderHelper::get_storage_providers_for_replication(
+            &content_id,
+            &assigned_ids,
+            replication_factor - assigned_ids.len(),
+        );
+        weight = weight.saturating_add(
+            T::StorageProviderHelper::get_storage_providers_for_replication_weight(),
+        );
+
+        let missing_count = replication_factor
+            .saturating_sub(assigned_ids.len())
+            .saturating_sub(storage_provider_ids.len());
+        if missing_count > 0 {
+            Self::deposit_event(RawEvent::ContentUnderReplicated(
+                content_id,
+                missing_count as u32,
+            ));
+        }
+
+        // Each relationship insertion reads and writes the next relationship id and the content
+        // relationships, and writes the relationship and its storage provider index entry.
+        weight = weight.saturating_add(
+            db_weight
+                .reads_writes(2, 4)
+                .saturating_mul(storage_provider_ids.len() as Weight),
+        );
+
+        for storage_provider_id in storage_provider_ids {
+            Self::insert_relationship(content_id, storage_provider_id);
+        }
+
+        weight
+    }
+
+    /// Builds the sets of storage relationships per storage provider id. Runs once: the
+    /// relationships are already indexed if the storage version is current. Returns the weight of
+    /// the migration.
+    pub fn migrate_relationships_by_storage_provider_id() -> Weight {
+        if Self::storage_version() >= STORAGE_VERSION {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut relationships_count: Weight = 0;
+
+        for (id, dosr) in <Relationships<T>>::iter() {
+            relationships_count += 1;
+
+            <RelationshipsByStorageProviderId<T>>::insert(dosr.storage_provider_id, id, ());
+        }
+
+        StorageVersion::put(STORAGE_VERSION);
+
+        // Reads: the version and the relationships. Writes: the version and the set entry per
+        // relationship.
+        T::DbWeight::get().reads_writes(1 + relationships_count, 1 + relationships_count)
+    }
+}
+
+impl<T: Trait> ContentRemovedHandler<T> for Module<T> {
+    fn content_removed(content_id: &T::ContentId) {
+        // Storage providers no longer keep the removed content.
+        for id in <RelationshipsByContentId<T>>::take(content_id) {
+            if let Some(dosr) = <Relationships<T>>::take(id) {
+                <RelationshipsByStorageProviderId<T>>::remove(dosr.storage_provider_id, id);
+            }
+        }
+    }
+}
+
+impl<T: Trait> WorkerExitHandler<T, StorageWorkingGroupInstance> for Module<T> {
+    fn worker_exited(storage_provider_id: &StorageProviderId<T>) {
+        // The storage provider no longer keeps the content. Other storage providers replace it
+        // in the next blocks.
+        <ExitedStorageProviderIds<T>>::mutate(|ids| ids.push(*storage_provider_id));
+    }
 }
@@ -13,6 +13,12 @@
 //! - [activate_data_object_type](./struct.Module.html#method.activate_data_object_type) -  Activates existing data object type.
 //! - [deactivate_data_object_type](./struct.Module.html#method.deactivate_data_object_type) -  Deactivates existing data object type.
 //!
+//! ## Constraints
+//!
+//! Data object types define the maximum size, the allowed MIME types and the allowed file
+//! extensions of their data objects, validated by the data directory on content upload, and the
+//! number of storage providers required to store them.
+//!
 
 // Clippy linter requirement.
 // Disable it because of the substrate lib design. Example:
@@ -23,17 +29,50 @@
 //#![warn(missing_docs)]
 
 use codec::{Codec, Decode, Encode};
-use frame_support::dispatch::DispatchError;
+use frame_support::dispatch::{DispatchError, DispatchResult};
+use frame_support::traits::Get;
 use frame_support::weights::Weight;
-use frame_support::{decl_error, decl_event, decl_module, decl_storage, Parameter};
+use frame_support::{
+    decl_error, decl_event, decl_module, decl_storage, ensure, IterableStorageMap, Parameter,
+};
 use sp_arithmetic::traits::BaseArithmetic;
 use sp_runtime::traits::{MaybeSerialize, Member};
+use sp_std::cell::Cell;
 use sp_std::vec::Vec;
 
 use crate::{StorageWorkingGroup, StorageWorkingGroupInstance};
 
 const DEFAULT_TYPE_DESCRIPTION: &str = "Default data object type for audio and video content.";
 const DEFAULT_FIRST_DATA_OBJECT_TYPE_ID: u8 = 1;
+const DEFAULT_REPLICATION_FACTOR: u32 = 1;
+
+// Storage version with the data object type constraints.
+const STORAGE_VERSION: u32 = 1;
+
+/// Extrinsic weights for the _Data object type registry_ module.
+/// `i` - data object type description length in bytes.
+pub trait WeightInfo {
+    fn register_data_object_type(i: u32) -> Weight;
+    fn update_data_object_type(i: u32) -> Weight;
+    fn activate_data_object_type() -> Weight;
+    fn deactivate_data_object_type() -> Weight;
+}
+
+// Default weights, used in the tests and by the runtimes without benchmarked weights.
+impl WeightInfo for () {
+    fn register_data_object_type(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn update_data_object_type(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn activate_data_object_type() -> Weight {
+        10_000_000
+    }
+    fn deactivate_data_object_type() -> Weight {
+        10_000_000
+    }
+}
 
 /// The _Data object type registry_ main _Trait_.
 pub trait Trait: system::Trait + working_group::Trait<StorageWorkingGroupInstance> {
@@ -49,6 +88,9 @@ pub trait Trait: system::Trait + working_group::Trait<StorageWorkingGroupInstanc
         + Copy
         + MaybeSerialize
         + PartialEq;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
 }
 
 decl_error! {
@@ -59,6 +101,18 @@ decl_error! {
 
         /// Require root origin in extrinsics
         RequireRootOrigin,
+
+        /// Data object type should be stored by at least one storage provider.
+        ZeroReplicationFactor,
+
+        /// Data object size exceeds the maximum size of its data object type.
+        DataObjectSizeExceeded,
+
+        /// MIME type of the data object is not allowed by its data object type.
+        MimeTypeNotAllowed,
+
+        /// File extension of the data object is not allowed by its data object type.
+        ExtensionNotAllowed,
     }
 }
 
@@ -70,6 +124,19 @@ pub struct DataObjectType {
 
     /// Active/Disabled flag.
     pub active: bool,
+
+    /// Maximum data object size in bytes. No limit if none.
+    pub max_size: Option<u64>,
+
+    /// Allowed MIME types of the data objects. Any MIME type is allowed if empty.
+    pub allowed_mime_types: Vec<Vec<u8>>,
+
+    /// Allowed file extensions of the data objects, without the leading dot. Any extension is
+    /// allowed if empty.
+    pub allowed_extensions: Vec<Vec<u8>>,
+
+    /// Number of storage providers required to store the data objects.
+    pub replication_factor: u32,
 }
 
 impl Default for DataObjectType {
@@ -77,10 +144,45 @@ impl Default for DataObjectType {
         DataObjectType {
             description: DEFAULT_TYPE_DESCRIPTION.as_bytes().to_vec(),
             active: true,
+            max_size: None,
+            allowed_mime_types: Vec::new(),
+            allowed_extensions: Vec::new(),
+            replication_factor: DEFAULT_REPLICATION_FACTOR,
         }
     }
 }
 
+impl DataObjectType {
+    /// Verifies that the data object size doesn't exceed the maximum size.
+    pub fn allows_size(&self, size: u64) -> bool {
+        self.max_size.map_or(true, |max_size| size <= max_size)
+    }
+
+    /// Verifies that the MIME type is allowed. MIME types are compared case-insensitively.
+    pub fn allows_mime_type(&self, mime_type: &[u8]) -> bool {
+        Self::is_allowed(&self.allowed_mime_types, mime_type)
+    }
+
+    /// Verifies that the file extension is allowed. Extensions are compared case-insensitively.
+    pub fn allows_extension(&self, extension: &[u8]) -> bool {
+        Self::is_allowed(&self.allowed_extensions, extension)
+    }
+
+    fn is_allowed(allowed_values: &[Vec<u8>], value: &[u8]) -> bool {
+        allowed_values.is_empty()
+            || allowed_values
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(value))
+    }
+}
+
+// Data object type encoding before the constraints were introduced.
+#[derive(Decode)]
+struct DataObjectTypeWithoutConstraints {
+    description: Vec<u8>,
+    active: bool,
+}
+
 decl_storage! {
     trait Store for Module<T: Trait> as DataObjectTypeRegistry {
         /// Data object type ids should start at this value.
@@ -94,6 +196,11 @@ decl_storage! {
         /// Mapping of Data object types.
         pub DataObjectTypes get(fn data_object_types): map hasher(blake2_128_concat)
             T::DataObjectTypeId => Option<DataObjectType>;
+
+        /// Version of the module storage. New chains start with the current version, the older
+        /// versions are updated by `migrate_data_object_types`.
+        pub StorageVersion get(fn storage_version) build(|_config: &GenesisConfig<T>|
+            STORAGE_VERSION): u32;
     }
 }
 
@@ -130,20 +237,28 @@ decl_module! {
 
                 <DataObjectTypes<T>>::insert(new_type_id, do_type);
                 <NextDataObjectTypeId<T>>::mutate(|n| { *n += T::DataObjectTypeId::from(1); });
+
+                return T::DbWeight::get().reads_writes(4, 2);
             }
 
-            10_000_000 //TODO: adjust weight
+            T::DbWeight::get().reads(2)
         }
 
         /// Registers the new data object type. Requires leader privileges.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::register_data_object_type(data_object_type.description.len() as u32)]
         pub fn register_data_object_type(origin, data_object_type: DataObjectType) {
             <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
 
+            Self::ensure_valid_data_object_type(&data_object_type)?;
+
             let new_do_type_id = Self::next_data_object_type_id();
             let do_type: DataObjectType = DataObjectType {
                 description: data_object_type.description.clone(),
                 active: data_object_type.active,
+                max_size: data_object_type.max_size,
+                allowed_mime_types: data_object_type.allowed_mime_types.clone(),
+                allowed_extensions: data_object_type.allowed_extensions.clone(),
+                replication_factor: data_object_type.replication_factor,
             };
 
             //
@@ -157,14 +272,20 @@ decl_module! {
         }
 
         /// Updates existing data object type. Requires leader privileges.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::update_data_object_type(data_object_type.description.len() as u32)]
         pub fn update_data_object_type(origin, id: T::DataObjectTypeId, data_object_type: DataObjectType) {
             <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
 
             let mut do_type = Self::ensure_data_object_type(id)?;
 
+            Self::ensure_valid_data_object_type(&data_object_type)?;
+
             do_type.description = data_object_type.description.clone();
             do_type.active = data_object_type.active;
+            do_type.max_size = data_object_type.max_size;
+            do_type.allowed_mime_types = data_object_type.allowed_mime_types.clone();
+            do_type.allowed_extensions = data_object_type.allowed_extensions.clone();
+            do_type.replication_factor = data_object_type.replication_factor;
 
             //
             // == MUTATION SAFE ==
@@ -176,7 +297,7 @@ decl_module! {
         }
 
         /// Activates existing data object type. Requires leader privileges.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::activate_data_object_type()]
         pub fn activate_data_object_type(origin, id: T::DataObjectTypeId) {
             <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
 
@@ -194,7 +315,7 @@ decl_module! {
         }
 
         /// Deactivates existing data object type. Requires leader privileges.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::deactivate_data_object_type()]
         pub fn deactivate_data_object_type(origin, id: T::DataObjectTypeId) {
             <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
 
@@ -217,12 +338,56 @@ impl<T: Trait> Module<T> {
     fn ensure_data_object_type(id: T::DataObjectTypeId) -> Result<DataObjectType, DispatchError> {
         Self::data_object_types(&id).ok_or_else(|| Error::<T>::DataObjectTypeNotFound.into())
     }
+
+    fn ensure_valid_data_object_type(do_type: &DataObjectType) -> DispatchResult {
+        ensure!(
+            do_type.replication_factor > 0,
+            Error::<T>::ZeroReplicationFactor
+        );
+
+        Ok(())
+    }
+
+    /// Adds the default constraints to the existing data object types: no size, MIME type and
+    /// extension limits and a single storage provider. Runs once: the data object types are
+    /// already migrated if the storage version is current. Returns the weight of the migration.
+    pub fn migrate_data_object_types() -> Weight {
+        if Self::storage_version() >= STORAGE_VERSION {
+            return T::DbWeight::get().reads(1);
+        }
+
+        // Counted in a cell, as the translation closure can't mutate the captured variables.
+        let do_types_count = Cell::new(0 as Weight);
+
+        <DataObjectTypes<T>>::translate(|_id, do_type: DataObjectTypeWithoutConstraints| {
+            do_types_count.set(do_types_count.get() + 1);
+
+            Some(DataObjectType {
+                description: do_type.description,
+                active: do_type.active,
+                ..DataObjectType::default()
+            })
+        });
+
+        StorageVersion::put(STORAGE_VERSION);
+
+        T::DbWeight::get().reads_writes(1 + do_types_count.get(), 1 + do_types_count.get())
+    }
 }
 
 /// Active data object type validator trait.
 pub trait IsActiveDataObjectType<T: Trait> {
     /// Ensures that data object type with given id is active.
     fn is_active_data_object_type(id: &T::DataObjectTypeId) -> bool;
+
+    /// Ensures that the data object size, MIME type and file extension are allowed by its data
+    /// object type.
+    fn ensure_data_object_constraints(
+        id: &T::DataObjectTypeId,
+        size: u64,
+        mime_type: &[u8],
+        extension: &[u8],
+    ) -> DispatchResult;
 }
 
 impl<T: Trait> IsActiveDataObjectType<T> for Module<T> {
@@ -232,4 +397,30 @@ impl<T: Trait> IsActiveDataObjectType<T> for Module<T> {
             Err(_err) => false,
         }
     }
+
+    fn ensure_data_object_constraints(
+        id: &T::DataObjectTypeId,
+        size: u64,
+        mime_type: &[u8],
+        extension: &[u8],
+    ) -> DispatchResult {
+        let do_type = Self::ensure_data_object_type(*id)?;
+
+        ensure!(
+            do_type.allows_size(size),
+            Error::<T>::DataObjectSizeExceeded
+        );
+
+        ensure!(
+            do_type.allows_mime_type(mime_type),
+            Error::<T>::MimeTypeNotAllowed
+        );
+
+        ensure!(
+            do_type.allows_extension(extension),
+            Error::<T>::ExtensionNotAllowed
+        );
+
+        Ok(())
+    }
 }
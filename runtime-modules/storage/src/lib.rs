@@ -5,6 +5,8 @@ pub mod data_directory;
 pub mod data_object_storage_registry;
 pub mod data_object_type_registry;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 mod tests;
 
 // The storage working group instance alias.
@@ -16,5 +18,8 @@ pub(crate) type StorageWorkingGroup<T> = working_group::Module<T, StorageWorking
 // Alias for the member id.
 pub(crate) type MemberId<T> = <T as membership::Trait>::MemberId;
 
+// Alias for the channel id. Channels are identified by the membership actor ids.
+pub(crate) type ChannelId<T> = <T as membership::Trait>::ActorId;
+
 /// Storage provider is a worker from the working group module.
 pub type StorageProviderId<T> = working_group::WorkerId<T>;
@@ -1,6 +1,8 @@
 #![cfg(test)]
 
 use frame_support::dispatch::DispatchError;
+use frame_support::storage::unhashed;
+use frame_support::{StorageMap, StorageValue};
 use sp_std::collections::btree_map::BTreeMap;
 use system::RawOrigin;
 
@@ -19,6 +21,8 @@ fn succeed_adding_content() {
             1234,
             0,
             vec![1, 3, 3, 7],
+            b"video/mp4".to_vec(),
+            b"mp4".to_vec(),
         );
         assert!(res.is_ok());
     });
@@ -36,6 +40,8 @@ fn add_content_fails_with_invalid_origin() {
             1234,
             0,
             vec![1, 3, 3, 7],
+            b"video/mp4".to_vec(),
+            b"mp4".to_vec(),
         );
         assert_eq!(res, Err(DispatchError::Other("Bad origin")));
     });
@@ -61,6 +67,8 @@ fn accept_and_reject_content_fail_with_invalid_storage_provider() {
             1234,
             0,
             vec![1, 2, 3, 4],
+            b"video/mp4".to_vec(),
+            b"mp4".to_vec(),
         );
         assert!(res.is_ok());
 
@@ -111,6 +119,8 @@ fn accept_content_as_liaison() {
             1234,
             0,
             vec![1, 2, 3, 4],
+            b"video/mp4".to_vec(),
+            b"mp4".to_vec(),
         );
         assert!(res.is_ok());
 
@@ -162,6 +172,8 @@ fn reject_content_as_liaison() {
             1234,
             0,
             vec![1, 2, 3, 4],
+            b"video/mp4".to_vec(),
+            b"mp4".to_vec(),
         );
         assert!(res.is_ok());
 
@@ -197,7 +209,7 @@ fn reject_content_as_liaison() {
 fn data_object_injection_works() {
     with_default_mock_builder(|| {
         // No objects in directory before injection
-        assert_eq!(TestDataDirectory::known_content_ids(), vec![]);
+        assert_eq!(TestDataDirectory::known_content_ids(0, 10), vec![]);
 
         // new objects to inject into the directory
         let mut objects = BTreeMap::new();
@@ -213,6 +225,8 @@ fn data_object_injection_works() {
             liaison: TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID,
             liaison_judgement: data_directory::LiaisonJudgement::Pending,
             ipfs_content_id: vec![],
+            mime_type: vec![],
+            extension: vec![],
         };
 
         let content_id_1 = 1;
@@ -225,7 +239,7 @@ fn data_object_injection_works() {
         assert!(res.is_ok());
 
         assert_eq!(
-            TestDataDirectory::known_content_ids(),
+            TestDataDirectory::known_content_ids(0, 10),
             vec![content_id_1, content_id_2]
         );
 
@@ -258,6 +272,8 @@ fn data_object_injection_overwrites_and_removes_duplicate_ids() {
             1,
             10,
             vec![8, 8, 8, 8],
+            b"video/mp4".to_vec(),
+            b"mp4".to_vec(),
         );
         assert!(res.is_ok());
         let res = TestDataDirectory::add_content(
@@ -267,6 +283,8 @@ fn data_object_injection_overwrites_and_removes_duplicate_ids() {
             2,
             20,
             vec![9, 9, 9, 9],
+            b"video/mp4".to_vec(),
+            b"mp4".to_vec(),
         );
         assert!(res.is_ok());
 
@@ -283,6 +301,8 @@ fn data_object_injection_overwrites_and_removes_duplicate_ids() {
             liaison: TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID,
             liaison_judgement: data_directory::LiaisonJudgement::Pending,
             ipfs_content_id: vec![5, 6, 7],
+            mime_type: vec![],
+            extension: vec![],
         };
 
         let object2 = data_directory::DataObjectInternal {
@@ -296,6 +316,8 @@ fn data_object_injection_overwrites_and_removes_duplicate_ids() {
             liaison: TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID,
             liaison_judgement: data_directory::LiaisonJudgement::Pending,
             ipfs_content_id: vec![5, 6, 7],
+            mime_type: vec![],
+            extension: vec![],
         };
 
         objects.insert(content_id_1, object1.clone());
@@ -305,7 +327,7 @@ fn data_object_injection_overwrites_and_removes_duplicate_ids() {
         assert!(res.is_ok());
 
         assert_eq!(
-            TestDataDirectory::known_content_ids(),
+            TestDataDirectory::known_content_ids(0, 10),
             vec![content_id_1, content_id_2]
         );
 
@@ -320,3 +342,868 @@ fn data_object_injection_overwrites_and_removes_duplicate_ids() {
         );
     });
 }
+
+fn add_content_with_relationship(content_id: u64, ipfs_content_id: Vec<u8>) -> u64 {
+    let sender = 1u64;
+    let member_id = 1u64;
+
+    let res = TestDataDirectory::add_content(
+        Origin::signed(sender),
+        member_id,
+        content_id,
+        1,
+        1234,
+        ipfs_content_id,
+        b"video/mp4".to_vec(),
+        b"mp4".to_vec(),
+    );
+    assert!(res.is_ok());
+
+    let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+    let res = TestDataDirectory::accept_content(
+        Origin::signed(storage_provider_account_id),
+        storage_provider_id,
+        content_id,
+    );
+    assert!(res.is_ok());
+
+    let relationship_id = TEST_FIRST_RELATIONSHIP_ID;
+    <data_object_storage_registry::Relationships<Test>>::insert(
+        relationship_id,
+        data_object_storage_registry::DataObjectStorageRelationship {
+            content_id,
+            storage_provider_id,
+            ready: true,
+        },
+    );
+    <data_object_storage_registry::RelationshipsByContentId<Test>>::insert(
+        content_id,
+        vec![relationship_id],
+    );
+
+    relationship_id
+}
+
+fn assert_content_removed(content_id: u64, relationship_id: u64) {
+    assert_eq!(
+        TestDataDirectory::data_object_by_content_id(content_id),
+        None
+    );
+    assert!(TestDataDirectory::known_content_ids(0, 10).is_empty());
+    assert!(TestDataObjectStorageRegistry::relationships_by_content_id(content_id).is_empty());
+    assert!(TestDataObjectStorageRegistry::relationships(relationship_id).is_none());
+}
+
+#[test]
+fn owner_can_delete_content() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let sender = 1u64;
+        let member_id = 1u64;
+        let content_id = 1;
+        let ipfs_content_id = vec![1, 3, 3, 7];
+        let relationship_id = add_content_with_relationship(content_id, ipfs_content_id.clone());
+
+        let res = TestDataDirectory::delete_content(Origin::signed(sender), member_id, content_id);
+        assert_eq!(res, Ok(()));
+
+        assert_content_removed(content_id, relationship_id);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::data_directory(data_directory::RawEvent::ContentDeleted(
+                content_id,
+                member_id,
+                ipfs_content_id
+            ))
+        );
+    });
+}
+
+#[test]
+fn delete_content_fails_with_not_owner() {
+    with_default_mock_builder(|| {
+        let sender = 1u64;
+        let not_owner_member_id = 2u64;
+        let content_id = 1;
+        add_content_with_relationship(content_id, vec![1, 3, 3, 7]);
+
+        let res = TestDataDirectory::delete_content(
+            Origin::signed(sender),
+            not_owner_member_id,
+            content_id,
+        );
+        assert_eq!(
+            res,
+            Err(data_directory::Error::<Test>::OwnerRequired.into())
+        );
+    });
+}
+
+#[test]
+fn delete_content_fails_with_missing_content() {
+    with_default_mock_builder(|| {
+        let sender = 1u64;
+        let member_id = 1u64;
+
+        let res = TestDataDirectory::delete_content(Origin::signed(sender), member_id, 1);
+        assert_eq!(res, Err(data_directory::Error::<Test>::CidNotFound.into()));
+    });
+}
+
+#[test]
+fn content_curator_can_takedown_content() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let content_id = 1;
+        let ipfs_content_id = vec![1, 3, 3, 7];
+        let relationship_id = add_content_with_relationship(content_id, ipfs_content_id.clone());
+
+        let res = TestDataDirectory::takedown_content(
+            Origin::signed(TEST_MOCK_CONTENT_CURATOR_ACCOUNT_ID),
+            content_id,
+        );
+        assert_eq!(res, Ok(()));
+
+        assert_content_removed(content_id, relationship_id);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::data_directory(data_directory::RawEvent::ContentTakenDown(
+                content_id,
+                ipfs_content_id
+            ))
+        );
+    });
+}
+
+#[test]
+fn storage_lead_can_takedown_content() {
+    with_default_mock_builder(|| {
+        let content_id = 1;
+        let relationship_id = add_content_with_relationship(content_id, vec![1, 3, 3, 7]);
+        let (lead_account_id, _) = set_storage_working_group_lead();
+
+        let res = TestDataDirectory::takedown_content(Origin::signed(lead_account_id), content_id);
+        assert_eq!(res, Ok(()));
+
+        assert_content_removed(content_id, relationship_id);
+    });
+}
+
+#[test]
+fn takedown_content_fails_with_invalid_origin() {
+    with_default_mock_builder(|| {
+        let content_id = 1;
+        add_content_with_relationship(content_id, vec![1, 3, 3, 7]);
+        set_storage_working_group_lead();
+
+        let res = TestDataDirectory::takedown_content(Origin::signed(55), content_id);
+        assert!(res.is_err());
+        assert!(TestDataDirectory::data_object_by_content_id(content_id).is_some());
+    });
+}
+
+fn mock_data_object() -> data_directory::DataObject<Test> {
+    data_directory::DataObjectInternal {
+        type_id: 1,
+        size: 1234,
+        added_at: data_directory::BlockAndTime {
+            block: 10,
+            time: 1024,
+        },
+        owner: 1,
+        liaison: TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID,
+        liaison_judgement: data_directory::LiaisonJudgement::Pending,
+        ipfs_content_id: vec![],
+        mime_type: vec![],
+        extension: vec![],
+    }
+}
+
+fn inject_data_objects_with_ids(content_ids: Vec<u64>) {
+    let object = mock_data_object();
+
+    let objects = content_ids
+        .into_iter()
+        .map(|content_id| (content_id, object.clone()))
+        .collect::<BTreeMap<_, _>>();
+
+    let res = TestDataDirectory::inject_data_objects(RawOrigin::Root.into(), objects);
+    assert!(res.is_ok());
+}
+
+#[test]
+fn known_content_ids_are_paginated() {
+    with_default_mock_builder(|| {
+        inject_data_objects_with_ids(vec![1, 2, 3, 4, 5]);
+
+        assert_eq!(TestDataDirectory::known_content_ids_count(), 5);
+        assert_eq!(TestDataDirectory::known_content_ids(0, 2), vec![1, 2]);
+        assert_eq!(TestDataDirectory::known_content_ids(2, 2), vec![3, 4]);
+        assert_eq!(TestDataDirectory::known_content_ids(4, 2), vec![5]);
+        assert_eq!(TestDataDirectory::known_content_ids(5, 2), vec![]);
+    });
+}
+
+#[test]
+fn remove_known_content_id_moves_last_id_into_its_position() {
+    with_default_mock_builder(|| {
+        inject_data_objects_with_ids(vec![1, 2, 3]);
+
+        let res = TestDataDirectory::remove_known_content_id(RawOrigin::Root.into(), 1);
+        assert!(res.is_ok());
+
+        assert_eq!(TestDataDirectory::known_content_ids_count(), 2);
+        assert_eq!(TestDataDirectory::known_content_ids(0, 10), vec![3, 2]);
+        assert_eq!(TestDataDirectory::known_content_index_by_id(3), Some(0));
+        assert_eq!(TestDataDirectory::known_content_index_by_id(1), None);
+    });
+}
+
+#[test]
+fn known_content_ids_migration_moves_deprecated_list_over_several_blocks() {
+    with_default_mock_builder(|| {
+        <data_directory::KnownContentIds<Test>>::put(vec![1, 2, 2, 3]);
+
+        // The storage predates the indexed known content ids.
+        data_directory::StorageVersion::put(2);
+
+        TestDataDirectory::migrate_storage();
+
+        // The deprecated list is not copied by the storage migration.
+        assert_eq!(
+            TestDataDirectory::known_content_ids_migration_position(),
+            Some(0)
+        );
+        assert_eq!(TestDataDirectory::known_content_ids_count(), 0);
+
+        // MaxKnownContentIdsMigratedPerBlock is 2 in the mock.
+        run_to_block(System::block_number() + 1);
+
+        assert_eq!(TestDataDirectory::known_content_ids(0, 10), vec![1, 2]);
+        assert_eq!(
+            TestDataDirectory::known_content_ids_migration_position(),
+            Some(2)
+        );
+
+        run_to_block(System::block_number() + 1);
+
+        assert_eq!(TestDataDirectory::known_content_ids(0, 10), vec![1, 2, 3]);
+        assert_eq!(
+            TestDataDirectory::known_content_ids_migration_position(),
+            None
+        );
+        assert!(!<data_directory::KnownContentIds<Test>>::exists());
+    });
+}
+
+#[test]
+fn known_content_id_removed_during_migration_is_not_moved() {
+    with_default_mock_builder(|| {
+        <data_directory::KnownContentIds<Test>>::put(vec![1, 2, 3]);
+        data_directory::StorageVersion::put(2);
+
+        TestDataDirectory::migrate_storage();
+
+        let res = TestDataDirectory::remove_known_content_id(RawOrigin::Root.into(), 2);
+        assert!(res.is_ok());
+
+        run_to_block(System::block_number() + 2);
+
+        assert_eq!(TestDataDirectory::known_content_ids(0, 10), vec![1, 3]);
+        assert_eq!(TestDataDirectory::known_content_index_by_id(2), None);
+
+        // The removed ids are cleared after the deprecated list is moved.
+        assert!(<data_directory::RemovedUnmigratedContentIds<Test>>::contains_key(2));
+
+        run_to_block(System::block_number() + 1);
+
+        assert!(!<data_directory::RemovedUnmigratedContentIds<Test>>::contains_key(2));
+    });
+}
+
+fn set_default_quota_limits(objects_limit: u64, size_limit: u64) {
+    let res = TestDataDirectory::set_default_quota_limits(
+        RawOrigin::Root.into(),
+        data_directory::QuotaLimits {
+            objects_limit,
+            size_limit,
+        },
+    );
+    assert!(res.is_ok());
+}
+
+#[test]
+fn added_content_is_counted_in_member_quota_until_deleted() {
+    with_default_mock_builder(|| {
+        let sender = 1u64;
+        let member_id = 1u64;
+        let quota_owner = data_directory::StorageObjectOwner::<Test>::Member(member_id);
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(sender),
+            member_id,
+            1,
+            1,
+            1234,
+            vec![1, 3, 3, 7],
+            b"video/mp4".to_vec(),
+            b"mp4".to_vec(),
+        );
+        assert!(res.is_ok());
+
+        assert_eq!(
+            TestDataDirectory::quota_usage_by_owner(&quota_owner),
+            data_directory::QuotaUsage {
+                objects: 1,
+                size: 1234,
+            }
+        );
+
+        let res = TestDataDirectory::delete_content(Origin::signed(sender), member_id, 1);
+        assert!(res.is_ok());
+
+        assert_eq!(
+            TestDataDirectory::quota_usage_by_owner(&quota_owner),
+            data_directory::QuotaUsage::default()
+        );
+    });
+}
+
+#[test]
+fn add_content_fails_with_exceeded_quota_objects_limit() {
+    with_default_mock_builder(|| {
+        set_default_quota_limits(1, 10_000);
+
+        let sender = 1u64;
+        let member_id = 1u64;
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(sender),
+            member_id,
+            1,
+            1,
+            1234,
+            vec![1],
+            b"video/mp4".to_vec(),
+            b"mp4".to_vec(),
+        );
+        assert!(res.is_ok());
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(sender),
+            member_id,
+            2,
+            1,
+            1234,
+            vec![2],
+            b"video/mp4".to_vec(),
+            b"mp4".to_vec(),
+        );
+        assert_eq!(
+            res,
+            Err(data_directory::Error::<Test>::QuotaObjectsLimitExceeded.into())
+        );
+    });
+}
+
+#[test]
+fn add_content_fails_with_exceeded_quota_size_limit() {
+    with_default_mock_builder(|| {
+        set_default_quota_limits(10, 2000);
+
+        let sender = 1u64;
+        let member_id = 1u64;
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(sender),
+            member_id,
+            1,
+            1,
+            1234,
+            vec![1],
+            b"video/mp4".to_vec(),
+            b"mp4".to_vec(),
+        );
+        assert!(res.is_ok());
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(sender),
+            member_id,
+            2,
+            1,
+            1234,
+            vec![2],
+            b"video/mp4".to_vec(),
+            b"mp4".to_vec(),
+        );
+        assert_eq!(
+            res,
+            Err(data_directory::Error::<Test>::QuotaSizeLimitExceeded.into())
+        );
+    });
+}
+
+#[test]
+fn channel_content_is_counted_in_channel_quota_until_taken_down() {
+    with_default_mock_builder(|| {
+        let sender = 1u64;
+        let member_id = TEST_MOCK_CHANNEL_OWNER_MEMBER_ID;
+        let channel_id = TEST_MOCK_CHANNEL_ID;
+        let content_id = 1;
+        let member_quota_owner = data_directory::StorageObjectOwner::<Test>::Member(member_id);
+        let channel_quota_owner = data_directory::StorageObjectOwner::<Test>::Channel(channel_id);
+
+        let res = TestDataDirectory::add_channel_content(
+            Origin::signed(sender),
+            member_id,
+            channel_id,
+            content_id,
+            1,
+            1234,
+            vec![1, 3, 3, 7],
+            b"video/mp4".to_vec(),
+            b"mp4".to_vec(),
+        );
+        assert!(res.is_ok());
+
+        assert_eq!(
+            TestDataDirectory::channel_id_by_content_id(content_id),
+            Some(channel_id)
+        );
+        assert_eq!(
+            TestDataDirectory::quota_usage_by_owner(&channel_quota_owner),
+            data_directory::QuotaUsage {
+                objects: 1,
+                size: 1234,
+            }
+        );
+        assert_eq!(
+            TestDataDirectory::quota_usage_by_owner(&member_quota_owner),
+            data_directory::QuotaUsage::default()
+        );
+
+        let res = TestDataDirectory::takedown_content(
+            Origin::signed(TEST_MOCK_CONTENT_CURATOR_ACCOUNT_ID),
+            content_id,
+        );
+        assert!(res.is_ok());
+
+        assert_eq!(
+            TestDataDirectory::channel_id_by_content_id(content_id),
+            None
+        );
+        assert_eq!(
+            TestDataDirectory::quota_usage_by_owner(&channel_quota_owner),
+            data_directory::QuotaUsage::default()
+        );
+    });
+}
+
+#[test]
+fn injected_content_deletion_keeps_member_quota_usage() {
+    with_default_mock_builder(|| {
+        let sender = 1u64;
+        let member_id = 1u64;
+        let quota_owner = data_directory::StorageObjectOwner::<Test>::Member(member_id);
+        let expected_usage = data_directory::QuotaUsage {
+            objects: 1,
+            size: 1234,
+        };
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(sender),
+            member_id,
+            1,
+            1,
+            1234,
+            vec![1, 3, 3, 7],
+            b"video/mp4".to_vec(),
+            b"mp4".to_vec(),
+        );
+        assert!(res.is_ok());
+
+        // The injected data object of the same owner is not counted.
+        inject_data_objects_with_ids(vec![2]);
+
+        assert_eq!(
+            TestDataDirectory::quota_usage_by_owner(&quota_owner),
+            expected_usage
+        );
+
+        let res = TestDataDirectory::delete_content(Origin::signed(sender), member_id, 2);
+        assert!(res.is_ok());
+
+        assert_eq!(
+            TestDataDirectory::quota_usage_by_owner(&quota_owner),
+            expected_usage
+        );
+    });
+}
+
+#[test]
+fn data_object_injection_releases_overwritten_channel_content_quota() {
+    with_default_mock_builder(|| {
+        let sender = 1u64;
+        let member_id = TEST_MOCK_CHANNEL_OWNER_MEMBER_ID;
+        let channel_id = TEST_MOCK_CHANNEL_ID;
+        let content_id = 1;
+        let channel_quota_owner = data_directory::StorageObjectOwner::<Test>::Channel(channel_id);
+
+        let res = TestDataDirectory::add_channel_content(
+            Origin::signed(sender),
+            member_id,
+            channel_id,
+            content_id,
+            1,
+            1234,
+            vec![1, 3, 3, 7],
+            b"video/mp4".to_vec(),
+            b"mp4".to_vec(),
+        );
+        assert!(res.is_ok());
+
+        inject_data_objects_with_ids(vec![content_id]);
+
+        assert_eq!(
+            TestDataDirectory::channel_id_by_content_id(content_id),
+            None
+        );
+        assert_eq!(
+            TestDataDirectory::quota_owner_by_content_id(content_id),
+            None
+        );
+        assert_eq!(
+            TestDataDirectory::quota_usage_by_owner(&channel_quota_owner),
+            data_directory::QuotaUsage::default()
+        );
+    });
+}
+
+#[test]
+fn add_channel_content_fails_with_not_channel_owner() {
+    with_default_mock_builder(|| {
+        let sender = 2u64;
+        let not_owner_member_id = 2u64;
+
+        let res = TestDataDirectory::add_channel_content(
+            Origin::signed(sender),
+            not_owner_member_id,
+            TEST_MOCK_CHANNEL_ID,
+            1,
+            1,
+            1234,
+            vec![1, 3, 3, 7],
+            b"video/mp4".to_vec(),
+            b"mp4".to_vec(),
+        );
+        assert_eq!(
+            res,
+            Err(data_directory::Error::<Test>::ChannelOwnerRequired.into())
+        );
+    });
+}
+
+#[test]
+fn storage_lead_can_set_quota_limits() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let (lead_account_id, _) = set_storage_working_group_lead();
+        let quota_owner = data_directory::StorageObjectOwner::<Test>::Channel(TEST_MOCK_CHANNEL_ID);
+        let limits = data_directory::QuotaLimits {
+            objects_limit: 1000,
+            size_limit: 50_000_000,
+        };
+
+        let res = TestDataDirectory::set_quota_limits(
+            Origin::signed(lead_account_id),
+            quota_owner.clone(),
+            Some(limits),
+        );
+        assert!(res.is_ok());
+
+        assert_eq!(TestDataDirectory::quota_limits(&quota_owner), limits);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::data_directory(data_directory::RawEvent::QuotaLimitsUpdated(
+                quota_owner.clone(),
+                Some(limits)
+            ))
+        );
+
+        // The default limits apply again.
+        let res =
+            TestDataDirectory::set_quota_limits(RawOrigin::Root.into(), quota_owner.clone(), None);
+        assert!(res.is_ok());
+
+        assert_eq!(TestDataDirectory::quota_limits_by_owner(&quota_owner), None);
+        assert_eq!(
+            TestDataDirectory::quota_limits(&quota_owner),
+            TestDataDirectory::default_quota_limits()
+        );
+    });
+}
+
+#[test]
+fn set_quota_limits_fails_with_invalid_origin() {
+    with_default_mock_builder(|| {
+        set_storage_working_group_lead();
+
+        let limits = data_directory::QuotaLimits {
+            objects_limit: 1000,
+            size_limit: 50_000_000,
+        };
+
+        let res = TestDataDirectory::set_quota_limits(
+            Origin::signed(55),
+            data_directory::StorageObjectOwner::<Test>::Member(1),
+            Some(limits),
+        );
+        assert!(res.is_err());
+
+        let res = TestDataDirectory::set_default_quota_limits(Origin::signed(55), limits);
+        assert!(res.is_err());
+    });
+}
+
+fn register_avatar_data_object_type() -> u64 {
+    let (lead_account_id, _) = set_storage_working_group_lead();
+
+    let type_id = TestDataObjectTypeRegistry::next_data_object_type_id();
+
+    let res = TestDataObjectTypeRegistry::register_data_object_type(
+        Origin::signed(lead_account_id),
+        TestDataObjectType {
+            description: b"avatar".to_vec(),
+            max_size: Some(1024),
+            allowed_mime_types: vec![b"image/png".to_vec()],
+            allowed_extensions: vec![b"png".to_vec()],
+            ..Default::default()
+        },
+    );
+    assert!(res.is_ok());
+
+    type_id
+}
+
+#[test]
+fn add_content_succeeds_with_data_object_type_constraints() {
+    with_default_mock_builder(|| {
+        let type_id = register_avatar_data_object_type();
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(1),
+            1,
+            1,
+            type_id,
+            1024,
+            vec![1],
+            b"image/png".to_vec(),
+            b"png".to_vec(),
+        );
+        assert!(res.is_ok());
+
+        let data_object = TestDataDirectory::data_object_by_content_id(1).unwrap();
+        assert_eq!(data_object.mime_type, b"image/png".to_vec());
+        assert_eq!(data_object.extension, b"png".to_vec());
+    });
+}
+
+#[test]
+fn add_content_fails_with_exceeded_data_object_type_max_size() {
+    with_default_mock_builder(|| {
+        let type_id = register_avatar_data_object_type();
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(1),
+            1,
+            1,
+            type_id,
+            1025,
+            vec![1],
+            b"image/png".to_vec(),
+            b"png".to_vec(),
+        );
+        assert_eq!(
+            res,
+            Err(data_object_type_registry::Error::<Test>::DataObjectSizeExceeded.into())
+        );
+    });
+}
+
+#[test]
+fn add_content_fails_with_not_allowed_mime_type() {
+    with_default_mock_builder(|| {
+        let type_id = register_avatar_data_object_type();
+
+        let res = TestDataDirectory::add_channel_content(
+            Origin::signed(1),
+            TEST_MOCK_CHANNEL_OWNER_MEMBER_ID,
+            TEST_MOCK_CHANNEL_ID,
+            1,
+            type_id,
+            1024,
+            vec![1],
+            b"video/mp4".to_vec(),
+            b"mp4".to_vec(),
+        );
+        assert_eq!(
+            res,
+            Err(data_object_type_registry::Error::<Test>::MimeTypeNotAllowed.into())
+        );
+    });
+}
+
+#[test]
+fn add_content_fails_with_not_allowed_extension() {
+    with_default_mock_builder(|| {
+        let type_id = register_avatar_data_object_type();
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(1),
+            1,
+            1,
+            type_id,
+            1024,
+            vec![1],
+            b"image/png".to_vec(),
+            b"mp4".to_vec(),
+        );
+        assert_eq!(
+            res,
+            Err(data_object_type_registry::Error::<Test>::ExtensionNotAllowed.into())
+        );
+    });
+}
+
+#[test]
+fn data_objects_migration_adds_empty_mime_type_and_extension() {
+    with_default_mock_builder(|| {
+        let content_id = 1;
+
+        // Data object encoding without the MIME type and the extension.
+        let old_data_object = (
+            1u64,
+            data_directory::BlockAndTime::<u64, u64> {
+                block: 10,
+                time: 1024,
+            },
+            1u64,
+            1234u64,
+            TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID,
+            data_directory::LiaisonJudgement::Accepted,
+            vec![1, 3, 3, 7],
+        );
+        unhashed::put(
+            &<data_directory::DataObjectByContentId<Test>>::hashed_key_for(content_id),
+            &old_data_object,
+        );
+
+        // The storage predates the data object media types.
+        data_directory::StorageVersion::put(0);
+
+        TestDataDirectory::migrate_storage();
+
+        assert_eq!(
+            TestDataDirectory::data_object_by_content_id(content_id),
+            Some(data_directory::DataObjectInternal {
+                owner: 1,
+                added_at: data_directory::BlockAndTime {
+                    block: 10,
+                    time: 1024,
+                },
+                type_id: 1,
+                size: 1234,
+                liaison: TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID,
+                liaison_judgement: data_directory::LiaisonJudgement::Accepted,
+                ipfs_content_id: vec![1, 3, 3, 7],
+                mime_type: vec![],
+                extension: vec![],
+            })
+        );
+    });
+}
+
+#[test]
+fn storage_migration_runs_once() {
+    with_default_mock_builder(|| {
+        data_directory::StorageVersion::put(0);
+
+        TestDataDirectory::migrate_storage();
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(1),
+            1,
+            1,
+            1,
+            1234,
+            vec![1, 3, 3, 7],
+            b"video/mp4".to_vec(),
+            b"mp4".to_vec(),
+        );
+        assert!(res.is_ok());
+
+        // The next runtime upgrade keeps the data objects in the current format.
+        TestDataDirectory::migrate_storage();
+
+        let data_object = TestDataDirectory::data_object_by_content_id(1).unwrap();
+        assert_eq!(data_object.mime_type, b"video/mp4".to_vec());
+        assert_eq!(data_object.extension, b"mp4".to_vec());
+    });
+}
+
+#[test]
+fn storage_quotas_migration_counts_existing_data_objects_and_keeps_default_limits() {
+    with_default_mock_builder(|| {
+        set_default_quota_limits(5, 5000);
+        inject_data_objects_with_ids(vec![1, 2]);
+
+        // The storage predates the storage quotas.
+        data_directory::StorageVersion::put(1);
+
+        TestDataDirectory::migrate_storage();
+
+        let quota_owner = data_directory::StorageObjectOwner::<Test>::Member(1);
+        let expected_usage = data_directory::QuotaUsage {
+            objects: 2,
+            size: 2468,
+        };
+
+        assert_eq!(
+            TestDataDirectory::default_quota_limits(),
+            data_directory::QuotaLimits {
+                objects_limit: 5,
+                size_limit: 5000,
+            }
+        );
+        assert_eq!(
+            TestDataDirectory::quota_usage_by_owner(&quota_owner),
+            expected_usage
+        );
+
+        // The next runtime upgrade doesn't count the data objects again.
+        TestDataDirectory::migrate_storage();
+
+        assert_eq!(
+            TestDataDirectory::quota_usage_by_owner(&quota_owner),
+            expected_usage
+        );
+    });
+}
+
+#[test]
+fn storage_quotas_migration_sets_absent_default_limits() {
+    with_default_mock_builder(|| {
+        data_directory::DefaultQuotaLimits::kill();
+        data_directory::StorageVersion::put(1);
+
+        TestDataDirectory::migrate_storage();
+
+        assert_eq!(
+            TestDataDirectory::default_quota_limits(),
+            data_directory::default_storage_quota_limits()
+        );
+    });
+}
@@ -1,6 +1,11 @@
 #![cfg(test)]
 
+use frame_support::traits::OnInitialize;
+use frame_support::{IterableStorageDoubleMap, StorageDoubleMap, StorageMap, StorageValue};
+use working_group::WorkerExitHandler;
+
 use super::mock::*;
+use crate::data_directory::ContentIdExists;
 
 #[test]
 fn initial_state() {
@@ -162,3 +167,301 @@ fn test_toggle_ready() {
         );
     });
 }
+
+fn set_mock_content_replication_factor(replication_factor: u32) {
+    let do_type_id = MockContent::get_data_object(&TEST_MOCK_EXISTING_CID)
+        .unwrap()
+        .type_id;
+
+    <data_object_type_registry::DataObjectTypes<Test>>::insert(
+        do_type_id,
+        TestDataObjectType {
+            replication_factor,
+            ..Default::default()
+        },
+    );
+}
+
+fn add_mock_content_relationship(storage_provider_id: u32, ready: bool) -> u64 {
+    let account_id = storage_provider_id as u64;
+    let relationship_id = TestDataObjectStorageRegistry::next_relationship_id();
+
+    let res = TestDataObjectStorageRegistry::add_relationship(
+        Origin::signed(account_id),
+        storage_provider_id,
+        TEST_MOCK_EXISTING_CID,
+    );
+    assert!(res.is_ok());
+
+    if ready {
+        let res = TestDataObjectStorageRegistry::set_relationship_ready(
+            Origin::signed(account_id),
+            storage_provider_id,
+            relationship_id,
+        );
+        assert!(res.is_ok());
+    }
+
+    relationship_id
+}
+
+fn mock_content_storage_provider_ids() -> Vec<u32> {
+    TestDataObjectStorageRegistry::relationships_by_content_id(TEST_MOCK_EXISTING_CID)
+        .into_iter()
+        .filter_map(TestDataObjectStorageRegistry::relationships)
+        .map(|dosr| dosr.storage_provider_id)
+        .collect()
+}
+
+fn storage_provider_relationship_ids(storage_provider_id: u32) -> Vec<u64> {
+    <data_object_storage_registry::RelationshipsByStorageProviderId<Test>>::iter_prefix(
+        storage_provider_id,
+    )
+    .map(|(id, _)| id)
+    .collect()
+}
+
+#[test]
+fn replication_factor_is_defined_by_data_object_type() {
+    with_default_mock_builder(|| {
+        assert_eq!(
+            TestDataObjectStorageRegistry::replication_factor(&TEST_MOCK_EXISTING_CID),
+            1
+        );
+
+        set_mock_content_replication_factor(3);
+
+        assert_eq!(
+            TestDataObjectStorageRegistry::replication_factor(&TEST_MOCK_EXISTING_CID),
+            3
+        );
+    });
+}
+
+#[test]
+fn under_replicated_objects_are_listed_until_enough_relationships_are_ready() {
+    with_default_mock_builder(|| {
+        <data_directory::KnownContentIds<Test>>::put(vec![TEST_MOCK_EXISTING_CID]);
+        data_directory::KnownContentIdsMigrationPosition::put(0);
+        run_to_block(System::block_number() + 1);
+
+        set_mock_content_replication_factor(2);
+
+        hire_storage_provider_with_id(1);
+        hire_storage_provider_with_id(2);
+
+        add_mock_content_relationship(1, true);
+        let relationship_id = add_mock_content_relationship(2, false);
+
+        assert_eq!(
+            TestDataObjectStorageRegistry::under_replicated_objects(0, 10),
+            vec![data_object_storage_registry::ObjectReplication {
+                content_id: TEST_MOCK_EXISTING_CID,
+                ready_replicas: 1,
+                replication_factor: 2,
+            }]
+        );
+
+        let res = TestDataObjectStorageRegistry::set_relationship_ready(
+            Origin::signed(2),
+            2,
+            relationship_id,
+        );
+        assert!(res.is_ok());
+
+        assert!(TestDataObjectStorageRegistry::under_replicated_objects(0, 10).is_empty());
+    });
+}
+
+#[test]
+fn leaving_storage_provider_is_replaced() {
+    with_default_mock_builder(|| {
+        set_mock_content_replication_factor(2);
+
+        hire_storage_provider_with_id(1);
+        hire_storage_provider_with_id(2);
+        hire_storage_provider_with_id(3);
+
+        let relationship_id = add_mock_content_relationship(1, true);
+        add_mock_content_relationship(2, true);
+
+        <working_group::WorkerById<Test, StorageWorkingGroupInstance>>::remove(1);
+        TestDataObjectStorageRegistry::worker_exited(&1);
+
+        assert_eq!(
+            TestDataObjectStorageRegistry::exited_storage_provider_ids(),
+            vec![1]
+        );
+
+        TestDataObjectStorageRegistry::on_initialize(1);
+
+        assert!(TestDataObjectStorageRegistry::exited_storage_provider_ids().is_empty());
+        assert!(TestDataObjectStorageRegistry::relationships(relationship_id).is_none());
+        assert!(storage_provider_relationship_ids(1).is_empty());
+        assert_eq!(mock_content_storage_provider_ids(), vec![2, 3]);
+        assert_eq!(storage_provider_relationship_ids(3).len(), 1);
+    });
+}
+
+#[test]
+fn leaving_storage_provider_is_not_replaced_with_enough_replicas() {
+    with_default_mock_builder(|| {
+        hire_storage_provider_with_id(1);
+        hire_storage_provider_with_id(2);
+        hire_storage_provider_with_id(3);
+
+        add_mock_content_relationship(1, true);
+        add_mock_content_relationship(2, true);
+
+        <working_group::WorkerById<Test, StorageWorkingGroupInstance>>::remove(1);
+        TestDataObjectStorageRegistry::worker_exited(&1);
+        TestDataObjectStorageRegistry::on_initialize(1);
+
+        assert_eq!(mock_content_storage_provider_ids(), vec![2]);
+    });
+}
+
+#[test]
+fn leaving_storage_provider_relationships_are_reassigned_in_batches() {
+    with_default_mock_builder(|| {
+        hire_storage_provider_with_id(1);
+        hire_storage_provider_with_id(2);
+
+        add_mock_content_relationship(1, true);
+        add_mock_content_relationship(1, true);
+        add_mock_content_relationship(1, true);
+
+        <working_group::WorkerById<Test, StorageWorkingGroupInstance>>::remove(1);
+        TestDataObjectStorageRegistry::worker_exited(&1);
+
+        // MaxReassignmentsPerBlock is 2 in the mock.
+        TestDataObjectStorageRegistry::on_initialize(1);
+
+        assert_eq!(storage_provider_relationship_ids(1).len(), 1);
+        assert_eq!(
+            TestDataObjectStorageRegistry::exited_storage_provider_ids(),
+            vec![1]
+        );
+        assert_eq!(mock_content_storage_provider_ids(), vec![1]);
+
+        TestDataObjectStorageRegistry::on_initialize(2);
+
+        assert!(storage_provider_relationship_ids(1).is_empty());
+        assert!(TestDataObjectStorageRegistry::exited_storage_provider_ids().is_empty());
+        assert_eq!(mock_content_storage_provider_ids(), vec![2]);
+    });
+}
+
+#[test]
+fn exited_storage_provider_reaching_the_reassignment_limit_is_carried_over() {
+    with_default_mock_builder(|| {
+        set_mock_content_replication_factor(3);
+
+        hire_storage_provider_with_id(1);
+        hire_storage_provider_with_id(2);
+        hire_storage_provider_with_id(3);
+
+        add_mock_content_relationship(1, true);
+        add_mock_content_relationship(1, true);
+        let relationship_id = add_mock_content_relationship(2, true);
+
+        <working_group::WorkerById<Test, StorageWorkingGroupInstance>>::remove(1);
+        TestDataObjectStorageRegistry::worker_exited(&1);
+        <working_group::WorkerById<Test, StorageWorkingGroupInstance>>::remove(2);
+        TestDataObjectStorageRegistry::worker_exited(&2);
+
+        // MaxReassignmentsPerBlock is 2 in the mock: the batch of the first storage provider
+        // uses the whole limit, so it stays queued until its relationships are known to be gone.
+        let weight = TestDataObjectStorageRegistry::on_initialize(1);
+
+        assert!(weight >= 2 * STORAGE_PROVIDERS_FOR_REPLICATION_WEIGHT);
+        assert!(storage_provider_relationship_ids(1).is_empty());
+        assert_eq!(storage_provider_relationship_ids(2), vec![relationship_id]);
+        assert_eq!(
+            TestDataObjectStorageRegistry::exited_storage_provider_ids(),
+            vec![1, 2]
+        );
+
+        TestDataObjectStorageRegistry::on_initialize(2);
+
+        assert!(storage_provider_relationship_ids(2).is_empty());
+        assert!(TestDataObjectStorageRegistry::exited_storage_provider_ids().is_empty());
+        assert_eq!(mock_content_storage_provider_ids(), vec![3]);
+    });
+}
+
+#[test]
+fn empty_exited_storage_providers_queue_is_cheap() {
+    with_default_mock_builder(|| {
+        assert!(
+            TestDataObjectStorageRegistry::on_initialize(1)
+                < STORAGE_PROVIDERS_FOR_REPLICATION_WEIGHT
+        );
+    });
+}
+
+#[test]
+fn relationships_by_storage_provider_id_migration_indexes_relationships() {
+    with_default_mock_builder(|| {
+        hire_storage_provider_with_id(1);
+
+        let relationship_id = add_mock_content_relationship(1, false);
+        <data_object_storage_registry::RelationshipsByStorageProviderId<Test>>::remove(
+            1,
+            relationship_id,
+        );
+        data_object_storage_registry::StorageVersion::put(0);
+
+        TestDataObjectStorageRegistry::migrate_relationships_by_storage_provider_id();
+
+        assert_eq!(storage_provider_relationship_ids(1), vec![relationship_id]);
+    });
+}
+
+#[test]
+fn relationships_by_storage_provider_id_migration_runs_once() {
+    with_default_mock_builder(|| {
+        hire_storage_provider_with_id(1);
+
+        let relationship_id = add_mock_content_relationship(1, false);
+        <data_object_storage_registry::RelationshipsByStorageProviderId<Test>>::remove(
+            1,
+            relationship_id,
+        );
+
+        // New chains start with the current storage version, so nothing is migrated.
+        TestDataObjectStorageRegistry::migrate_relationships_by_storage_provider_id();
+
+        assert!(storage_provider_relationship_ids(1).is_empty());
+    });
+}
+
+#[test]
+fn leaving_storage_provider_without_enough_replacements_leaves_content_under_replicated() {
+    with_default_mock_builder(|| {
+        // Events are not emitted on block 0.
+        run_to_block(1);
+
+        set_mock_content_replication_factor(3);
+
+        hire_storage_provider_with_id(1);
+        hire_storage_provider_with_id(2);
+        hire_storage_provider_with_id(3);
+
+        add_mock_content_relationship(1, true);
+        add_mock_content_relationship(2, true);
+
+        <working_group::WorkerById<Test, StorageWorkingGroupInstance>>::remove(1);
+        TestDataObjectStorageRegistry::worker_exited(&1);
+        TestDataObjectStorageRegistry::on_initialize(1);
+
+        assert_eq!(mock_content_storage_provider_ids(), vec![2, 3]);
+        assert!(System::events().iter().any(|record| record.event
+            == MetaEvent::data_object_storage_registry(
+                data_object_storage_registry::RawEvent::ContentUnderReplicated(
+                    TEST_MOCK_EXISTING_CID,
+                    1,
+                )
+            )));
+    });
+}
@@ -1,5 +1,6 @@
 #![cfg(test)]
 
+use frame_support::storage::unhashed;
 use frame_support::{StorageMap, StorageValue};
 use system::{EventRecord, Phase, RawOrigin};
 
@@ -62,6 +63,7 @@ fn succeed_register() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            ..Default::default()
         };
         let res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -87,6 +89,7 @@ fn activate_data_object_type_fails_with_invalid_lead() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            ..Default::default()
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -127,6 +130,7 @@ fn deactivate_data_object_type_fails_with_invalid_lead() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: true,
+            ..Default::default()
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -167,6 +171,7 @@ fn update_data_object_type_fails_with_invalid_lead() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            ..Default::default()
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -178,6 +183,7 @@ fn update_data_object_type_fails_with_invalid_lead() {
         let updated1: TestDataObjectType = TestDataObjectType {
             description: "bar".as_bytes().to_vec(),
             active: false,
+            ..Default::default()
         };
 
         let invalid_leader_account_id = 2;
@@ -212,6 +218,7 @@ fn update_existing() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            ..Default::default()
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -226,6 +233,7 @@ fn update_existing() {
         let updated1: TestDataObjectType = TestDataObjectType {
             description: "bar".as_bytes().to_vec(),
             active: false,
+            ..Default::default()
         };
         let res = TestDataObjectTypeRegistry::update_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -238,6 +246,7 @@ fn update_existing() {
         let updated3: TestDataObjectType = TestDataObjectType {
             description: "bar".as_bytes().to_vec(),
             active: false,
+            ..Default::default()
         };
         let res = TestDataObjectTypeRegistry::update_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -265,6 +274,7 @@ fn register_data_object_type_failed_with_no_lead() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            ..Default::default()
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -292,6 +302,7 @@ fn activate_existing() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            ..Default::default()
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -351,3 +362,140 @@ fn activate_existing() {
         assert!(!data.unwrap().active);
     });
 }
+
+#[test]
+fn register_data_object_type_fails_with_zero_replication_factor() {
+    with_default_mock_builder(|| {
+        SetLeadFixture::set_default_lead();
+
+        let data: TestDataObjectType = TestDataObjectType {
+            replication_factor: 0,
+            ..Default::default()
+        };
+        let res = TestDataObjectTypeRegistry::register_data_object_type(
+            RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
+            data,
+        );
+        assert_eq!(
+            res,
+            Err(data_object_type_registry::Error::<Test>::ZeroReplicationFactor.into())
+        );
+    });
+}
+
+#[test]
+fn update_data_object_type_sets_constraints() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        SetLeadFixture::set_default_lead();
+
+        let res = TestDataObjectTypeRegistry::register_data_object_type(
+            RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
+            TestDataObjectType::default(),
+        );
+        assert!(res.is_ok());
+
+        let dot_id = get_last_data_object_type_id();
+        let updated: TestDataObjectType = TestDataObjectType {
+            description: "avatar".as_bytes().to_vec(),
+            active: true,
+            max_size: Some(1024),
+            allowed_mime_types: vec![b"image/png".to_vec()],
+            allowed_extensions: vec![b"png".to_vec()],
+            replication_factor: 3,
+        };
+        let res = TestDataObjectTypeRegistry::update_data_object_type(
+            RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
+            dot_id,
+            updated.clone(),
+        );
+        assert!(res.is_ok());
+
+        assert_eq!(
+            TestDataObjectTypeRegistry::data_object_types(dot_id),
+            Some(updated)
+        );
+    });
+}
+
+#[test]
+fn data_object_type_constraints() {
+    let do_type = TestDataObjectType {
+        max_size: Some(1024),
+        allowed_mime_types: vec![b"image/png".to_vec(), b"image/jpeg".to_vec()],
+        allowed_extensions: vec![b"png".to_vec(), b"jpg".to_vec()],
+        ..Default::default()
+    };
+
+    assert!(do_type.allows_size(1024));
+    assert!(!do_type.allows_size(1025));
+    assert!(do_type.allows_mime_type(b"image/jpeg"));
+    assert!(do_type.allows_mime_type(b"Image/PNG"));
+    assert!(!do_type.allows_mime_type(b"video/mp4"));
+    assert!(do_type.allows_extension(b"jpg"));
+    assert!(do_type.allows_extension(b"PNG"));
+    assert!(!do_type.allows_extension(b"mp4"));
+
+    let unconstrained = TestDataObjectType::default();
+
+    assert!(unconstrained.allows_size(u64::max_value()));
+    assert!(unconstrained.allows_mime_type(b"video/mp4"));
+    assert!(unconstrained.allows_extension(b"mp4"));
+}
+
+#[test]
+fn data_object_types_migration_adds_default_constraints() {
+    with_default_mock_builder(|| {
+        let dot_id = TEST_FIRST_DATA_OBJECT_TYPE_ID;
+
+        // Data object type encoding without the constraints.
+        let old_do_type = (b"foo".to_vec(), false);
+        unhashed::put(
+            &<data_object_type_registry::DataObjectTypes<Test>>::hashed_key_for(dot_id),
+            &old_do_type,
+        );
+
+        // The storage predates the data object type constraints.
+        data_object_type_registry::StorageVersion::put(0);
+
+        TestDataObjectTypeRegistry::migrate_data_object_types();
+
+        assert_eq!(
+            TestDataObjectTypeRegistry::data_object_types(dot_id),
+            Some(TestDataObjectType {
+                description: b"foo".to_vec(),
+                active: false,
+                ..Default::default()
+            })
+        );
+    });
+}
+
+#[test]
+fn data_object_types_migration_runs_once() {
+    with_default_mock_builder(|| {
+        let dot_id = TEST_FIRST_DATA_OBJECT_TYPE_ID;
+        let do_type = TestDataObjectType {
+            description: b"avatar".to_vec(),
+            active: true,
+            max_size: Some(1024),
+            allowed_mime_types: vec![b"image/png".to_vec()],
+            allowed_extensions: vec![b"png".to_vec()],
+            replication_factor: 3,
+        };
+
+        data_object_type_registry::StorageVersion::put(0);
+        TestDataObjectTypeRegistry::migrate_data_object_types();
+
+        <data_object_type_registry::DataObjectTypes<Test>>::insert(dot_id, do_type.clone());
+
+        // The next runtime upgrade keeps the data object types in the current format.
+        TestDataObjectTypeRegistry::migrate_data_object_types();
+
+        assert_eq!(
+            TestDataObjectTypeRegistry::data_object_types(dot_id),
+            Some(do_type)
+        );
+    });
+}
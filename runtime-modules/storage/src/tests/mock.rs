@@ -1,7 +1,9 @@
 #![cfg(test)]
 
-use frame_support::storage::StorageMap;
+use frame_support::dispatch::DispatchResult;
+use frame_support::storage::{IterableStorageMap, StorageMap};
 use frame_support::traits::{OnFinalize, OnInitialize};
+use frame_support::weights::Weight;
 use frame_support::{impl_outer_event, impl_outer_origin, parameter_types};
 use sp_core::H256;
 use sp_runtime::{
@@ -9,6 +11,7 @@ use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
     Perbill,
 };
+use std::cell::RefCell;
 
 use crate::data_directory::ContentIdExists;
 use crate::data_object_type_registry::IsActiveDataObjectType;
@@ -38,6 +41,7 @@ impl_outer_event! {
         balances<T>,
         members<T>,
         working_group_mod StorageWorkingGroupInstance <T>,
+        recurringrewards<T>,
         system<T>,
     }
 }
@@ -49,18 +53,39 @@ pub const TEST_FIRST_METADATA_ID: u64 = 4000;
 
 pub const TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID: u32 = 1;
 pub const TEST_MOCK_EXISTING_CID: u64 = 42;
+pub const TEST_MOCK_CONTENT_CURATOR_ACCOUNT_ID: u64 = 100;
+pub const TEST_MOCK_CHANNEL_ID: u32 = 7;
+pub const TEST_MOCK_CHANNEL_OWNER_MEMBER_ID: u64 = 1;
 
 pub struct AnyDataObjectTypeIsActive {}
 impl<T: data_object_type_registry::Trait> IsActiveDataObjectType<T> for AnyDataObjectTypeIsActive {
     fn is_active_data_object_type(_which: &T::DataObjectTypeId) -> bool {
         true
     }
+
+    // Only the registered data object types have constraints.
+    fn ensure_data_object_constraints(
+        which: &T::DataObjectTypeId,
+        size: u64,
+        mime_type: &[u8],
+        extension: &[u8],
+    ) -> DispatchResult {
+        if !<data_object_type_registry::DataObjectTypes<T>>::contains_key(which) {
+            return Ok(());
+        }
+
+        data_object_type_registry::Module::<T>::ensure_data_object_constraints(
+            which, size, mime_type, extension,
+        )
+    }
 }
 
 pub struct MockContent {}
 impl ContentIdExists<Test> for MockContent {
+    // The content added to the data directory by the benchmarks exists as well.
     fn has_content(which: &<Test as data_directory::Trait>::ContentId) -> bool {
         *which == TEST_MOCK_EXISTING_CID
+            || <data_directory::DataObjectByContentId<Test>>::contains_key(which)
     }
 
     fn get_data_object(
@@ -78,12 +103,45 @@ impl ContentIdExists<Test> for MockContent {
                 liaison: TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID,
                 liaison_judgement: data_directory::LiaisonJudgement::Pending,
                 ipfs_content_id: vec![],
+                mime_type: vec![],
+                extension: vec![],
             }),
             _ => Err("nope, missing"),
         }
     }
 }
 
+pub struct MockContentCurators {}
+impl data_directory::ContentCurators<Test> for MockContentCurators {
+    fn is_content_curator(account_id: &u64) -> bool {
+        *account_id == TEST_MOCK_CONTENT_CURATOR_ACCOUNT_ID
+    }
+}
+
+thread_local! {
+    // Owner of the mock channel created by the benchmarks.
+    static BENCHMARK_CHANNEL_OWNER_MEMBER_ID: RefCell<Option<u64>> = RefCell::new(None);
+}
+
+pub struct MockChannelOwners {}
+impl data_directory::ChannelOwners<Test> for MockChannelOwners {
+    fn is_channel_owner(member_id: &u64, channel_id: &u32) -> bool {
+        let is_benchmark_owner = BENCHMARK_CHANNEL_OWNER_MEMBER_ID
+            .with(|owner_member_id| *owner_member_id.borrow() == Some(*member_id));
+
+        (*member_id == TEST_MOCK_CHANNEL_OWNER_MEMBER_ID || is_benchmark_owner)
+            && *channel_id == TEST_MOCK_CHANNEL_ID
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn create_benchmark_channel(member_id: &u64) -> u32 {
+        BENCHMARK_CHANNEL_OWNER_MEMBER_ID
+            .with(|owner_member_id| *owner_member_id.borrow_mut() = Some(*member_id));
+
+        TEST_MOCK_CHANNEL_ID
+    }
+}
+
 // Workaround for https://github.com/rust-lang/rust/issues/26925 . Remove when sorted.
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct Test;
@@ -94,6 +152,8 @@ parameter_types! {
     pub const AvailableBlockRatio: Perbill = Perbill::one();
     pub const MinimumPeriod: u64 = 5;
     pub const MaxObjectsPerInjection: u32 = 5;
+    pub const MaxKnownContentIdsMigratedPerBlock: u32 = 2;
+    pub const MaxReassignmentsPerBlock: u32 = 2;
 }
 
 impl system::Trait for Test {
@@ -153,11 +213,14 @@ parameter_types! {
 impl working_group::Trait<StorageWorkingGroupInstance> for Test {
     type Event = MetaEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WeightInfo = ();
+    type WorkerExitHandler = TestDataObjectStorageRegistry;
 }
 
 impl data_object_type_registry::Trait for Test {
     type Event = MetaEvent;
     type DataObjectTypeId = u64;
+    type WeightInfo = ();
 }
 
 impl data_directory::Trait for Test {
@@ -167,12 +230,46 @@ impl data_directory::Trait for Test {
     type IsActiveDataObjectType = AnyDataObjectTypeIsActive;
     type MemberOriginValidator = ();
     type MaxObjectsPerInjection = MaxObjectsPerInjection;
+    type MaxKnownContentIdsMigratedPerBlock = MaxKnownContentIdsMigratedPerBlock;
+    type ContentCurators = MockContentCurators;
+    type ContentRemovedHandler = TestDataObjectStorageRegistry;
+    type ChannelOwners = MockChannelOwners;
+    type WeightInfo = ();
 }
 
+// Weight of the storage provider lookup for the content replication.
+pub const STORAGE_PROVIDERS_FOR_REPLICATION_WEIGHT: Weight = 1_000_000;
+
 impl crate::data_directory::StorageProviderHelper<Test> for () {
     fn get_random_storage_provider() -> Result<u32, &'static str> {
         Ok(1)
     }
+
+    fn get_storage_providers_for_replication(
+        _content_id: &u64,
+        excluded_ids: &[u32],
+        count: usize,
+    ) -> Vec<u32> {
+        let mut ids: Vec<u32> =
+            <working_group::WorkerById<Test, StorageWorkingGroupInstance>>::iter()
+                .map(|(id, _)| id)
+                .filter(|id| !excluded_ids.contains(id))
+                .collect();
+        ids.sort();
+
+        ids.into_iter().take(count).collect()
+    }
+
+    fn get_storage_providers_for_replication_weight() -> Weight {
+        STORAGE_PROVIDERS_FOR_REPLICATION_WEIGHT
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn create_benchmark_storage_provider(account_id: &u64) -> u32 {
+        <working_group::Module<Test, StorageWorkingGroupInstance>>::insert_benchmark_worker(
+            account_id,
+        )
+    }
 }
 
 impl common::origin::ActorOriginValidator<Origin, u64, u64> for () {
@@ -181,12 +278,19 @@ impl common::origin::ActorOriginValidator<Origin, u64, u64> for () {
 
         Ok(signed_account_id)
     }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn create_benchmark_actor(account_id: &u64) -> u64 {
+        *account_id
+    }
 }
 
 impl data_object_storage_registry::Trait for Test {
     type Event = MetaEvent;
     type DataObjectStorageRelationshipId = u64;
     type ContentIdExists = MockContent;
+    type MaxReassignmentsPerBlock = MaxReassignmentsPerBlock;
+    type WeightInfo = ();
 }
 
 impl membership::Trait for Test {
@@ -195,6 +299,7 @@ impl membership::Trait for Test {
     type SubscriptionId = u32;
     type PaidTermId = u32;
     type ActorId = u32;
+    type WeightInfo = ();
 }
 
 impl stake::Trait for Test {
@@ -210,10 +315,20 @@ impl minting::Trait for Test {
     type MintId = u64;
 }
 
+parameter_types! {
+    pub const MaxPayoutsPerBlock: u32 = 100;
+}
+
 impl recurringrewards::Trait for Test {
+    type Event = MetaEvent;
     type PayoutStatusHandler = ();
     type RecipientId = u64;
     type RewardRelationshipId = u64;
+    type MaxPayoutsPerBlock = MaxPayoutsPerBlock;
+}
+
+parameter_types! {
+    pub const ApplicationsRetentionPeriod: u64 = 100;
 }
 
 impl hiring::Trait for Test {
@@ -221,6 +336,7 @@ impl hiring::Trait for Test {
     type ApplicationId = u64;
     type ApplicationDeactivatedHandler = ();
     type StakeHandlerProvider = hiring::Module<Self>;
+    type ApplicationsRetentionPeriod = ApplicationsRetentionPeriod;
 }
 
 pub struct ExtBuilder {
@@ -228,6 +344,7 @@ pub struct ExtBuilder {
     first_content_id: u64,
     first_relationship_id: u64,
     first_metadata_id: u64,
+    default_quota_limits: data_directory::QuotaLimits,
 }
 
 impl Default for ExtBuilder {
@@ -237,6 +354,10 @@ impl Default for ExtBuilder {
             first_content_id: 2,
             first_relationship_id: 3,
             first_metadata_id: 4,
+            default_quota_limits: data_directory::QuotaLimits {
+                objects_limit: 100,
+                size_limit: 1_000_000,
+            },
         }
     }
 }
@@ -275,6 +396,14 @@ impl ExtBuilder {
         .assimilate_storage(&mut t)
         .unwrap();
 
+        data_directory::GenesisConfig::<Test> {
+            data_object_by_content_id: vec![],
+            known_content_ids: vec![],
+            default_quota_limits: self.default_quota_limits,
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+
         membership::GenesisConfig::<Test> {
             default_paid_membership_fee: 0,
             members: vec![membership::genesis::Member {
@@ -313,8 +442,11 @@ pub fn with_default_mock_builder<R, F: FnOnce() -> R>(f: F) -> R {
 }
 
 pub(crate) fn hire_storage_provider() -> (u64, u32) {
-    let storage_provider_id = 1;
-    let role_account_id = 1;
+    hire_storage_provider_with_id(1)
+}
+
+pub(crate) fn hire_storage_provider_with_id(storage_provider_id: u32) -> (u64, u32) {
+    let role_account_id = storage_provider_id as u64;
 
     let storage_provider = working_group::Worker {
         member_id: 1,
@@ -331,6 +463,14 @@ pub(crate) fn hire_storage_provider() -> (u64, u32) {
     (role_account_id, storage_provider_id)
 }
 
+pub(crate) fn set_storage_working_group_lead() -> (u64, u32) {
+    let (role_account_id, storage_provider_id) = hire_storage_provider();
+
+    <working_group::CurrentLead<Test, StorageWorkingGroupInstance>>::put(storage_provider_id);
+
+    (role_account_id, storage_provider_id)
+}
+
 // Recommendation from Parity on testing on_finalize
 // https://substrate.dev/docs/en/next/development/module/tests
 pub fn run_to_block(n: u64) {
@@ -3,4 +3,4 @@
 mod data_directory;
 mod data_object_storage_registry;
 mod data_object_type_registry;
-mod mock;
+pub(crate) mod mock;
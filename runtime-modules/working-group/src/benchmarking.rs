@@ -0,0 +1,502 @@
+//! Working group module benchmarking.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks_instance};
+use system::RawOrigin;
+
+const SEED: u32 = 0;
+const MAX_TEXT_LENGTH: u32 = 5000;
+const STAKE: u32 = 1000;
+const REWARD: u32 = 100;
+
+fn text(len: u32) -> Vec<u8> {
+    vec![b'a'; len as usize]
+}
+
+// Relaxes the text constraints to the benchmarked lengths and hires the leader.
+fn create_lead<T: Trait<I>, I: Instance>() -> T::AccountId {
+    let constraint = InputValidationLengthConstraint::new(1, MAX_TEXT_LENGTH as u16);
+
+    <OpeningHumanReadableText<I>>::put(constraint);
+    <WorkerApplicationHumanReadableText<I>>::put(constraint);
+    <WorkerExitRationaleText<I>>::put(constraint);
+
+    let lead_account_id: T::AccountId = account("lead", 0, SEED);
+    let leader_worker_id = Module::<T, I>::insert_benchmark_lead(&lead_account_id);
+
+    Module::<T, I>::worker_by_id(leader_worker_id).role_account_id
+}
+
+fn reward_policy<T: Trait<I>, I: Instance>() -> RewardPolicy<BalanceOfMint<T>, T::BlockNumber> {
+    RewardPolicy {
+        amount_per_payout: REWARD.into(),
+        next_payment_at_block: <system::Module<T>>::block_number() + One::one(),
+        payout_interval: Some(One::one()),
+    }
+}
+
+// Hires the worker with the role stake and the reward relationship (the worst case
+// for the worker management).
+fn create_worker<T: Trait<I>, I: Instance>() -> (T::AccountId, WorkerId<T>) {
+    let account_id: T::AccountId = account("worker", 0, SEED);
+    let worker_id = Module::<T, I>::hire_benchmark_worker(
+        OpeningType::Worker,
+        &account_id,
+        Some(STAKE.into()),
+        Some(reward_policy::<T, I>()),
+    );
+
+    (account_id, worker_id)
+}
+
+fn add_opening<T: Trait<I>, I: Instance>(
+    lead_account_id: &T::AccountId,
+    activate_at: hiring::ActivateOpeningAt<T::BlockNumber>,
+) -> OpeningId<T> {
+    let commitment = OpeningPolicyCommitment {
+        role_staking_policy: Some(hiring::StakingPolicy {
+            amount: STAKE.into(),
+            amount_mode: hiring::StakingAmountLimitMode::AtLeast,
+            crowded_out_unstaking_period_length: None,
+            review_period_expired_unstaking_period_length: None,
+        }),
+        ..OpeningPolicyCommitment::default()
+    };
+
+    let opening_id = Module::<T, I>::next_opening_id();
+    Module::<T, I>::add_opening(
+        RawOrigin::Signed(lead_account_id.clone()).into(),
+        activate_at,
+        commitment,
+        text(10),
+        OpeningType::Worker,
+    )
+    .expect("opening added");
+
+    opening_id
+}
+
+// Applies on the opening with the role stake on behalf of the new member.
+fn apply_on_opening<T: Trait<I>, I: Instance>(
+    opening_id: OpeningId<T>,
+    index: u32,
+) -> (T::AccountId, ApplicationId<T>) {
+    let account_id: T::AccountId = account("applicant", index, SEED);
+    let member_id = <membership::Module<T>>::insert_benchmark_member(&account_id);
+    let _ = CurrencyOf::<T>::deposit_creating(&account_id, STAKE.into());
+
+    let application_id = Module::<T, I>::next_application_id();
+    Module::<T, I>::apply_on_opening(
+        RawOrigin::Signed(account_id.clone()).into(),
+        member_id,
+        opening_id,
+        account_id.clone(),
+        Some(STAKE.into()),
+        None,
+        text(10),
+    )
+    .expect("applied on opening");
+
+    (account_id, application_id)
+}
+
+fn is_application_active<T: Trait<I>, I: Instance>(application_id: ApplicationId<T>) -> bool {
+    let application = Module::<T, I>::application_by_id(application_id);
+
+    hiring::ApplicationById::<T>::get(application.hiring_application_id).stage
+        == hiring::ApplicationStage::Active
+}
+
+fn reward_relationship_id<T: Trait<I>, I: Instance>(
+    worker_id: WorkerId<T>,
+) -> T::RewardRelationshipId {
+    Module::<T, I>::worker_by_id(worker_id)
+        .reward_relationship
+        .expect("reward relationship set")
+}
+
+benchmarks_instance! {
+    _ { }
+
+    update_role_account {
+        let lead_account_id = create_lead::<T, I>();
+        let (account_id, worker_id) = create_worker::<T, I>();
+        Module::<T, I>::set_deputy_permissions(
+            RawOrigin::Signed(lead_account_id).into(),
+            worker_id,
+            DeputyPermissions::default(),
+        )
+        .expect("deputy permissions set");
+        let new_role_account_id: T::AccountId = account("new_role_account", 0, SEED);
+    }: _(RawOrigin::Signed(account_id), worker_id, new_role_account_id.clone())
+    verify {
+        assert_eq!(
+            Module::<T, I>::worker_by_id(worker_id).role_account_id,
+            new_role_account_id
+        );
+    }
+
+    update_reward_account {
+        create_lead::<T, I>();
+        let (account_id, worker_id) = create_worker::<T, I>();
+        let new_reward_account_id: T::AccountId = account("new_reward_account", 0, SEED);
+    }: _(RawOrigin::Signed(account_id), worker_id, new_reward_account_id.clone())
+    verify {
+        let relationship_id = reward_relationship_id::<T, I>(worker_id);
+        let relationship = recurringrewards::Module::<T>::reward_relationships(relationship_id);
+
+        assert_eq!(relationship.account, new_reward_account_id);
+    }
+
+    update_reward_amount {
+        let lead_account_id = create_lead::<T, I>();
+        let (_, worker_id) = create_worker::<T, I>();
+        let new_amount: BalanceOfMint<T> = (2 * REWARD).into();
+    }: _(RawOrigin::Signed(lead_account_id), worker_id, new_amount)
+    verify {
+        let relationship_id = reward_relationship_id::<T, I>(worker_id);
+        let relationship = recurringrewards::Module::<T>::reward_relationships(relationship_id);
+
+        assert_eq!(relationship.amount_per_payout, new_amount);
+    }
+
+    leave_role {
+        let i in 1 .. MAX_TEXT_LENGTH;
+
+        create_lead::<T, I>();
+        let (account_id, worker_id) = create_worker::<T, I>();
+    }: _(RawOrigin::Signed(account_id), worker_id, text(i))
+    verify {
+        assert!(!<WorkerById<T, I>>::contains_key(worker_id));
+    }
+
+    terminate_role {
+        let i in 1 .. MAX_TEXT_LENGTH;
+
+        let lead_account_id = create_lead::<T, I>();
+        let (_, worker_id) = create_worker::<T, I>();
+    }: _(RawOrigin::Signed(lead_account_id), worker_id, text(i), true)
+    verify {
+        assert!(!<WorkerById<T, I>>::contains_key(worker_id));
+    }
+
+    add_opening {
+        let i in 1 .. MAX_TEXT_LENGTH;
+
+        let lead_account_id = create_lead::<T, I>();
+        let opening_id = Module::<T, I>::next_opening_id();
+    }: _(
+        RawOrigin::Signed(lead_account_id),
+        hiring::ActivateOpeningAt::CurrentBlock,
+        OpeningPolicyCommitment::default(),
+        text(i),
+        OpeningType::Worker
+    )
+    verify {
+        assert!(<OpeningById<T, I>>::contains_key(opening_id));
+    }
+
+    accept_applications {
+        let lead_account_id = create_lead::<T, I>();
+        let activate_at = <system::Module<T>>::block_number() + One::one();
+        let opening_id = add_opening::<T, I>(
+            &lead_account_id,
+            hiring::ActivateOpeningAt::ExactBlock(activate_at),
+        );
+    }: _(RawOrigin::Signed(lead_account_id), opening_id)
+    verify {
+        let opening = Module::<T, I>::opening_by_id(opening_id);
+        let hiring_opening = hiring::OpeningById::<T>::get(opening.hiring_opening_id);
+
+        assert!(matches!(hiring_opening.stage, hiring::OpeningStage::Active { .. }));
+    }
+
+    apply_on_opening {
+        let i in 1 .. MAX_TEXT_LENGTH;
+
+        let lead_account_id = create_lead::<T, I>();
+        let opening_id = add_opening::<T, I>(
+            &lead_account_id,
+            hiring::ActivateOpeningAt::CurrentBlock,
+        );
+        let account_id: T::AccountId = account("applicant", 0, SEED);
+        let member_id = <membership::Module<T>>::insert_benchmark_member(&account_id);
+        let _ = CurrencyOf::<T>::deposit_creating(&account_id, STAKE.into());
+        let application_id = Module::<T, I>::next_application_id();
+    }: _(
+        RawOrigin::Signed(account_id.clone()),
+        member_id,
+        opening_id,
+        account_id.clone(),
+        Some(STAKE.into()),
+        None,
+        text(i)
+    )
+    verify {
+        assert!(<ApplicationById<T, I>>::contains_key(application_id));
+    }
+
+    withdraw_application {
+        let lead_account_id = create_lead::<T, I>();
+        let opening_id = add_opening::<T, I>(
+            &lead_account_id,
+            hiring::ActivateOpeningAt::CurrentBlock,
+        );
+        let (account_id, application_id) = apply_on_opening::<T, I>(opening_id, 0);
+    }: _(RawOrigin::Signed(account_id), application_id)
+    verify {
+        assert!(!is_application_active::<T, I>(application_id));
+    }
+
+    terminate_application {
+        let lead_account_id = create_lead::<T, I>();
+        let opening_id = add_opening::<T, I>(
+            &lead_account_id,
+            hiring::ActivateOpeningAt::CurrentBlock,
+        );
+        let (_, application_id) = apply_on_opening::<T, I>(opening_id, 0);
+    }: _(RawOrigin::Signed(lead_account_id), application_id)
+    verify {
+        assert!(!is_application_active::<T, I>(application_id));
+    }
+
+    begin_applicant_review {
+        let lead_account_id = create_lead::<T, I>();
+        let opening_id = add_opening::<T, I>(
+            &lead_account_id,
+            hiring::ActivateOpeningAt::CurrentBlock,
+        );
+        apply_on_opening::<T, I>(opening_id, 0);
+    }: _(RawOrigin::Signed(lead_account_id), opening_id)
+    verify {
+        let opening = Module::<T, I>::opening_by_id(opening_id);
+        let hiring_opening = hiring::OpeningById::<T>::get(opening.hiring_opening_id);
+
+        assert!(matches!(
+            hiring_opening.stage,
+            hiring::OpeningStage::Active {
+                stage: hiring::ActiveOpeningStage::ReviewPeriod { .. },
+                ..
+            }
+        ));
+    }
+
+    fill_opening {
+        // Half of the limit leaves room for the leader and for the workers hired by the
+        // benchmark tests running the setup repeatedly in the same storage.
+        let a in 1 .. T::MaxWorkerNumberLimit::get() / 2;
+
+        let lead_account_id = create_lead::<T, I>();
+        let opening_id = add_opening::<T, I>(
+            &lead_account_id,
+            hiring::ActivateOpeningAt::CurrentBlock,
+        );
+        let application_ids = (0..a)
+            .map(|index| apply_on_opening::<T, I>(opening_id, index).1)
+            .collect::<ApplicationIdSet<T>>();
+        Module::<T, I>::begin_applicant_review(
+            RawOrigin::Signed(lead_account_id.clone()).into(),
+            opening_id,
+        )
+        .expect("review began");
+        let worker_id = Module::<T, I>::next_worker_id();
+    }: _(
+        RawOrigin::Signed(lead_account_id),
+        opening_id,
+        application_ids,
+        Some(reward_policy::<T, I>())
+    )
+    verify {
+        assert!(<WorkerById<T, I>>::contains_key(worker_id));
+    }
+
+    slash_stake {
+        let lead_account_id = create_lead::<T, I>();
+        let (_, worker_id) = create_worker::<T, I>();
+        let member_id = Module::<T, I>::worker_by_id(worker_id).member_id;
+    }: _(RawOrigin::Signed(lead_account_id), worker_id, (STAKE / 2).into())
+    verify {
+        assert_eq!(Module::<T, I>::role_history(member_id, worker_id).slash_count, 1);
+    }
+
+    decrease_stake {
+        let lead_account_id = create_lead::<T, I>();
+        let (account_id, worker_id) = create_worker::<T, I>();
+        let amount: BalanceOf<T> = (STAKE / 2).into();
+        let balance = CurrencyOf::<T>::free_balance(&account_id);
+    }: _(RawOrigin::Signed(lead_account_id), worker_id, amount)
+    verify {
+        assert_eq!(CurrencyOf::<T>::free_balance(&account_id), balance + amount);
+    }
+
+    increase_stake {
+        create_lead::<T, I>();
+        let (account_id, worker_id) = create_worker::<T, I>();
+        let amount: BalanceOf<T> = STAKE.into();
+        let _ = CurrencyOf::<T>::deposit_creating(&account_id, amount);
+        let balance = CurrencyOf::<T>::free_balance(&account_id);
+    }: _(RawOrigin::Signed(account_id.clone()), worker_id, amount)
+    verify {
+        assert_eq!(CurrencyOf::<T>::free_balance(&account_id), balance - amount);
+    }
+
+    set_mint_capacity {
+        let capacity: minting::BalanceOf<T> = STAKE.into();
+    }: _(RawOrigin::Root, capacity)
+    verify {
+        assert_eq!(<minting::Module<T>>::mints(Module::<T, I>::mint()).capacity(), capacity);
+    }
+
+    spend_from_mint {
+        let i in 1 .. MAX_TEXT_LENGTH;
+
+        let lead_account_id = create_lead::<T, I>();
+        let amount: minting::BalanceOf<T> = REWARD.into();
+        Module::<T, I>::set_mint_capacity(RawOrigin::Root.into(), amount)
+            .expect("mint capacity set");
+        Module::<T, I>::set_spending_limit(
+            RawOrigin::Root.into(),
+            Some(SpendingLimit {
+                amount_per_period: amount,
+                period_length: One::one(),
+            }),
+        )
+        .expect("spending limit set");
+        let account_id: T::AccountId = account("recipient", 0, SEED);
+        let member_id = <membership::Module<T>>::insert_benchmark_member(&account_id);
+        let balance = <T as minting::Trait>::Currency::free_balance(&account_id);
+    }: _(
+        RawOrigin::Signed(lead_account_id),
+        PaymentRecipient::Member(member_id),
+        amount,
+        text(i)
+    )
+    verify {
+        assert_eq!(
+            <T as minting::Trait>::Currency::free_balance(&account_id),
+            balance + amount
+        );
+    }
+
+    set_spending_limit {
+        let spending_limit = SpendingLimit {
+            amount_per_period: REWARD.into(),
+            period_length: One::one(),
+        };
+    }: _(RawOrigin::Root, Some(spending_limit.clone()))
+    verify {
+        assert_eq!(Module::<T, I>::payment_spending_limit(), Some(spending_limit));
+    }
+
+    set_missed_reward_payout_policy {
+    }: _(RawOrigin::Root, recurringrewards::MissedPayoutPolicy::AccrueDebt)
+    verify {
+        assert_eq!(
+            Module::<T, I>::missed_reward_payout_policy(),
+            recurringrewards::MissedPayoutPolicy::AccrueDebt
+        );
+    }
+
+    set_worker_missed_reward_payout_policy {
+        let lead_account_id = create_lead::<T, I>();
+        let (_, worker_id) = create_worker::<T, I>();
+    }: _(
+        RawOrigin::Signed(lead_account_id),
+        worker_id,
+        recurringrewards::MissedPayoutPolicy::AccrueDebt
+    )
+    verify {
+        let relationship_id = reward_relationship_id::<T, I>(worker_id);
+
+        assert_eq!(
+            recurringrewards::Module::<T>::missed_payout_policy(relationship_id),
+            recurringrewards::MissedPayoutPolicy::AccrueDebt
+        );
+    }
+
+    set_deputy_permissions {
+        let lead_account_id = create_lead::<T, I>();
+        let (_, worker_id) = create_worker::<T, I>();
+        let permissions = DeputyPermissions {
+            manage_openings: true,
+            update_rewards: true,
+            max_slash_amount: Some(STAKE.into()),
+        };
+    }: _(RawOrigin::Signed(lead_account_id), worker_id, permissions.clone())
+    verify {
+        assert_eq!(Module::<T, I>::deputy_permissions_by_worker_id(worker_id), permissions);
+    }
+
+    revoke_deputy_permissions {
+        let lead_account_id = create_lead::<T, I>();
+        let (_, worker_id) = create_worker::<T, I>();
+        Module::<T, I>::set_deputy_permissions(
+            RawOrigin::Signed(lead_account_id.clone()).into(),
+            worker_id,
+            DeputyPermissions::default(),
+        )
+        .expect("deputy permissions set");
+    }: _(RawOrigin::Signed(lead_account_id), worker_id)
+    verify {
+        assert!(!<DeputyPermissionsByWorkerId<T, I>>::contains_key(worker_id));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::mock::{build_test_externalities, Test, TestWorkingGroupInstance};
+    use frame_support::assert_ok;
+
+    // Every benchmark runs in the fresh storage: the setups hire the workers, which are
+    // limited by the mock.
+    fn assert_benchmark_runs(benchmark: fn() -> Result<(), &'static str>) {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(benchmark());
+        });
+    }
+
+    #[test]
+    fn working_group_benchmarks_run_successfully() {
+        assert_benchmark_runs(test_benchmark_update_role_account::<Test, TestWorkingGroupInstance>);
+        assert_benchmark_runs(
+            test_benchmark_update_reward_account::<Test, TestWorkingGroupInstance>,
+        );
+        assert_benchmark_runs(
+            test_benchmark_update_reward_amount::<Test, TestWorkingGroupInstance>,
+        );
+        assert_benchmark_runs(test_benchmark_leave_role::<Test, TestWorkingGroupInstance>);
+        assert_benchmark_runs(test_benchmark_terminate_role::<Test, TestWorkingGroupInstance>);
+        assert_benchmark_runs(test_benchmark_add_opening::<Test, TestWorkingGroupInstance>);
+        assert_benchmark_runs(test_benchmark_accept_applications::<Test, TestWorkingGroupInstance>);
+        assert_benchmark_runs(test_benchmark_apply_on_opening::<Test, TestWorkingGroupInstance>);
+        assert_benchmark_runs(
+            test_benchmark_withdraw_application::<Test, TestWorkingGroupInstance>,
+        );
+        assert_benchmark_runs(
+            test_benchmark_terminate_application::<Test, TestWorkingGroupInstance>,
+        );
+        assert_benchmark_runs(
+            test_benchmark_begin_applicant_review::<Test, TestWorkingGroupInstance>,
+        );
+        assert_benchmark_runs(test_benchmark_fill_opening::<Test, TestWorkingGroupInstance>);
+        assert_benchmark_runs(test_benchmark_slash_stake::<Test, TestWorkingGroupInstance>);
+        assert_benchmark_runs(test_benchmark_decrease_stake::<Test, TestWorkingGroupInstance>);
+        assert_benchmark_runs(test_benchmark_increase_stake::<Test, TestWorkingGroupInstance>);
+        assert_benchmark_runs(test_benchmark_set_mint_capacity::<Test, TestWorkingGroupInstance>);
+        assert_benchmark_runs(test_benchmark_spend_from_mint::<Test, TestWorkingGroupInstance>);
+        assert_benchmark_runs(test_benchmark_set_spending_limit::<Test, TestWorkingGroupInstance>);
+        assert_benchmark_runs(
+            test_benchmark_set_missed_reward_payout_policy::<Test, TestWorkingGroupInstance>,
+        );
+        assert_benchmark_runs(
+            test_benchmark_set_worker_missed_reward_payout_policy::<Test, TestWorkingGroupInstance>,
+        );
+        assert_benchmark_runs(
+            test_benchmark_set_deputy_permissions::<Test, TestWorkingGroupInstance>,
+        );
+        assert_benchmark_runs(
+            test_benchmark_revoke_deputy_permissions::<Test, TestWorkingGroupInstance>,
+        );
+    }
+}
@@ -305,6 +305,39 @@ decl_error! {
 
         /// Minting error: NextAdjustmentInPast
         MintingErrorNextAdjustmentInPast,
+
+        /// Minting error: NotEnoughCapacity
+        MintingErrorNotEnoughCapacity,
+
+        /// Payment amount cannot be zero.
+        PaymentAmountCannotBeZero,
+
+        /// Payment rationale text is too short.
+        PaymentRationaleTextTooShort,
+
+        /// Payment rationale text is too long.
+        PaymentRationaleTextTooLong,
+
+        /// Payments within the spending period exceed the spending limit.
+        SpendingLimitExceeded,
+
+        /// Spending period length cannot be zero.
+        SpendingPeriodLengthCannotBeZero,
+
+        /// Payments are disabled: the spending limit is not set.
+        SpendingLimitIsNotSet,
+
+        /// The leader cannot be paid from the working group mint.
+        CannotPayLeader,
+
+        /// Cannot delegate the permissions to the leader.
+        CannotSetLeaderAsDeputy,
+
+        /// Worker has no delegated permissions.
+        WorkerIsNotDeputy,
+
+        /// Role account is used by another deputy.
+        DeputyRoleAccountIsUsed,
     }
 }
 
@@ -682,3 +715,14 @@ impl<T: Trait<I>, I: Instance> sp_std::convert::From<WrappedError<minting::Gener
         }
     }
 }
+
+impl<T: Trait<I>, I: Instance> sp_std::convert::From<WrappedError<minting::TransferError>>
+    for Error<T, I>
+{
+    fn from(wrapper: WrappedError<minting::TransferError>) -> Self {
+        match wrapper.error {
+            minting::TransferError::MintNotFound => Error::CannotFindMint,
+            minting::TransferError::NotEnoughCapacity => Error::MintingErrorNotEnoughCapacity,
+        }
+    }
+}
@@ -32,6 +32,8 @@
 //! - [leave_role](./struct.Module.html#method.leave_role) - Leave the role by the active worker/lead.
 //! - [terminate_role](./struct.Module.html#method.terminate_role) - Terminate the worker/lead role.
 //! - [set_mint_capacity](./struct.Module.html#method.set_mint_capacity) -  Sets the capacity to enable working group budget.
+//! - [spend_from_mint](./struct.Module.html#method.spend_from_mint) - Makes a one-off payment from the working group mint.
+//! - [set_spending_limit](./struct.Module.html#method.set_spending_limit) - Sets the spending limit of the one-off payments.
 //!
 //! ### Stakes
 //!
@@ -39,6 +41,11 @@
 //! - [decrease_stake](./struct.Module.html#method.decrease_stake) - Decreases the worker/lead stake and returns the remainder to the worker _role_account_.
 //! - [increase_stake](./struct.Module.html#method.increase_stake) - Increases the worker/lead stake.
 //!
+//! ### Deputies
+//!
+//! - [set_deputy_permissions](./struct.Module.html#method.set_deputy_permissions) - Delegates the leader permissions to the worker.
+//! - [revoke_deputy_permissions](./struct.Module.html#method.revoke_deputy_permissions) - Revokes the permissions delegated to the worker.
+//!
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -46,6 +53,8 @@
 // Do not delete! Cannot be uncommented by default, because of Parity decl_module! issue.
 //#![warn(missing_docs)]
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 #[cfg(test)]
 mod tests;
 mod types;
@@ -53,10 +62,11 @@ mod types;
 mod errors;
 
 use frame_support::dispatch::{DispatchError, DispatchResult};
-use frame_support::storage::IterableStorageMap;
+use frame_support::storage::{IterableStorageDoubleMap, IterableStorageMap};
 use frame_support::traits::{Currency, ExistenceRequirement, Get, Imbalance, WithdrawReasons};
+use frame_support::weights::Weight;
 use frame_support::{decl_event, decl_module, decl_storage, ensure, print, StorageValue};
-use sp_arithmetic::traits::{Bounded, One, Zero};
+use sp_arithmetic::traits::{Bounded, One, Saturating, Zero};
 use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
 use sp_std::vec;
 use sp_std::vec::Vec;
@@ -68,10 +78,14 @@ use errors::WrappedError;
 
 pub use errors::Error;
 pub use types::{
-    Application, Opening, OpeningPolicyCommitment, OpeningType, RewardPolicy, RoleStakeProfile,
-    Worker,
+    Application, DeputyPermissions, Opening, OpeningPolicyCommitment, OpeningType,
+    PaymentRecipient, RewardPolicy, RoleExit, RoleHistoryEntry, RoleStakeProfile, SpendingLimit,
+    SpendingPeriod, Worker,
 };
 
+// Storage version with the workers indexed by their role accounts.
+const STORAGE_VERSION: u32 = 1;
+
 /// Stake identifier in staking module
 pub type StakeId<T> = <T as stake::Trait>::StakeId;
 
@@ -135,6 +149,26 @@ type WorkerOf<T> = Worker<
     MemberId<T>,
 >;
 
+// Type simplification
+type RoleHistoryEntryOf<T> = RoleHistoryEntry<
+    WorkerId<T>,
+    <T as system::Trait>::BlockNumber,
+    BalanceOf<T>,
+    BalanceOfMint<T>,
+>;
+
+// Type simplification
+type DeputyPermissionsOf<T> = DeputyPermissions<BalanceOf<T>>;
+
+// Type simplification
+type PaymentRecipientOf<T> = PaymentRecipient<WorkerId<T>, MemberId<T>>;
+
+// Type simplification
+type SpendingLimitOf<T> = SpendingLimit<BalanceOfMint<T>, <T as system::Trait>::BlockNumber>;
+
+// Type simplification
+type SpendingPeriodOf<T> = SpendingPeriod<BalanceOfMint<T>, <T as system::Trait>::BlockNumber>;
+
 // Type simplification
 type OpeningOf<T> = Opening<
     <T as hiring::Trait>::OpeningId,
@@ -147,6 +181,104 @@ type OpeningOf<T> = Opening<
 type ApplicationOf<T> =
     Application<<T as system::Trait>::AccountId, OpeningId<T>, MemberId<T>, HiringApplicationId<T>>;
 
+/// Extrinsic weights for the _Working group_ module. Shared by all the module instances.
+/// `i` - length of the provided text (rationale or human readable text) in bytes,
+/// `a` - number of the applications to fill the opening with.
+pub trait WeightInfo {
+    fn update_role_account() -> Weight;
+    fn update_reward_account() -> Weight;
+    fn update_reward_amount() -> Weight;
+    fn leave_role(i: u32) -> Weight;
+    fn terminate_role(i: u32) -> Weight;
+    fn add_opening(i: u32) -> Weight;
+    fn accept_applications() -> Weight;
+    fn apply_on_opening(i: u32) -> Weight;
+    fn withdraw_application() -> Weight;
+    fn terminate_application() -> Weight;
+    fn begin_applicant_review() -> Weight;
+    fn fill_opening(a: u32) -> Weight;
+    fn slash_stake() -> Weight;
+    fn decrease_stake() -> Weight;
+    fn increase_stake() -> Weight;
+    fn set_mint_capacity() -> Weight;
+    fn spend_from_mint(i: u32) -> Weight;
+    fn set_spending_limit() -> Weight;
+    fn set_missed_reward_payout_policy() -> Weight;
+    fn set_worker_missed_reward_payout_policy() -> Weight;
+    fn set_deputy_permissions() -> Weight;
+    fn revoke_deputy_permissions() -> Weight;
+}
+
+// Default weights, used in the tests and by the runtimes without benchmarked weights.
+impl WeightInfo for () {
+    fn update_role_account() -> Weight {
+        10_000_000
+    }
+    fn update_reward_account() -> Weight {
+        10_000_000
+    }
+    fn update_reward_amount() -> Weight {
+        10_000_000
+    }
+    fn leave_role(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn terminate_role(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn add_opening(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn accept_applications() -> Weight {
+        10_000_000
+    }
+    fn apply_on_opening(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn withdraw_application() -> Weight {
+        10_000_000
+    }
+    fn terminate_application() -> Weight {
+        10_000_000
+    }
+    fn begin_applicant_review() -> Weight {
+        10_000_000
+    }
+    fn fill_opening(_a: u32) -> Weight {
+        10_000_000
+    }
+    fn slash_stake() -> Weight {
+        10_000_000
+    }
+    fn decrease_stake() -> Weight {
+        10_000_000
+    }
+    fn increase_stake() -> Weight {
+        10_000_000
+    }
+    fn set_mint_capacity() -> Weight {
+        10_000_000
+    }
+    fn spend_from_mint(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn set_spending_limit() -> Weight {
+        10_000_000
+    }
+    fn set_missed_reward_payout_policy() -> Weight {
+        10_000_000
+    }
+    fn set_worker_missed_reward_payout_policy() -> Weight {
+        10_000_000
+    }
+    fn set_deputy_permissions() -> Weight {
+        10_000_000
+    }
+    fn revoke_deputy_permissions() -> Weight {
+        10_000_000
+    }
+}
+
 /// The _Working group_ main _Trait_
 pub trait Trait<I: Instance>:
     system::Trait
@@ -161,6 +293,22 @@ pub trait Trait<I: Instance>:
 
     /// Defines max workers number in the working group.
     type MaxWorkerNumberLimit: Get<u32>;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
+
+    /// Handles the workers leaving the working group.
+    type WorkerExitHandler: WorkerExitHandler<Self, I>;
+}
+
+/// Handles the workers leaving the working group.
+pub trait WorkerExitHandler<T: Trait<I>, I: Instance> {
+    /// Called after the worker was removed from the working group.
+    fn worker_exited(worker_id: &WorkerId<T>);
+}
+
+impl<T: Trait<I>, I: Instance> WorkerExitHandler<T, I> for () {
+    fn worker_exited(_worker_id: &WorkerId<T>) {}
 }
 
 decl_event!(
@@ -175,6 +323,10 @@ decl_event!(
         RationaleText = Vec<u8>,
         MintBalanceOf = minting::BalanceOf<T>,
         <T as minting::Trait>::MintId,
+        PaymentRecipient = PaymentRecipientOf<T>,
+        SpendingLimit = SpendingLimitOf<T>,
+        DeputyPermissions = DeputyPermissionsOf<T>,
+        MissedPayoutPolicy = recurringrewards::MissedPayoutPolicy,
     {
         /// Emits on setting the leader.
         /// Params:
@@ -277,6 +429,41 @@ decl_event!(
         /// - mint id.
         /// - new mint balance.
         MintCapacityChanged(MintId, MintBalanceOf),
+
+        /// Emits on making the one-off payment from the working group mint.
+        /// Params:
+        /// - payment recipient.
+        /// - account the payment was deposited to.
+        /// - payment amount.
+        /// - rationale text.
+        PaymentMade(PaymentRecipient, AccountId, MintBalanceOf, RationaleText),
+
+        /// Emits on setting the spending limit of the one-off payments.
+        /// Params:
+        /// - new spending limit, no limit when not set.
+        SpendingLimitSet(Option<SpendingLimit>),
+
+        /// Emits on setting the policy applied to the missed reward payouts of the hired workers.
+        /// Params:
+        /// - new missed payout policy.
+        MissedRewardPayoutPolicySet(MissedPayoutPolicy),
+
+        /// Emits on setting the policy applied to the missed reward payouts of the worker.
+        /// Params:
+        /// - worker id.
+        /// - new missed payout policy.
+        WorkerMissedRewardPayoutPolicySet(WorkerId, MissedPayoutPolicy),
+
+        /// Emits on setting the deputy permissions of the worker.
+        /// Params:
+        /// - worker id.
+        /// - deputy permissions.
+        DeputyPermissionsSet(WorkerId, DeputyPermissions),
+
+        /// Emits on revoking the deputy permissions of the worker.
+        /// Params:
+        /// - worker id.
+        DeputyPermissionsRevoked(WorkerId),
     }
 );
 
@@ -325,6 +512,42 @@ decl_storage! {
         /// Required by StakingEventsHandler callback call to refund the balance on unstaking.
         pub MemberIdByHiringApplicationId get(fn member_id_by_hiring_application_id):
             map hasher(blake2_128_concat) HiringApplicationId<T> =>  MemberId<T>;
+
+        /// Limit of the one-off payments from the working group mint per spending period.
+        /// Payments are disabled when not set.
+        pub PaymentSpendingLimit get(fn payment_spending_limit): Option<SpendingLimitOf<T>>;
+
+        /// Payments made within the current spending period.
+        pub CurrentSpendingPeriod get(fn current_spending_period): SpendingPeriodOf<T>;
+
+        /// Policy applied to the missed reward payouts of the workers hired from now on.
+        /// Missed payouts are discarded by default.
+        pub MissedRewardPayoutPolicy get(fn missed_reward_payout_policy):
+            recurringrewards::MissedPayoutPolicy;
+
+        /// Permissions delegated by the current leader to the workers (deputies).
+        /// Cleared when the leader changes.
+        pub DeputyPermissionsByWorkerId get(fn deputy_permissions_by_worker_id):
+            map hasher(blake2_128_concat) WorkerId<T> => DeputyPermissionsOf<T>;
+
+        /// Deputies by their role accounts. A role account can be used by a single deputy.
+        pub DeputyWorkerIdByRoleAccountId get(fn deputy_worker_id_by_role_account_id):
+            map hasher(blake2_128_concat) T::AccountId => Option<WorkerId<T>>;
+
+        /// Workers by their role accounts. A role account can be used by several workers.
+        pub WorkerIdsByRoleAccountId: double_map
+            hasher(blake2_128_concat) T::AccountId, hasher(blake2_128_concat) WorkerId<T> => ();
+
+        /// Role history of the members: appointments, slashes, rewards and exits.
+        /// Contains the roles appointed since the role history introduction.
+        pub RoleHistory get(fn role_history): double_map
+            hasher(blake2_128_concat) MemberId<T>, hasher(blake2_128_concat) WorkerId<T> =>
+                RoleHistoryEntryOf<T>;
+
+        /// Version of the module storage. New chains start with the current version, the older
+        /// versions are updated by `migrate_worker_ids_by_role_account_id`.
+        pub StorageVersion get(fn storage_version) build(|_config: &GenesisConfig<T, I>|
+            STORAGE_VERSION): u32;
     }
         add_extra_genesis {
         config(phantom): sp_std::marker::PhantomData<I>;
@@ -357,7 +580,7 @@ decl_module! {
         // ****************** Roles lifecycle **********************
 
         /// Update the associated role account of the active worker/lead.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::update_role_account()]
         pub fn update_role_account(
             origin,
             worker_id: WorkerId<T>,
@@ -371,6 +594,11 @@ decl_module! {
                 membership::Module::<T>::ensure_member_controller_account_signed(origin, &worker.member_id)
             )?;
 
+            let is_deputy = <DeputyPermissionsByWorkerId<T, I>>::contains_key(worker_id);
+            if is_deputy {
+                Self::ensure_deputy_role_account_is_free(&worker_id, &new_role_account_id)?;
+            }
+
             //
             // == MUTATION SAFE ==
             //
@@ -379,13 +607,20 @@ decl_module! {
             WorkerById::<T, I>::mutate(worker_id, |worker| {
                 worker.role_account_id = new_role_account_id.clone()
             });
+            <WorkerIdsByRoleAccountId<T, I>>::remove(&worker.role_account_id, worker_id);
+            <WorkerIdsByRoleAccountId<T, I>>::insert(&new_role_account_id, worker_id, ());
+
+            if is_deputy {
+                <DeputyWorkerIdByRoleAccountId<T, I>>::remove(&worker.role_account_id);
+                <DeputyWorkerIdByRoleAccountId<T, I>>::insert(&new_role_account_id, worker_id);
+            }
 
             // Trigger event
             Self::deposit_event(RawEvent::WorkerRoleAccountUpdated(worker_id, new_role_account_id));
         }
 
         /// Update the reward account associated with a set reward relationship for the active worker.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::update_reward_account()]
         pub fn update_reward_account(
             origin,
             worker_id: WorkerId<T>,
@@ -417,14 +652,18 @@ decl_module! {
 
         /// Update the reward amount associated with a set reward relationship for the active worker.
         /// Require signed leader origin or the root (to update leader reward amount).
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::update_reward_amount()]
         pub fn update_reward_amount(
             origin,
             worker_id: WorkerId<T>,
             new_amount: BalanceOfMint<T>
         ) {
-            // Ensure lead is set and is origin signer or it is the council.
-            Self::ensure_origin_for_leader(origin, worker_id)?;
+            // Ensure lead is set and is origin signer (or a permitted deputy) or it is the council.
+            Self::ensure_origin_for_leader_or_deputy(
+                origin,
+                worker_id,
+                |permissions| permissions.update_rewards
+            )?;
 
             // Ensuring worker actually exists
             let worker = Self::ensure_worker_exists(&worker_id)?;
@@ -451,7 +690,7 @@ decl_module! {
         }
 
         /// Leave the role by the active worker.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::leave_role(rationale_text.len() as u32)]
         pub fn leave_role(
             origin,
             worker_id: WorkerId<T>,
@@ -460,6 +699,8 @@ decl_module! {
             // Ensure there is a signer which matches role account of worker corresponding to provided id.
             let active_worker = Self::ensure_worker_signed(origin, &worker_id)?;
 
+            Self::ensure_worker_exit_rationale_text_is_valid(&rationale_text)?;
+
             //
             // == MUTATION SAFE ==
             //
@@ -474,7 +715,7 @@ decl_module! {
 
         /// Terminate the active worker by the lead.
         /// Require signed leader origin or the root (to terminate the leader role).
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::terminate_role(rationale_text.len() as u32)]
         pub fn terminate_role(
             origin,
             worker_id: WorkerId<T>,
@@ -512,7 +753,7 @@ decl_module! {
 
         /// Add an opening for a worker role.
         /// Require signed leader origin or the root (to add opening for the leader position).
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::add_opening(human_readable_text.len() as u32)]
         pub fn add_opening(
             origin,
             activate_at: hiring::ActivateOpeningAt<T::BlockNumber>,
@@ -568,7 +809,7 @@ decl_module! {
 
         /// Begin accepting worker applications to an opening that is active.
         /// Require signed leader origin or the root (to accept applications for the leader position).
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::accept_applications()]
         pub fn accept_applications(origin, opening_id: OpeningId<T>)  {
             // Ensure opening exists in this working group
             // NB: Even though call to hiring module will have implicit check for
@@ -595,7 +836,7 @@ decl_module! {
         }
 
         /// Apply on a worker opening.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::apply_on_opening(human_readable_text.len() as u32)]
         pub fn apply_on_opening(
             origin,
             member_id: T::MemberId,
@@ -690,7 +931,7 @@ decl_module! {
         }
 
         /// Withdraw the worker application. Can be done by the worker itself only.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::withdraw_application()]
         pub fn withdraw_application(
             origin,
             application_id: ApplicationId<T>
@@ -726,14 +967,17 @@ decl_module! {
         }
 
         /// Terminate the worker application. Can be done by the lead only.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::terminate_application()]
         pub fn terminate_application(
             origin,
             application_id: ApplicationId<T>
         ) {
 
-            // Ensure lead is set and is origin signer
-            Self::ensure_origin_is_active_leader(origin)?;
+            // Ensure lead (or a deputy managing openings) is set and is origin signer
+            Self::ensure_origin_is_active_leader_or_deputy(
+                origin,
+                |permissions| permissions.manage_openings
+            )?;
 
             // Ensuring worker application actually exists
             let (application, _, opening) = Self::ensure_application_exists(&application_id)?;
@@ -758,7 +1002,7 @@ decl_module! {
 
         /// Begin reviewing, and therefore not accepting new applications.
         /// Require signed leader origin or the root (to begin review applications for the leader position).
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::begin_applicant_review()]
         pub fn begin_applicant_review(origin, opening_id: OpeningId<T>) {
             // Ensure opening exists
             // NB: Even though call to hiring modul will have implicit check for
@@ -784,7 +1028,7 @@ decl_module! {
 
         /// Fill opening for worker/lead.
         /// Require signed leader origin or the root (to fill opening for the leader position).
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::fill_opening(successful_application_ids.len() as u32)]
         pub fn fill_opening(
             origin,
             opening_id: OpeningId<T>,
@@ -888,10 +1132,15 @@ decl_module! {
         /// Slashes the worker stake, demands a leader origin. No limits, no actions on zero stake.
         /// If slashing balance greater than the existing stake - stake is slashed to zero.
         /// Require signed leader origin or the root (to slash the leader stake).
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::slash_stake()]
         pub fn slash_stake(origin, worker_id: WorkerId<T>, balance: BalanceOf<T>) {
-            // Ensure lead is set or it is the council terminating the leader.
-            Self::ensure_origin_for_leader(origin, worker_id)?;
+            // Ensure lead is set (or a deputy permitted to slash the balance) or it is the council
+            // slashing the leader.
+            Self::ensure_origin_for_leader_or_deputy(origin, worker_id, |permissions| {
+                permissions
+                    .max_slash_amount
+                    .map_or(false, |max_slash_amount| balance <= max_slash_amount)
+            })?;
 
             // Ensuring worker actually exists.
             let worker = Self::ensure_worker_exists(&worker_id)?;
@@ -905,7 +1154,7 @@ decl_module! {
             //
 
             // This external module call both checks and mutates the state.
-            ensure_on_wrapped_error!(
+            let slash_outcome = ensure_on_wrapped_error!(
                 <stake::Module<T>>::slash_immediate(
                     &stake_profile.stake_id,
                     balance,
@@ -913,13 +1162,18 @@ decl_module! {
                 )
             )?;
 
+            Self::update_role_history(worker.member_id, worker_id, |entry| {
+                entry.slash_count = entry.slash_count.saturating_add(1);
+                entry.total_slashed = entry.total_slashed.saturating_add(slash_outcome.actually_slashed);
+            });
+
             Self::deposit_event(RawEvent::StakeSlashed(worker_id));
         }
 
         /// Decreases the worker/lead stake and returns the remainder to the worker role_account_id.
         /// Can be decreased to zero, no actions on zero stake.
         /// Require signed leader origin or the root (to decrease the leader stake).
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::decrease_stake()]
         pub fn decrease_stake(origin, worker_id: WorkerId<T>, balance: BalanceOf<T>) {
             // Ensure lead is set or it is the council terminating the leader.
             Self::ensure_origin_for_leader(origin, worker_id)?;
@@ -948,7 +1202,7 @@ decl_module! {
 
         /// Increases the worker/lead stake, demands a worker origin. Transfers tokens from the worker
         /// role_account_id to the stake. No limits on the stake.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::increase_stake()]
         pub fn increase_stake(origin, worker_id: WorkerId<T>, balance: BalanceOf<T>) {
             // Checks worker origin, worker existence
             let worker = Self::ensure_worker_signed(origin, &worker_id)?;
@@ -974,7 +1228,7 @@ decl_module! {
         }
 
         /// Sets the capacity to enable working group budget. Requires root origin.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::set_mint_capacity()]
         pub fn set_mint_capacity(
             origin,
             new_capacity: minting::BalanceOf<T>
@@ -1003,6 +1257,182 @@ decl_module! {
                 Self::deposit_event(RawEvent::MintCapacityChanged(mint_id, new_capacity));
             }
         }
+
+        /// Makes a one-off payment from the working group mint to the worker or member account:
+        /// bounties, reimbursements, etc. The total amount of the payments per spending period is
+        /// limited by the spending limit, no payments are allowed without the limit. The leader
+        /// cannot pay themselves. Require signed leader origin.
+        #[weight = <T as Trait<I>>::WeightInfo::spend_from_mint(rationale_text.len() as u32)]
+        pub fn spend_from_mint(
+            origin,
+            recipient: PaymentRecipientOf<T>,
+            amount: minting::BalanceOf<T>,
+            rationale_text: Vec<u8>
+        ) {
+            // Ensure lead is set and is origin signer.
+            Self::ensure_origin_is_active_leader(origin)?;
+
+            ensure!(amount != Zero::zero(), Error::<T, I>::PaymentAmountCannotBeZero);
+
+            Self::ensure_payment_rationale_text_is_valid(&rationale_text)?;
+
+            let recipient_account_id = Self::ensure_payment_recipient_account(&recipient)?;
+
+            let spending_period = Self::ensure_payment_is_within_spending_limit(amount)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // This external module call both checks and mutates the state.
+            ensure_on_wrapped_error!(
+                <minting::Module<T>>::transfer_tokens(Self::mint(), amount, &recipient_account_id)
+            )?;
+
+            <CurrentSpendingPeriod<T, I>>::put(spending_period);
+
+            Self::deposit_event(
+                RawEvent::PaymentMade(recipient, recipient_account_id, amount, rationale_text)
+            );
+        }
+
+        /// Sets the limit of the one-off payments from the working group mint per spending period
+        /// and starts a new spending period. Removes the limit (disabling the payments) when
+        /// not provided.
+        /// Require root origin.
+        #[weight = <T as Trait<I>>::WeightInfo::set_spending_limit()]
+        pub fn set_spending_limit(origin, spending_limit: Option<SpendingLimitOf<T>>) {
+            ensure_root(origin)?;
+
+            if let Some(ref spending_limit) = spending_limit {
+                ensure!(
+                    spending_limit.period_length != Zero::zero(),
+                    Error::<T, I>::SpendingPeriodLengthCannotBeZero
+                );
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            if let Some(ref spending_limit) = spending_limit {
+                <PaymentSpendingLimit<T, I>>::put(spending_limit);
+            } else {
+                <PaymentSpendingLimit<T, I>>::kill();
+            }
+
+            <CurrentSpendingPeriod<T, I>>::put(SpendingPeriod {
+                started_at_block: <system::Module<T>>::block_number(),
+                spent: Zero::zero(),
+            });
+
+            Self::deposit_event(RawEvent::SpendingLimitSet(spending_limit));
+        }
+
+        /// Sets the policy applied to the missed reward payouts of the workers hired from now on.
+        /// Accruing the missed payouts as debt pays the workers when the mint regains capacity.
+        /// The policy of the hired workers is set with `set_worker_missed_reward_payout_policy`.
+        /// Require root origin.
+        #[weight = <T as Trait<I>>::WeightInfo::set_missed_reward_payout_policy()]
+        pub fn set_missed_reward_payout_policy(
+            origin,
+            policy: recurringrewards::MissedPayoutPolicy
+        ) {
+            ensure_root(origin)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <MissedRewardPayoutPolicy<I>>::put(policy);
+
+            Self::deposit_event(RawEvent::MissedRewardPayoutPolicySet(policy));
+        }
+
+        /// Sets the policy applied to the missed reward payouts of the active worker.
+        /// Switching to the `Discard` policy writes off the accrued debt.
+        /// Require signed leader origin or the root (to set the leader policy).
+        #[weight = <T as Trait<I>>::WeightInfo::set_worker_missed_reward_payout_policy()]
+        pub fn set_worker_missed_reward_payout_policy(
+            origin,
+            worker_id: WorkerId<T>,
+            policy: recurringrewards::MissedPayoutPolicy
+        ) {
+            // Ensure lead is set and is origin signer (or a permitted deputy) or it is the council.
+            Self::ensure_origin_for_leader_or_deputy(
+                origin,
+                worker_id,
+                |permissions| permissions.update_rewards
+            )?;
+
+            // Ensuring worker actually exists
+            let worker = Self::ensure_worker_exists(&worker_id)?;
+
+            // Ensure the worker actually has a recurring reward
+            let relationship_id = Self::ensure_worker_has_recurring_reward(&worker)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            ensure_on_wrapped_error!(
+                recurringrewards::Module::<T>::set_missed_payout_policy(relationship_id, policy)
+            )?;
+
+            Self::deposit_event(RawEvent::WorkerMissedRewardPayoutPolicySet(worker_id, policy));
+        }
+
+        // ****************** Deputies **********************
+
+        /// Delegates the permissions to the worker (deputy), replaces the existing permissions.
+        /// Require signed leader origin.
+        #[weight = <T as Trait<I>>::WeightInfo::set_deputy_permissions()]
+        pub fn set_deputy_permissions(
+            origin,
+            worker_id: WorkerId<T>,
+            permissions: DeputyPermissionsOf<T>
+        ) {
+            // Ensure lead is set and is origin signer.
+            Self::ensure_origin_is_active_leader(origin)?;
+
+            let worker = Self::ensure_worker_exists(&worker_id)?;
+
+            ensure!(
+                Self::current_lead() != Some(worker_id),
+                Error::<T, I>::CannotSetLeaderAsDeputy
+            );
+
+            Self::ensure_deputy_role_account_is_free(&worker_id, &worker.role_account_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <DeputyPermissionsByWorkerId<T, I>>::insert(worker_id, permissions.clone());
+            <DeputyWorkerIdByRoleAccountId<T, I>>::insert(&worker.role_account_id, worker_id);
+
+            Self::deposit_event(RawEvent::DeputyPermissionsSet(worker_id, permissions));
+        }
+
+        /// Revokes the permissions delegated to the worker (deputy). Require signed leader origin.
+        #[weight = <T as Trait<I>>::WeightInfo::revoke_deputy_permissions()]
+        pub fn revoke_deputy_permissions(origin, worker_id: WorkerId<T>) {
+            // Ensure lead is set and is origin signer.
+            Self::ensure_origin_is_active_leader(origin)?;
+
+            ensure!(
+                <DeputyPermissionsByWorkerId<T, I>>::contains_key(worker_id),
+                Error::<T, I>::WorkerIsNotDeputy
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::remove_deputy(&worker_id);
+
+            Self::deposit_event(RawEvent::DeputyPermissionsRevoked(worker_id));
+        }
     }
 }
 
@@ -1110,8 +1540,10 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
     ) -> DispatchResult {
         match opening_type {
             OpeningType::Worker => {
-                // Ensure lead is set and is origin signer.
-                Self::ensure_origin_is_active_leader(origin)
+                // Ensure lead (or a deputy managing openings) is set and is origin signer.
+                Self::ensure_origin_is_active_leader_or_deputy(origin, |permissions| {
+                    permissions.manage_openings
+                })
             }
             OpeningType::Leader => {
                 // Council proposal.
@@ -1123,18 +1555,34 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
     fn ensure_origin_for_leader(
         origin: T::Origin,
         worker_id: WorkerId<T>,
+    ) -> Result<ExitInitiationOrigin, DispatchError> {
+        Self::ensure_origin_for_leader_or_deputy(origin, worker_id, |_| false)
+    }
+
+    // Ensures origin is the council for the leader and the leader (or a deputy with the permission
+    // provided) for the regular workers. Only the leader manages the deputies.
+    fn ensure_origin_for_leader_or_deputy(
+        origin: T::Origin,
+        worker_id: WorkerId<T>,
+        has_permission: impl Fn(&DeputyPermissionsOf<T>) -> bool,
     ) -> Result<ExitInitiationOrigin, DispatchError> {
         let leader_worker_id = Self::ensure_lead_is_set()?;
 
-        let (worker_opening_type, exit_origin) = if leader_worker_id == worker_id {
-            (OpeningType::Leader, ExitInitiationOrigin::Sudo)
+        if leader_worker_id == worker_id {
+            // Council proposal.
+            ensure_root(origin)?;
+
+            Ok(ExitInitiationOrigin::Sudo)
         } else {
-            (OpeningType::Worker, ExitInitiationOrigin::Lead)
-        };
+            // Deputies cannot manage themselves or the other deputies.
+            let is_deputy = <DeputyPermissionsByWorkerId<T, I>>::contains_key(worker_id);
 
-        Self::ensure_origin_for_opening_type(origin, worker_opening_type)?;
+            Self::ensure_origin_is_active_leader_or_deputy(origin, |permissions| {
+                !is_deputy && has_permission(permissions)
+            })?;
 
-        Ok(exit_origin)
+            Ok(ExitInitiationOrigin::Lead)
+        }
     }
 
     fn ensure_lead_is_set() -> Result<WorkerId<T>, Error<T, I>> {
@@ -1178,6 +1626,29 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         Self::ensure_is_lead_account(signer)
     }
 
+    /// Ensures origin is signed by the leader or by the deputy with the permission provided.
+    /// Returns the leader check error otherwise.
+    pub fn ensure_origin_is_active_leader_or_deputy(
+        origin: T::Origin,
+        has_permission: impl Fn(&DeputyPermissionsOf<T>) -> bool,
+    ) -> DispatchResult {
+        // Ensure is signed
+        let signer = ensure_signed(origin)?;
+
+        Self::ensure_is_lead_account(signer.clone()).or_else(|err| {
+            let is_permitted_deputy = Self::deputy_worker_id_by_role_account_id(&signer)
+                .map_or(false, |worker_id| {
+                    has_permission(&Self::deputy_permissions_by_worker_id(worker_id))
+                });
+
+            if is_permitted_deputy {
+                Ok(())
+            } else {
+                Err(err)
+            }
+        })
+    }
+
     fn ensure_opening_exists(opening_id: &OpeningId<T>) -> Result<OpeningInfo<T>, Error<T, I>> {
         ensure!(
             OpeningById::<T, I>::contains_key(opening_id),
@@ -1329,6 +1800,96 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
             )
             .map_err(|e| DispatchError::Other(e))
     }
+
+    // Payment rationale text shares the length limits with the worker exit rationale text.
+    fn ensure_payment_rationale_text_is_valid(text: &[u8]) -> DispatchResult {
+        Self::worker_exit_rationale_text()
+            .ensure_valid(
+                text.len(),
+                Error::<T, I>::PaymentRationaleTextTooShort.into(),
+                Error::<T, I>::PaymentRationaleTextTooLong.into(),
+            )
+            .map_err(|e| DispatchError::Other(e))
+    }
+
+    // Returns the account to deposit the payment to.
+    fn ensure_payment_recipient_account(
+        recipient: &PaymentRecipientOf<T>,
+    ) -> Result<T::AccountId, Error<T, I>> {
+        match recipient {
+            PaymentRecipient::Worker(worker_id) => {
+                let worker = Self::ensure_worker_exists(worker_id)?;
+
+                // Covers the leader worker and the other workers of the leader member.
+                ensure!(
+                    !Self::is_leader_member(worker.member_id),
+                    Error::<T, I>::CannotPayLeader
+                );
+
+                if let Some(relationship_id) = worker.reward_relationship {
+                    let relationship =
+                        recurringrewards::Module::<T>::reward_relationships(relationship_id);
+
+                    Ok(relationship.account)
+                } else {
+                    let member = membership::Module::<T>::membership(worker.member_id);
+
+                    Ok(member.root_account)
+                }
+            }
+            PaymentRecipient::Member(member_id) => {
+                let member = membership::Module::<T>::ensure_membership(*member_id)
+                    .map_err(|_| Error::<T, I>::MembershipInvalidMemberId)?;
+
+                ensure!(
+                    !Self::is_leader_member(*member_id),
+                    Error::<T, I>::CannotPayLeader
+                );
+
+                Ok(member.root_account)
+            }
+        }
+    }
+
+    fn is_leader_member(member_id: T::MemberId) -> bool {
+        Self::current_lead().map_or(false, |lead_id| {
+            Self::worker_by_id(lead_id).member_id == member_id
+        })
+    }
+
+    // Ensures the payment is within the spending limit and returns the updated spending period.
+    fn ensure_payment_is_within_spending_limit(
+        amount: BalanceOfMint<T>,
+    ) -> Result<SpendingPeriodOf<T>, Error<T, I>> {
+        let spending_limit =
+            Self::payment_spending_limit().ok_or(Error::<T, I>::SpendingLimitIsNotSet)?;
+
+        let mut spending_period = Self::current_spending_period();
+        let current_block = <system::Module<T>>::block_number();
+
+        // Start a new spending period if the current one has expired.
+        let spending_period_end = spending_period
+            .started_at_block
+            .saturating_add(spending_limit.period_length);
+
+        if current_block >= spending_period_end {
+            spending_period = SpendingPeriod {
+                started_at_block: current_block,
+                spent: Zero::zero(),
+            };
+        }
+
+        let spent = spending_period.spent.saturating_add(amount);
+
+        ensure!(
+            spent <= spending_limit.amount_per_period,
+            Error::<T, I>::SpendingLimitExceeded
+        );
+
+        spending_period.spent = spent;
+
+        Ok(spending_period)
+    }
 }
 
 /// Creates default text constraint.
@@ -1396,6 +1957,49 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
             .collect()
     }
 
+    /// Returns role history of the member. Total reward received is actualized for the roles
+    /// still held by the member.
+    pub fn member_role_history(member_id: MemberId<T>) -> Vec<RoleHistoryEntryOf<T>> {
+        <RoleHistory<T, I>>::iter_prefix_values(member_id)
+            .map(|mut entry| {
+                if entry.exit.is_none() {
+                    if let Ok(worker) = Self::ensure_worker_exists(&entry.worker_id) {
+                        entry.total_reward_received = Self::worker_total_reward_received(&worker);
+                    }
+                }
+
+                entry
+            })
+            .collect()
+    }
+
+    /// Checks whether the account is the role account of an existing worker or the leader.
+    pub fn is_worker_role_account(account_id: &T::AccountId) -> bool {
+        <WorkerIdsByRoleAccountId<T, I>>::iter_prefix(account_id)
+            .next()
+            .is_some()
+    }
+
+    /// Indexes the existing workers by their role accounts. Runs once: the workers are already
+    /// indexed if the storage version is current. Returns the weight of the migration.
+    pub fn migrate_worker_ids_by_role_account_id() -> Weight {
+        if Self::storage_version() >= STORAGE_VERSION {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut workers_count: Weight = 0;
+
+        for (worker_id, worker) in <WorkerById<T, I>>::iter() {
+            <WorkerIdsByRoleAccountId<T, I>>::insert(&worker.role_account_id, worker_id, ());
+            workers_count += 1;
+        }
+
+        <StorageVersion<I>>::put(STORAGE_VERSION);
+
+        // Reads: the version and the workers. Writes: the version and the index entry per worker.
+        T::DbWeight::get().reads_writes(1 + workers_count, 1 + workers_count)
+    }
+
     /// Returns all existing worker id list.
     pub fn get_all_worker_ids() -> Vec<WorkerId<T>> {
         <WorkerById<T, I>>::iter()
@@ -1435,6 +2039,11 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
             // Attempt to deactivate
             recurringrewards::Module::<T>::try_to_deactivate_relationship(reward_relationship_id)
                 .map_err(|_| Error::<T, I>::RelationshipMustExist)?;
+
+            // The missed payouts accrued as debt are not paid after the exit: settle the debt
+            // now and write off what the mint cannot pay.
+            recurringrewards::Module::<T>::settle_payout_debt(reward_relationship_id)
+                .map_err(|_| Error::<T, I>::RelationshipMustExist)?;
         }; // else: Did not deactivate, there was no reward relationship!
 
         // Unstake if stake profile exists
@@ -1461,10 +2070,25 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
             }
         }
 
+        // Record the exit in the role history.
+        let total_reward_received = Self::worker_total_reward_received(worker);
+        Self::update_role_history(worker.member_id, *worker_id, |entry| {
+            entry.total_reward_received = total_reward_received;
+            entry.exit = Some(RoleExit {
+                exited_at_block: <system::Module<T>>::block_number(),
+                exit_initiation_origin: exit_initiation_origin.clone(),
+                rationale_text: rationale_text.to_vec(),
+            });
+        });
+
         // Remove the worker from the storage.
+        Self::remove_deputy(worker_id);
         WorkerById::<T, I>::remove(worker_id);
+        <WorkerIdsByRoleAccountId<T, I>>::remove(&worker.role_account_id, worker_id);
         Self::decrease_active_worker_counter();
 
+        T::WorkerExitHandler::worker_exited(worker_id);
+
         // Trigger the event
         let event = match exit_initiation_origin {
             ExitInitiationOrigin::Lead => {
@@ -1512,6 +2136,9 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         // Update current lead
         <CurrentLead<T, I>>::put(worker_id);
 
+        // Permissions were delegated by the previous leader.
+        Self::clear_deputies();
+
         // Trigger an event
         Self::deposit_event(RawEvent::LeaderSet(worker_id));
     }
@@ -1522,10 +2149,50 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
             // Update current lead
             <CurrentLead<T, I>>::kill();
 
+            // Permissions were delegated by the leaving leader.
+            Self::clear_deputies();
+
             Self::deposit_event(RawEvent::LeaderUnset());
         }
     }
 
+    // Revokes all the delegated deputy permissions.
+    fn clear_deputies() {
+        let deputy_worker_ids = <DeputyPermissionsByWorkerId<T, I>>::iter()
+            .map(|(worker_id, _)| worker_id)
+            .collect::<Vec<_>>();
+
+        for worker_id in deputy_worker_ids {
+            Self::remove_deputy(&worker_id);
+        }
+    }
+
+    // Revokes the permissions delegated to the worker if any. Requires an existing worker.
+    fn remove_deputy(worker_id: &WorkerId<T>) {
+        if <DeputyPermissionsByWorkerId<T, I>>::contains_key(worker_id) {
+            let role_account_id = Self::worker_by_id(worker_id).role_account_id;
+
+            <DeputyPermissionsByWorkerId<T, I>>::remove(worker_id);
+            <DeputyWorkerIdByRoleAccountId<T, I>>::remove(role_account_id);
+        }
+    }
+
+    // Ensures the role account is not used by another deputy.
+    fn ensure_deputy_role_account_is_free(
+        worker_id: &WorkerId<T>,
+        role_account_id: &T::AccountId,
+    ) -> Result<(), Error<T, I>> {
+        let is_used_by_another_deputy = Self::deputy_worker_id_by_role_account_id(role_account_id)
+            .map_or(false, |deputy_worker_id| deputy_worker_id != *worker_id);
+
+        ensure!(
+            !is_used_by_another_deputy,
+            Error::<T, I>::DeputyRoleAccountIsUsed
+        );
+
+        Ok(())
+    }
+
     // Processes successful application during the fill_opening().
     fn fulfill_successful_applications(
         opening: &OpeningOf<T>,
@@ -1562,6 +2229,12 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
                     )
                     .expect("Failed to create reward relationship!");
 
+                    <recurringrewards::Module<T>>::set_missed_payout_policy(
+                        relationship_id,
+                        Self::missed_reward_payout_policy(),
+                    )
+                    .expect("Reward relationship was just created!");
+
                     Some(relationship_id)
                 } else {
                     None
@@ -1597,8 +2270,25 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
 
                 // Store a worker
                 <WorkerById<T, I>>::insert(new_worker_id, worker);
+                <WorkerIdsByRoleAccountId<T, I>>::insert(
+                    &successful_application.role_account_id,
+                    new_worker_id,
+                    (),
+                );
                 Self::increase_active_worker_counter();
 
+                // Record the appointment in the role history.
+                <RoleHistory<T, I>>::insert(
+                    successful_application.member_id,
+                    new_worker_id,
+                    RoleHistoryEntry {
+                        worker_id: new_worker_id,
+                        is_lead: matches!(opening.opening_type, OpeningType::Leader),
+                        appointed_at_block: <system::Module<T>>::block_number(),
+                        ..Default::default()
+                    },
+                );
+
                 // Update next worker id
                 <NextWorkerId<T, I>>::mutate(|id| *id += <WorkerId<T> as One>::one());
 
@@ -1613,6 +2303,29 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         application_id_to_worker_id
     }
 
+    // Updates the role history entry if exists. Roles appointed before the role history
+    // introduction have no entries.
+    fn update_role_history(
+        member_id: MemberId<T>,
+        worker_id: WorkerId<T>,
+        update: impl FnOnce(&mut RoleHistoryEntryOf<T>),
+    ) {
+        if <RoleHistory<T, I>>::contains_key(member_id, worker_id) {
+            <RoleHistory<T, I>>::mutate(member_id, worker_id, update);
+        }
+    }
+
+    // Returns total reward received by the worker, zero for the workers without reward.
+    fn worker_total_reward_received(worker: &WorkerOf<T>) -> BalanceOfMint<T> {
+        worker
+            .reward_relationship
+            .map(|relationship_id| {
+                recurringrewards::Module::<T>::reward_relationships(relationship_id)
+                    .total_reward_received()
+            })
+            .unwrap_or_else(Zero::zero)
+    }
+
     // Increases active worker counter (saturating).
     fn increase_active_worker_counter() {
         let next_active_worker_count_value = Self::active_worker_count().saturating_add(1);
@@ -1625,3 +2338,88 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         <ActiveWorkerCount<I>>::put(next_active_worker_count_value);
     }
 }
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<T: Trait<I>, I: Instance> Module<T, I> {
+    /// Hires the leader with the account as its member and role account, unless the leader
+    /// is set. Returns the leader worker id.
+    /// Used by the benchmarks of this and the dependent modules.
+    pub fn insert_benchmark_lead(account_id: &T::AccountId) -> WorkerId<T> {
+        Self::current_lead().unwrap_or_else(|| {
+            Self::hire_benchmark_worker(OpeningType::Leader, account_id, None, None)
+        })
+    }
+
+    /// Hires the worker without the role stake and the reward with the account as its member
+    /// and role account. Requires the leader. Returns the worker id.
+    /// Used by the benchmarks of the dependent modules.
+    pub fn insert_benchmark_worker(account_id: &T::AccountId) -> WorkerId<T> {
+        Self::hire_benchmark_worker(OpeningType::Worker, account_id, None, None)
+    }
+
+    // Hires the worker through the whole hiring flow. The role stake is deposited to the account
+    // before applying.
+    fn hire_benchmark_worker(
+        opening_type: OpeningType,
+        account_id: &T::AccountId,
+        role_stake: Option<BalanceOf<T>>,
+        reward_policy: Option<RewardPolicy<BalanceOfMint<T>, T::BlockNumber>>,
+    ) -> WorkerId<T> {
+        let origin = match opening_type {
+            OpeningType::Leader => system::RawOrigin::Root,
+            OpeningType::Worker => {
+                let leader_worker_id = Self::current_lead().expect("leader set");
+
+                system::RawOrigin::Signed(Self::worker_by_id(leader_worker_id).role_account_id)
+            }
+        };
+
+        let member_id = <membership::Module<T>>::insert_benchmark_member(account_id);
+
+        let commitment = OpeningPolicyCommitment {
+            role_staking_policy: role_stake.map(|amount| hiring::StakingPolicy {
+                amount,
+                amount_mode: hiring::StakingAmountLimitMode::AtLeast,
+                crowded_out_unstaking_period_length: None,
+                review_period_expired_unstaking_period_length: None,
+            }),
+            ..OpeningPolicyCommitment::default()
+        };
+
+        let opening_id = Self::next_opening_id();
+        Self::add_opening(
+            origin.clone().into(),
+            hiring::ActivateOpeningAt::CurrentBlock,
+            commitment,
+            b"opening".to_vec(),
+            opening_type,
+        )
+        .expect("opening added");
+
+        if let Some(role_stake) = role_stake {
+            let _ = CurrencyOf::<T>::deposit_creating(account_id, role_stake);
+        }
+
+        let application_id = Self::next_application_id();
+        Self::apply_on_opening(
+            system::RawOrigin::Signed(account_id.clone()).into(),
+            member_id,
+            opening_id,
+            account_id.clone(),
+            role_stake,
+            None,
+            b"application".to_vec(),
+        )
+        .expect("applied on opening");
+
+        Self::begin_applicant_review(origin.clone().into(), opening_id).expect("review began");
+
+        let worker_id = Self::next_worker_id();
+        let mut application_ids = BTreeSet::new();
+        application_ids.insert(application_id);
+        Self::fill_opening(origin.into(), opening_id, application_ids, reward_policy)
+            .expect("opening filled");
+
+        worker_id
+    }
+}
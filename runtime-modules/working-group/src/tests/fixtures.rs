@@ -8,8 +8,8 @@ use super::mock::{
 };
 use crate::tests::fill_worker_position;
 use crate::types::{
-    Application, Opening, OpeningPolicyCommitment, OpeningType, RewardPolicy, RoleStakeProfile,
-    Worker,
+    Application, Opening, OpeningPolicyCommitment, OpeningType, PaymentRecipient, RewardPolicy,
+    RoleStakeProfile, Worker,
 };
 use crate::RawEvent;
 use common::constraints::InputValidationLengthConstraint;
@@ -130,6 +130,7 @@ impl TerminateWorkerRoleFixture {
 pub(crate) struct LeaveWorkerRoleFixture {
     worker_id: u64,
     origin: RawOrigin<u64>,
+    text: Vec<u8>,
 }
 
 impl LeaveWorkerRoleFixture {
@@ -137,18 +138,22 @@ impl LeaveWorkerRoleFixture {
         Self {
             worker_id,
             origin: RawOrigin::Signed(1),
+            text: b"rationale_text".to_vec(),
         }
     }
     pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
         Self { origin, ..self }
     }
 
+    pub fn with_text(self, text: Vec<u8>) -> Self {
+        Self { text, ..self }
+    }
+
     pub fn call_and_assert(&self, expected_result: DispatchResult) {
-        let rationale_text = b"rationale_text".to_vec();
         let actual_result = TestWorkingGroup::leave_role(
             self.origin.clone().into(),
             self.worker_id,
-            rationale_text.clone(),
+            self.text.clone(),
         );
         assert_eq!(actual_result, expected_result);
 
@@ -363,6 +368,13 @@ impl FillWorkerOpeningFixture {
             let actual_worker = TestWorkingGroup::worker_by_id(worker_id);
 
             assert_eq!(actual_worker, expected_worker);
+
+            if let Some(relationship_id) = reward_relationship {
+                assert_eq!(
+                    recurringrewards::Module::<Test>::missed_payout_policy(relationship_id),
+                    TestWorkingGroup::missed_reward_payout_policy()
+                );
+            }
         }
 
         saved_worker_next_id
@@ -930,3 +942,72 @@ impl SlashWorkerStakeFixture {
         }
     }
 }
+
+pub struct SpendFromMintFixture {
+    origin: RawOrigin<u64>,
+    recipient: PaymentRecipient<u64, u64>,
+    recipient_account_id: u64,
+    amount: u64,
+    rationale_text: Vec<u8>,
+}
+
+impl SpendFromMintFixture {
+    pub fn default_for_recipient(
+        recipient: PaymentRecipient<u64, u64>,
+        recipient_account_id: u64,
+    ) -> Self {
+        let lead_account_id = get_current_lead_account_id();
+
+        Self {
+            origin: RawOrigin::Signed(lead_account_id),
+            recipient,
+            recipient_account_id,
+            amount: 10,
+            rationale_text: b"rationale_text".to_vec(),
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_amount(self, amount: u64) -> Self {
+        Self { amount, ..self }
+    }
+
+    pub fn with_rationale_text(self, rationale_text: Vec<u8>) -> Self {
+        Self {
+            rationale_text,
+            ..self
+        }
+    }
+
+    fn mint_capacity() -> u64 {
+        <minting::Module<Test>>::mints(TestWorkingGroup::mint()).capacity()
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_balance = Balances::free_balance(&self.recipient_account_id);
+        let old_mint_capacity = Self::mint_capacity();
+
+        let actual_result = TestWorkingGroup::spend_from_mint(
+            self.origin.clone().into(),
+            self.recipient.clone(),
+            self.amount,
+            self.rationale_text.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_balance = Balances::free_balance(&self.recipient_account_id);
+        let new_mint_capacity = Self::mint_capacity();
+
+        if actual_result.is_ok() {
+            assert_eq!(new_balance, old_balance + self.amount);
+            assert_eq!(new_mint_capacity, old_mint_capacity - self.amount);
+        } else {
+            assert_eq!(new_balance, old_balance);
+            assert_eq!(new_mint_capacity, old_mint_capacity);
+        }
+    }
+}
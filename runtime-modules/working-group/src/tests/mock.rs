@@ -31,6 +31,7 @@ impl_outer_event! {
         balances<T>,
         working_group TestWorkingGroupInstance <T>,
         membership_mod<T>,
+        recurringrewards<T>,
         system<T>,
     }
 }
@@ -76,11 +77,16 @@ impl system::Trait for Test {
     type OnKilledAccount = ();
 }
 
+parameter_types! {
+    pub const ApplicationsRetentionPeriod: u64 = 100;
+}
+
 impl hiring::Trait for Test {
     type OpeningId = u64;
     type ApplicationId = u64;
     type ApplicationDeactivatedHandler = ();
     type StakeHandlerProvider = hiring::Module<Self>;
+    type ApplicationsRetentionPeriod = ApplicationsRetentionPeriod;
 }
 
 impl minting::Trait for Test {
@@ -102,6 +108,7 @@ impl membership::Trait for Test {
     type PaidTermId = u64;
     type SubscriptionId = u64;
     type ActorId = u64;
+    type WeightInfo = ();
 }
 
 impl common::currency::GovernanceCurrency for Test {
@@ -122,10 +129,16 @@ impl balances::Trait for Test {
     type AccountStore = System;
 }
 
+parameter_types! {
+    pub const MaxPayoutsPerBlock: u32 = 100;
+}
+
 impl recurringrewards::Trait for Test {
+    type Event = TestEvent;
     type PayoutStatusHandler = ();
     type RecipientId = u64;
     type RewardRelationshipId = u64;
+    type MaxPayoutsPerBlock = MaxPayoutsPerBlock;
 }
 
 pub type Balances = balances::Module<Test>;
@@ -138,6 +151,8 @@ parameter_types! {
 impl Trait<TestWorkingGroupInstance> for Test {
     type Event = TestEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WeightInfo = ();
+    type WorkerExitHandler = ();
 }
 
 pub type Membership = membership::Module<Test>;
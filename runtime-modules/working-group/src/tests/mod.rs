@@ -1,20 +1,25 @@
 mod fixtures;
 mod hiring_workflow;
-mod mock;
+pub(crate) mod mock;
 
 use frame_support::dispatch::DispatchError;
-use frame_support::storage::{StorageMap, StorageValue};
+use frame_support::storage::{StorageDoubleMap, StorageMap, StorageValue};
+use frame_support::traits::OnFinalize;
 use std::collections::BTreeMap;
 use system::RawOrigin;
 
 use crate::tests::hiring_workflow::HiringWorkflow;
-use crate::types::{OpeningPolicyCommitment, OpeningType, RewardPolicy};
+use crate::types::{
+    DeputyPermissions, ExitInitiationOrigin, OpeningPolicyCommitment, OpeningType,
+    PaymentRecipient, RewardPolicy, RoleExit, RoleHistoryEntry, SpendingLimit,
+};
 use crate::{Error, RawEvent, Worker};
 use common::constraints::InputValidationLengthConstraint;
 use fixtures::*;
 use mock::{
-    build_test_externalities, run_to_block, Test, TestWorkingGroup, TestWorkingGroupInstance,
-    WORKING_GROUP_CONSTRAINT_DIFF, WORKING_GROUP_CONSTRAINT_MIN, WORKING_GROUP_MINT_CAPACITY,
+    build_test_externalities, run_to_block, Test, TestEvent, TestWorkingGroup,
+    TestWorkingGroupInstance, WORKING_GROUP_CONSTRAINT_DIFF, WORKING_GROUP_CONSTRAINT_MIN,
+    WORKING_GROUP_MINT_CAPACITY,
 };
 
 #[test]
@@ -1151,6 +1156,49 @@ fn update_worker_role_account_by_leader_succeeds() {
     });
 }
 
+#[test]
+fn workers_are_indexed_by_role_accounts() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+        let role_account_id = TestWorkingGroup::worker_by_id(worker_id).role_account_id;
+        let new_account_id = 10;
+
+        assert!(TestWorkingGroup::is_worker_role_account(&role_account_id));
+        assert!(!TestWorkingGroup::is_worker_role_account(&new_account_id));
+
+        UpdateWorkerRoleAccountFixture::default_with_ids(worker_id, new_account_id)
+            .call_and_assert(Ok(()));
+
+        assert!(TestWorkingGroup::is_worker_role_account(&new_account_id));
+
+        TerminateWorkerRoleFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        assert!(!TestWorkingGroup::is_worker_role_account(&new_account_id));
+    });
+}
+
+#[test]
+fn worker_ids_by_role_account_id_migration_runs_once() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+        let role_account_id = TestWorkingGroup::worker_by_id(worker_id).role_account_id;
+
+        <crate::WorkerIdsByRoleAccountId<Test, TestWorkingGroupInstance>>::remove_prefix(
+            role_account_id,
+        );
+
+        // New chains start with the current storage version, so nothing is migrated.
+        TestWorkingGroup::migrate_worker_ids_by_role_account_id();
+
+        assert!(!TestWorkingGroup::is_worker_role_account(&role_account_id));
+
+        <crate::StorageVersion<TestWorkingGroupInstance>>::put(0);
+        TestWorkingGroup::migrate_worker_ids_by_role_account_id();
+
+        assert!(TestWorkingGroup::is_worker_role_account(&role_account_id));
+    });
+}
+
 #[test]
 fn update_worker_role_account_fails_with_invalid_origin() {
     build_test_externalities().execute_with(|| {
@@ -1533,6 +1581,23 @@ fn leave_worker_role_fails_with_invalid_recurring_reward_relationships() {
     });
 }
 
+#[test]
+fn leave_worker_role_fails_with_invalid_text() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        let leave_worker_role_fixture =
+            LeaveWorkerRoleFixture::default_for_worker_id(worker_id).with_text(Vec::new());
+        leave_worker_role_fixture
+            .call_and_assert(Err(DispatchError::Other("WorkerExitRationaleTextTooShort")));
+
+        let leave_worker_role_fixture = LeaveWorkerRoleFixture::default_for_worker_id(worker_id)
+            .with_text([b'x'; WORKING_GROUP_CONSTRAINT_DIFF as usize + 2].to_vec());
+        leave_worker_role_fixture
+            .call_and_assert(Err(DispatchError::Other("WorkerExitRationaleTextTooLong")));
+    });
+}
+
 #[test]
 fn leave_worker_role_succeeds_with_stakes() {
     build_test_externalities().execute_with(|| {
@@ -2352,3 +2417,864 @@ fn fill_opening_cannot_hire_more_workers_using_several_applicationst_han_allows_
         hiring_workflow.execute()
     });
 }
+
+#[test]
+fn role_history_records_appointment_slashes_and_exit() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let worker_id = fill_worker_position_with_stake(100);
+        let member_id = TestWorkingGroup::worker_by_id(worker_id).member_id;
+
+        let slash_stake_fixture =
+            SlashWorkerStakeFixture::default_for_worker_id(worker_id).with_balance(10);
+        slash_stake_fixture.call_and_assert(Ok(()));
+        slash_stake_fixture.call_and_assert(Ok(()));
+
+        assert_eq!(
+            TestWorkingGroup::member_role_history(member_id),
+            vec![RoleHistoryEntry {
+                worker_id,
+                is_lead: false,
+                appointed_at_block: 1,
+                slash_count: 2,
+                total_slashed: 20,
+                total_reward_received: 0,
+                exit: None,
+            }]
+        );
+
+        run_to_block(5);
+
+        let leave_worker_role_fixture = LeaveWorkerRoleFixture::default_for_worker_id(worker_id);
+        leave_worker_role_fixture.call_and_assert(Ok(()));
+
+        assert_eq!(
+            TestWorkingGroup::role_history(member_id, worker_id).exit,
+            Some(RoleExit {
+                exited_at_block: 5,
+                exit_initiation_origin: ExitInitiationOrigin::Worker,
+                rationale_text: b"rationale_text".to_vec(),
+            })
+        );
+    });
+}
+
+#[test]
+fn role_history_records_leader_termination() {
+    build_test_externalities().execute_with(|| {
+        let leader_worker_id = HireLeadFixture::default().hire_lead();
+        let member_id = TestWorkingGroup::worker_by_id(leader_worker_id).member_id;
+
+        assert!(TestWorkingGroup::role_history(member_id, leader_worker_id).is_lead);
+
+        let terminate_worker_role_fixture =
+            TerminateWorkerRoleFixture::default_for_worker_id(leader_worker_id)
+                .with_origin(RawOrigin::Root);
+        terminate_worker_role_fixture.call_and_assert(Ok(()));
+
+        let history = TestWorkingGroup::member_role_history(member_id);
+        assert_eq!(history.len(), 1);
+        assert_eq!(
+            history[0]
+                .exit
+                .clone()
+                .map(|exit| exit.exit_initiation_origin),
+            Some(ExitInitiationOrigin::Sudo)
+        );
+    });
+}
+
+// Sets the spending limit enough for the test payments.
+fn set_default_spending_limit() {
+    let spending_limit = SpendingLimit {
+        amount_per_period: 1_000_000,
+        period_length: 10,
+    };
+
+    assert_eq!(
+        TestWorkingGroup::set_spending_limit(RawOrigin::Root.into(), Some(spending_limit)),
+        Ok(())
+    );
+}
+
+#[test]
+fn spend_from_mint_succeeds() {
+    build_test_externalities().execute_with(|| {
+        /*
+           Events are not emitted on block 0.
+           So any dispatchable calls made during genesis block formation will have no events emitted.
+           https://substrate.dev/recipes/2-appetizers/4-events.html
+        */
+        run_to_block(1);
+
+        HireLeadFixture::default().hire_lead();
+        set_default_spending_limit();
+
+        let worker_id = HiringWorkflow::default()
+            .disable_setup_environment()
+            .add_application_with_origin(b"worker_handle".to_vec(), RawOrigin::Signed(3), 3)
+            .execute()
+            .unwrap();
+        let worker_root_account_id = 3;
+        let member_id = 2;
+        let member_root_account_id = 2;
+
+        SpendFromMintFixture::default_for_recipient(
+            PaymentRecipient::Worker(worker_id),
+            worker_root_account_id,
+        )
+        .call_and_assert(Ok(()));
+
+        SpendFromMintFixture::default_for_recipient(
+            PaymentRecipient::Member(member_id),
+            member_root_account_id,
+        )
+        .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::PaymentMade(
+            PaymentRecipient::Member(member_id),
+            member_root_account_id,
+            10,
+            b"rationale_text".to_vec(),
+        ));
+    });
+}
+
+#[test]
+fn spend_from_mint_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        SpendFromMintFixture::default_for_recipient(PaymentRecipient::Member(2), 2)
+            .with_origin(RawOrigin::Signed(2))
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::IsNotLeadAccount.into(),
+            ));
+    });
+}
+
+#[test]
+fn spend_from_mint_fails_with_invalid_recipient() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+        set_default_spending_limit();
+
+        let invalid_worker_id = 11;
+        SpendFromMintFixture::default_for_recipient(PaymentRecipient::Worker(invalid_worker_id), 2)
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::WorkerDoesNotExist.into(),
+            ));
+
+        let invalid_member_id = 11;
+        SpendFromMintFixture::default_for_recipient(PaymentRecipient::Member(invalid_member_id), 2)
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::MembershipInvalidMemberId.into(),
+            ));
+    });
+}
+
+#[test]
+fn spend_from_mint_fails_with_invalid_amount_and_text() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+        set_default_spending_limit();
+
+        SpendFromMintFixture::default_for_recipient(PaymentRecipient::Member(2), 2)
+            .with_amount(0)
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::PaymentAmountCannotBeZero.into(),
+            ));
+
+        SpendFromMintFixture::default_for_recipient(PaymentRecipient::Member(2), 2)
+            .with_amount(1_000)
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::MintingErrorNotEnoughCapacity.into(),
+            ));
+
+        SpendFromMintFixture::default_for_recipient(PaymentRecipient::Member(2), 2)
+            .with_rationale_text(Vec::new())
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::PaymentRationaleTextTooShort.into(),
+            ));
+    });
+}
+
+#[test]
+fn spend_from_mint_is_limited_per_spending_period() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let spending_limit = SpendingLimit {
+            amount_per_period: 30,
+            period_length: 10,
+        };
+        assert_eq!(
+            TestWorkingGroup::set_spending_limit(RawOrigin::Root.into(), Some(spending_limit)),
+            Ok(())
+        );
+
+        let spend_from_mint_fixture =
+            SpendFromMintFixture::default_for_recipient(PaymentRecipient::Member(2), 2)
+                .with_amount(20);
+
+        spend_from_mint_fixture.call_and_assert(Ok(()));
+        spend_from_mint_fixture.call_and_assert(Err(
+            Error::<Test, TestWorkingGroupInstance>::SpendingLimitExceeded.into(),
+        ));
+
+        // New spending period.
+        run_to_block(10);
+        spend_from_mint_fixture.call_and_assert(Ok(()));
+
+        // No limit: payments are disabled.
+        assert_eq!(
+            TestWorkingGroup::set_spending_limit(RawOrigin::Root.into(), None),
+            Ok(())
+        );
+        spend_from_mint_fixture.call_and_assert(Err(
+            Error::<Test, TestWorkingGroupInstance>::SpendingLimitIsNotSet.into(),
+        ));
+    });
+}
+
+#[test]
+fn spend_from_mint_fails_without_spending_limit() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        SpendFromMintFixture::default_for_recipient(PaymentRecipient::Member(2), 2)
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::SpendingLimitIsNotSet.into(),
+            ));
+    });
+}
+
+#[test]
+fn spend_from_mint_fails_for_leader() {
+    build_test_externalities().execute_with(|| {
+        let leader_worker_id = HireLeadFixture::default().hire_lead();
+        let leader = get_worker_by_id(leader_worker_id);
+        let leader_root_account_id = 1;
+        set_default_spending_limit();
+
+        SpendFromMintFixture::default_for_recipient(
+            PaymentRecipient::Worker(leader_worker_id),
+            leader_root_account_id,
+        )
+        .call_and_assert(Err(
+            Error::<Test, TestWorkingGroupInstance>::CannotPayLeader.into(),
+        ));
+
+        SpendFromMintFixture::default_for_recipient(
+            PaymentRecipient::Member(leader.member_id),
+            leader_root_account_id,
+        )
+        .call_and_assert(Err(
+            Error::<Test, TestWorkingGroupInstance>::CannotPayLeader.into(),
+        ));
+    });
+}
+
+#[test]
+fn spend_from_mint_fails_for_other_worker_of_leader_member() {
+    build_test_externalities().execute_with(|| {
+        let leader_worker_id = HireLeadFixture::default().hire_lead();
+        let leader = get_worker_by_id(leader_worker_id);
+        set_default_spending_limit();
+
+        let worker_id = HiringWorkflow::default()
+            .disable_setup_environment()
+            .add_application_with_origin(
+                b"worker_handle".to_vec(),
+                RawOrigin::Signed(1),
+                leader.member_id,
+            )
+            .execute()
+            .unwrap();
+        let worker_root_account_id = 1;
+
+        SpendFromMintFixture::default_for_recipient(
+            PaymentRecipient::Worker(worker_id),
+            worker_root_account_id,
+        )
+        .call_and_assert(Err(
+            Error::<Test, TestWorkingGroupInstance>::CannotPayLeader.into(),
+        ));
+    });
+}
+
+#[test]
+fn set_spending_limit_fails_with_invalid_parameters() {
+    build_test_externalities().execute_with(|| {
+        let spending_limit = SpendingLimit {
+            amount_per_period: 30,
+            period_length: 0,
+        };
+
+        assert_eq!(
+            TestWorkingGroup::set_spending_limit(
+                RawOrigin::Signed(1).into(),
+                Some(spending_limit.clone())
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+
+        assert_eq!(
+            TestWorkingGroup::set_spending_limit(RawOrigin::Root.into(), Some(spending_limit)),
+            Err(Error::<Test, TestWorkingGroupInstance>::SpendingPeriodLengthCannotBeZero.into())
+        );
+    });
+}
+
+#[test]
+fn missed_reward_payout_policy_is_applied_to_hired_workers() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        assert_eq!(
+            TestWorkingGroup::missed_reward_payout_policy(),
+            recurringrewards::MissedPayoutPolicy::Discard
+        );
+
+        assert_eq!(
+            TestWorkingGroup::set_missed_reward_payout_policy(
+                RawOrigin::Signed(1).into(),
+                recurringrewards::MissedPayoutPolicy::AccrueDebt
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+
+        assert_eq!(
+            TestWorkingGroup::set_missed_reward_payout_policy(
+                RawOrigin::Root.into(),
+                recurringrewards::MissedPayoutPolicy::AccrueDebt
+            ),
+            Ok(())
+        );
+        EventFixture::assert_last_crate_event(RawEvent::MissedRewardPayoutPolicySet(
+            recurringrewards::MissedPayoutPolicy::AccrueDebt,
+        ));
+
+        let leader_worker_id = HireLeadFixture::default()
+            .with_reward_policy(RewardPolicy {
+                amount_per_payout: 1000,
+                next_payment_at_block: 20,
+                payout_interval: None,
+            })
+            .hire_lead();
+
+        let relationship_id = TestWorkingGroup::worker_by_id(leader_worker_id)
+            .reward_relationship
+            .unwrap();
+        assert_eq!(
+            recurringrewards::Module::<Test>::missed_payout_policy(relationship_id),
+            recurringrewards::MissedPayoutPolicy::AccrueDebt
+        );
+    });
+}
+
+#[test]
+fn missed_reward_payout_policy_is_set_for_existing_worker() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        // The worker is hired with the default policy.
+        let worker_id = fill_default_worker_position();
+        let relationship_id = TestWorkingGroup::worker_by_id(worker_id)
+            .reward_relationship
+            .unwrap();
+        assert_eq!(
+            recurringrewards::Module::<Test>::missed_payout_policy(relationship_id),
+            recurringrewards::MissedPayoutPolicy::Discard
+        );
+
+        assert_eq!(
+            TestWorkingGroup::set_worker_missed_reward_payout_policy(
+                RawOrigin::None.into(),
+                worker_id,
+                recurringrewards::MissedPayoutPolicy::AccrueDebt
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+
+        let lead_account_id = 1;
+        assert_eq!(
+            TestWorkingGroup::set_worker_missed_reward_payout_policy(
+                RawOrigin::Signed(lead_account_id).into(),
+                worker_id,
+                recurringrewards::MissedPayoutPolicy::AccrueDebt
+            ),
+            Ok(())
+        );
+        EventFixture::assert_last_crate_event(RawEvent::WorkerMissedRewardPayoutPolicySet(
+            worker_id,
+            recurringrewards::MissedPayoutPolicy::AccrueDebt,
+        ));
+
+        assert_eq!(
+            recurringrewards::Module::<Test>::missed_payout_policy(relationship_id),
+            recurringrewards::MissedPayoutPolicy::AccrueDebt
+        );
+    });
+}
+
+#[test]
+fn set_worker_missed_reward_payout_policy_fails_for_worker_without_reward() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireLeadFixture::default().hire_lead();
+
+        assert_eq!(
+            TestWorkingGroup::set_worker_missed_reward_payout_policy(
+                RawOrigin::Root.into(),
+                worker_id,
+                recurringrewards::MissedPayoutPolicy::AccrueDebt
+            ),
+            Err(Error::<Test, TestWorkingGroupInstance>::WorkerHasNoReward.into())
+        );
+    });
+}
+
+#[test]
+fn leaving_worker_payout_debt_is_settled_and_written_off() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        assert_eq!(
+            TestWorkingGroup::set_missed_reward_payout_policy(
+                RawOrigin::Root.into(),
+                recurringrewards::MissedPayoutPolicy::AccrueDebt
+            ),
+            Ok(())
+        );
+
+        let leader_worker_id = HireLeadFixture::default()
+            .with_reward_policy(RewardPolicy {
+                amount_per_payout: 1000,
+                next_payment_at_block: 2,
+                payout_interval: None,
+            })
+            .hire_lead();
+
+        let relationship_id = TestWorkingGroup::worker_by_id(leader_worker_id)
+            .reward_relationship
+            .unwrap();
+
+        // the mint pays 100 of the 1000 payout, the rest is accrued as debt
+        run_to_block(2);
+        <recurringrewards::Module<Test> as OnFinalize<u64>>::on_finalize(2);
+        assert_eq!(
+            recurringrewards::Module::<Test>::payout_debt(relationship_id),
+            900
+        );
+
+        // the mint can settle only the part of the debt on the exit
+        assert!(minting::Module::<Test>::set_mint_capacity(TestWorkingGroup::mint(), 500).is_ok());
+
+        LeaveWorkerRoleFixture::default_for_worker_id(leader_worker_id).call_and_assert(Ok(()));
+
+        assert_eq!(
+            recurringrewards::Module::<Test>::payout_debt(relationship_id),
+            0
+        );
+
+        let events: Vec<TestEvent> = system::Module::<Test>::events()
+            .into_iter()
+            .map(|record| record.event)
+            .collect();
+        assert!(events.contains(&TestEvent::recurringrewards(
+            recurringrewards::RawEvent::PayoutDebtSettled(relationship_id, 500)
+        )));
+        assert!(events.contains(&TestEvent::recurringrewards(
+            recurringrewards::RawEvent::PayoutDebtWrittenOff(relationship_id, 400)
+        )));
+    });
+}
+
+const DEPUTY_ACCOUNT_ID: u64 = 20;
+
+// Hires the leader and the worker with the separate role account, returns the worker id.
+fn hire_deputy() -> u64 {
+    HireLeadFixture::default().hire_lead();
+
+    let deputy_worker_id = HiringWorkflow::default()
+        .disable_setup_environment()
+        .add_application_with_origin(b"deputy_handle".to_vec(), RawOrigin::Signed(2), 2)
+        .execute()
+        .unwrap();
+
+    assert_eq!(
+        TestWorkingGroup::update_role_account(
+            RawOrigin::Signed(2).into(),
+            deputy_worker_id,
+            DEPUTY_ACCOUNT_ID
+        ),
+        Ok(())
+    );
+
+    deputy_worker_id
+}
+
+fn set_deputy_permissions(worker_id: u64, permissions: DeputyPermissions<u64>) {
+    let lead_account_id = 1;
+
+    assert_eq!(
+        TestWorkingGroup::set_deputy_permissions(
+            RawOrigin::Signed(lead_account_id).into(),
+            worker_id,
+            permissions.clone()
+        ),
+        Ok(())
+    );
+
+    assert_eq!(
+        TestWorkingGroup::deputy_permissions_by_worker_id(worker_id),
+        permissions
+    );
+}
+
+#[test]
+fn set_deputy_permissions_succeeds() {
+    build_test_externalities().execute_with(|| {
+        /*
+           Events are not emitted on block 0.
+           So any dispatchable calls made during genesis block formation will have no events emitted.
+           https://substrate.dev/recipes/2-appetizers/4-events.html
+        */
+        run_to_block(1);
+
+        let deputy_worker_id = hire_deputy();
+        let permissions = DeputyPermissions {
+            manage_openings: true,
+            ..DeputyPermissions::default()
+        };
+
+        set_deputy_permissions(deputy_worker_id, permissions.clone());
+
+        EventFixture::assert_last_crate_event(RawEvent::DeputyPermissionsSet(
+            deputy_worker_id,
+            permissions,
+        ));
+    });
+}
+
+#[test]
+fn set_deputy_permissions_fails_with_invalid_parameters() {
+    build_test_externalities().execute_with(|| {
+        let deputy_worker_id = hire_deputy();
+        let leader_worker_id = TestWorkingGroup::current_lead().unwrap();
+        let lead_account_id = 1;
+
+        assert_eq!(
+            TestWorkingGroup::set_deputy_permissions(
+                RawOrigin::Signed(DEPUTY_ACCOUNT_ID).into(),
+                deputy_worker_id,
+                DeputyPermissions::default()
+            ),
+            Err(Error::<Test, TestWorkingGroupInstance>::IsNotLeadAccount.into())
+        );
+
+        assert_eq!(
+            TestWorkingGroup::set_deputy_permissions(
+                RawOrigin::Signed(lead_account_id).into(),
+                leader_worker_id,
+                DeputyPermissions::default()
+            ),
+            Err(Error::<Test, TestWorkingGroupInstance>::CannotSetLeaderAsDeputy.into())
+        );
+
+        let invalid_worker_id = 11;
+        assert_eq!(
+            TestWorkingGroup::set_deputy_permissions(
+                RawOrigin::Signed(lead_account_id).into(),
+                invalid_worker_id,
+                DeputyPermissions::default()
+            ),
+            Err(Error::<Test, TestWorkingGroupInstance>::WorkerDoesNotExist.into())
+        );
+    });
+}
+
+#[test]
+fn deputy_can_manage_openings_with_permission() {
+    build_test_externalities().execute_with(|| {
+        let deputy_worker_id = hire_deputy();
+
+        let add_opening_fixture =
+            AddWorkerOpeningFixture::default().with_origin(RawOrigin::Signed(DEPUTY_ACCOUNT_ID));
+
+        add_opening_fixture.call_and_assert(Err(
+            Error::<Test, TestWorkingGroupInstance>::IsNotLeadAccount.into(),
+        ));
+
+        set_deputy_permissions(
+            deputy_worker_id,
+            DeputyPermissions {
+                update_rewards: true,
+                ..DeputyPermissions::default()
+            },
+        );
+
+        add_opening_fixture.call_and_assert(Err(
+            Error::<Test, TestWorkingGroupInstance>::IsNotLeadAccount.into(),
+        ));
+
+        set_deputy_permissions(
+            deputy_worker_id,
+            DeputyPermissions {
+                manage_openings: true,
+                ..DeputyPermissions::default()
+            },
+        );
+
+        let opening_id = add_opening_fixture.call_and_assert(Ok(()));
+
+        BeginReviewWorkerApplicationsFixture::default_for_opening_id(opening_id)
+            .with_origin(RawOrigin::Signed(DEPUTY_ACCOUNT_ID))
+            .call_and_assert(Ok(()));
+
+        // Leader openings are still managed by the council only.
+        AddWorkerOpeningFixture::default()
+            .with_origin(RawOrigin::Signed(DEPUTY_ACCOUNT_ID))
+            .with_opening_type(OpeningType::Leader)
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn deputy_can_slash_stakes_and_update_rewards_with_permissions() {
+    build_test_externalities().execute_with(|| {
+        let deputy_worker_id = hire_deputy();
+
+        increase_total_balance_issuance_using_account_id(3, 10000);
+        let worker_id = HiringWorkflow::default()
+            .disable_setup_environment()
+            .with_role_stake(Some(100))
+            .with_reward_policy(Some(RewardPolicy {
+                amount_per_payout: 1000,
+                next_payment_at_block: 20,
+                payout_interval: None,
+            }))
+            .add_application_with_origin(b"worker_handle".to_vec(), RawOrigin::Signed(3), 3)
+            .execute()
+            .unwrap();
+
+        set_deputy_permissions(
+            deputy_worker_id,
+            DeputyPermissions {
+                update_rewards: true,
+                max_slash_amount: Some(10),
+                ..DeputyPermissions::default()
+            },
+        );
+
+        SlashWorkerStakeFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(DEPUTY_ACCOUNT_ID))
+            .with_balance(10)
+            .call_and_assert(Ok(()));
+
+        SlashWorkerStakeFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(DEPUTY_ACCOUNT_ID))
+            .with_balance(11)
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::IsNotLeadAccount.into(),
+            ));
+
+        UpdateWorkerRewardAmountFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(DEPUTY_ACCOUNT_ID))
+            .call_and_assert(Ok(()));
+
+        // Deputies cannot terminate the workers.
+        TerminateWorkerRoleFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(DEPUTY_ACCOUNT_ID))
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::IsNotLeadAccount.into(),
+            ));
+    });
+}
+
+#[test]
+fn deputy_cannot_manage_itself_or_other_deputies() {
+    build_test_externalities().execute_with(|| {
+        let deputy_worker_id = hire_deputy();
+
+        increase_total_balance_issuance_using_account_id(3, 10000);
+        let other_deputy_worker_id = HiringWorkflow::default()
+            .disable_setup_environment()
+            .with_role_stake(Some(100))
+            .with_reward_policy(Some(RewardPolicy {
+                amount_per_payout: 1000,
+                next_payment_at_block: 20,
+                payout_interval: None,
+            }))
+            .add_application_with_origin(b"other_deputy".to_vec(), RawOrigin::Signed(3), 3)
+            .execute()
+            .unwrap();
+
+        let permissions = DeputyPermissions {
+            update_rewards: true,
+            max_slash_amount: Some(10),
+            ..DeputyPermissions::default()
+        };
+        set_deputy_permissions(deputy_worker_id, permissions.clone());
+        set_deputy_permissions(other_deputy_worker_id, permissions);
+
+        for worker_id in vec![deputy_worker_id, other_deputy_worker_id] {
+            SlashWorkerStakeFixture::default_for_worker_id(worker_id)
+                .with_origin(RawOrigin::Signed(DEPUTY_ACCOUNT_ID))
+                .with_balance(10)
+                .call_and_assert(Err(
+                    Error::<Test, TestWorkingGroupInstance>::IsNotLeadAccount.into(),
+                ));
+
+            UpdateWorkerRewardAmountFixture::default_for_worker_id(worker_id)
+                .with_origin(RawOrigin::Signed(DEPUTY_ACCOUNT_ID))
+                .call_and_assert(Err(
+                    Error::<Test, TestWorkingGroupInstance>::IsNotLeadAccount.into(),
+                ));
+
+            TerminateWorkerRoleFixture::default_for_worker_id(worker_id)
+                .with_origin(RawOrigin::Signed(DEPUTY_ACCOUNT_ID))
+                .call_and_assert(Err(
+                    Error::<Test, TestWorkingGroupInstance>::IsNotLeadAccount.into(),
+                ));
+        }
+
+        // The leader still manages the deputies.
+        SlashWorkerStakeFixture::default_for_worker_id(other_deputy_worker_id)
+            .with_balance(10)
+            .call_and_assert(Ok(()));
+
+        UpdateWorkerRewardAmountFixture::default_for_worker_id(other_deputy_worker_id)
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn revoke_deputy_permissions_succeeds() {
+    build_test_externalities().execute_with(|| {
+        /*
+           Events are not emitted on block 0.
+           So any dispatchable calls made during genesis block formation will have no events emitted.
+           https://substrate.dev/recipes/2-appetizers/4-events.html
+        */
+        run_to_block(1);
+
+        let deputy_worker_id = hire_deputy();
+        let lead_account_id = 1;
+
+        set_deputy_permissions(deputy_worker_id, DeputyPermissions::default());
+
+        assert_eq!(
+            TestWorkingGroup::revoke_deputy_permissions(
+                RawOrigin::Signed(lead_account_id).into(),
+                deputy_worker_id
+            ),
+            Ok(())
+        );
+
+        assert!(!<crate::DeputyPermissionsByWorkerId<
+            Test,
+            TestWorkingGroupInstance,
+        >>::contains_key(deputy_worker_id));
+        EventFixture::assert_last_crate_event(RawEvent::DeputyPermissionsRevoked(deputy_worker_id));
+
+        assert_eq!(
+            TestWorkingGroup::revoke_deputy_permissions(
+                RawOrigin::Signed(lead_account_id).into(),
+                deputy_worker_id
+            ),
+            Err(Error::<Test, TestWorkingGroupInstance>::WorkerIsNotDeputy.into())
+        );
+    });
+}
+
+#[test]
+fn deputy_permissions_are_cleared_on_leader_change() {
+    build_test_externalities().execute_with(|| {
+        let deputy_worker_id = hire_deputy();
+        let leader_worker_id = TestWorkingGroup::current_lead().unwrap();
+
+        set_deputy_permissions(deputy_worker_id, DeputyPermissions::default());
+
+        LeaveWorkerRoleFixture::default_for_worker_id(leader_worker_id).call_and_assert(Ok(()));
+
+        assert!(!<crate::DeputyPermissionsByWorkerId<
+            Test,
+            TestWorkingGroupInstance,
+        >>::contains_key(deputy_worker_id));
+        assert_eq!(
+            TestWorkingGroup::deputy_worker_id_by_role_account_id(DEPUTY_ACCOUNT_ID),
+            None
+        );
+    });
+}
+
+#[test]
+fn deputy_role_account_cannot_be_shared_between_deputies() {
+    build_test_externalities().execute_with(|| {
+        let deputy_worker_id = hire_deputy();
+        let lead_account_id = 1;
+
+        set_deputy_permissions(deputy_worker_id, DeputyPermissions::default());
+        assert_eq!(
+            TestWorkingGroup::deputy_worker_id_by_role_account_id(DEPUTY_ACCOUNT_ID),
+            Some(deputy_worker_id)
+        );
+
+        let worker_id = HiringWorkflow::default()
+            .disable_setup_environment()
+            .add_application_with_origin(b"worker_handle".to_vec(), RawOrigin::Signed(3), 3)
+            .execute()
+            .unwrap();
+
+        // Regular workers can share the role account with the deputy.
+        assert_eq!(
+            TestWorkingGroup::update_role_account(
+                RawOrigin::Signed(3).into(),
+                worker_id,
+                DEPUTY_ACCOUNT_ID
+            ),
+            Ok(())
+        );
+
+        assert_eq!(
+            TestWorkingGroup::set_deputy_permissions(
+                RawOrigin::Signed(lead_account_id).into(),
+                worker_id,
+                DeputyPermissions::default()
+            ),
+            Err(Error::<Test, TestWorkingGroupInstance>::DeputyRoleAccountIsUsed.into())
+        );
+
+        // The deputy index follows the role account updates.
+        let new_deputy_account_id = 21;
+        assert_eq!(
+            TestWorkingGroup::update_role_account(
+                RawOrigin::Signed(2).into(),
+                deputy_worker_id,
+                new_deputy_account_id
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            TestWorkingGroup::deputy_worker_id_by_role_account_id(DEPUTY_ACCOUNT_ID),
+            None
+        );
+        assert_eq!(
+            TestWorkingGroup::deputy_worker_id_by_role_account_id(new_deputy_account_id),
+            Some(deputy_worker_id)
+        );
+
+        set_deputy_permissions(worker_id, DeputyPermissions::default());
+
+        assert_eq!(
+            TestWorkingGroup::update_role_account(
+                RawOrigin::Signed(2).into(),
+                deputy_worker_id,
+                DEPUTY_ACCOUNT_ID
+            ),
+            Err(Error::<Test, TestWorkingGroupInstance>::DeputyRoleAccountIsUsed.into())
+        );
+    });
+}
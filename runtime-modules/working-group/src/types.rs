@@ -2,6 +2,7 @@
 
 use codec::{Decode, Encode};
 use sp_std::collections::btree_set::BTreeSet;
+use sp_std::vec::Vec;
 
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
@@ -236,6 +237,46 @@ pub enum ExitInitiationOrigin {
     Sudo,
 }
 
+/// Exit details of the working group role.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Clone, PartialEq)]
+pub struct RoleExit<BlockNumber> {
+    /// Block of the role exit.
+    pub exited_at_block: BlockNumber,
+
+    /// Origin of the exit initiation.
+    pub exit_initiation_origin: ExitInitiationOrigin,
+
+    /// Exit rationale text.
+    pub rationale_text: Vec<u8>,
+}
+
+/// Record of the working group role held by a member: appointment, slashes, rewards and exit.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq)]
+pub struct RoleHistoryEntry<WorkerId, BlockNumber, Balance, RewardBalance> {
+    /// Worker id of the role.
+    pub worker_id: WorkerId,
+
+    /// Defines whether the role was the leader role.
+    pub is_lead: bool,
+
+    /// Block of the appointment (opening fill).
+    pub appointed_at_block: BlockNumber,
+
+    /// Number of the role stake slashes.
+    pub slash_count: u32,
+
+    /// Total amount slashed from the role stake.
+    pub total_slashed: Balance,
+
+    /// Total reward received for the role. Recorded on exit.
+    pub total_reward_received: RewardBalance,
+
+    /// Exit details, when set the role is no longer held.
+    pub exit: Option<RoleExit<BlockNumber>>,
+}
+
 /// The recurring reward if any to be assigned to an actor when filling in the position.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Debug)]
@@ -249,3 +290,52 @@ pub struct RewardPolicy<Balance, BlockNumber> {
     /// Optional payout interval.
     pub payout_interval: Option<BlockNumber>,
 }
+
+/// Recipient of the one-off payment from the working group mint.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Clone, PartialEq)]
+pub enum PaymentRecipient<WorkerId, MemberId> {
+    /// Worker/lead, the payment is deposited to the reward account (member root account
+    /// for the workers without reward).
+    Worker(WorkerId),
+
+    /// Member, the payment is deposited to the member root account.
+    Member(MemberId),
+}
+
+/// Limit of the one-off payments from the working group mint.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Clone, PartialEq)]
+pub struct SpendingLimit<Balance, BlockNumber> {
+    /// Maximum total amount of the payments per spending period.
+    pub amount_per_period: Balance,
+
+    /// Spending period length (in blocks).
+    pub period_length: BlockNumber,
+}
+
+/// Payments made within the current spending period.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq)]
+pub struct SpendingPeriod<Balance, BlockNumber> {
+    /// Block the spending period started at.
+    pub started_at_block: BlockNumber,
+
+    /// Total amount paid within the spending period.
+    pub spent: Balance,
+}
+
+/// Permissions delegated by the leader to the worker (deputy).
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq)]
+pub struct DeputyPermissions<Balance> {
+    /// Manage the worker openings: add openings, accept and terminate applications,
+    /// begin the application review and fill openings.
+    pub manage_openings: bool,
+
+    /// Update the worker reward amounts.
+    pub update_rewards: bool,
+
+    /// When set, allows slashing the worker stakes up to the provided amount per slash.
+    pub max_slash_amount: Option<Balance>,
+}
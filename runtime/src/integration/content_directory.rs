@@ -41,4 +41,13 @@ impl content_directory::ActorAuthenticator for Runtime {
         )
         .is_ok()
     }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn create_benchmark_lead() -> AccountId {
+        let lead_account_id = frame_benchmarking::account("content_directory_lead", 0, 0);
+        let lead_id =
+            ContentDirectoryWorkingGroup::<Runtime>::insert_benchmark_lead(&lead_account_id);
+
+        ContentDirectoryWorkingGroup::<Runtime>::worker_by_id(lead_id).role_account_id
+    }
 }
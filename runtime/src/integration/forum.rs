@@ -28,4 +28,19 @@ impl forum::ForumUserRegistry<AccountId> for ShimMembershipRegistry {
             None
         }
     }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn add_benchmark_forum_user(id: &AccountId) {
+        membership::Module::<Runtime>::insert_benchmark_member(id);
+    }
+}
+
+/// Registry which allows workers of the forum working group to moderate the forum.
+pub struct ForumWorkingGroupModeratorRegistry {}
+
+impl forum::ForumModeratorRegistry<AccountId> for ForumWorkingGroupModeratorRegistry {
+    fn is_forum_moderator(id: &AccountId) -> bool {
+        // The forum working group leader is a worker as well.
+        crate::ForumWorkingGroup::is_worker_role_account(id)
+    }
 }
@@ -31,6 +31,25 @@ impl<T: governance::council::Trait + membership::Trait>
 
         Err("Council validation failed: account id doesn't belong to a council member")
     }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn create_benchmark_actor(account_id: &<T as system::Trait>::AccountId) -> MemberId<T> {
+        use frame_support::StorageValue;
+
+        let member_id = <MembershipOriginValidator<T>>::create_benchmark_actor(account_id);
+
+        if !<governance::council::Module<T>>::is_councilor(account_id) {
+            <governance::council::ActiveCouncil<T>>::mutate(|council| {
+                council.push(governance::election::Seat {
+                    member: account_id.clone(),
+                    stake: Default::default(),
+                    backers: sp_std::vec::Vec::new(),
+                })
+            });
+        }
+
+        member_id
+    }
 }
 
 impl<T: governance::council::Trait> VotersParameters for CouncilManager<T> {
@@ -40,6 +40,11 @@ impl<T: membership::Trait>
 
         Err("Membership validation failed: cannot find a profile for a member")
     }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn create_benchmark_actor(account_id: &<T as system::Trait>::AccountId) -> MemberId<T> {
+        <membership::Module<T>>::insert_benchmark_member(account_id)
+    }
 }
 
 #[cfg(test)]
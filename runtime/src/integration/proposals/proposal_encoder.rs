@@ -1,4 +1,4 @@
-use crate::{Call, Runtime};
+use crate::{data_directory, Call, Runtime};
 use common::working_group::WorkingGroup;
 use proposals_codex::{ProposalDetails, ProposalDetailsOf, ProposalEncoder};
 use working_group::OpeningType;
@@ -22,6 +22,7 @@ macro_rules! wrap_working_group_call {
                 Call::ContentDirectoryWorkingGroup($working_group_instance_call)
             }
             WorkingGroup::Storage => Call::StorageWorkingGroup($working_group_instance_call),
+            WorkingGroup::Forum => Call::ForumWorkingGroup($working_group_instance_call),
         }
     }};
 }
@@ -44,9 +45,12 @@ impl ProposalEncoder<Runtime> for ExtrinsicProposalEncoder {
             ProposalDetails::SetValidatorCount(new_validator_count) => Call::Staking(
                 pallet_staking::Call::set_validator_count(new_validator_count),
             ),
-            ProposalDetails::RuntimeUpgrade(wasm_code) => Call::ProposalsCodex(
-                proposals_codex::Call::execute_runtime_upgrade_proposal(wasm_code),
-            ),
+            // ********** Deprecated: replaced by the RuntimeUpgradeByHash.
+            // It is kept only for backward compatibility in the Pioneer. **********
+            ProposalDetails::RuntimeUpgrade(_) => {
+                print("Error: Calling deprecated RuntimeUpgrade encoding option.");
+                return Vec::new();
+            }
             // ********** Deprecated during the Babylon release.
             ProposalDetails::DeprecatedSetLead(_) => {
                 print("Error: Calling deprecated SetLead encoding option.");
@@ -126,6 +130,51 @@ impl ProposalEncoder<Runtime> for ExtrinsicProposalEncoder {
                     Wg::terminate_role_call(terminate_role_params)
                 )
             }
+            // Ranked choice options are encoded separately: each option is converted to
+            // the proposal details of its type.
+            ProposalDetails::RankedChoice(_) => {
+                print("Error: Calling RankedChoice encoding option. Options should be encoded.");
+                return Vec::new();
+            }
+            // Batch items are encoded in order and executed by the codex atomically.
+            ProposalDetails::Batch(items) => {
+                Call::ProposalsCodex(proposals_codex::Call::execute_batch_proposal(
+                    items
+                        .into_iter()
+                        .map(|item| Self::encode_proposal(item.into()))
+                        .collect(),
+                ))
+            }
+            ProposalDetails::RuntimeUpgradeByHash(runtime_upgrade_parameters) => {
+                Call::ProposalsCodex(proposals_codex::Call::execute_runtime_upgrade_proposal(
+                    runtime_upgrade_parameters,
+                ))
+            }
+            ProposalDetails::AmendConstitution(constitution_text) => Call::ProposalsCodex(
+                proposals_codex::Call::execute_amend_constitution_proposal(constitution_text),
+            ),
+            ProposalDetails::SetProposalParameters(proposal_type, proposal_parameters) => {
+                Call::ProposalsCodex(
+                    proposals_codex::Call::execute_set_proposal_parameters_proposal(
+                        proposal_type,
+                        proposal_parameters,
+                    ),
+                )
+            }
+            ProposalDetails::SetDefaultStorageQuotaLimits(objects_limit, size_limit) => {
+                Call::DataDirectory(data_directory::Call::set_default_quota_limits(
+                    data_directory::QuotaLimits {
+                        objects_limit,
+                        size_limit,
+                    },
+                ))
+            }
+            ProposalDetails::SetWorkingGroupSpendingLimit(spending_limit, working_group) => {
+                wrap_working_group_call!(
+                    working_group,
+                    Wg::create_set_spending_limit_call(spending_limit)
+                )
+            }
         };
 
         call.encode()
@@ -191,6 +240,15 @@ where
         working_group::Call::<T, I>::set_mint_capacity(mint_balance)
     }
 
+    // Generic call constructor for the working group 'set spending limit'.
+    fn create_set_spending_limit_call(
+        spending_limit: Option<
+            working_group::SpendingLimit<working_group::BalanceOfMint<T>, T::BlockNumber>,
+        >,
+    ) -> working_group::Call<T, I> {
+        working_group::Call::<T, I>::set_spending_limit(spending_limit)
+    }
+
     // Generic call constructor for the working group 'decrease stake'.
     fn create_decrease_stake_call(
         worker_id: working_group::WorkerId<T>,
@@ -1,7 +1,11 @@
-use frame_support::traits::Randomness;
+use frame_support::traits::{Get, Randomness};
+use frame_support::weights::Weight;
+use frame_support::StorageMap;
 use sp_std::vec::Vec;
 
-use crate::{ActorId, Runtime};
+use crate::{
+    AccountId, ActorId, ContentDirectoryWorkingGroupInstance, ContentId, MemberId, Runtime,
+};
 
 /// Provides random storage provider id. We use it when assign the content to the storage provider.
 pub struct StorageProviderHelper;
@@ -22,15 +26,112 @@ impl storage::data_directory::StorageProviderHelper<Runtime> for StorageProvider
             Ok(live_ids[index])
         }
     }
+
+    fn get_storage_providers_for_replication(
+        content_id: &ContentId,
+        excluded_ids: &[ActorId],
+        count: usize,
+    ) -> Vec<ActorId> {
+        let live_ids: Vec<ActorId> = crate::StorageWorkingGroup::get_all_worker_ids()
+            .into_iter()
+            .filter(|id| !excluded_ids.contains(id))
+            .filter(|id| !<service_discovery::Module<Runtime>>::is_account_info_expired(id))
+            .collect();
+
+        // Only the live storage providers are assigned. The content id is used as the randomness
+        // subject, so the content processed in the same block goes to different storage providers.
+        Self::rotate_randomly(live_ids, content_id.as_bytes())
+            .into_iter()
+            .take(count)
+            .collect()
+    }
+
+    fn get_storage_providers_for_replication_weight() -> Weight {
+        // Reads the worker count, the randomness and every storage worker with its service
+        // discovery record.
+        let storage_workers_count = crate::StorageWorkingGroup::active_worker_count() as Weight;
+
+        <Runtime as system::Trait>::DbWeight::get().reads(2 + 2 * storage_workers_count)
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn create_benchmark_storage_provider(account_id: &AccountId) -> ActorId {
+        let storage_provider_id = crate::StorageWorkingGroup::insert_benchmark_worker(account_id);
+
+        // The service discovery record never expires, so the storage provider stays live.
+        <service_discovery::AccountInfoByStorageProviderId<Runtime>>::insert(
+            storage_provider_id,
+            service_discovery::ServiceProviderRecord {
+                identity: Vec::new(),
+                expires_at: crate::BlockNumber::max_value(),
+            },
+        );
+
+        storage_provider_id
+    }
 }
 
 impl StorageProviderHelper {
+    // Rotates the ids by the random offset, so the assigned storage providers vary.
+    fn rotate_randomly(mut ids: Vec<ActorId>, subject: &[u8]) -> Vec<ActorId> {
+        if !ids.is_empty() {
+            let seed = crate::RandomnessCollectiveFlip::random(subject);
+            let offset = Self::index_from_seed(seed.as_ref(), ids.len());
+            ids.rotate_left(offset);
+        }
+
+        ids
+    }
+
     fn random_index(upper_bound: usize) -> usize {
         let seed = crate::RandomnessCollectiveFlip::random_seed();
+        Self::index_from_seed(seed.as_ref(), upper_bound)
+    }
+
+    fn index_from_seed(seed: &[u8], upper_bound: usize) -> usize {
         let mut rand: u64 = 0;
-        for offset in 0..8 {
-            rand += (seed.as_ref()[offset] as u64) << offset;
+        for (offset, byte) in seed.iter().take(8).enumerate() {
+            rand += (*byte as u64) << offset;
         }
         (rand as usize) % upper_bound
     }
 }
+
+/// Authorizes the content directory curators to take down the content.
+pub struct ContentCurators;
+
+impl storage::data_directory::ContentCurators<Runtime> for ContentCurators {
+    fn is_content_curator(account_id: &AccountId) -> bool {
+        working_group::Module::<Runtime, ContentDirectoryWorkingGroupInstance>::is_worker_role_account(
+            account_id,
+        )
+    }
+}
+
+/// Authorizes the content working group channel owners to add the channel content.
+pub struct ChannelOwners;
+
+impl storage::data_directory::ChannelOwners<Runtime> for ChannelOwners {
+    fn is_channel_owner(member_id: &MemberId, channel_id: &ActorId) -> bool {
+        <content_working_group::ChannelById<Runtime>>::contains_key(channel_id)
+            && <content_working_group::ChannelById<Runtime>>::get(channel_id).owner == *member_id
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn create_benchmark_channel(member_id: &MemberId) -> ActorId {
+        use frame_support::StorageValue;
+
+        let channel_id = <content_working_group::NextChannelId<Runtime>>::get();
+
+        <content_working_group::ChannelById<Runtime>>::insert(
+            channel_id,
+            content_working_group::Channel {
+                owner: *member_id,
+                ..Default::default()
+            },
+        );
+        <content_working_group::NextChannelId<Runtime>>::put(channel_id + 1);
+
+        channel_id
+    }
+}
@@ -1,24 +1,79 @@
 use codec::Encode;
 use frame_support::debug;
-use frame_support::weights::{WeightToFeeCoefficients, WeightToFeePolynomial};
+use frame_support::traits::Get;
+use frame_support::weights::{
+    WeightToFeeCoefficient, WeightToFeeCoefficients, WeightToFeePolynomial,
+};
+use pallet_transaction_payment::Multiplier;
+use smallvec::smallvec;
 use sp_runtime::generic;
 use sp_runtime::generic::SignedPayload;
-use sp_runtime::SaturatedConversion;
+use sp_runtime::traits::Convert;
+use sp_runtime::{FixedPointNumber, Perquintill, SaturatedConversion};
+use sp_std::marker::PhantomData;
 
 use crate::{AccountId, Balance, BlockHashCount, Index, SignedExtra, UncheckedExtrinsic};
-use crate::{Call, Runtime, System};
+use crate::{Call, MaximumBlockWeight, Runtime, System};
+use crate::{WeightFeeFractionalCoefficient, WeightFeeIntegerCoefficient};
 
-/// Stub for zero transaction weights.
-pub struct NoWeights;
-impl WeightToFeePolynomial for NoWeights {
+/// Linear weight-to-fee conversion: `fee = weight * (integer + fractional coefficient)`.
+/// Coefficients are defined by the `WeightFeeIntegerCoefficient` and
+/// `WeightFeeFractionalCoefficient` runtime parameters.
+pub struct WeightToFee;
+impl WeightToFeePolynomial for WeightToFee {
     type Balance = Balance;
 
     fn polynomial() -> WeightToFeeCoefficients<Self::Balance> {
-        Default::default()
+        smallvec![WeightToFeeCoefficient {
+            coeff_integer: WeightFeeIntegerCoefficient::get(),
+            coeff_frac: WeightFeeFractionalCoefficient::get(),
+            negative: false,
+            degree: 1,
+        }]
     }
+}
+
+/// Updates the fee multiplier based on the fullness of the previous block.
+///
+/// The multiplier rises when the block weight exceeds the target fullness `T` and falls
+/// when the block is emptier than the target, according to:
+/// `next = previous + v * diff + (v * diff)^2 / 2`, where
+/// `diff = (block_weight - target_weight) / max_weight` and `v = 0.00004`.
+/// The multiplier never drops below `-1` (zero weight fee).
+pub struct TargetedFeeAdjustment<T>(PhantomData<T>);
+impl<T: Get<Perquintill>> Convert<Multiplier, Multiplier> for TargetedFeeAdjustment<T> {
+    fn convert(multiplier: Multiplier) -> Multiplier {
+        let max_weight = MaximumBlockWeight::get();
+        let block_weight = System::all_extrinsics_weight().total().min(max_weight);
+        let target_weight = (T::get() * max_weight) as u128;
+        let block_weight = block_weight as u128;
+
+        // determines if the first term is positive
+        let positive = block_weight >= target_weight;
+        let diff_abs = block_weight.max(target_weight) - block_weight.min(target_weight);
+        // safe, diff_abs cannot exceed u64
+        let diff = Multiplier::saturating_from_rational(diff_abs, max_weight.max(1));
+        let diff_squared = diff.saturating_mul(diff);
+
+        // 0.00004 = 4/100_000
+        let v = Multiplier::saturating_from_rational(4, 100_000);
+        // 0.00004^2 = 16/10^10, taking the /2 into account: 8/10^10
+        let v_squared_2 = Multiplier::saturating_from_rational(8, 10_000_000_000u64);
+
+        let first_term = v.saturating_mul(diff);
+        let second_term = v_squared_2.saturating_mul(diff_squared);
 
-    fn calc(_weight: &u64) -> Self::Balance {
-        Default::default()
+        if positive {
+            let excess = first_term.saturating_add(second_term);
+            multiplier.saturating_add(excess)
+        } else {
+            // first_term > second_term for any diff <= 1, safe subtraction
+            let negative = first_term.saturating_sub(second_term);
+            multiplier
+                .saturating_sub(negative)
+                // -1 means the weight fee is zero, do not reduce it any further
+                .max(Multiplier::saturating_from_integer(-1))
+        }
     }
 }
 
@@ -19,6 +19,7 @@ pub mod primitives;
 mod runtime_api;
 #[cfg(test)]
 mod tests; // Runtime integration tests
+mod weights;
 
 use frame_support::traits::KeyOwnerProofSystem;
 use frame_support::weights::{
@@ -33,7 +34,7 @@ use sp_authority_discovery::AuthorityId as AuthorityDiscoveryId;
 use sp_core::crypto::KeyTypeId;
 use sp_runtime::curve::PiecewiseLinear;
 use sp_runtime::traits::{BlakeTwo256, Block as BlockT, IdentityLookup, OpaqueKeys, Saturating};
-use sp_runtime::{create_runtime_str, generic, impl_opaque_keys, Perbill};
+use sp_runtime::{create_runtime_str, generic, impl_opaque_keys, Perbill, Perquintill};
 use sp_std::boxed::Box;
 use sp_std::vec::Vec;
 #[cfg(feature = "std")]
@@ -75,10 +76,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: create_runtime_str!("joystream-node"),
     impl_name: create_runtime_str!("joystream-node"),
     authoring_version: 7,
-    spec_version: 9,
+    spec_version: 10,
     impl_version: 0,
     apis: crate::runtime_api::EXPORTED_RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 2,
 };
 
 /// The version information used to identify this runtime when compiled natively.
@@ -225,15 +226,23 @@ impl pallet_balances::Trait for Runtime {
 }
 
 parameter_types! {
-    pub const TransactionByteFee: Balance = 0; // TODO: adjust fee
+    pub const TransactionByteFee: Balance = 1;
+    /// Integer part of the fee charged per weight unit.
+    pub const WeightFeeIntegerCoefficient: Balance = 0;
+    /// Fractional part of the fee charged per weight unit: 1 token per 10^8 weight units,
+    /// so the base extrinsic weight costs about one token.
+    pub const WeightFeeFractionalCoefficient: Perbill = Perbill::from_parts(10);
+    /// Block fullness the fee multiplier is steering to.
+    pub const TargetBlockFullness: Perquintill = Perquintill::from_percent(25);
 }
 
 impl pallet_transaction_payment::Trait for Runtime {
     type Currency = Balances;
     type OnTransactionPayment = ();
     type TransactionByteFee = TransactionByteFee;
-    type WeightToFee = integration::transactions::NoWeights; // TODO: adjust weight
-    type FeeMultiplierUpdate = (); // TODO: adjust fee
+    type WeightToFee = integration::transactions::WeightToFee;
+    type FeeMultiplierUpdate =
+        integration::transactions::TargetedFeeAdjustment<TargetBlockFullness>;
 }
 
 impl pallet_sudo::Trait for Runtime {
@@ -425,6 +434,11 @@ impl content_directory::Trait for Runtime {
     type TextMaxLengthConstraint = TextMaxLengthConstraint;
     type HashedTextMaxLengthConstraint = HashedTextMaxLengthConstraint;
     type IndividualEntitiesCreationLimit = IndividualEntitiesCreationLimit;
+    type WeightInfo = weights::content_directory::WeightInfo;
+}
+
+parameter_types! {
+    pub const HiringApplicationsRetentionPeriod: BlockNumber = 7 * DAYS;
 }
 
 impl hiring::Trait for Runtime {
@@ -432,6 +446,7 @@ impl hiring::Trait for Runtime {
     type ApplicationId = u64;
     type ApplicationDeactivatedHandler = (); // TODO - what needs to happen?
     type StakeHandlerProvider = hiring::Module<Self>;
+    type ApplicationsRetentionPeriod = HiringApplicationsRetentionPeriod;
 }
 
 impl minting::Trait for Runtime {
@@ -439,10 +454,16 @@ impl minting::Trait for Runtime {
     type MintId = u64;
 }
 
+parameter_types! {
+    pub const MaxRewardPayoutsPerBlock: u32 = 100;
+}
+
 impl recurring_rewards::Trait for Runtime {
+    type Event = Event;
     type PayoutStatusHandler = (); // TODO - deal with successful and failed payouts
     type RecipientId = u64;
     type RewardRelationshipId = u64;
+    type MaxPayoutsPerBlock = MaxRewardPayoutsPerBlock;
 }
 
 parameter_types! {
@@ -456,7 +477,10 @@ impl stake::Trait for Runtime {
         crate::integration::proposals::StakingEventsHandler<Self>,
         (
             crate::integration::working_group::ContentDirectoryWGStakingEventsHandler<Self>,
-            crate::integration::working_group::StorageWgStakingEventsHandler<Self>,
+            (
+                crate::integration::working_group::StorageWgStakingEventsHandler<Self>,
+                crate::integration::working_group::ForumWgStakingEventsHandler<Self>,
+            ),
         ),
     );
     type StakeId = u64;
@@ -465,6 +489,7 @@ impl stake::Trait for Runtime {
 
 impl content_wg::Trait for Runtime {
     type Event = Event;
+    type WeightInfo = weights::content_working_group::WeightInfo;
 }
 
 impl common::currency::GovernanceCurrency for Runtime {
@@ -474,24 +499,29 @@ impl common::currency::GovernanceCurrency for Runtime {
 impl governance::election::Trait for Runtime {
     type Event = Event;
     type CouncilElected = (Council, integration::proposals::CouncilElectedHandler);
+    type WeightInfo = weights::election::WeightInfo;
 }
 
 impl governance::council::Trait for Runtime {
     type Event = Event;
     type CouncilTermEnded = (CouncilElection,);
+    type WeightInfo = weights::council::WeightInfo;
 }
 
 impl memo::Trait for Runtime {
     type Event = Event;
+    type WeightInfo = weights::memo::WeightInfo;
 }
 
 parameter_types! {
     pub const MaxObjectsPerInjection: u32 = 100;
+    pub const MaxKnownContentIdsMigratedPerBlock: u32 = 500;
 }
 
 impl storage::data_object_type_registry::Trait for Runtime {
     type Event = Event;
     type DataObjectTypeId = u64;
+    type WeightInfo = weights::data_object_type_registry::WeightInfo;
 }
 
 impl storage::data_directory::Trait for Runtime {
@@ -501,12 +531,23 @@ impl storage::data_directory::Trait for Runtime {
     type IsActiveDataObjectType = DataObjectTypeRegistry;
     type MemberOriginValidator = MembershipOriginValidator<Self>;
     type MaxObjectsPerInjection = MaxObjectsPerInjection;
+    type MaxKnownContentIdsMigratedPerBlock = MaxKnownContentIdsMigratedPerBlock;
+    type ContentCurators = integration::storage::ContentCurators;
+    type ContentRemovedHandler = DataObjectStorageRegistry;
+    type ChannelOwners = integration::storage::ChannelOwners;
+    type WeightInfo = weights::data_directory::WeightInfo;
+}
+
+parameter_types! {
+    pub const MaxStorageReassignmentsPerBlock: u32 = 100;
 }
 
 impl storage::data_object_storage_registry::Trait for Runtime {
     type Event = Event;
     type DataObjectStorageRelationshipId = u64;
     type ContentIdExists = DataDirectory;
+    type MaxReassignmentsPerBlock = MaxStorageReassignmentsPerBlock;
+    type WeightInfo = weights::data_object_storage_registry::WeightInfo;
 }
 
 impl membership::Trait for Runtime {
@@ -515,15 +556,21 @@ impl membership::Trait for Runtime {
     type PaidTermId = u64;
     type SubscriptionId = u64;
     type ActorId = ActorId;
+    type WeightInfo = weights::membership::WeightInfo;
 }
 
 impl forum::Trait for Runtime {
     type Event = Event;
     type MembershipRegistry = integration::forum::ShimMembershipRegistry;
+    type ModeratorRegistry = integration::forum::ForumWorkingGroupModeratorRegistry;
     type ThreadId = ThreadId;
     type PostId = PostId;
+    type WeightInfo = weights::forum::WeightInfo;
 }
 
+// The forum working group instance alias.
+pub type ForumWorkingGroupInstance = working_group::Instance1;
+
 // The storage working group instance alias.
 pub type StorageWorkingGroupInstance = working_group::Instance2;
 
@@ -534,18 +581,30 @@ parameter_types! {
     pub const MaxWorkerNumberLimit: u32 = 100;
 }
 
+impl working_group::Trait<ForumWorkingGroupInstance> for Runtime {
+    type Event = Event;
+    type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WeightInfo = weights::working_group::WeightInfo;
+    type WorkerExitHandler = ();
+}
+
 impl working_group::Trait<StorageWorkingGroupInstance> for Runtime {
     type Event = Event;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WeightInfo = weights::working_group::WeightInfo;
+    type WorkerExitHandler = DataObjectStorageRegistry;
 }
 
 impl working_group::Trait<ContentDirectoryWorkingGroupInstance> for Runtime {
     type Event = Event;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WeightInfo = weights::working_group::WeightInfo;
+    type WorkerExitHandler = ();
 }
 
 impl service_discovery::Trait for Runtime {
     type Event = Event;
+    type WeightInfo = weights::service_discovery::WeightInfo;
 }
 
 parameter_types! {
@@ -554,6 +613,9 @@ parameter_types! {
     pub const ProposalTitleMaxLength: u32 = 40;
     pub const ProposalDescriptionMaxLength: u32 = 3000;
     pub const ProposalMaxActiveProposalLimit: u32 = 5;
+    pub const ProposalMaxOptions: u32 = 10;
+    pub const ProposalVoteRationaleMaxLength: u32 = 3000;
+    pub const ProposalMaxExactExecutionBlockDelay: BlockNumber = 30 * DAYS;
 }
 
 impl proposals_engine::Trait for Runtime {
@@ -568,7 +630,11 @@ impl proposals_engine::Trait for Runtime {
     type TitleMaxLength = ProposalTitleMaxLength;
     type DescriptionMaxLength = ProposalDescriptionMaxLength;
     type MaxActiveProposalLimit = ProposalMaxActiveProposalLimit;
+    type MaxProposalOptions = ProposalMaxOptions;
+    type VoteRationaleMaxLength = ProposalVoteRationaleMaxLength;
+    type MaxExactExecutionBlockDelay = ProposalMaxExactExecutionBlockDelay;
     type DispatchableCallCode = Call;
+    type WeightInfo = weights::proposals_engine::WeightInfo;
 }
 impl Default for Call {
     fn default() -> Self {
@@ -592,18 +658,24 @@ impl proposals_discussion::Trait for Runtime {
     type ThreadTitleLengthLimit = ProposalThreadTitleLengthLimit;
     type PostLengthLimit = ProposalPostLengthLimit;
     type MaxThreadInARowNumber = ProposalMaxThreadInARowNumber;
+    type WeightInfo = weights::proposals_discussion::WeightInfo;
 }
 
 parameter_types! {
     pub const TextProposalMaxLength: u32 = 5_000;
     pub const RuntimeUpgradeWasmProposalMaxLength: u32 = 3_000_000;
+    pub const RuntimeUpgradeWasmDepositPerByte: Balance = 1;
+    pub const ConstitutionMaxLength: u32 = 50_000;
 }
 
 impl proposals_codex::Trait for Runtime {
     type MembershipOriginValidator = MembershipOriginValidator<Self>;
     type TextProposalMaxLength = TextProposalMaxLength;
     type RuntimeUpgradeWasmProposalMaxLength = RuntimeUpgradeWasmProposalMaxLength;
+    type RuntimeUpgradeWasmDepositPerByte = RuntimeUpgradeWasmDepositPerByte;
+    type ConstitutionMaxLength = ConstitutionMaxLength;
     type ProposalEncoder = ExtrinsicProposalEncoder;
+    type WeightInfo = weights::proposals_codex::WeightInfo;
 }
 
 parameter_types! {
@@ -664,7 +736,7 @@ construct_runtime!(
         VersionedStorePermissions: versioned_store_permissions::{Module, Call, Storage, Config<T>},
         Stake: stake::{Module, Call, Storage},
         Minting: minting::{Module, Call, Storage},
-        RecurringRewards: recurring_rewards::{Module, Call, Storage},
+        RecurringRewards: recurring_rewards::{Module, Call, Storage, Event<T>},
         Hiring: hiring::{Module, Call, Storage},
         ContentWorkingGroup: content_wg::{Module, Call, Storage, Event<T>, Config<T>},
         ContentDirectory: content_directory::{Module, Call, Storage, Event<T>, Config<T>},
@@ -678,8 +750,9 @@ construct_runtime!(
         ProposalsDiscussion: proposals_discussion::{Module, Call, Storage, Event<T>},
         ProposalsCodex: proposals_codex::{Module, Call, Storage, Config<T>},
         // --- Working groups
-        // reserved for the future use: ForumWorkingGroup: working_group::<Instance1>::{Module, Call, Storage, Event<T>},
         StorageWorkingGroup: working_group::<Instance2>::{Module, Call, Storage, Config<T>, Event<T>},
         ContentDirectoryWorkingGroup: working_group::<Instance3>::{Module, Call, Storage, Config<T>, Event<T>},
+        // New modules are appended to keep the indices of the existing modules.
+        ForumWorkingGroup: working_group::<Instance1>::{Module, Call, Storage, Config<T>, Event<T>},
     }
 );
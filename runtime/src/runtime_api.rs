@@ -1,5 +1,5 @@
 use frame_support::inherent::{CheckInherentsResult, InherentData};
-use frame_support::traits::{KeyOwnerProofSystem, OnRuntimeUpgrade, Randomness};
+use frame_support::traits::{Get, KeyOwnerProofSystem, OnRuntimeUpgrade, Randomness};
 use frame_support::unsigned::{TransactionSource, TransactionValidity};
 use pallet_grandpa::fg_primitives;
 use pallet_transaction_payment_rpc_runtime_api::RuntimeDispatchInfo;
@@ -13,14 +13,24 @@ use sp_std::vec::Vec;
 use crate::constants::PRIMARY_PROBABILITY;
 use crate::integration::content_directory::ContentDirectoryWorkingGroup;
 use crate::{
-    content_directory, AccountId, AuthorityDiscoveryId, Balance, BlockNumber, EpochDuration,
-    GrandpaAuthorityList, GrandpaId, Hash, Index, RuntimeVersion, Signature, VERSION,
+    content_directory, data_directory, data_object_storage_registry, working_group, AccountId,
+    ActorId, AuthorityDiscoveryId, Balance, BlockNumber, ContentId, EpochDuration,
+    ForumWorkingGroupInstance, GrandpaAuthorityList, GrandpaId, Hash, Index, MemberId,
+    ProposalsConfigParameters, RuntimeVersion, Signature, VERSION,
 };
 use crate::{
-    AllModules, AuthorityDiscovery, Babe, Call, Grandpa, Historical, InherentDataExt,
-    RandomnessCollectiveFlip, Runtime, SessionKeys, System, TransactionPayment,
+    AllModules, AuthorityDiscovery, Babe, Call, DataDirectory, DataObjectStorageRegistry,
+    DataObjectTypeRegistry, ForumWorkingGroup, Grandpa, Historical, InherentDataExt,
+    ProposalsCodex, ProposalsEngine, RandomnessCollectiveFlip, Runtime, SessionKeys,
+    StorageWorkingGroup, System, TransactionPayment,
 };
 use frame_support::weights::Weight;
+use frame_support::{StorageMap, StorageValue};
+
+use codec::{Decode, Encode};
+use common::working_group::WorkingGroup;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 
 /// The SignedExtension to the basic transaction logic.
 pub type SignedExtra = (
@@ -60,6 +70,13 @@ pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<AccountId, Call, Signa
 pub struct CustomOnRuntimeUpgrade;
 impl OnRuntimeUpgrade for CustomOnRuntimeUpgrade {
     fn on_runtime_upgrade() -> Weight {
+        let db_weight = <Runtime as system::Trait>::DbWeight::get();
+
+        // Each working group initialization reads the block number and the next mint id and writes
+        // the mint, the next mint id, the mint id of the group and three text constraints.
+        let working_group_initialization_weight = db_weight.reads_writes(2, 6);
+        let mut weight = working_group_initialization_weight;
+
         let default_text_constraint = crate::working_group::default_text_constraint();
         let default_content_working_group_mint_capacity = 0;
 
@@ -70,12 +87,69 @@ impl OnRuntimeUpgrade for CustomOnRuntimeUpgrade {
             default_content_working_group_mint_capacity,
         );
 
+        // Forum moderators are hired through the forum working group. The group is initialized
+        // once: its mint exists after the initialization.
+        weight = weight.saturating_add(db_weight.reads(1));
+        if !working_group::Mint::<Runtime, ForumWorkingGroupInstance>::exists() {
+            let default_forum_working_group_mint_capacity = 0;
+
+            ForumWorkingGroup::initialize_working_group(
+                default_text_constraint,
+                default_text_constraint,
+                default_text_constraint,
+                default_forum_working_group_mint_capacity,
+            );
+            weight = weight.saturating_add(working_group_initialization_weight);
+        }
+
+        // Workers are indexed by their role accounts to authorize the forum moderators and
+        // the content curators.
+        weight = weight.saturating_add(ForumWorkingGroup::migrate_worker_ids_by_role_account_id());
+        weight =
+            weight.saturating_add(StorageWorkingGroup::migrate_worker_ids_by_role_account_id());
+        weight = weight.saturating_add(
+            ContentDirectoryWorkingGroup::<Runtime>::migrate_worker_ids_by_role_account_id(),
+        );
+
         // Next Id's are configured at genesis. Applications and tools are harcoded to expect initial
         // values of the ids to start at 1. With a runtime upgrade the initial values will not be
         // configured and get an initial default value of zero. This corrects this problem.
         content_directory::Module::<Runtime>::set_initial_ids_to_one();
+        weight = weight.saturating_add(db_weight.writes(3));
+
+        // Proposal types added after the genesis need their voting and grace periods: the unset
+        // periods would expire the proposals of these types at once.
+        weight = weight.saturating_add(ProposalsCodex::migrate_proposal_periods(
+            ProposalsConfigParameters::default(),
+        ));
+
+        // Stake timers are processed from the block-indexed queue. Existing stakes with
+        // active slashing or unstaking timers should be queued to be finalized.
+        weight =
+            weight.saturating_add(stake::Module::<Runtime>::schedule_stakes_with_active_timers());
+
+        // Reward payouts are processed from the block-indexed queue as well.
+        weight = weight.saturating_add(
+            recurring_rewards::Module::<Runtime>::schedule_active_reward_relationships(),
+        );
+
+        // Opening stage transitions are processed from the block-indexed queues.
+        weight =
+            weight.saturating_add(hiring::Module::<Runtime>::schedule_opening_stage_transitions());
 
-        10_000_000 // TODO: adjust weight
+        // Data object types define the constraints of their data objects.
+        weight = weight.saturating_add(DataObjectTypeRegistry::migrate_data_object_types());
+
+        // Uploads are limited by the storage quotas, counting the existing data objects. Data
+        // objects keep the MIME type and the extension declared on upload.
+        weight = weight.saturating_add(DataDirectory::migrate_storage());
+
+        // Storage relationships are indexed by the storage providers to replace the leaving ones.
+        weight = weight.saturating_add(
+            DataObjectStorageRegistry::migrate_relationships_by_storage_provider_id(),
+        );
+
+        weight
     }
 }
 
@@ -89,6 +163,96 @@ pub type Executive = frame_executive::Executive<
     CustomOnRuntimeUpgrade,
 >;
 
+/// Proposal id type used by the runtime.
+pub type ProposalId = <Runtime as proposals_engine::Trait>::ProposalId;
+
+/// Proposal with its decoded details and remaining periods, returned by the `ProposalsApi`.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub struct ProposalInfo {
+    /// Proposal id
+    pub id: ProposalId,
+
+    /// Proposal data: parameters, status and voting results
+    pub proposal: proposals_engine::ProposalOf<Runtime>,
+
+    /// Proposal details provided on the proposal creation
+    pub details: proposals_codex::ProposalDetailsOf<Runtime>,
+
+    /// Exact execution block of the approved proposal, if provided on the proposal creation
+    pub exact_execution_block: Option<BlockNumber>,
+
+    /// Blocks left until the voting period expiration (active proposals only)
+    pub remaining_voting_blocks: Option<BlockNumber>,
+
+    /// Blocks left until the grace period expiration or the exact execution block
+    /// (pending execution proposals only)
+    pub remaining_grace_blocks: Option<BlockNumber>,
+}
+
+impl ProposalInfo {
+    fn new(id: ProposalId, proposal: proposals_engine::ProposalOf<Runtime>) -> Self {
+        let now = System::block_number();
+        let exact_execution_block = ProposalsEngine::exact_execution_block(id);
+
+        ProposalInfo {
+            id,
+            details: proposals_codex::ProposalDetailsByProposalId::<Runtime>::get(id),
+            remaining_voting_blocks: proposal.remaining_voting_period(now),
+            remaining_grace_blocks: proposal.remaining_grace_period(now, exact_execution_block),
+            exact_execution_block,
+            proposal,
+        }
+    }
+}
+
+/// Working group role history entry, returned by the `WorkingGroupApi`.
+pub type RoleHistoryEntry = working_group::RoleHistoryEntry<ActorId, BlockNumber, Balance, Balance>;
+
+/// Owner of the storage quota, used by the `DataDirectoryApi`.
+pub type StorageObjectOwner = data_directory::StorageObjectOwnerInternal<MemberId, ActorId>;
+
+/// Replication state of the data object, used by the `DataDirectoryApi`.
+pub type ObjectReplication = data_object_storage_registry::ObjectReplication<ContentId>;
+
+sp_api::decl_runtime_apis! {
+    /// Provides proposals data with the decoded details.
+    pub trait ProposalsApi {
+        /// Returns proposals open for voting.
+        fn active_proposals() -> Vec<ProposalInfo>;
+
+        /// Returns approved proposals with the grace period in effect.
+        fn pending_execution_proposals() -> Vec<ProposalInfo>;
+
+        /// Returns the proposal by its id, if exists.
+        fn proposal(proposal_id: ProposalId) -> Option<ProposalInfo>;
+    }
+
+    /// Provides working groups data.
+    pub trait WorkingGroupApi {
+        /// Returns the member role history across all working groups.
+        fn member_role_history(member_id: MemberId) -> Vec<(WorkingGroup, RoleHistoryEntry)>;
+    }
+
+    /// Provides data directory data.
+    pub trait DataDirectoryApi {
+        /// Returns the number of content ids known to the system.
+        fn known_content_ids_count() -> u64;
+
+        /// Returns a page of the content ids known to the system.
+        fn known_content_ids(start: u64, limit: u64) -> Vec<ContentId>;
+
+        /// Returns the storage quota limits of the member or the channel.
+        fn quota_limits(owner: StorageObjectOwner) -> data_directory::QuotaLimits;
+
+        /// Returns the storage quota usage of the member or the channel.
+        fn quota_usage(owner: StorageObjectOwner) -> data_directory::QuotaUsage;
+
+        /// Returns the under-replicated objects from the page of the content ids known to the system.
+        fn under_replicated_objects(start: u64, limit: u64) -> Vec<ObjectReplication>;
+    }
+}
+
 /// Export of the private const generated within the macro.
 pub const EXPORTED_RUNTIME_API_VERSIONS: sp_version::ApisVec = RUNTIME_API_VERSIONS;
 
@@ -236,4 +400,141 @@ impl_runtime_apis! {
             SessionKeys::decode_into_raw_public_keys(&encoded)
         }
     }
+
+    impl self::ProposalsApi<Block> for Runtime {
+        fn active_proposals() -> Vec<ProposalInfo> {
+            ProposalsEngine::get_active_proposals()
+                .into_iter()
+                .map(|(id, proposal)| ProposalInfo::new(id, proposal))
+                .collect()
+        }
+
+        fn pending_execution_proposals() -> Vec<ProposalInfo> {
+            ProposalsEngine::get_pending_execution_proposals()
+                .into_iter()
+                .map(|(id, proposal)| ProposalInfo::new(id, proposal))
+                .collect()
+        }
+
+        fn proposal(proposal_id: ProposalId) -> Option<ProposalInfo> {
+            if proposals_engine::Proposals::<Runtime>::contains_key(proposal_id) {
+                Some(ProposalInfo::new(proposal_id, ProposalsEngine::proposals(proposal_id)))
+            } else {
+                None
+            }
+        }
+    }
+
+    impl self::WorkingGroupApi<Block> for Runtime {
+        fn member_role_history(member_id: MemberId) -> Vec<(WorkingGroup, RoleHistoryEntry)> {
+            let storage_role_history = StorageWorkingGroup::member_role_history(member_id)
+                .into_iter()
+                .map(|entry| (WorkingGroup::Storage, entry));
+
+            let content_role_history =
+                ContentDirectoryWorkingGroup::<Runtime>::member_role_history(member_id)
+                    .into_iter()
+                    .map(|entry| (WorkingGroup::Content, entry));
+
+            let forum_role_history = ForumWorkingGroup::member_role_history(member_id)
+                .into_iter()
+                .map(|entry| (WorkingGroup::Forum, entry));
+
+            storage_role_history
+                .chain(content_role_history)
+                .chain(forum_role_history)
+                .collect()
+        }
+    }
+
+    impl self::DataDirectoryApi<Block> for Runtime {
+        fn known_content_ids_count() -> u64 {
+            DataDirectory::known_content_ids_count()
+        }
+
+        fn known_content_ids(start: u64, limit: u64) -> Vec<ContentId> {
+            DataDirectory::known_content_ids(start, limit)
+        }
+
+        fn quota_limits(owner: StorageObjectOwner) -> data_directory::QuotaLimits {
+            DataDirectory::quota_limits(&owner)
+        }
+
+        fn quota_usage(owner: StorageObjectOwner) -> data_directory::QuotaUsage {
+            DataDirectory::quota_usage_by_owner(&owner)
+        }
+
+        fn under_replicated_objects(start: u64, limit: u64) -> Vec<ObjectReplication> {
+            DataObjectStorageRegistry::under_replicated_objects(start, limit)
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    impl frame_benchmarking::Benchmark<Block> for Runtime {
+        fn dispatch_benchmark(
+            pallet: Vec<u8>,
+            benchmark: Vec<u8>,
+            lowest_range_values: Vec<u32>,
+            highest_range_values: Vec<u32>,
+            steps: Vec<u32>,
+            repeat: u32,
+        ) -> Result<Vec<frame_benchmarking::BenchmarkBatch>, sp_runtime::RuntimeString> {
+            use frame_benchmarking::{add_benchmark, BenchmarkBatch, Benchmarking};
+            use crate::{
+                ContentDirectory, ContentDirectoryWorkingGroup, ContentWorkingGroup, Council,
+                CouncilElection, DataDirectory, DataObjectStorageRegistry, DataObjectTypeRegistry,
+                Discovery, Forum, ForumWorkingGroup, Members, Memo, ProposalsCodex,
+                ProposalsDiscussion, ProposalsEngine, StorageWorkingGroup,
+            };
+
+            let mut batches = Vec::<BenchmarkBatch>::new();
+            let params = (
+                &pallet,
+                &benchmark,
+                &lowest_range_values,
+                &highest_range_values,
+                &steps,
+                repeat,
+            );
+
+            add_benchmark!(params, batches, b"council", Council);
+            add_benchmark!(params, batches, b"election", CouncilElection);
+            add_benchmark!(params, batches, b"membership", Members);
+            add_benchmark!(params, batches, b"memo", Memo);
+            add_benchmark!(params, batches, b"forum", Forum);
+            add_benchmark!(params, batches, b"forum_working_group", ForumWorkingGroup);
+            add_benchmark!(
+                params,
+                batches,
+                b"content_directory_working_group",
+                ContentDirectoryWorkingGroup
+            );
+            add_benchmark!(params, batches, b"content_directory", ContentDirectory);
+            add_benchmark!(params, batches, b"proposals_engine", ProposalsEngine);
+            add_benchmark!(params, batches, b"proposals_discussion", ProposalsDiscussion);
+            add_benchmark!(params, batches, b"proposals_codex", ProposalsCodex);
+            add_benchmark!(params, batches, b"storage_working_group", StorageWorkingGroup);
+            add_benchmark!(params, batches, b"data_directory", DataDirectory);
+            add_benchmark!(
+                params,
+                batches,
+                b"data_object_type_registry",
+                DataObjectTypeRegistry
+            );
+            add_benchmark!(
+                params,
+                batches,
+                b"data_object_storage_registry",
+                DataObjectStorageRegistry
+            );
+            add_benchmark!(params, batches, b"service_discovery", Discovery);
+            add_benchmark!(params, batches, b"content_working_group", ContentWorkingGroup);
+
+            if batches.is_empty() {
+                return Err("Benchmark not found for this pallet.".into());
+            }
+
+            Ok(batches)
+        }
+    }
 }
@@ -5,6 +5,7 @@
 
 mod proposals_integration;
 mod storage_integration;
+mod transaction_fees;
 use sp_runtime::BuildStorage;
 
 pub(crate) fn initial_test_ext() -> sp_io::TestExternalities {
@@ -10,8 +10,8 @@ use governance::election_params::ElectionParameters;
 use membership;
 use proposals_engine::{
     ActiveStake, ApprovedProposalStatus, BalanceOf, FinalizationData, Proposal,
-    ProposalDecisionStatus, ProposalParameters, ProposalStatus, VoteKind, VotersParameters,
-    VotingResults,
+    ProposalDecisionStatus, ProposalParameters, ProposalStatus, RankedBallot, VoteKind,
+    VotersParameters, VotingResults,
 };
 
 use frame_support::dispatch::{DispatchError, DispatchResult};
@@ -123,19 +123,39 @@ impl VoteGenerator {
         assert_eq!(self.vote(vote_kind.clone()), expected_result);
     }
 
-    fn vote(&mut self, vote_kind: VoteKind) -> DispatchResult {
+    fn vote_ranked_and_assert_ok(&mut self, ballot: RankedBallot) {
+        self.next_voter();
+
+        assert_eq!(
+            ProposalsEngine::vote_ranked(
+                system::RawOrigin::Signed(self.current_account_id.clone()).into(),
+                self.current_voter_id,
+                self.proposal_id,
+                ballot,
+                Vec::new(),
+            ),
+            Ok(())
+        );
+    }
+
+    fn next_voter(&mut self) {
         if self.auto_increment_voter_id {
             self.current_account_id_seed += 1;
             self.current_voter_id += 1;
             let account_id: [u8; 32] = [self.current_account_id_seed; 32];
             self.current_account_id = account_id.into();
         }
+    }
+
+    fn vote(&mut self, vote_kind: VoteKind) -> DispatchResult {
+        self.next_voter();
 
         ProposalsEngine::vote(
             system::RawOrigin::Signed(self.current_account_id.clone()).into(),
             self.current_voter_id,
             self.proposal_id,
             vote_kind,
+            Vec::new(),
         )
     }
 }
@@ -219,6 +239,7 @@ impl DummyProposalFixture {
             self.title,
             self.description,
             self.stake_balance,
+            None,
             self.proposal_code,
         );
         assert_eq!(proposal_id_result, result);
@@ -558,6 +579,7 @@ fn text_proposal_execution_succeeds() {
                 b"body".to_vec(),
                 Some(<BalanceOf<Runtime>>::from(25000u32)),
                 b"text".to_vec(),
+                None,
             )
         })
         .with_member_id(member_id as u64);
@@ -566,6 +588,110 @@ fn text_proposal_execution_succeeds() {
     });
 }
 
+#[test]
+fn amend_constitution_proposal_execution_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = 10;
+        let account_id: [u8; 32] = [member_id; 32];
+
+        assert_eq!(ProposalCodex::current_constitution(), None);
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            ProposalCodex::create_amend_constitution_proposal(
+                RawOrigin::Signed(account_id.into()).into(),
+                member_id as u64,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Runtime>>::from(200_000_u32)),
+                b"constitution".to_vec(),
+                None,
+            )
+        })
+        .with_member_id(member_id as u64);
+
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        let constitution = ProposalCodex::current_constitution().unwrap();
+        assert_eq!(ProposalCodex::constitution_version_count(), 1);
+        assert_eq!(constitution.text, b"constitution".to_vec());
+        assert_eq!(
+            constitution.text_hash,
+            sp_io::hashing::blake2_256(b"constitution")
+        );
+    });
+}
+
+#[test]
+fn set_default_storage_quota_limits_proposal_execution_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = 10;
+        let account_id: [u8; 32] = [member_id; 32];
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            ProposalCodex::create_set_default_storage_quota_limits_proposal(
+                RawOrigin::Signed(account_id.into()).into(),
+                member_id as u64,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Runtime>>::from(50_000_u32)),
+                1000,
+                50_000_000,
+                None,
+            )
+        })
+        .with_member_id(member_id as u64);
+
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        assert_eq!(
+            crate::DataDirectory::default_quota_limits(),
+            crate::data_directory::QuotaLimits {
+                objects_limit: 1000,
+                size_limit: 50_000_000,
+            }
+        );
+    });
+}
+
+#[test]
+fn set_proposal_parameters_proposal_execution_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = 10;
+        let account_id: [u8; 32] = [member_id; 32];
+
+        let new_parameters = ProposalParameters {
+            voting_period: 100,
+            grace_period: 0,
+            approval_quorum_percentage: 50,
+            approval_threshold_percentage: 60,
+            slashing_quorum_percentage: 50,
+            slashing_threshold_percentage: 60,
+            required_stake: Some(<BalanceOf<Runtime>>::from(50_000_u32)),
+        };
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            ProposalCodex::create_set_proposal_parameters_proposal(
+                RawOrigin::Signed(account_id.into()).into(),
+                member_id as u64,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Runtime>>::from(200_000_u32)),
+                proposals_codex::ProposalType::Text,
+                new_parameters,
+                None,
+            )
+        })
+        .with_member_id(member_id as u64);
+
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        assert_eq!(
+            ProposalCodex::proposal_parameters_by_type(proposals_codex::ProposalType::Text),
+            Some(new_parameters)
+        );
+    });
+}
+
 #[test]
 fn spending_proposal_execution_succeeds() {
     initial_test_ext().execute_with(|| {
@@ -586,6 +712,7 @@ fn spending_proposal_execution_succeeds() {
                 Some(<BalanceOf<Runtime>>::from(25_000_u32)),
                 new_balance,
                 target_account_id.clone().into(),
+                None,
             )
         })
         .with_member_id(member_id as u64);
@@ -599,6 +726,70 @@ fn spending_proposal_execution_succeeds() {
     });
 }
 
+#[test]
+fn ranked_choice_proposal_execution_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = 10;
+        let account_id: [u8; 32] = [member_id; 32];
+        let spending_balance = <BalanceOf<Runtime>>::from(5555u32);
+
+        let first_target_account_id: AccountId32 = [12; 32].into();
+        let second_target_account_id: AccountId32 = [13; 32].into();
+
+        assert!(
+            Council::set_council_mint_capacity(RawOrigin::Root.into(), spending_balance).is_ok()
+        );
+
+        setup_members(15);
+        setup_council();
+        increase_total_balance_issuance_using_account_id(account_id.into(), 500000);
+
+        let options = vec![
+            proposals_codex::RankedChoiceOption::Spending(
+                spending_balance,
+                first_target_account_id.clone(),
+            ),
+            proposals_codex::RankedChoiceOption::Spending(
+                spending_balance,
+                second_target_account_id.clone(),
+            ),
+        ];
+
+        assert_eq!(
+            ProposalCodex::create_ranked_choice_proposal(
+                RawOrigin::Signed(account_id.into()).into(),
+                member_id as u64,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Runtime>>::from(200_000_u32)),
+                options,
+                None,
+            ),
+            Ok(())
+        );
+
+        let proposal_id = 1;
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_ranked_and_assert_ok(vec![0, 1]);
+        vote_generator.vote_ranked_and_assert_ok(vec![1, 0]);
+        vote_generator.vote_ranked_and_assert_ok(vec![1]);
+        vote_generator.vote_ranked_and_assert_ok(vec![1, 0]);
+        vote_generator.vote_ranked_and_assert_ok(vec![0]);
+
+        run_to_block(2);
+
+        assert_eq!(
+            ProposalsEngine::proposals(proposal_id).status,
+            ProposalStatus::approved(ApprovedProposalStatus::Executed, 0)
+        );
+        assert_eq!(Balances::free_balance(first_target_account_id), 0);
+        assert_eq!(
+            Balances::free_balance(second_target_account_id),
+            spending_balance
+        );
+    });
+}
+
 #[test]
 fn set_election_parameters_proposal_execution_succeeds() {
     initial_test_ext().execute_with(|| {
@@ -625,6 +816,7 @@ fn set_election_parameters_proposal_execution_succeeds() {
                 b"body".to_vec(),
                 Some(<BalanceOf<Runtime>>::from(200_000_u32)),
                 election_parameters,
+                None,
             )
         });
         codex_extrinsic_test_fixture.call_extrinsic_and_assert();
@@ -650,10 +842,108 @@ fn set_validator_count_proposal_execution_succeeds() {
                 b"body".to_vec(),
                 Some(<BalanceOf<Runtime>>::from(100_000_u32)),
                 new_validator_count,
+                None,
+            )
+        });
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        assert_eq!(<pallet_staking::ValidatorCount>::get(), new_validator_count);
+    });
+}
+
+#[test]
+fn batch_proposal_execution_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = 1;
+        let account_id: [u8; 32] = [member_id; 32];
+
+        let new_validator_count = 8;
+        let spending_balance = <BalanceOf<Runtime>>::from(5555u32);
+        let target_account_id: AccountId32 = [12; 32].into();
+
+        assert!(
+            Council::set_council_mint_capacity(RawOrigin::Root.into(), spending_balance).is_ok()
+        );
+        assert_eq!(<pallet_staking::ValidatorCount>::get(), 0);
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            ProposalCodex::create_batch_proposal(
+                RawOrigin::Signed(account_id.clone().into()).into(),
+                member_id as u64,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Runtime>>::from(200_000_u32)),
+                vec![
+                    proposals_codex::BatchProposalItem::SetValidatorCount(new_validator_count),
+                    proposals_codex::BatchProposalItem::Spending(
+                        spending_balance,
+                        target_account_id.clone(),
+                    ),
+                ],
+                None,
             )
         });
         codex_extrinsic_test_fixture.call_extrinsic_and_assert();
 
         assert_eq!(<pallet_staking::ValidatorCount>::get(), new_validator_count);
+        assert_eq!(Balances::free_balance(target_account_id), spending_balance);
+    });
+}
+
+#[test]
+fn batch_proposal_execution_reverts_all_items_on_failure() {
+    initial_test_ext().execute_with(|| {
+        let member_id = 1;
+        let account_id: [u8; 32] = [member_id; 32];
+
+        let council_mint_capacity = <BalanceOf<Runtime>>::from(5555u32);
+        let target_account_id: AccountId32 = [12; 32].into();
+
+        assert!(
+            Council::set_council_mint_capacity(RawOrigin::Root.into(), council_mint_capacity)
+                .is_ok()
+        );
+
+        setup_members(15);
+        setup_council();
+        increase_total_balance_issuance_using_account_id(account_id.into(), 500000);
+
+        // The spending item exceeds the council mint capacity.
+        assert_eq!(
+            ProposalCodex::create_batch_proposal(
+                RawOrigin::Signed(account_id.into()).into(),
+                member_id as u64,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Runtime>>::from(200_000_u32)),
+                vec![
+                    proposals_codex::BatchProposalItem::SetValidatorCount(8),
+                    proposals_codex::BatchProposalItem::Spending(
+                        council_mint_capacity + 1,
+                        target_account_id.clone(),
+                    ),
+                ],
+                None,
+            ),
+            Ok(())
+        );
+
+        let proposal_id = 1;
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        for _ in 0..5 {
+            vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        }
+
+        run_to_block(2);
+
+        assert_eq!(
+            ProposalsEngine::proposals(proposal_id).status,
+            ProposalStatus::approved(
+                ApprovedProposalStatus::failed_execution("NotEnoughCapacity"),
+                0
+            )
+        );
+        assert_eq!(<pallet_staking::ValidatorCount>::get(), 0);
+        assert_eq!(Balances::free_balance(target_account_id), 0);
     });
 }
@@ -8,11 +8,11 @@ use system::RawOrigin;
 use common::working_group::WorkingGroup;
 use hiring::ActivateOpeningAt;
 use proposals_codex::AddOpeningParameters;
-use working_group::{OpeningPolicyCommitment, RewardPolicy};
+use working_group::{OpeningPolicyCommitment, RewardPolicy, SpendingLimit};
 
 use crate::{
     Balance, BlockNumber, ContentDirectoryWorkingGroup, ContentDirectoryWorkingGroupInstance,
-    StorageWorkingGroup, StorageWorkingGroupInstance,
+    ForumWorkingGroup, ForumWorkingGroupInstance, StorageWorkingGroup, StorageWorkingGroupInstance,
 };
 use sp_std::collections::btree_set::BTreeSet;
 
@@ -52,6 +52,14 @@ fn add_opening(
             >>::contains_key(opening_id));
             opening_id
         }
+        WorkingGroup::Forum => {
+            let opening_id = ForumWorkingGroup::next_opening_id();
+            assert!(!<working_group::OpeningById<
+                Runtime,
+                ForumWorkingGroupInstance,
+            >>::contains_key(opening_id));
+            opening_id
+        }
     };
 
     let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
@@ -69,6 +77,7 @@ fn add_opening(
                 human_readable_text: Vec::new(),
                 working_group,
             },
+            None,
         )
     })
     .with_expected_proposal_id(expected_proposal_id)
@@ -98,6 +107,7 @@ fn begin_review_applications(
             Some(<BalanceOf<Runtime>>::from(25_000_u32)),
             opening_id,
             working_group,
+            None,
         )
     })
     .disable_setup_enviroment()
@@ -132,6 +142,7 @@ fn fill_opening(
                 reward_policy: reward_policy.clone(),
                 working_group,
             },
+            None,
         )
     })
     .disable_setup_enviroment()
@@ -170,6 +181,7 @@ fn decrease_stake(
             leader_worker_id,
             stake_amount,
             working_group,
+            None,
         )
     })
     .disable_setup_enviroment()
@@ -200,6 +212,7 @@ fn slash_stake(
             leader_worker_id,
             stake_amount,
             working_group,
+            None,
         )
     })
     .disable_setup_enviroment()
@@ -230,6 +243,7 @@ fn set_reward(
             leader_worker_id,
             reward_amount,
             working_group,
+            None,
         )
     })
     .disable_setup_enviroment()
@@ -271,6 +285,7 @@ fn set_mint_capacity<
             Some(<BalanceOf<Runtime>>::from(50_000_u32)),
             mint_capacity,
             working_group,
+            None,
         )
     })
     .with_setup_enviroment(setup_environment)
@@ -304,6 +319,7 @@ fn terminate_role(
                 slash,
                 working_group,
             },
+            None,
         )
     })
     .disable_setup_enviroment()
@@ -330,6 +346,12 @@ fn create_add_working_group_leader_opening_proposal_execution_succeeds() {
                     StorageWorkingGroupInstance,
                 >(group);
             }
+            WorkingGroup::Forum => {
+                run_create_add_working_group_leader_opening_proposal_execution_succeeds::<
+                    Runtime,
+                    ForumWorkingGroupInstance,
+                >(group);
+            }
         }
     }
 }
@@ -388,6 +410,12 @@ fn create_begin_review_working_group_leader_applications_proposal_execution_succ
                 StorageWorkingGroupInstance,
             >(group);
             }
+            WorkingGroup::Forum => {
+                run_create_begin_review_working_group_leader_applications_proposal_execution_succeeds::<
+                Runtime,
+                ForumWorkingGroupInstance,
+            >(group);
+            }
         }
     }
 }
@@ -468,6 +496,12 @@ fn create_fill_working_group_leader_opening_proposal_execution_succeeds() {
                     StorageWorkingGroupInstance,
                 >(group);
             }
+            WorkingGroup::Forum => {
+                run_create_fill_working_group_leader_opening_proposal_execution_succeeds::<
+                    Runtime,
+                    ForumWorkingGroupInstance,
+                >(group);
+            }
         }
     }
 
@@ -545,6 +579,12 @@ fn create_fill_working_group_leader_opening_proposal_execution_succeeds() {
                         StorageWorkingGroupInstance,
                     >(group);
                 }
+                WorkingGroup::Forum => {
+                    run_create_decrease_group_leader_stake_proposal_execution_succeeds::<
+                        Runtime,
+                        ForumWorkingGroupInstance,
+                    >(group);
+                }
             }
         }
     }
@@ -661,6 +701,12 @@ fn run_create_decrease_group_leader_stake_proposal_execution_succeeds<
                         StorageWorkingGroupInstance,
                     >(group)
                 }
+                WorkingGroup::Forum => {
+                    run_create_slash_group_leader_stake_proposal_execution_succeeds::<
+                        Runtime,
+                        ForumWorkingGroupInstance,
+                    >(group)
+                }
             }
         }
     }
@@ -778,6 +824,12 @@ fn run_create_slash_group_leader_stake_proposal_execution_succeeds<
                         StorageWorkingGroupInstance,
                     >(group);
                 }
+                WorkingGroup::Forum => {
+                    run_create_set_working_group_mint_capacity_proposal_execution_succeeds::<
+                        Runtime,
+                        ForumWorkingGroupInstance,
+                    >(group);
+                }
             }
         }
 
@@ -834,6 +886,12 @@ fn run_create_slash_group_leader_stake_proposal_execution_succeeds<
                             StorageWorkingGroupInstance,
                         >(group);
                     }
+                    WorkingGroup::Forum => {
+                        run_create_set_working_group_mint_capacity_proposal_execution_succeeds::<
+                            Runtime,
+                            ForumWorkingGroupInstance,
+                        >(group);
+                    }
                 }
             }
         }
@@ -957,6 +1015,12 @@ fn run_create_slash_group_leader_stake_proposal_execution_succeeds<
                             StorageWorkingGroupInstance,
                         >(group);
                     }
+                    WorkingGroup::Forum => {
+                        run_create_terminate_group_leader_role_proposal_execution_succeeds::<
+                            Runtime,
+                            ForumWorkingGroupInstance,
+                        >(group);
+                    }
                 }
             }
         }
@@ -1076,6 +1140,9 @@ fn run_create_slash_group_leader_stake_proposal_execution_succeeds<
                     WorkingGroup::Storage => {
                         run_create_terminate_group_leader_role_proposal_with_slashing_execution_succeeds::<Runtime, StorageWorkingGroupInstance>(group);
                     }
+                    WorkingGroup::Forum => {
+                        run_create_terminate_group_leader_role_proposal_with_slashing_execution_succeeds::<Runtime, ForumWorkingGroupInstance>(group);
+                    }
                 }
             }
         }
@@ -1191,3 +1258,66 @@ fn run_create_slash_group_leader_stake_proposal_execution_succeeds<
         }
     }
 }
+
+#[test]
+fn create_set_working_group_spending_limit_proposal_execution_succeeds() {
+    // This uses strum crate for enum iteration
+    for group in WorkingGroup::iter() {
+        match group {
+            WorkingGroup::Content => {
+                run_create_set_working_group_spending_limit_proposal_execution_succeeds::<
+                    ContentDirectoryWorkingGroupInstance,
+                >(group);
+            }
+            WorkingGroup::Storage => {
+                run_create_set_working_group_spending_limit_proposal_execution_succeeds::<
+                    StorageWorkingGroupInstance,
+                >(group);
+            }
+            WorkingGroup::Forum => {
+                run_create_set_working_group_spending_limit_proposal_execution_succeeds::<
+                    ForumWorkingGroupInstance,
+                >(group);
+            }
+        }
+    }
+}
+
+fn run_create_set_working_group_spending_limit_proposal_execution_succeeds<
+    I: working_group::Instance,
+>(
+    working_group: WorkingGroup,
+) where
+    Runtime: working_group::Trait<I>,
+{
+    initial_test_ext().execute_with(|| {
+        let member_id: MemberId = 1;
+        let account_id: [u8; 32] = [member_id as u8; 32];
+
+        let spending_limit = SpendingLimit {
+            amount_per_period: 100,
+            period_length: 10,
+        };
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            ProposalCodex::create_set_working_group_spending_limit_proposal(
+                RawOrigin::Signed(account_id.into()).into(),
+                member_id,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Runtime>>::from(50_000_u32)),
+                Some(spending_limit.clone()),
+                working_group,
+                None,
+            )
+        })
+        .with_member_id(member_id);
+
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        assert_eq!(
+            WorkingGroupInstance::<Runtime, I>::payment_spending_limit(),
+            Some(spending_limit)
+        );
+    });
+}
@@ -0,0 +1,205 @@
+//! Transaction fee tests: weight-to-fee polynomial and block fullness fee multiplier.
+
+use super::initial_test_ext;
+use crate::integration::transactions::{TargetedFeeAdjustment, WeightToFee};
+use crate::{Call, MaximumBlockWeight, Runtime, TargetBlockFullness, TransactionByteFee};
+
+use codec::Encode;
+use frame_support::traits::Get;
+use frame_support::weights::constants::ExtrinsicBaseWeight;
+use frame_support::weights::{GetDispatchInfo, WeightToFeePolynomial};
+use frame_support::StorageValue;
+use pallet_transaction_payment::Multiplier;
+use sp_runtime::traits::Convert;
+use sp_runtime::FixedPointNumber;
+
+type System = system::Module<Runtime>;
+type TransactionPayment = pallet_transaction_payment::Module<Runtime>;
+type FeeAdjustment = TargetedFeeAdjustment<TargetBlockFullness>;
+
+// Fee charged for the call with the current fee multiplier and no tip.
+fn fee_for(call: Call) -> u128 {
+    let len = call.encode().len() as u32;
+    TransactionPayment::compute_fee(len, &call.get_dispatch_info(), 0)
+}
+
+fn memo_call(memo_len: usize) -> Call {
+    Call::Memo(memo::Call::update_memo(vec![0u8; memo_len]))
+}
+
+fn forum_create_thread_call(text_len: usize) -> Call {
+    Call::Forum(forum::Call::create_thread(
+        1,
+        b"title".to_vec(),
+        vec![0u8; text_len],
+    ))
+}
+
+fn forum_add_post_call(text_len: usize) -> Call {
+    Call::Forum(forum::Call::add_post(1, vec![0u8; text_len]))
+}
+
+fn membership_buy_call(about_len: usize) -> Call {
+    Call::Members(membership::Call::buy_membership(
+        0,
+        Some(b"handle".to_vec()),
+        None,
+        Some(vec![0u8; about_len]),
+    ))
+}
+
+fn content_directory_transaction_call(operations_number: usize) -> Call {
+    let operations = (0..operations_number)
+        .map(|_| {
+            content_directory::OperationType::CreateEntity(
+                content_directory::CreateEntityOperation::<Runtime> { class_id: 1 },
+            )
+        })
+        .collect();
+
+    Call::ContentDirectory(content_directory::Call::transaction(
+        content_directory::Actor::Lead,
+        operations,
+    ))
+}
+
+#[test]
+fn weight_to_fee_is_linear_and_non_zero() {
+    let base_fee = WeightToFee::calc(&ExtrinsicBaseWeight::get());
+    assert!(base_fee > 0);
+
+    assert_eq!(WeightToFee::calc(&0), 0);
+
+    // Fees are rounded to the whole units. The base fee is off by less than one unit, so ten
+    // times the weight is charged ten times the base fee within ten units.
+    let multiplied_fee = WeightToFee::calc(&(10 * ExtrinsicBaseWeight::get()));
+    let rounding_error = 10;
+    assert!(multiplied_fee + rounding_error >= 10 * base_fee);
+    assert!(multiplied_fee <= 10 * base_fee + rounding_error);
+    assert!(multiplied_fee > base_fee);
+}
+
+#[test]
+fn representative_calls_are_charged() {
+    initial_test_ext().execute_with(|| {
+        let calls = vec![
+            memo_call(10),
+            forum_create_thread_call(100),
+            forum_add_post_call(100),
+            membership_buy_call(100),
+            content_directory_transaction_call(1),
+        ];
+
+        for call in calls {
+            let len = call.encode().len() as u128;
+            let info = call.get_dispatch_info();
+            let fee = fee_for(call);
+
+            // length fee + base fee + weight fee
+            let expected_fee = len * TransactionByteFee::get()
+                + WeightToFee::calc(&ExtrinsicBaseWeight::get())
+                + WeightToFee::calc(&info.weight);
+
+            assert_eq!(fee, expected_fee);
+            assert!(fee > len * TransactionByteFee::get());
+        }
+    });
+}
+
+#[test]
+fn fees_grow_with_call_complexity() {
+    initial_test_ext().execute_with(|| {
+        // Forum: the longer the post the higher the fee.
+        assert!(fee_for(forum_add_post_call(10_000)) > fee_for(forum_add_post_call(10)));
+        assert!(fee_for(forum_create_thread_call(10_000)) > fee_for(forum_create_thread_call(10)));
+
+        // Membership: the longer the profile the higher the fee.
+        assert!(fee_for(membership_buy_call(10_000)) > fee_for(membership_buy_call(10)));
+
+        // Content directory: every operation in the transaction is charged.
+        let single_operation_fee = fee_for(content_directory_transaction_call(1));
+        let ten_operations_fee = fee_for(content_directory_transaction_call(10));
+        assert!(ten_operations_fee > single_operation_fee);
+
+        // A content directory batch is more expensive than a simple forum post.
+        assert!(ten_operations_fee > fee_for(forum_add_post_call(10)));
+    });
+}
+
+#[test]
+fn fee_multiplier_stays_at_target_block_fullness() {
+    initial_test_ext().execute_with(|| {
+        let target_weight = TargetBlockFullness::get() * MaximumBlockWeight::get();
+        System::set_block_limits(target_weight, 0);
+
+        let multiplier = Multiplier::saturating_from_integer(0);
+        assert_eq!(FeeAdjustment::convert(multiplier), multiplier);
+    });
+}
+
+#[test]
+fn fee_multiplier_increases_for_full_blocks() {
+    initial_test_ext().execute_with(|| {
+        System::set_block_limits(MaximumBlockWeight::get(), 0);
+
+        let mut multiplier = Multiplier::saturating_from_integer(0);
+        for _ in 0..10 {
+            let next_multiplier = FeeAdjustment::convert(multiplier);
+            assert!(next_multiplier > multiplier);
+            multiplier = next_multiplier;
+        }
+    });
+}
+
+#[test]
+fn fee_multiplier_decreases_for_empty_blocks() {
+    initial_test_ext().execute_with(|| {
+        System::set_block_limits(0, 0);
+
+        let mut multiplier = Multiplier::saturating_from_integer(0);
+        for _ in 0..10 {
+            let next_multiplier = FeeAdjustment::convert(multiplier);
+            assert!(next_multiplier < multiplier);
+            multiplier = next_multiplier;
+        }
+    });
+}
+
+#[test]
+fn fees_follow_fee_multiplier() {
+    initial_test_ext().execute_with(|| {
+        let call = content_directory_transaction_call(10);
+        let neutral_fee = fee_for(call.clone());
+
+        // Congested network: weight fee is doubled.
+        pallet_transaction_payment::NextFeeMultiplier::put(Multiplier::saturating_from_integer(1));
+        assert!(fee_for(call.clone()) > neutral_fee);
+
+        // Idle network: weight fee is halved.
+        pallet_transaction_payment::NextFeeMultiplier::put(Multiplier::saturating_from_rational(
+            -1, 2,
+        ));
+        assert!(fee_for(call.clone()) < neutral_fee);
+
+        // Minimal multiplier: only the length fee and the base fee are charged.
+        pallet_transaction_payment::NextFeeMultiplier::put(Multiplier::saturating_from_integer(-1));
+        let len = call.encode().len() as u128;
+        assert_eq!(
+            fee_for(call),
+            len * TransactionByteFee::get() + WeightToFee::calc(&ExtrinsicBaseWeight::get())
+        );
+    });
+}
+
+#[test]
+fn fee_multiplier_is_bounded_from_below() {
+    initial_test_ext().execute_with(|| {
+        System::set_block_limits(0, 0);
+
+        let minimal_multiplier = Multiplier::saturating_from_integer(-1);
+        assert_eq!(
+            FeeAdjustment::convert(minimal_multiplier),
+            minimal_multiplier
+        );
+    });
+}
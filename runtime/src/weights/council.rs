@@ -0,0 +1,42 @@
+//! Weights for the council module.
+
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub struct WeightInfo;
+
+impl governance::council::WeightInfo for WeightInfo {
+    fn set_council(c: u32) -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add((25_000_000 as Weight).saturating_mul(c as Weight))
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+            .saturating_add(DbWeight::get().writes((3 as Weight).saturating_mul(c as Weight)))
+    }
+    fn add_council_member() -> Weight {
+        (60_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+    }
+    fn remove_council_member() -> Weight {
+        (50_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn set_term_ends_at() -> Weight {
+        (15_000_000 as Weight).saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn set_council_mint_capacity() -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn spend_from_council_mint() -> Weight {
+        (60_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn set_council_rewards() -> Weight {
+        (20_000_000 as Weight).saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+}
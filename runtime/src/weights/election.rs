@@ -0,0 +1,51 @@
+//! Weights for the council election module.
+
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub struct WeightInfo;
+
+impl governance::election::WeightInfo for WeightInfo {
+    fn apply() -> Weight {
+        (110_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(10 as Weight))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+    }
+    fn vote() -> Weight {
+        (100_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(9 as Weight))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+    }
+    fn reveal(s: u32) -> Weight {
+        (80_000_000 as Weight)
+            .saturating_add((500 as Weight).saturating_mul(s as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn set_stage_announcing() -> Weight {
+        (15_000_000 as Weight).saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn set_stage_revealing() -> Weight {
+        (15_000_000 as Weight).saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn set_stage_voting() -> Weight {
+        (15_000_000 as Weight).saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn set_election_parameters() -> Weight {
+        (25_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().writes(7 as Weight))
+    }
+    fn force_stop_election() -> Weight {
+        (200_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(10 as Weight))
+            .saturating_add(DbWeight::get().writes(10 as Weight))
+    }
+    fn force_start_election() -> Weight {
+        (70_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(8 as Weight))
+            .saturating_add(DbWeight::get().writes(6 as Weight))
+    }
+    fn set_auto_start() -> Weight {
+        (10_000_000 as Weight).saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+}
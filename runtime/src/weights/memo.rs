@@ -0,0 +1,14 @@
+//! Weights for the memo module.
+
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub struct WeightInfo;
+
+impl memo::WeightInfo for WeightInfo {
+    fn update_memo(m: u32) -> Weight {
+        (25_000_000 as Weight)
+            .saturating_add((1_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+}
@@ -0,0 +1,32 @@
+//! Extrinsic weights for the Joystream runtime modules.
+//!
+//! Each submodule implements the `WeightInfo` trait of the corresponding module. The weights
+//! consist of the base execution time, the per-component execution time and the database
+//! reads and writes priced with `RocksDbWeight`.
+//!
+//! The weights below are not generated by benchmarks: they are estimates derived from the
+//! storage access and complexity of the extrinsics, and should be replaced by the generated
+//! weights. All the modules below have `frame-benchmarking` suites, registered in
+//! `dispatch_benchmark`, to generate their weights on the reference hardware:
+//! `joystream-node benchmark --chain=dev --execution=wasm --wasm-execution=compiled
+//! --pallet=<pallet> --extrinsic=* --steps=50 --repeat=20`.
+//!
+//! The codex `execute_runtime_upgrade_proposal` has no benchmark, as it requires a valid
+//! runtime, and keeps its estimate. The versioned store modules, replaced by the content
+//! directory, keep the flat placeholder weights.
+
+pub mod content_directory;
+pub mod content_working_group;
+pub mod council;
+pub mod data_directory;
+pub mod data_object_storage_registry;
+pub mod data_object_type_registry;
+pub mod election;
+pub mod forum;
+pub mod membership;
+pub mod memo;
+pub mod proposals_codex;
+pub mod proposals_discussion;
+pub mod proposals_engine;
+pub mod service_discovery;
+pub mod working_group;
@@ -0,0 +1,142 @@
+//! Weights for the proposals codex module.
+
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub struct WeightInfo;
+
+impl proposals_codex::WeightInfo for WeightInfo {
+    fn create_text_proposal(t: u32) -> Weight {
+        (250_000_000 as Weight)
+            .saturating_add((2_000 as Weight).saturating_mul(t as Weight))
+            .saturating_add(DbWeight::get().reads(16 as Weight))
+            .saturating_add(DbWeight::get().writes(12 as Weight))
+    }
+    fn create_runtime_upgrade_proposal() -> Weight {
+        (250_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(16 as Weight))
+            .saturating_add(DbWeight::get().writes(12 as Weight))
+    }
+    fn create_set_election_parameters_proposal() -> Weight {
+        (260_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(16 as Weight))
+            .saturating_add(DbWeight::get().writes(12 as Weight))
+    }
+    fn create_spending_proposal() -> Weight {
+        (250_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(16 as Weight))
+            .saturating_add(DbWeight::get().writes(12 as Weight))
+    }
+    fn create_set_validator_count_proposal() -> Weight {
+        (250_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(17 as Weight))
+            .saturating_add(DbWeight::get().writes(12 as Weight))
+    }
+    fn create_add_working_group_leader_opening_proposal() -> Weight {
+        (260_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(16 as Weight))
+            .saturating_add(DbWeight::get().writes(12 as Weight))
+    }
+    fn create_begin_review_working_group_leader_applications_proposal() -> Weight {
+        (250_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(16 as Weight))
+            .saturating_add(DbWeight::get().writes(12 as Weight))
+    }
+    fn create_fill_working_group_leader_opening_proposal() -> Weight {
+        (260_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(16 as Weight))
+            .saturating_add(DbWeight::get().writes(12 as Weight))
+    }
+    fn create_set_working_group_mint_capacity_proposal() -> Weight {
+        (250_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(16 as Weight))
+            .saturating_add(DbWeight::get().writes(12 as Weight))
+    }
+    fn create_decrease_working_group_leader_stake_proposal() -> Weight {
+        (250_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(16 as Weight))
+            .saturating_add(DbWeight::get().writes(12 as Weight))
+    }
+    fn create_slash_working_group_leader_stake_proposal() -> Weight {
+        (250_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(16 as Weight))
+            .saturating_add(DbWeight::get().writes(12 as Weight))
+    }
+    fn create_set_working_group_leader_reward_proposal() -> Weight {
+        (250_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(16 as Weight))
+            .saturating_add(DbWeight::get().writes(12 as Weight))
+    }
+    fn create_terminate_working_group_leader_role_proposal() -> Weight {
+        (250_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(16 as Weight))
+            .saturating_add(DbWeight::get().writes(12 as Weight))
+    }
+    fn create_ranked_choice_proposal(o: u32) -> Weight {
+        (250_000_000 as Weight)
+            .saturating_add((5_000_000 as Weight).saturating_mul(o as Weight))
+            .saturating_add(DbWeight::get().reads(16 as Weight))
+            .saturating_add(DbWeight::get().writes(13 as Weight))
+    }
+    fn create_batch_proposal(i: u32) -> Weight {
+        (250_000_000 as Weight)
+            .saturating_add((3_000_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(16 as Weight))
+            .saturating_add(DbWeight::get().writes(12 as Weight))
+    }
+    fn create_amend_constitution_proposal(c: u32) -> Weight {
+        (250_000_000 as Weight)
+            .saturating_add((2_000 as Weight).saturating_mul(c as Weight))
+            .saturating_add(DbWeight::get().reads(16 as Weight))
+            .saturating_add(DbWeight::get().writes(12 as Weight))
+    }
+    fn create_set_proposal_parameters_proposal() -> Weight {
+        (250_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(17 as Weight))
+            .saturating_add(DbWeight::get().writes(12 as Weight))
+    }
+    fn create_set_default_storage_quota_limits_proposal() -> Weight {
+        (250_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(17 as Weight))
+            .saturating_add(DbWeight::get().writes(12 as Weight))
+    }
+    fn create_set_working_group_spending_limit_proposal() -> Weight {
+        (250_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(17 as Weight))
+            .saturating_add(DbWeight::get().writes(12 as Weight))
+    }
+    fn upload_runtime_upgrade_wasm(w: u32) -> Weight {
+        (100_000_000 as Weight)
+            .saturating_add((2_000 as Weight).saturating_mul(w as Weight))
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn remove_runtime_upgrade_wasm() -> Weight {
+        (100_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn execute_text_proposal(t: u32) -> Weight {
+        (10_000_000 as Weight).saturating_add((500 as Weight).saturating_mul(t as Weight))
+    }
+    // Runtime upgrade wasm code is read from the storage: the max wasm code length is assumed.
+    fn execute_runtime_upgrade_proposal() -> Weight {
+        (15_500_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+    }
+    fn execute_batch_proposal(i: u32) -> Weight {
+        (50_000_000 as Weight)
+            .saturating_add((100_000_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads((5 as Weight).saturating_mul(i as Weight)))
+            .saturating_add(DbWeight::get().writes((5 as Weight).saturating_mul(i as Weight)))
+    }
+    fn execute_amend_constitution_proposal(c: u32) -> Weight {
+        (20_000_000 as Weight)
+            .saturating_add((1_000 as Weight).saturating_mul(c as Weight))
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn execute_set_proposal_parameters_proposal() -> Weight {
+        (20_000_000 as Weight).saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+}
@@ -0,0 +1,31 @@
+//! Weights for the proposals engine module.
+
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub struct WeightInfo;
+
+impl proposals_engine::WeightInfo for WeightInfo {
+    fn vote(r: u32) -> Weight {
+        (80_000_000 as Weight)
+            .saturating_add((1_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(DbWeight::get().reads(9 as Weight))
+            .saturating_add(DbWeight::get().writes(5 as Weight))
+    }
+    fn vote_ranked(o: u32, r: u32) -> Weight {
+        (90_000_000 as Weight)
+            .saturating_add((500_000 as Weight).saturating_mul(o as Weight))
+            .saturating_add((1_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(DbWeight::get().reads(9 as Weight))
+            .saturating_add(DbWeight::get().writes(6 as Weight))
+    }
+    fn cancel_proposal() -> Weight {
+        (120_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(7 as Weight))
+            .saturating_add(DbWeight::get().writes(6 as Weight))
+    }
+    fn veto_proposal() -> Weight {
+        (120_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(6 as Weight))
+    }
+}
@@ -0,0 +1,27 @@
+//! Weights for the service discovery module.
+
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub struct WeightInfo;
+
+impl service_discovery::WeightInfo for WeightInfo {
+    fn set_ipns_id(i: u32) -> Weight {
+        (45_000_000 as Weight)
+            .saturating_add((500 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn unset_ipns_id() -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn set_default_lifetime() -> Weight {
+        (15_000_000 as Weight).saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn set_bootstrap_endpoints(e: u32) -> Weight {
+        (15_000_000 as Weight)
+            .saturating_add((1_000_000 as Weight).saturating_mul(e as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+}
@@ -1,5 +1,6 @@
 import axios, { AxiosRequestConfig } from 'axios'
 import fs from 'fs'
+import path from 'path'
 import ipfsHash from 'ipfs-only-hash'
 import { ContentId, DataObject } from '@joystream/types/media'
 import BN from 'bn.js'
@@ -8,16 +9,22 @@ import { BaseCommand } from './base'
 import { DiscoveryClient } from '@joystream/service-discovery'
 import Debug from 'debug'
 import chalk from 'chalk'
+import mime from 'mime-types'
 import { aliceKeyPair } from './dev'
 const debug = Debug('joystream:storage-cli:upload')
 
 // Defines maximum content length for the assets (files). Limits the upload.
 const MAX_CONTENT_LENGTH = 500 * 1024 * 1024 // 500Mb
 
+// Defines the MIME type of the assets with unknown file extensions.
+const DEFAULT_MIME_TYPE = 'application/octet-stream'
+
 // Defines the necessary parameters for the AddContent runtime tx.
 interface AddContentParams {
   accountId: string
   ipfsCid: string
+  mimeType: string
+  extension: string
   contentId: ContentId
   fileSize: BN
   dataObjectTypeId: number
@@ -98,6 +105,8 @@ export class UploadCommand extends BaseCommand {
     return {
       accountId,
       ipfsCid: await this.computeIpfsHash(),
+      mimeType: mime.lookup(this.mediaSourceFilePath) || DEFAULT_MIME_TYPE,
+      extension: path.extname(this.mediaSourceFilePath).slice(1),
       contentId: ContentId.generate(this.api.api.registry),
       fileSize: new BN(this.getFileSize()),
       dataObjectTypeId,
@@ -114,7 +123,9 @@ export class UploadCommand extends BaseCommand {
         p.contentId,
         p.dataObjectTypeId,
         p.fileSize,
-        p.ipfsCid
+        p.ipfsCid,
+        p.mimeType,
+        p.extension
       )
 
       if (dataObject.isNone) {
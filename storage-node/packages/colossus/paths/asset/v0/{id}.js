@@ -22,6 +22,9 @@ const debug = require('debug')('joystream:colossus:api:asset')
 const filter = require('@joystream/storage-node-backend/filter')
 const ipfsProxy = require('../../../lib/middleware/ipfs_proxy')
 
+// MIME type of the content of the unknown type.
+const DEFAULT_MIME_TYPE = 'application/octet-stream'
+
 function errorHandler(response, err, code) {
   debug(err)
   response.status(err.code || code || 500).send({ message: err.toString() })
@@ -107,6 +110,22 @@ module.exports = function (storage, runtime, ipfsHttpGatewayUrl, anonymous) {
               await runtime.assets.rejectContent(roleAddress, providerId, id)
               return
             }
+
+            // The detected MIME type should match the MIME type declared in the data object.
+            // Content of the unknown type is detected as the default 'application/octet-stream'.
+            const declaredMimeType = dataObject.mime_type.toString().toLowerCase()
+            const detectedMimeType = (info.mimeType || '').toLowerCase()
+            const isDetected = detectedMimeType && detectedMimeType !== DEFAULT_MIME_TYPE
+            if (declaredMimeType && isDetected && declaredMimeType !== detectedMimeType) {
+              debug('Rejecting content. MIME type does not match value in data object')
+              stream.end()
+              res.status(415).send({ message: "Uploaded content doesn't match declared MIME type" })
+
+              // Reject the content
+              await runtime.assets.rejectContent(roleAddress, providerId, id)
+              return
+            }
+
             debug('Content accepted.')
             accepted = true
 
@@ -29,9 +29,17 @@ class AssetsApi {
   /*
    * Create and return a data object.
    */
-  async createDataObject(accountId, memberId, contentId, doTypeId, size, ipfsCid) {
+  async createDataObject(accountId, memberId, contentId, doTypeId, size, ipfsCid, mimeType, extension) {
     contentId = parseContentId(contentId)
-    const tx = this.base.api.tx.dataDirectory.addContent(memberId, contentId, doTypeId, size, ipfsCid)
+    const tx = this.base.api.tx.dataDirectory.addContent(
+      memberId,
+      contentId,
+      doTypeId,
+      size,
+      ipfsCid,
+      mimeType,
+      extension
+    )
     await this.base.signAndSend(accountId, tx)
 
     // If the data object constructed properly, we should now be able to return
@@ -141,10 +149,20 @@ class AssetsApi {
   }
 
   /*
-   * Returns array of know content ids
+   * Returns array of know content ids, queried in pages of at most pageSize ids
    */
-  async getKnownContentIds() {
-    return this.base.api.query.dataDirectory.knownContentIds()
+  async getKnownContentIds(pageSize = 1000) {
+    const count = (await this.base.api.query.dataDirectory.knownContentIdsCount()).toNumber()
+    const contentIds = []
+
+    for (let start = 0; start < count; start += pageSize) {
+      const end = Math.min(start + pageSize, count)
+      const indices = Array.from({ length: end - start }, (_, i) => start + i)
+      const page = await this.base.api.query.dataDirectory.knownContentIdByIndex.multi(indices)
+      contentIds.push(...page)
+    }
+
+    return contentIds
   }
 }
 
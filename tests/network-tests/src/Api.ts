@@ -208,14 +208,36 @@ export class Api {
     return this.estimateTxFee(this.api.tx.councilElection.reveal(hashedVote, nominee, salt))
   }
 
-  public estimateProposeRuntimeUpgradeFee(stake: BN, name: string, description: string, runtime: Bytes | string): BN {
+  public estimateProposeRuntimeUpgradeFee(
+    stake: BN,
+    name: string,
+    description: string,
+    runtime: Bytes | string,
+    specVersion: number
+  ): BN {
     return this.estimateTxFee(
-      this.api.tx.proposalsCodex.createRuntimeUpgradeProposal(stake, name, description, stake, runtime)
+      this.api.tx.proposalsCodex.createRuntimeUpgradeProposal(
+        stake,
+        name,
+        description,
+        stake,
+        {
+          wasm_hash: Utils.hashRuntime(runtime),
+          spec_version: specVersion,
+        },
+        null
+      )
     )
   }
 
+  public estimateUploadRuntimeUpgradeWasmFee(runtime: Bytes | string): BN {
+    return this.estimateTxFee(this.api.tx.proposalsCodex.uploadRuntimeUpgradeWasm(runtime))
+  }
+
   public estimateProposeTextFee(stake: BN, name: string, description: string, text: string): BN {
-    return this.estimateTxFee(this.api.tx.proposalsCodex.createTextProposal(stake, name, description, stake, text))
+    return this.estimateTxFee(
+      this.api.tx.proposalsCodex.createTextProposal(stake, name, description, stake, text, null)
+    )
   }
 
   public estimateProposeSpendingFee(
@@ -226,13 +248,13 @@ export class Api {
     destination: string
   ): BN {
     return this.estimateTxFee(
-      this.api.tx.proposalsCodex.createSpendingProposal(stake, title, description, stake, balance, destination)
+      this.api.tx.proposalsCodex.createSpendingProposal(stake, title, description, stake, balance, destination, null)
     )
   }
 
   public estimateProposeValidatorCountFee(title: string, description: string, stake: BN): BN {
     return this.estimateTxFee(
-      this.api.tx.proposalsCodex.createSetValidatorCountProposal(stake, title, description, stake, stake)
+      this.api.tx.proposalsCodex.createSetValidatorCountProposal(stake, title, description, stake, stake, null)
     )
   }
 
@@ -250,16 +272,23 @@ export class Api {
     minVotingStake: BN
   ): BN {
     return this.estimateTxFee(
-      this.api.tx.proposalsCodex.createSetElectionParametersProposal(stake, title, description, stake, {
-        announcing_period: announcingPeriod,
-        voting_period: votingPeriod,
-        revealing_period: revealingPeriod,
-        council_size: councilSize,
-        candidacy_limit: candidacyLimit,
-        new_term_duration: newTermDuration,
-        min_council_stake: minCouncilStake,
-        min_voting_stake: minVotingStake,
-      })
+      this.api.tx.proposalsCodex.createSetElectionParametersProposal(
+        stake,
+        title,
+        description,
+        stake,
+        {
+          announcing_period: announcingPeriod,
+          voting_period: votingPeriod,
+          revealing_period: revealingPeriod,
+          council_size: councilSize,
+          candidacy_limit: candidacyLimit,
+          new_term_duration: newTermDuration,
+          min_council_stake: minCouncilStake,
+          min_voting_stake: minVotingStake,
+        },
+        null
+      )
     )
   }
 
@@ -268,7 +297,8 @@ export class Api {
       this.api.tx.proposalsEngine.vote(
         this.api.createType('MemberId', 0),
         this.api.createType('ProposalId', 0),
-        'Approve'
+        'Approve',
+        ''
       )
     )
   }
@@ -458,7 +488,8 @@ export class Api {
           commitment: commitment,
           human_readable_text: 'Opening readable text',
           working_group: 'Storage',
-        }
+        },
+        null
       )
     )
   }
@@ -471,7 +502,8 @@ export class Api {
         'Some testing text used for estimation purposes which is longer than text expected during the test',
         null,
         this.api.createType('OpeningId', 0),
-        'Storage'
+        'Storage',
+        null
       )
     )
   }
@@ -494,7 +526,8 @@ export class Api {
         'Some testing text used for estimation purposes which is longer than text expected during the test',
         'Some testing text used for estimation purposes which is longer than text expected during the test',
         null,
-        fillOpeningParameters
+        fillOpeningParameters,
+        null
       )
     )
   }
@@ -511,7 +544,8 @@ export class Api {
           'rationale': 'Exceptionaly long and extraordinary descriptive rationale',
           'slash': true,
           'working_group': 'Storage',
-        }
+        },
+        null
       )
     )
   }
@@ -525,7 +559,8 @@ export class Api {
         null,
         this.api.createType('WorkerId', 0),
         0,
-        'Storage'
+        'Storage',
+        null
       )
     )
   }
@@ -539,7 +574,8 @@ export class Api {
         null,
         this.api.createType('WorkerId', 0),
         0,
-        'Storage'
+        'Storage',
+        null
       )
     )
   }
@@ -553,7 +589,8 @@ export class Api {
         null,
         this.api.createType('WorkerId', 0),
         0,
-        'Storage'
+        'Storage',
+        null
       )
     )
   }
@@ -566,7 +603,8 @@ export class Api {
         'Some testing text used for estimation purposes which is longer than text expected during the test',
         null,
         0,
-        'Storage'
+        'Storage',
+        null
       )
     )
   }
@@ -648,15 +686,30 @@ export class Api {
     stake: BN,
     name: string,
     description: string,
-    runtime: Bytes | string
+    runtime: Bytes | string,
+    specVersion: number
   ): Promise<ISubmittableResult> {
     const memberId: MemberId = (await this.getMemberIds(account))[0]
     return this.sender.signAndSend(
-      this.api.tx.proposalsCodex.createRuntimeUpgradeProposal(memberId, name, description, stake, runtime),
+      this.api.tx.proposalsCodex.createRuntimeUpgradeProposal(
+        memberId,
+        name,
+        description,
+        stake,
+        {
+          wasm_hash: Utils.hashRuntime(runtime),
+          spec_version: specVersion,
+        },
+        null
+      ),
       account
     )
   }
 
+  public async uploadRuntimeUpgradeWasm(account: string, runtime: Bytes | string): Promise<ISubmittableResult> {
+    return this.sender.signAndSend(this.api.tx.proposalsCodex.uploadRuntimeUpgradeWasm(runtime), account)
+  }
+
   public async proposeText(
     account: string,
     stake: BN,
@@ -666,7 +719,7 @@ export class Api {
   ): Promise<ISubmittableResult> {
     const memberId: MemberId = (await this.getMemberIds(account))[0]
     return this.sender.signAndSend(
-      this.api.tx.proposalsCodex.createTextProposal(memberId, name, description, stake, text),
+      this.api.tx.proposalsCodex.createTextProposal(memberId, name, description, stake, text, null),
       account
     )
   }
@@ -681,7 +734,15 @@ export class Api {
   ): Promise<ISubmittableResult> {
     const memberId: MemberId = (await this.getMemberIds(account))[0]
     return this.sender.signAndSend(
-      this.api.tx.proposalsCodex.createSpendingProposal(memberId, title, description, stake, balance, destination),
+      this.api.tx.proposalsCodex.createSpendingProposal(
+        memberId,
+        title,
+        description,
+        stake,
+        balance,
+        destination,
+        null
+      ),
       account
     )
   }
@@ -695,7 +756,14 @@ export class Api {
   ): Promise<ISubmittableResult> {
     const memberId: MemberId = (await this.getMemberIds(account))[0]
     return this.sender.signAndSend(
-      this.api.tx.proposalsCodex.createSetValidatorCountProposal(memberId, title, description, stake, validatorCount),
+      this.api.tx.proposalsCodex.createSetValidatorCountProposal(
+        memberId,
+        title,
+        description,
+        stake,
+        validatorCount,
+        null
+      ),
       account
     )
   }
@@ -716,16 +784,23 @@ export class Api {
   ): Promise<ISubmittableResult> {
     const memberId: MemberId = (await this.getMemberIds(account))[0]
     return this.sender.signAndSend(
-      this.api.tx.proposalsCodex.createSetElectionParametersProposal(memberId, title, description, stake, {
-        announcing_period: announcingPeriod,
-        voting_period: votingPeriod,
-        revealing_period: revealingPeriod,
-        council_size: councilSize,
-        candidacy_limit: candidacyLimit,
-        new_term_duration: newTermDuration,
-        min_council_stake: minCouncilStake,
-        min_voting_stake: minVotingStake,
-      }),
+      this.api.tx.proposalsCodex.createSetElectionParametersProposal(
+        memberId,
+        title,
+        description,
+        stake,
+        {
+          announcing_period: announcingPeriod,
+          voting_period: votingPeriod,
+          revealing_period: revealingPeriod,
+          council_size: councilSize,
+          candidacy_limit: candidacyLimit,
+          new_term_duration: newTermDuration,
+          min_council_stake: minCouncilStake,
+          min_voting_stake: minVotingStake,
+        },
+        null
+      ),
       account
     )
   }
@@ -746,14 +821,15 @@ export class Api {
         description,
         stake,
         openingId,
-        this.api.createType('WorkingGroup', workingGroup)
+        this.api.createType('WorkingGroup', workingGroup),
+        null
       ),
       account
     )
   }
 
   public approveProposal(account: string, memberId: MemberId, proposal: ProposalId): Promise<ISubmittableResult> {
-    return this.sender.signAndSend(this.api.tx.proposalsEngine.vote(memberId, proposal, 'Approve'), account)
+    return this.sender.signAndSend(this.api.tx.proposalsEngine.vote(memberId, proposal, 'Approve', ''), account)
   }
 
   public async batchApproveProposal(proposal: ProposalId): Promise<ISubmittableResult[]> {
@@ -766,6 +842,10 @@ export class Api {
     )
   }
 
+  public getRuntimeUpgradeWasmDepositPerByte(): BN {
+    return this.api.createType('BalanceOf', this.api.consts.proposalsCodex.runtimeUpgradeWasmDepositPerByte)
+  }
+
   public getBlockDuration(): BN {
     return this.api.createType('Moment', this.api.consts.babe.expectedBlockTime)
   }
@@ -1198,7 +1278,8 @@ export class Api {
           commitment: commitment,
           human_readable_text: leaderOpening.text,
           working_group: leaderOpening.workingGroup,
-        }
+        },
+        null
       ),
       leaderOpening.account
     )
@@ -1235,7 +1316,8 @@ export class Api {
         fillOpening.title,
         fillOpening.description,
         fillOpening.proposalStake,
-        fillOpeningParameters
+        fillOpeningParameters,
+        null
       ),
       fillOpening.account
     )
@@ -1263,7 +1345,8 @@ export class Api {
           rationale,
           slash,
           'working_group': workingGroup,
-        }
+        },
+        null
       ),
       account
     )
@@ -1287,7 +1370,8 @@ export class Api {
         proposalStake,
         workerId,
         rewardAmount,
-        this.api.createType('WorkingGroup', workingGroup)
+        this.api.createType('WorkingGroup', workingGroup),
+        null
       ),
       account
     )
@@ -1311,7 +1395,8 @@ export class Api {
         proposalStake,
         workerId,
         rewardAmount,
-        this.api.createType('WorkingGroup', workingGroup)
+        this.api.createType('WorkingGroup', workingGroup),
+        null
       ),
       account
     )
@@ -1335,7 +1420,8 @@ export class Api {
         proposalStake,
         workerId,
         rewardAmount,
-        this.api.createType('WorkingGroup', workingGroup)
+        this.api.createType('WorkingGroup', workingGroup),
+        null
       ),
       account
     )
@@ -1357,7 +1443,8 @@ export class Api {
         description,
         proposalStake,
         mintCapacity,
-        this.api.createType('WorkingGroup', workingGroup)
+        this.api.createType('WorkingGroup', workingGroup),
+        null
       ),
       account
     )
@@ -665,11 +665,13 @@ export class ValidatorCountProposalFixture extends BaseFixture {
 export class UpdateRuntimeFixture extends BaseFixture {
   private proposer: string
   private runtimePath: string
+  private runtimeSpecVersion: number
 
-  constructor(api: Api, proposer: string, runtimePath: string) {
+  constructor(api: Api, proposer: string, runtimePath: string, runtimeSpecVersion: number) {
     super(api)
     this.proposer = proposer
     this.runtimePath = runtimePath
+    this.runtimeSpecVersion = runtimeSpecVersion
   }
 
   public async execute(): Promise<void> {
@@ -683,9 +685,15 @@ export class UpdateRuntimeFixture extends BaseFixture {
       proposalStake,
       description,
       description,
-      runtime
+      runtime,
+      this.runtimeSpecVersion
+    )
+    const runtimeUploadFee: BN = this.api.estimateUploadRuntimeUpgradeWasmFee(runtime)
+    const runtimeUploadDeposit: BN = this.api.getRuntimeUpgradeWasmDepositPerByte().muln((runtime.length - 2) / 2)
+    this.api.treasuryTransferBalance(
+      this.proposer,
+      runtimeProposalFee.add(proposalStake).add(runtimeUploadFee).add(runtimeUploadDeposit)
     )
-    this.api.treasuryTransferBalance(this.proposer, runtimeProposalFee.add(proposalStake))
 
     // Proposal creation
     const result = await this.api.proposeRuntime(
@@ -693,11 +701,16 @@ export class UpdateRuntimeFixture extends BaseFixture {
       proposalStake,
       'testing runtime' + uuid().substring(0, 8),
       'runtime to test proposal functionality' + uuid().substring(0, 8),
-      runtime
+      runtime,
+      this.runtimeSpecVersion
     )
     const proposalNumber: ProposalId = this.api.findProposalCreatedEvent(result.events) as ProposalId
     assert.notEqual(proposalNumber, undefined)
 
+    // Runtime wasm upload
+    const uploadResult = await this.api.uploadRuntimeUpgradeWasm(this.proposer, runtime)
+    this.expectDispatchSuccess(uploadResult, 'Runtime Wasm Upload Expected To Be Successful')
+
     // Approving runtime update proposal
     const approveProposalFixture = new VoteForProposalFixture(this.api, proposalNumber)
     await approveProposalFixture.execute()
@@ -15,6 +15,7 @@ export default async function updateRuntime({ api, env, lock }: FlowProps): Prom
 
   const paidTerms: PaidTermId = api.createPaidTermId(new BN(+env.MEMBERSHIP_PAID_TERMS!))
   const runtimePath: string = env.RUNTIME_WASM_PATH!
+  const runtimeSpecVersion: number = +env.RUNTIME_WASM_SPEC_VERSION!
 
   // Pre-conditions: members and council
   const council = await api.getCouncil()
@@ -22,7 +23,12 @@ export default async function updateRuntime({ api, env, lock }: FlowProps): Prom
 
   const proposer = council[0].member.toString()
 
-  const updateRuntimeFixture: UpdateRuntimeFixture = new UpdateRuntimeFixture(api, proposer, runtimePath)
+  const updateRuntimeFixture: UpdateRuntimeFixture = new UpdateRuntimeFixture(
+    api,
+    proposer,
+    runtimePath,
+    runtimeSpecVersion
+  )
   await new FixtureRunner(updateRuntimeFixture).run()
 
   // Some tests after runtime update
@@ -1,3 +1,4 @@
+import { Bytes } from '@polkadot/types'
 import { IExtrinsic } from '@polkadot/types/types'
 import { compactToU8a, stringToU8a } from '@polkadot/util'
 import { blake2AsHex } from '@polkadot/util-crypto'
@@ -47,6 +48,10 @@ export class Utils {
     return '0x' + fs.readFileSync(path).toString('hex')
   }
 
+  public static hashRuntime(runtime: Bytes | string): string {
+    return blake2AsHex(runtime, 256)
+  }
+
   public static camelToSnakeCase(key: string): string {
     return key.replace(/[A-Z]/g, (letter) => `_${letter.toLowerCase()}`)
   }
@@ -163,6 +163,34 @@ export interface Backers extends Vec<Backer> {}
 /** @name BalanceOfMint */
 export interface BalanceOfMint extends u128 {}
 
+/** @name BatchProposalItem */
+export interface BatchProposalItem extends Enum {
+  readonly isText: boolean;
+  readonly asText: Text;
+  readonly isSetElectionParameters: boolean;
+  readonly asSetElectionParameters: ElectionParameters;
+  readonly isSpending: boolean;
+  readonly asSpending: ITuple<[Balance, AccountId]>;
+  readonly isSetValidatorCount: boolean;
+  readonly asSetValidatorCount: u32;
+  readonly isAddWorkingGroupLeaderOpening: boolean;
+  readonly asAddWorkingGroupLeaderOpening: AddOpeningParameters;
+  readonly isBeginReviewWorkingGroupLeaderApplications: boolean;
+  readonly asBeginReviewWorkingGroupLeaderApplications: ITuple<[OpeningId, WorkingGroup]>;
+  readonly isFillWorkingGroupLeaderOpening: boolean;
+  readonly asFillWorkingGroupLeaderOpening: FillOpeningParameters;
+  readonly isSetWorkingGroupMintCapacity: boolean;
+  readonly asSetWorkingGroupMintCapacity: ITuple<[Balance, WorkingGroup]>;
+  readonly isDecreaseWorkingGroupLeaderStake: boolean;
+  readonly asDecreaseWorkingGroupLeaderStake: ITuple<[WorkerId, Balance, WorkingGroup]>;
+  readonly isSlashWorkingGroupLeaderStake: boolean;
+  readonly asSlashWorkingGroupLeaderStake: ITuple<[WorkerId, Balance, WorkingGroup]>;
+  readonly isSetWorkingGroupLeaderReward: boolean;
+  readonly asSetWorkingGroupLeaderReward: ITuple<[WorkerId, Balance, WorkingGroup]>;
+  readonly isTerminateWorkingGroupLeaderRole: boolean;
+  readonly asTerminateWorkingGroupLeaderRole: TerminateRoleParameters;
+}
+
 /** @name BlockAndTime */
 export interface BlockAndTime extends Struct {
   readonly block: u32;
@@ -273,6 +301,13 @@ export interface ClassPermissionsType extends Null {}
 /** @name ClassPropertyValue */
 export interface ClassPropertyValue extends Null {}
 
+/** @name ConstitutionVersion */
+export interface ConstitutionVersion extends Struct {
+  readonly text: Bytes;
+  readonly text_hash: Hash;
+  readonly amended_at: u32;
+}
+
 /** @name ContentId */
 export interface ContentId extends U8aFixed {}
 
@@ -388,6 +423,8 @@ export interface DataObject extends Struct {
   readonly liaison: StorageProviderId;
   readonly liaison_judgement: LiaisonJudgement;
   readonly ipfs_content_id: Text;
+  readonly mime_type: Text;
+  readonly extension: Text;
 }
 
 /** @name DataObjectsMap */
@@ -407,6 +444,10 @@ export interface DataObjectStorageRelationshipId extends u64 {}
 export interface DataObjectType extends Struct {
   readonly description: Text;
   readonly active: bool;
+  readonly max_size: Option<u64>;
+  readonly allowed_mime_types: Vec<Text>;
+  readonly allowed_extensions: Vec<Text>;
+  readonly replication_factor: u32;
 }
 
 /** @name DataObjectTypeId */
@@ -420,6 +461,13 @@ export interface Deactivated extends Struct {
   readonly started_review_period_at_block: Option<u32>;
 }
 
+/** @name DeputyPermissionsOf */
+export interface DeputyPermissionsOf extends Struct {
+  readonly manage_openings: bool;
+  readonly update_rewards: bool;
+  readonly max_slash_amount: Option<u128>;
+}
+
 /** @name DiscussionPost */
 export interface DiscussionPost extends Struct {
   readonly text: Bytes;
@@ -525,6 +573,13 @@ export interface ExecutionFailed extends Struct {
   readonly error: Text;
 }
 
+/** @name ExitInitiationOrigin */
+export interface ExitInitiationOrigin extends Enum {
+  readonly isLead: boolean;
+  readonly isWorker: boolean;
+  readonly isSudo: boolean;
+}
+
 /** @name ExitedLeadRole */
 export interface ExitedLeadRole extends Struct {
   readonly initiated_at_block_number: u32;
@@ -675,6 +730,12 @@ export interface MintBalanceOf extends u128 {}
 /** @name MintId */
 export interface MintId extends u64 {}
 
+/** @name MissedPayoutPolicy */
+export interface MissedPayoutPolicy extends Enum {
+  readonly isDiscard: boolean;
+  readonly isAccrueDebt: boolean;
+}
+
 /** @name ModerationAction */
 export interface ModerationAction extends Struct {
   readonly moderated_at: BlockAndTime;
@@ -691,6 +752,13 @@ export interface NextAdjustment extends Struct {
 /** @name Nonce */
 export interface Nonce extends u64 {}
 
+/** @name ObjectReplication */
+export interface ObjectReplication extends Struct {
+  readonly content_id: ContentId;
+  readonly ready_replicas: u32;
+  readonly replication_factor: u32;
+}
+
 /** @name Opening */
 export interface Opening extends Struct {
   readonly created: u32;
@@ -801,6 +869,14 @@ export interface ParametrizedPropertyValue extends Enum {
   readonly asInternalEntityVec: Vec<ParameterizedEntity>;
 }
 
+/** @name PaymentRecipientOf */
+export interface PaymentRecipientOf extends Enum {
+  readonly isWorker: boolean;
+  readonly asWorker: WorkerId;
+  readonly isMember: boolean;
+  readonly asMember: MemberId;
+}
+
 /** @name Post */
 export interface Post extends Struct {
   readonly id: PostId;
@@ -931,6 +1007,20 @@ export interface ProposalDetails extends Enum {
   readonly asSetWorkingGroupLeaderReward: ITuple<[WorkerId, Balance, WorkingGroup]>;
   readonly isTerminateWorkingGroupLeaderRole: boolean;
   readonly asTerminateWorkingGroupLeaderRole: TerminateRoleParameters;
+  readonly isRankedChoice: boolean;
+  readonly asRankedChoice: Vec<RankedChoiceOption>;
+  readonly isBatch: boolean;
+  readonly asBatch: Vec<BatchProposalItem>;
+  readonly isRuntimeUpgradeByHash: boolean;
+  readonly asRuntimeUpgradeByHash: RuntimeUpgradeParameters;
+  readonly isAmendConstitution: boolean;
+  readonly asAmendConstitution: Bytes;
+  readonly isSetProposalParameters: boolean;
+  readonly asSetProposalParameters: ITuple<[ProposalType, ProposalParameters]>;
+  readonly isSetDefaultStorageQuotaLimits: boolean;
+  readonly asSetDefaultStorageQuotaLimits: ITuple<[u64, u64]>;
+  readonly isSetWorkingGroupSpendingLimit: boolean;
+  readonly asSetWorkingGroupSpendingLimit: ITuple<[Option<SpendingLimitOf>, WorkingGroup]>;
 }
 
 /** @name ProposalDetailsOf */
@@ -969,6 +1059,20 @@ export interface ProposalDetailsOf extends Enum {
   readonly asSetWorkingGroupLeaderReward: ITuple<[WorkerId, Balance, WorkingGroup]>;
   readonly isTerminateWorkingGroupLeaderRole: boolean;
   readonly asTerminateWorkingGroupLeaderRole: TerminateRoleParameters;
+  readonly isRankedChoice: boolean;
+  readonly asRankedChoice: Vec<RankedChoiceOption>;
+  readonly isBatch: boolean;
+  readonly asBatch: Vec<BatchProposalItem>;
+  readonly isRuntimeUpgradeByHash: boolean;
+  readonly asRuntimeUpgradeByHash: RuntimeUpgradeParameters;
+  readonly isAmendConstitution: boolean;
+  readonly asAmendConstitution: Bytes;
+  readonly isSetProposalParameters: boolean;
+  readonly asSetProposalParameters: ITuple<[ProposalType, ProposalParameters]>;
+  readonly isSetDefaultStorageQuotaLimits: boolean;
+  readonly asSetDefaultStorageQuotaLimits: ITuple<[u64, u64]>;
+  readonly isSetWorkingGroupSpendingLimit: boolean;
+  readonly asSetWorkingGroupSpendingLimit: ITuple<[Option<SpendingLimitOf>, WorkingGroup]>;
 }
 
 /** @name ProposalId */
@@ -1004,6 +1108,53 @@ export interface ProposalStatus extends Enum {
   readonly asFinalized: Finalized;
 }
 
+/** @name ProposalType */
+export interface ProposalType extends Enum {
+  readonly isText: boolean;
+  readonly isRuntimeUpgrade: boolean;
+  readonly isSetElectionParameters: boolean;
+  readonly isSpending: boolean;
+  readonly isSetValidatorCount: boolean;
+  readonly isAddWorkingGroupLeaderOpening: boolean;
+  readonly isBeginReviewWorkingGroupLeaderApplications: boolean;
+  readonly isFillWorkingGroupLeaderOpening: boolean;
+  readonly isSetWorkingGroupMintCapacity: boolean;
+  readonly isDecreaseWorkingGroupLeaderStake: boolean;
+  readonly isSlashWorkingGroupLeaderStake: boolean;
+  readonly isSetWorkingGroupLeaderReward: boolean;
+  readonly isTerminateWorkingGroupLeaderRole: boolean;
+  readonly isRankedChoice: boolean;
+  readonly isBatch: boolean;
+  readonly isAmendConstitution: boolean;
+  readonly isSetProposalParameters: boolean;
+  readonly isSetDefaultStorageQuotaLimits: boolean;
+  readonly isSetWorkingGroupSpendingLimit: boolean;
+}
+
+/** @name QuotaLimits */
+export interface QuotaLimits extends Struct {
+  readonly objects_limit: u64;
+  readonly size_limit: u64;
+}
+
+/** @name QuotaUsage */
+export interface QuotaUsage extends Struct {
+  readonly objects: u64;
+  readonly size: u64;
+}
+
+/** @name RankedChoiceOption */
+export interface RankedChoiceOption extends Enum {
+  readonly isText: boolean;
+  readonly asText: Text;
+  readonly isSetElectionParameters: boolean;
+  readonly asSetElectionParameters: ElectionParameters;
+  readonly isSpending: boolean;
+  readonly asSpending: ITuple<[Balance, AccountId]>;
+  readonly isSetWorkingGroupMintCapacity: boolean;
+  readonly asSetWorkingGroupMintCapacity: ITuple<[Balance, WorkingGroup]>;
+}
+
 /** @name RationaleText */
 export interface RationaleText extends Bytes {}
 
@@ -1061,6 +1212,24 @@ export interface RewardRelationship extends Struct {
 /** @name RewardRelationshipId */
 export interface RewardRelationshipId extends u64 {}
 
+/** @name RoleExit */
+export interface RoleExit extends Struct {
+  readonly exited_at_block: u32;
+  readonly exit_initiation_origin: ExitInitiationOrigin;
+  readonly rationale_text: Bytes;
+}
+
+/** @name RoleHistoryEntryOf */
+export interface RoleHistoryEntryOf extends Struct {
+  readonly worker_id: WorkerId;
+  readonly is_lead: bool;
+  readonly appointed_at_block: u32;
+  readonly slash_count: u32;
+  readonly total_slashed: u128;
+  readonly total_reward_received: u128;
+  readonly exit: Option<RoleExit>;
+}
+
 /** @name RoleParameters */
 export interface RoleParameters extends Struct {
   readonly min_stake: u128;
@@ -1082,6 +1251,12 @@ export interface RoleStakeProfile extends Struct {
   readonly exit_unstaking_period: Option<u32>;
 }
 
+/** @name RuntimeUpgradeParameters */
+export interface RuntimeUpgradeParameters extends Struct {
+  readonly wasm_hash: Hash;
+  readonly spec_version: u32;
+}
+
 /** @name SameController */
 export interface SameController extends bool {}
 
@@ -1148,6 +1323,18 @@ export interface SlashingTerms extends Enum {
   readonly asSlashable: SlashableTerms;
 }
 
+/** @name SpendingLimitOf */
+export interface SpendingLimitOf extends Struct {
+  readonly amount_per_period: u128;
+  readonly period_length: u32;
+}
+
+/** @name SpendingPeriodOf */
+export interface SpendingPeriodOf extends Struct {
+  readonly started_at_block: u32;
+  readonly spent: u128;
+}
+
 /** @name Stake */
 export interface Stake extends Struct {
   readonly created: u32;
@@ -1196,6 +1383,14 @@ export interface StakingStatus extends Enum {
 /** @name Status */
 export interface Status extends bool {}
 
+/** @name StorageObjectOwner */
+export interface StorageObjectOwner extends Enum {
+  readonly isMember: boolean;
+  readonly asMember: MemberId;
+  readonly isChannel: boolean;
+  readonly asChannel: ChannelId;
+}
+
 /** @name StorageProviderId */
 export interface StorageProviderId extends u64 {}
 
@@ -1388,6 +1583,7 @@ export interface WorkerOf extends Struct {
 export interface WorkingGroup extends Enum {
   readonly isStorage: boolean;
   readonly isContent: boolean;
+  readonly isForum: boolean;
 }
 
 /** @name WorkingGroupOpeningPolicyCommitment */
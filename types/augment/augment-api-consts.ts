@@ -54,6 +54,14 @@ declare module '@polkadot/metadata/Decorated/consts/types' {
       windowSize: AugmentedConst<BlockNumber>;
     };
     proposalsCodex: {
+      /**
+       * Exports max allowed constitution text length const.
+       **/
+      constitutionMaxLength: AugmentedConst<u32>;
+      /**
+       * Exports the deposit per byte of the uploaded runtime upgrade wasm code const.
+       **/
+      runtimeUpgradeWasmDepositPerByte: AugmentedConst<BalanceOf>;
       /**
        * Exports max wasm code length of the runtime upgrade proposal const.
        **/
@@ -94,6 +102,10 @@ declare module '@polkadot/metadata/Decorated/consts/types' {
        * Exports const -  max simultaneous active proposals number.
        **/
       maxActiveProposalLimit: AugmentedConst<u32>;
+      /**
+       * Exports const -  max allowed delay between the proposal creation and its exact execution block.
+       **/
+      maxExactExecutionBlockDelay: AugmentedConst<BlockNumber>;
       /**
        * Exports const -  the fee is applied when the proposal gets rejected. A fee would be slashed (burned).
        **/
@@ -3,7 +3,7 @@
 
 import { Compact, Option, Raw, Vec } from '@polkadot/types/codec';
 import { BitVec, Bytes, Data, DoNotConstruct, Null, StorageKey, Text, Type, U256, bool, i128, i16, i256, i32, i64, i8, u128, u16, u256, u32, u64, u8, usize } from '@polkadot/types/primitive';
-import { AcceptingApplications, ActivateOpeningAt, ActiveOpeningStage, ActiveOpeningStageVariant, ActiveStake, Actor, ActorId, AddOpeningParameters, AddSchemaSupportToEntityOperation, AdjustCapacityBy, AdjustOnInterval, Application, ApplicationDeactivationCause, ApplicationId, ApplicationIdSet, ApplicationIdToWorkerIdMap, ApplicationOf, ApplicationRationingPolicy, ApplicationStage, Approved, Backer, Backers, BalanceOfMint, BlockAndTime, Category, CategoryId, Channel, ChannelContentType, ChannelCurationStatus, ChannelId, ChannelPublicationStatus, ChildPositionInParentCategory, Class, ClassId, ClassOf, ClassPermissions, ClassPermissionsType, ClassPropertyValue, ContentId, CreateEntityOperation, Credential, CredentialSet, CurationActor, Curator, CuratorApplication, CuratorApplicationId, CuratorApplicationIdSet, CuratorApplicationIdToCuratorIdMap, CuratorExitInitiationOrigin, CuratorExitSummary, CuratorGroup, CuratorGroupId, CuratorId, CuratorInduction, CuratorOpening, CuratorOpeningId, CuratorRoleStage, CuratorRoleStakeProfile, DataObject, DataObjectStorageRelationship, DataObjectStorageRelationshipId, DataObjectType, DataObjectTypeId, DataObjectsMap, Deactivated, DiscussionPost, DiscussionThread, ElectionParameters, ElectionStage, ElectionStake, Entity, EntityController, EntityCreationVoucher, EntityId, EntityOf, EntityPermissions, EntityReferenceCounterSideEffect, EntryMethod, ExecutionFailed, ExitedLeadRole, FailedAt, FillOpeningParameters, Finalized, HashedTextMaxLength, HiringApplicationId, IPNSIdentity, InactiveApplicationStage, InboundReferenceCounter, InputEntityValuesMap, InputPropertyValue, InputValidationLengthConstraint, InputValue, Lead, LeadId, LeadRoleState, LiaisonJudgement, MemberId, Membership, MemoText, Mint, MintBalanceOf, MintId, ModerationAction, NextAdjustment, Nonce, Opening, OpeningDeactivationCause, OpeningId, OpeningOf, OpeningPolicyCommitment, OpeningStage, OpeningType, Operation, OperationType, OptionalText, PaidMembershipTerms, PaidTermId, ParameterizedEntity, ParametrizedClassPropertyValue, ParametrizedPropertyValue, Post, PostId, PostTextChange, Principal, PrincipalId, Property, PropertyId, PropertyLockingPolicy, PropertyType, PropertyTypeSingle, PropertyTypeVector, ProposalDecisionStatus, ProposalDetails, ProposalDetailsOf, ProposalId, ProposalOf, ProposalParameters, ProposalStatus, RationaleText, Recipient, RecipientId, ReferenceConstraint, ReferenceCounterSideEffects, Reply, ReplyId, ReviewPeriod, RewardPolicy, RewardRelationship, RewardRelationshipId, RoleParameters, RoleStakeProfile, SameController, Schema, SchemaId, SealedVote, Seat, Seats, ServiceProviderRecord, SetLeadParams, SideEffect, SideEffects, Slash, SlashableTerms, SlashingTerms, Stake, StakeId, Staked, StakedStatus, StakingAmountLimitMode, StakingPolicy, StakingStatus, Status, StorageProviderId, StoredPropertyValue, StoredValue, SubscriptionId, TerminateRoleParameters, TextMaxLength, Thread, ThreadCounter, ThreadId, TransferableStake, Unstaking, UnstakingApplicationStage, UpdatePropertyValuesOperation, Url, VecInputValue, VecMaxLength, VecStoredPropertyValue, VecStoredValue, VoteKind, VotingResults, WaitingToBeingOpeningStageVariant, WorkerId, WorkerOf, WorkingGroup, WorkingGroupOpeningPolicyCommitment, WorkingGroupUnstaker } from './all';
+import { AcceptingApplications, ActivateOpeningAt, ActiveOpeningStage, ActiveOpeningStageVariant, ActiveStake, Actor, ActorId, AddOpeningParameters, AddSchemaSupportToEntityOperation, AdjustCapacityBy, AdjustOnInterval, Application, ApplicationDeactivationCause, ApplicationId, ApplicationIdSet, ApplicationIdToWorkerIdMap, ApplicationOf, ApplicationRationingPolicy, ApplicationStage, Approved, Backer, Backers, BalanceOfMint, BatchProposalItem, BlockAndTime, Category, CategoryId, Channel, ChannelContentType, ChannelCurationStatus, ChannelId, ChannelPublicationStatus, ChildPositionInParentCategory, Class, ClassId, ClassOf, ClassPermissions, ClassPermissionsType, ClassPropertyValue, ConstitutionVersion, ContentId, CreateEntityOperation, Credential, CredentialSet, CurationActor, Curator, CuratorApplication, CuratorApplicationId, CuratorApplicationIdSet, CuratorApplicationIdToCuratorIdMap, CuratorExitInitiationOrigin, CuratorExitSummary, CuratorGroup, CuratorGroupId, CuratorId, CuratorInduction, CuratorOpening, CuratorOpeningId, CuratorRoleStage, CuratorRoleStakeProfile, DataObject, DataObjectStorageRelationship, DataObjectStorageRelationshipId, DataObjectType, DataObjectTypeId, DataObjectsMap, Deactivated, DeputyPermissionsOf, DiscussionPost, DiscussionThread, ElectionParameters, ElectionStage, ElectionStake, Entity, EntityController, EntityCreationVoucher, EntityId, EntityOf, EntityPermissions, EntityReferenceCounterSideEffect, EntryMethod, ExecutionFailed, ExitInitiationOrigin, ExitedLeadRole, FailedAt, FillOpeningParameters, Finalized, HashedTextMaxLength, HiringApplicationId, IPNSIdentity, InactiveApplicationStage, InboundReferenceCounter, InputEntityValuesMap, InputPropertyValue, InputValidationLengthConstraint, InputValue, Lead, LeadId, LeadRoleState, LiaisonJudgement, MemberId, Membership, MemoText, Mint, MintBalanceOf, MintId, MissedPayoutPolicy, ModerationAction, NextAdjustment, Nonce, ObjectReplication, Opening, OpeningDeactivationCause, OpeningId, OpeningOf, OpeningPolicyCommitment, OpeningStage, OpeningType, Operation, OperationType, OptionalText, PaidMembershipTerms, PaidTermId, ParameterizedEntity, ParametrizedClassPropertyValue, ParametrizedPropertyValue, PaymentRecipientOf, Post, PostId, PostTextChange, Principal, PrincipalId, Property, PropertyId, PropertyLockingPolicy, PropertyType, PropertyTypeSingle, PropertyTypeVector, ProposalDecisionStatus, ProposalDetails, ProposalDetailsOf, ProposalId, ProposalOf, ProposalParameters, ProposalStatus, ProposalType, QuotaLimits, QuotaUsage, RankedChoiceOption, RationaleText, Recipient, RecipientId, ReferenceConstraint, ReferenceCounterSideEffects, Reply, ReplyId, ReviewPeriod, RewardPolicy, RewardRelationship, RewardRelationshipId, RoleExit, RoleHistoryEntryOf, RoleParameters, RoleStakeProfile, RuntimeUpgradeParameters, SameController, Schema, SchemaId, SealedVote, Seat, Seats, ServiceProviderRecord, SetLeadParams, SideEffect, SideEffects, Slash, SlashableTerms, SlashingTerms, SpendingLimitOf, SpendingPeriodOf, Stake, StakeId, Staked, StakedStatus, StakingAmountLimitMode, StakingPolicy, StakingStatus, Status, StorageObjectOwner, StorageProviderId, StoredPropertyValue, StoredValue, SubscriptionId, TerminateRoleParameters, TextMaxLength, Thread, ThreadCounter, ThreadId, TransferableStake, Unstaking, UnstakingApplicationStage, UpdatePropertyValuesOperation, Url, VecInputValue, VecMaxLength, VecStoredPropertyValue, VecStoredValue, VoteKind, VotingResults, WaitingToBeingOpeningStageVariant, WorkerId, WorkerOf, WorkingGroup, WorkingGroupOpeningPolicyCommitment, WorkingGroupUnstaker } from './all';
 import { BlockAttestations, IncludedBlocks, MoreAttestations } from '@polkadot/types/interfaces/attestations';
 import { RawAuraPreDigest } from '@polkadot/types/interfaces/aura';
 import { ExtrinsicOrHash, ExtrinsicStatus } from '@polkadot/types/interfaces/author';
@@ -2030,6 +2030,9 @@ declare module '@polkadot/types/types/registry' {
     RewardRelationship: RewardRelationship;
     'Option<RewardRelationship>': Option<RewardRelationship>;
     'Vec<RewardRelationship>': Vec<RewardRelationship>;
+    MissedPayoutPolicy: MissedPayoutPolicy;
+    'Option<MissedPayoutPolicy>': Option<MissedPayoutPolicy>;
+    'Vec<MissedPayoutPolicy>': Vec<MissedPayoutPolicy>;
     ApplicationId: ApplicationId;
     'Compact<ApplicationId>': Compact<ApplicationId>;
     'Option<ApplicationId>': Option<ApplicationId>;
@@ -2224,6 +2227,27 @@ declare module '@polkadot/types/types/registry' {
     RoleStakeProfile: RoleStakeProfile;
     'Option<RoleStakeProfile>': Option<RoleStakeProfile>;
     'Vec<RoleStakeProfile>': Vec<RoleStakeProfile>;
+    ExitInitiationOrigin: ExitInitiationOrigin;
+    'Option<ExitInitiationOrigin>': Option<ExitInitiationOrigin>;
+    'Vec<ExitInitiationOrigin>': Vec<ExitInitiationOrigin>;
+    RoleExit: RoleExit;
+    'Option<RoleExit>': Option<RoleExit>;
+    'Vec<RoleExit>': Vec<RoleExit>;
+    RoleHistoryEntryOf: RoleHistoryEntryOf;
+    'Option<RoleHistoryEntryOf>': Option<RoleHistoryEntryOf>;
+    'Vec<RoleHistoryEntryOf>': Vec<RoleHistoryEntryOf>;
+    PaymentRecipientOf: PaymentRecipientOf;
+    'Option<PaymentRecipientOf>': Option<PaymentRecipientOf>;
+    'Vec<PaymentRecipientOf>': Vec<PaymentRecipientOf>;
+    SpendingLimitOf: SpendingLimitOf;
+    'Option<SpendingLimitOf>': Option<SpendingLimitOf>;
+    'Vec<SpendingLimitOf>': Vec<SpendingLimitOf>;
+    SpendingPeriodOf: SpendingPeriodOf;
+    'Option<SpendingPeriodOf>': Option<SpendingPeriodOf>;
+    'Vec<SpendingPeriodOf>': Vec<SpendingPeriodOf>;
+    DeputyPermissionsOf: DeputyPermissionsOf;
+    'Option<DeputyPermissionsOf>': Option<DeputyPermissionsOf>;
+    'Vec<DeputyPermissionsOf>': Vec<DeputyPermissionsOf>;
     Url: Url;
     'Option<Url>': Option<Url>;
     'Vec<Url>': Vec<Url>;
@@ -2259,6 +2283,18 @@ declare module '@polkadot/types/types/registry' {
     DataObjectsMap: DataObjectsMap;
     'Option<DataObjectsMap>': Option<DataObjectsMap>;
     'Vec<DataObjectsMap>': Vec<DataObjectsMap>;
+    StorageObjectOwner: StorageObjectOwner;
+    'Option<StorageObjectOwner>': Option<StorageObjectOwner>;
+    'Vec<StorageObjectOwner>': Vec<StorageObjectOwner>;
+    QuotaLimits: QuotaLimits;
+    'Option<QuotaLimits>': Option<QuotaLimits>;
+    'Vec<QuotaLimits>': Vec<QuotaLimits>;
+    QuotaUsage: QuotaUsage;
+    'Option<QuotaUsage>': Option<QuotaUsage>;
+    'Vec<QuotaUsage>': Vec<QuotaUsage>;
+    ObjectReplication: ObjectReplication;
+    'Option<ObjectReplication>': Option<ObjectReplication>;
+    'Vec<ObjectReplication>': Vec<ObjectReplication>;
     ProposalId: ProposalId;
     'Compact<ProposalId>': Compact<ProposalId>;
     'Option<ProposalId>': Option<ProposalId>;
@@ -2302,6 +2338,21 @@ declare module '@polkadot/types/types/registry' {
     TerminateRoleParameters: TerminateRoleParameters;
     'Option<TerminateRoleParameters>': Option<TerminateRoleParameters>;
     'Vec<TerminateRoleParameters>': Vec<TerminateRoleParameters>;
+    RuntimeUpgradeParameters: RuntimeUpgradeParameters;
+    'Option<RuntimeUpgradeParameters>': Option<RuntimeUpgradeParameters>;
+    'Vec<RuntimeUpgradeParameters>': Vec<RuntimeUpgradeParameters>;
+    ProposalType: ProposalType;
+    'Option<ProposalType>': Option<ProposalType>;
+    'Vec<ProposalType>': Vec<ProposalType>;
+    ConstitutionVersion: ConstitutionVersion;
+    'Option<ConstitutionVersion>': Option<ConstitutionVersion>;
+    'Vec<ConstitutionVersion>': Vec<ConstitutionVersion>;
+    RankedChoiceOption: RankedChoiceOption;
+    'Option<RankedChoiceOption>': Option<RankedChoiceOption>;
+    'Vec<RankedChoiceOption>': Vec<RankedChoiceOption>;
+    BatchProposalItem: BatchProposalItem;
+    'Option<BatchProposalItem>': Option<BatchProposalItem>;
+    'Vec<BatchProposalItem>': Vec<BatchProposalItem>;
     ActiveStake: ActiveStake;
     'Option<ActiveStake>': Option<ActiveStake>;
     'Vec<ActiveStake>': Vec<ActiveStake>;
@@ -1,7 +1,8 @@
-import { Option, Vec as Vector, BTreeMap, u64, bool, Text, Null } from '@polkadot/types'
+import { Option, Vec as Vector, BTreeMap, u32, u64, bool, Text, Null } from '@polkadot/types'
 import { BlockAndTime, JoyEnum, JoyStructDecorated, Hash } from './common'
 import { MemberId } from './members'
 import { StorageProviderId } from './working-group' // this should be in discovery really
+import ChannelId from './content-working-group/ChannelId'
 import { randomAsU8a } from '@polkadot/util-crypto'
 import { encodeAddress, decodeAddress } from '@polkadot/keyring'
 import { RegistryTypes, Registry } from '@polkadot/types/types'
@@ -48,6 +49,8 @@ export class DataObject extends JoyStructDecorated({
   liaison: StorageProviderId,
   liaison_judgement: LiaisonJudgement,
   ipfs_content_id: Text,
+  mime_type: Text,
+  extension: Text,
 }) {
   /** Actually it's 'size', but 'size' is already reserved by a parent class. */
   get size_in_bytes(): u64 {
@@ -64,10 +67,42 @@ export class DataObjectStorageRelationship extends JoyStructDecorated({
 export class DataObjectType extends JoyStructDecorated({
   description: Text,
   active: bool,
+  max_size: Option.with(u64),
+  allowed_mime_types: Vector.with(Text),
+  allowed_extensions: Vector.with(Text),
+  replication_factor: u32,
 }) {}
 
 export class DataObjectsMap extends BTreeMap.with(ContentId, DataObject) {}
 
+export const StorageObjectOwnerDef = {
+  Member: MemberId,
+  Channel: ChannelId,
+} as const
+export type StorageObjectOwnerKey = keyof typeof StorageObjectOwnerDef
+export class StorageObjectOwner extends JoyEnum(StorageObjectOwnerDef) {}
+
+export class QuotaLimits extends JoyStructDecorated({
+  objects_limit: u64,
+  size_limit: u64,
+}) {}
+
+export class QuotaUsage extends JoyStructDecorated({
+  objects: u64,
+  size: u64,
+}) {
+  /** Actually it's 'size', but 'size' is already reserved by a parent class. */
+  get size_in_bytes(): u64 {
+    return this.get('size') as u64
+  }
+}
+
+export class ObjectReplication extends JoyStructDecorated({
+  content_id: ContentId,
+  ready_replicas: u32,
+  replication_factor: u32,
+}) {}
+
 export const mediaTypes: RegistryTypes = {
   ContentId,
   LiaisonJudgement,
@@ -77,6 +112,10 @@ export const mediaTypes: RegistryTypes = {
   DataObjectTypeId,
   DataObjectType,
   DataObjectsMap,
+  StorageObjectOwner,
+  QuotaLimits,
+  QuotaUsage,
+  ObjectReplication,
 }
 
 export default mediaTypes
@@ -1,14 +1,14 @@
-import { Text, u32, Tuple, u8, Vec, Option, Null, Bytes } from '@polkadot/types'
+import { Text, u32, u64, Tuple, u8, Vec, Option, Null, Bytes } from '@polkadot/types'
 import { bool, u128 } from '@polkadot/types/primitive'
 import { BlockNumber, Balance } from '@polkadot/types/interfaces'
 import AccountId from '@polkadot/types/generic/AccountId'
-import { ThreadId, WorkingGroup, JoyEnum, JoyStructDecorated } from './common'
+import { ThreadId, WorkingGroup, JoyEnum, JoyStructDecorated, Hash } from './common'
 import { MemberId } from './members'
 import { RoleParameters } from './roles'
 import { StakeId } from './stake'
 import { ElectionParameters } from './council'
 import { ActivateOpeningAt, OpeningId, ApplicationId } from './hiring'
-import { WorkingGroupOpeningPolicyCommitment, WorkerId, RewardPolicy } from './working-group'
+import { WorkingGroupOpeningPolicyCommitment, WorkerId, RewardPolicy, SpendingLimit } from './working-group'
 
 export type IVotingResults = {
   abstensions: u32
@@ -249,6 +249,78 @@ export class TerminateRoleParameters
   })
   implements ITerminateRoleParameters {}
 
+export type IRuntimeUpgradeParameters = {
+  wasm_hash: Hash
+  spec_version: u32
+}
+
+export class RuntimeUpgradeParameters
+  extends JoyStructDecorated({
+    wasm_hash: Hash,
+    spec_version: u32,
+  })
+  implements IRuntimeUpgradeParameters {}
+
+export const ProposalTypeDef = {
+  Text: Null,
+  RuntimeUpgrade: Null,
+  SetElectionParameters: Null,
+  Spending: Null,
+  SetValidatorCount: Null,
+  AddWorkingGroupLeaderOpening: Null,
+  BeginReviewWorkingGroupLeaderApplications: Null,
+  FillWorkingGroupLeaderOpening: Null,
+  SetWorkingGroupMintCapacity: Null,
+  DecreaseWorkingGroupLeaderStake: Null,
+  SlashWorkingGroupLeaderStake: Null,
+  SetWorkingGroupLeaderReward: Null,
+  TerminateWorkingGroupLeaderRole: Null,
+  RankedChoice: Null,
+  Batch: Null,
+  AmendConstitution: Null,
+  SetProposalParameters: Null,
+  SetDefaultStorageQuotaLimits: Null,
+  SetWorkingGroupSpendingLimit: Null,
+} as const
+export type ProposalTypeKey = keyof typeof ProposalTypeDef
+export class ProposalType extends JoyEnum(ProposalTypeDef) {}
+
+export type IConstitutionVersion = {
+  text: Bytes
+  text_hash: Hash
+  amended_at: BlockNumber
+}
+
+export class ConstitutionVersion
+  extends JoyStructDecorated({
+    text: Bytes,
+    text_hash: Hash,
+    amended_at: BlockNumber,
+  })
+  implements IConstitutionVersion {}
+
+export class RankedChoiceOption extends JoyEnum({
+  Text: Text,
+  SetElectionParameters: ElectionParameters,
+  Spending: SpendingParams,
+  SetWorkingGroupMintCapacity: Tuple.with(['Balance', WorkingGroup]),
+} as const) {}
+
+export class BatchProposalItem extends JoyEnum({
+  Text: Text,
+  SetElectionParameters: ElectionParameters,
+  Spending: SpendingParams,
+  SetValidatorCount: u32,
+  AddWorkingGroupLeaderOpening: AddOpeningParameters,
+  BeginReviewWorkingGroupLeaderApplications: Tuple.with([OpeningId, WorkingGroup]),
+  FillWorkingGroupLeaderOpening: FillOpeningParameters,
+  SetWorkingGroupMintCapacity: Tuple.with(['Balance', WorkingGroup]),
+  DecreaseWorkingGroupLeaderStake: Tuple.with([WorkerId, 'Balance', WorkingGroup]),
+  SlashWorkingGroupLeaderStake: Tuple.with([WorkerId, 'Balance', WorkingGroup]),
+  SetWorkingGroupLeaderReward: Tuple.with([WorkerId, 'Balance', WorkingGroup]),
+  TerminateWorkingGroupLeaderRole: TerminateRoleParameters,
+} as const) {}
+
 export class ProposalDetails extends JoyEnum({
   Text: Text,
   RuntimeUpgrade: Bytes,
@@ -267,6 +339,13 @@ export class ProposalDetails extends JoyEnum({
   SlashWorkingGroupLeaderStake: Tuple.with([WorkerId, 'Balance', WorkingGroup]),
   SetWorkingGroupLeaderReward: Tuple.with([WorkerId, 'Balance', WorkingGroup]),
   TerminateWorkingGroupLeaderRole: TerminateRoleParameters,
+  RankedChoice: Vec.with(RankedChoiceOption),
+  Batch: Vec.with(BatchProposalItem),
+  RuntimeUpgradeByHash: RuntimeUpgradeParameters,
+  AmendConstitution: Bytes,
+  SetProposalParameters: Tuple.with([ProposalType, ProposalParameters]),
+  SetDefaultStorageQuotaLimits: Tuple.with([u64, u64]),
+  SetWorkingGroupSpendingLimit: Tuple.with([Option.with(SpendingLimit), WorkingGroup]),
 } as const) {}
 
 // export default proposalTypes;
@@ -285,6 +364,11 @@ export const proposalsTypes = {
   AddOpeningParameters,
   FillOpeningParameters,
   TerminateRoleParameters,
+  RuntimeUpgradeParameters,
+  ConstitutionVersion,
+  ProposalType,
+  RankedChoiceOption,
+  BatchProposalItem,
   // Expose in registry for api.createType purposes:
   ActiveStake,
   Finalized,
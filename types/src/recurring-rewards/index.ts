@@ -1,7 +1,7 @@
-import { u64, u128, Option, u32 } from '@polkadot/types'
+import { u64, u128, Option, u32, Null } from '@polkadot/types'
 import { Balance, BlockNumber } from '@polkadot/types/interfaces'
 import { MintId } from '../mint'
-import { JoyStructDecorated } from '../common'
+import { JoyStructDecorated, JoyEnum } from '../common'
 import AccountId from '@polkadot/types/generic/AccountId'
 import { RegistryTypes } from '@polkadot/types/types'
 
@@ -42,10 +42,18 @@ export class RewardRelationship
   })
   implements IRewardRelationship {}
 
+export const MissedPayoutPolicyDef = {
+  Discard: Null,
+  AccrueDebt: Null,
+} as const
+export type MissedPayoutPolicyKey = keyof typeof MissedPayoutPolicyDef
+export class MissedPayoutPolicy extends JoyEnum(MissedPayoutPolicyDef) {}
+
 export const recurringRewardsTypes: RegistryTypes = {
   RecipientId,
   RewardRelationshipId,
   Recipient,
   RewardRelationship,
+  MissedPayoutPolicy,
 }
 export default recurringRewardsTypes
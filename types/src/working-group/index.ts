@@ -1,5 +1,5 @@
 import { Bytes, BTreeMap, BTreeSet, Option } from '@polkadot/types'
-import { Null, u32, u128 } from '@polkadot/types/primitive'
+import { Null, bool, u32, u128 } from '@polkadot/types/primitive'
 import AccountId from '@polkadot/types/generic/AccountId'
 import { BlockNumber, Balance } from '@polkadot/types/interfaces'
 import { MemberId, ActorId } from '../members'
@@ -73,6 +73,48 @@ export class Worker
   }
 }
 
+export class ExitInitiationOrigin extends JoyEnum({
+  Lead: Null,
+  Worker: Null,
+  Sudo: Null,
+} as const) {}
+
+export type IRoleExit = {
+  exited_at_block: BlockNumber
+  exit_initiation_origin: ExitInitiationOrigin
+  rationale_text: Bytes
+}
+
+export class RoleExit
+  extends JoyStructDecorated({
+    exited_at_block: u32, // BlockNumber
+    exit_initiation_origin: ExitInitiationOrigin,
+    rationale_text: Bytes,
+  })
+  implements IRoleExit {}
+
+export type IRoleHistoryEntry = {
+  worker_id: WorkerId
+  is_lead: bool
+  appointed_at_block: BlockNumber
+  slash_count: u32
+  total_slashed: Balance
+  total_reward_received: Balance
+  exit: Option<RoleExit>
+}
+
+export class RoleHistoryEntry
+  extends JoyStructDecorated({
+    worker_id: WorkerId,
+    is_lead: bool,
+    appointed_at_block: u32, // BlockNumber
+    slash_count: u32,
+    total_slashed: u128, // Balance
+    total_reward_received: u128, // Balance
+    exit: Option.with(RoleExit),
+  })
+  implements IRoleHistoryEntry {}
+
 export type IWorkingGroupOpeningPolicyCommitment = {
   application_rationing_policy: Option<ApplicationRationingPolicy>
   max_review_period_length: BlockNumber
@@ -159,6 +201,49 @@ export class RewardPolicy
   })
   implements IRewardPolicy {}
 
+export class PaymentRecipient extends JoyEnum({
+  Worker: WorkerId,
+  Member: MemberId,
+} as const) {}
+
+export type ISpendingLimit = {
+  amount_per_period: Balance
+  period_length: BlockNumber
+}
+
+export class SpendingLimit
+  extends JoyStructDecorated({
+    amount_per_period: u128, // Balance
+    period_length: u32, // BlockNumber
+  })
+  implements ISpendingLimit {}
+
+export type ISpendingPeriod = {
+  started_at_block: BlockNumber
+  spent: Balance
+}
+
+export class SpendingPeriod
+  extends JoyStructDecorated({
+    started_at_block: u32, // BlockNumber
+    spent: u128, // Balance
+  })
+  implements ISpendingPeriod {}
+
+export type IDeputyPermissions = {
+  manage_openings: bool
+  update_rewards: bool
+  max_slash_amount: Option<Balance>
+}
+
+export class DeputyPermissions
+  extends JoyStructDecorated({
+    manage_openings: bool,
+    update_rewards: bool,
+    max_slash_amount: Option.with(u128), // Option<Balance>
+  })
+  implements IDeputyPermissions {}
+
 // Needed for types augment tool
 export { OpeningId, ApplicationId }
 
@@ -178,6 +263,13 @@ export const workingGroupTypes: RegistryTypes = {
   // Expose in registry for api.createType purposes:
   WorkingGroupOpeningPolicyCommitment,
   RoleStakeProfile,
+  ExitInitiationOrigin,
+  RoleExit,
+  RoleHistoryEntryOf: RoleHistoryEntry,
+  PaymentRecipientOf: PaymentRecipient,
+  SpendingLimitOf: SpendingLimit,
+  SpendingPeriodOf: SpendingPeriod,
+  DeputyPermissionsOf: DeputyPermissions,
 }
 
 export default workingGroupTypes
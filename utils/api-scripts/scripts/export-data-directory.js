@@ -6,8 +6,22 @@
 // or copy and paste the code into the pioneer javascript toolbox at:
 // https://testnet.joystream.org/#/js
 
+// Queries the known content ids in pages of at most pageSize ids
+const getKnownContentIds = async (api, pageSize = 1000) => {
+  const count = (await api.query.dataDirectory.knownContentIdsCount()).toNumber()
+  const ids = []
+
+  for (let start = 0; start < count; start += pageSize) {
+    const end = Math.min(start + pageSize, count)
+    const indices = Array.from({ length: end - start }, (_, i) => start + i)
+    ids.push(...(await api.query.dataDirectory.knownContentIdByIndex.multi(indices)))
+  }
+
+  return ids
+}
+
 const script = async ({ api }) => {
-  const ids = await api.query.dataDirectory.knownContentIds()
+  const ids = await getKnownContentIds(api)
 
   // When a BTreeMap is constructed for injection the node will fail to decode
   // it if its not sorted.